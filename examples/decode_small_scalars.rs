@@ -0,0 +1,87 @@
+use ion_rs::raw_reader::RawStreamItem;
+use ion_rs::result::IonResult;
+use ion_rs::{BinaryWriterBuilder, IonType, RawBinaryReader, StreamReader, Writer};
+use std::io;
+use std::time::Instant;
+
+// Measures how quickly the binary reader decodes a stream of structs whose fields are small
+// integers, short strings, and symbols with one-byte field IDs, which dominate most binary Ion.
+//
+//     cargo run --release --example decode_small_scalars [number of passes]
+fn main() -> IonResult<()> {
+    let passes: usize = std::env::args()
+        .nth(1)
+        .and_then(|arg| arg.parse().ok())
+        .unwrap_or(20);
+    let data = small_scalars(100_000)?;
+
+    let mut scalars = 0;
+    let start = Instant::now();
+    for _ in 0..passes {
+        let mut reader = RawBinaryReader::new(io::Cursor::new(data.as_slice()));
+        scalars += read_scalars(&mut reader)?;
+    }
+    let elapsed = start.elapsed();
+    let megabytes = (data.len() * passes) as f64 / (1024.0 * 1024.0);
+    println!(
+        "Read {} scalars ({:.1} MiB) in {:.2?}: {:.1} ns/scalar, {:.1} MiB/s",
+        scalars,
+        megabytes,
+        elapsed,
+        elapsed.as_nanos() as f64 / scalars as f64,
+        megabytes / elapsed.as_secs_f64()
+    );
+    Ok(())
+}
+
+// Encodes `count` structs like `{id: 42, name: "name 42", quantity: 42, status: shipped}`.
+fn small_scalars(count: usize) -> IonResult<Vec<u8>> {
+    let mut data = Vec::new();
+    let mut writer = BinaryWriterBuilder::new().build(&mut data)?;
+    for i in 0..count {
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("id");
+        writer.write_i64(i as i64)?;
+        writer.set_field_name("name");
+        writer.write_string(format!("name {}", i % 1_000))?;
+        writer.set_field_name("quantity");
+        writer.write_i64((i % 100) as i64 - 50)?;
+        writer.set_field_name("status");
+        writer.write_symbol(if i % 3 == 0 { "pending" } else { "shipped" })?;
+        writer.step_out()?;
+    }
+    writer.flush()?;
+    drop(writer);
+    Ok(data)
+}
+
+// Reads every scalar in the stream, returning the number that were read.
+fn read_scalars<R: ion_rs::IonDataSource>(reader: &mut RawBinaryReader<R>) -> IonResult<usize> {
+    let mut scalars = 0;
+    loop {
+        match reader.next()? {
+            RawStreamItem::Value(IonType::Struct | IonType::List) => reader.step_in()?,
+            RawStreamItem::Value(ion_type) => {
+                if reader.parent_type() == Some(IonType::Struct) {
+                    reader.field_name()?;
+                }
+                match ion_type {
+                    IonType::Integer => {
+                        reader.read_i64()?;
+                    }
+                    IonType::String => {
+                        reader.read_str()?;
+                    }
+                    IonType::Symbol => {
+                        reader.read_symbol()?;
+                    }
+                    _ => {}
+                }
+                scalars += 1;
+            }
+            RawStreamItem::Nothing if reader.depth() > 0 => reader.step_out()?,
+            RawStreamItem::Nothing => return Ok(scalars),
+            _ => {}
+        }
+    }
+}
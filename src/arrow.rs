@@ -0,0 +1,584 @@
+//! Conversions between streams of Ion structs and Arrow [RecordBatch]es.
+//!
+//! Each struct corresponds to a row of the batch, and each field to a column. The following
+//! Arrow types are supported:
+//!
+//! | Ion type             | Arrow type                                               |
+//! |----------------------|----------------------------------------------------------|
+//! | `null`               | `Null`                                                   |
+//! | `bool`               | `Boolean`                                                |
+//! | `int`                | `Int64`                                                  |
+//! | `float`              | `Float64`                                                |
+//! | `decimal`            | `Decimal128`                                             |
+//! | `timestamp`          | `Timestamp` with any unit, with or without a time zone   |
+//! | `string` or `symbol` | `Utf8`                                                   |
+//! | `blob` or `clob`     | `Binary`                                                 |
+//!
+//! Nested lists and structs are not yet supported.
+#![cfg(feature = "arrow")]
+
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    BinaryBuilder, BooleanBuilder, Decimal128Builder, Float64Builder, Int64Builder, StringBuilder,
+};
+use arrow_array::types::{
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
+};
+use arrow_array::{
+    Array, ArrayRef, BinaryArray, BooleanArray, Decimal128Array, Float64Array, Int64Array,
+    NullArray, PrimitiveArray, RecordBatch, StringArray,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+use crate::value::owned::{OwnedElement, OwnedStruct, OwnedValue};
+use crate::value::{Element, Struct, SymbolToken};
+
+// The largest number of digits that a Decimal128 can hold.
+const MAX_DECIMAL_PRECISION: u8 = 38;
+
+/// Infers an Arrow [Schema] that describes every struct in `elements`.
+///
+/// A column is created for each field name that appears in any of the structs; columns are
+/// sorted by name. Columns are nullable if any struct is missing the field or has a null value
+/// for it. Integers and floats in the same column are inferred to be `Float64`, and integers and
+/// decimals to be `Decimal128` with the largest scale that any of the decimals have.
+pub fn infer_schema<'a, I>(elements: I) -> IonResult<Schema>
+where
+    I: IntoIterator<Item = &'a OwnedElement>,
+{
+    // The type, maximum decimal scale, and nullability of each column.
+    let mut columns: Vec<(String, IonType, i64, bool)> = Vec::new();
+    let mut num_rows = 0;
+    for element in elements {
+        let row = as_struct(element)?;
+        for (name, value) in row.iter() {
+            let name = field_name(name)?;
+            let index = match columns.iter().position(|column| column.0 == name) {
+                Some(index) => index,
+                None => {
+                    // The field was missing from the preceding rows.
+                    columns.push((name.to_owned(), IonType::Null, 0, num_rows > 0));
+                    columns.len() - 1
+                }
+            };
+            let column = &mut columns[index];
+            if value.is_null() {
+                column.3 = true;
+                continue;
+            }
+            if let Some(decimal) = value.as_decimal() {
+                column.2 = column.2.max(decimal.scale());
+            }
+            column.1 = match (column.1, value.ion_type()) {
+                (IonType::Null, ion_type) => ion_type,
+                (current, ion_type) if current == ion_type => current,
+                (IonType::Integer, IonType::Float) | (IonType::Float, IonType::Integer) => {
+                    IonType::Float
+                }
+                (IonType::Integer, IonType::Decimal) | (IonType::Decimal, IonType::Integer) => {
+                    IonType::Decimal
+                }
+                (IonType::String, IonType::Symbol) | (IonType::Symbol, IonType::String) => {
+                    IonType::String
+                }
+                (IonType::Blob, IonType::Clob) | (IonType::Clob, IonType::Blob) => IonType::Blob,
+                (current, ion_type) => {
+                    return illegal_operation(format!(
+                        "field '{}' contains both {} and {} values",
+                        name, current, ion_type
+                    ))
+                }
+            };
+        }
+        num_rows += 1;
+        for column in columns.iter_mut() {
+            if row.get(&column.0).is_none() {
+                column.3 = true;
+            }
+        }
+    }
+    columns.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut fields = Vec::with_capacity(columns.len());
+    for (name, ion_type, scale, nullable) in columns {
+        let data_type = match ion_type {
+            IonType::Null => DataType::Null,
+            IonType::Boolean => DataType::Boolean,
+            IonType::Integer => DataType::Int64,
+            IonType::Float => DataType::Float64,
+            IonType::Decimal => {
+                let scale = i8::try_from(scale)
+                    .ok()
+                    .filter(|scale| *scale as u8 <= MAX_DECIMAL_PRECISION)
+                    .ok_or_else(|| {
+                        decoding_error_raw(format!(
+                            "the decimals in field '{}' have too many digits",
+                            name
+                        ))
+                    })?;
+                DataType::Decimal128(MAX_DECIMAL_PRECISION, scale.max(0))
+            }
+            IonType::Timestamp => {
+                DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("+00:00")))
+            }
+            IonType::String | IonType::Symbol => DataType::Utf8,
+            IonType::Blob | IonType::Clob => DataType::Binary,
+            IonType::List | IonType::SExpression | IonType::Struct => {
+                return illegal_operation(format!(
+                    "field '{}' contains {} values, which are not yet supported",
+                    name, ion_type
+                ))
+            }
+        };
+        // Null columns can only be nullable.
+        fields.push(Field::new(
+            name,
+            data_type.clone(),
+            nullable || data_type == DataType::Null,
+        ));
+    }
+    Ok(Schema::new(fields))
+}
+
+/// Converts `elements`, each of which must be a struct, into a [RecordBatch] with the specified
+/// schema. Fields that are not in the schema are ignored. Missing fields and null values are
+/// represented as nulls, which is an error if the column is not nullable.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use std::sync::Arc;
+/// use ion_rs::arrow::{infer_schema, to_record_batch};
+/// use ion_rs::value::reader::{native_element_reader, ElementReader};
+///
+/// let rows = native_element_reader().read_all(b"{id: 1, price: 2.50} {id: 2}")?;
+/// let schema = Arc::new(infer_schema(&rows)?);
+/// let batch = to_record_batch(&rows, schema)?;
+/// assert_eq!(batch.num_rows(), 2);
+/// assert_eq!(batch.column(1).null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_record_batch(elements: &[OwnedElement], schema: SchemaRef) -> IonResult<RecordBatch> {
+    let rows = elements
+        .iter()
+        .map(as_struct)
+        .collect::<IonResult<Vec<_>>>()?;
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(schema.fields().len());
+    for field in schema.fields() {
+        let values: Vec<Option<&OwnedElement>> = rows
+            .iter()
+            .map(|row| row.get(field.name()).filter(|value| !value.is_null()))
+            .collect();
+        columns.push(build_column(field, &values)?);
+    }
+    RecordBatch::try_new(schema, columns)
+        .map_err(|error| decoding_error_raw(format!("could not create a RecordBatch: {}", error)))
+}
+
+/// Converts each row of `batch` into a struct. Null values are omitted from the structs.
+pub fn from_record_batch(batch: &RecordBatch) -> IonResult<Vec<OwnedElement>> {
+    let schema = batch.schema();
+    let mut rows: Vec<Vec<(String, OwnedElement)>> = vec![Vec::new(); batch.num_rows()];
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        for (index, row) in rows.iter_mut().enumerate() {
+            // Null arrays do not have a validity buffer, so their values do not report themselves
+            // as null.
+            if column.is_null(index) || field.data_type() == &DataType::Null {
+                continue;
+            }
+            row.push((field.name().clone(), read_value(field, column, index)?));
+        }
+    }
+    Ok(rows
+        .into_iter()
+        .map(|fields| OwnedStruct::from_iter(fields).into())
+        .collect())
+}
+
+fn as_struct(element: &OwnedElement) -> IonResult<&OwnedStruct> {
+    match element.as_struct() {
+        Some(row) => Ok(row),
+        None => illegal_operation(format!(
+            "only structs can be converted to Arrow rows; found a {}",
+            element.ion_type()
+        )),
+    }
+}
+
+fn field_name<S: SymbolToken>(name: &S) -> IonResult<&str> {
+    match name.text() {
+        Some(text) => Ok(text),
+        None => illegal_operation("field names without text cannot be converted to Arrow columns"),
+    }
+}
+
+fn type_mismatch<T>(field: &Field, value: &OwnedElement) -> IonResult<T> {
+    decoding_error(format!(
+        "field '{}' has type {}, which cannot hold the {} value {}",
+        field.name(),
+        field.data_type(),
+        value.ion_type(),
+        value
+    ))
+}
+
+fn build_column(field: &Field, values: &[Option<&OwnedElement>]) -> IonResult<ArrayRef> {
+    let array: ArrayRef = match field.data_type() {
+        DataType::Null => {
+            if let Some(value) = values.iter().flatten().next() {
+                return type_mismatch(field, value);
+            }
+            Arc::new(NullArray::new(values.len()))
+        }
+        DataType::Boolean => {
+            let mut builder = BooleanBuilder::with_capacity(values.len());
+            for value in values {
+                match value {
+                    None => builder.append_null(),
+                    Some(value) => match value.as_bool() {
+                        Some(value) => builder.append_value(value),
+                        None => return type_mismatch(field, value),
+                    },
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Int64 => {
+            let mut builder = Int64Builder::with_capacity(values.len());
+            for value in values {
+                match value {
+                    None => builder.append_null(),
+                    Some(value) => match value.as_integer() {
+                        Some(Integer::I64(value)) => builder.append_value(*value),
+                        _ => return type_mismatch(field, value),
+                    },
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Float64 => {
+            let mut builder = Float64Builder::with_capacity(values.len());
+            for value in values {
+                match value {
+                    None => builder.append_null(),
+                    Some(value) => match (value.as_f64(), value.as_integer()) {
+                        (Some(value), _) => builder.append_value(value),
+                        (_, Some(integer)) => match integer_to_f64(integer) {
+                            Some(value) => builder.append_value(value),
+                            None => return type_mismatch(field, value),
+                        },
+                        _ => return type_mismatch(field, value),
+                    },
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Decimal128(precision, scale) => {
+            let mut builder = Decimal128Builder::with_capacity(values.len())
+                .with_precision_and_scale(*precision, *scale)
+                .map_err(|error| decoding_error_raw(error.to_string()))?;
+            for value in values {
+                match value {
+                    None => builder.append_null(),
+                    Some(value) => match decimal_to_i128(value, *precision, *scale) {
+                        Some(value) => builder.append_value(value),
+                        None => return type_mismatch(field, value),
+                    },
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Timestamp(unit, time_zone) => {
+            let mut timestamps = Vec::with_capacity(values.len());
+            for value in values {
+                timestamps.push(match value {
+                    None => None,
+                    Some(value) => match value.as_timestamp().and_then(|t| epoch_time(t, unit)) {
+                        Some(time) => Some(time),
+                        None => return type_mismatch(field, value),
+                    },
+                });
+            }
+            let time_zone = time_zone.clone();
+            match unit {
+                TimeUnit::Second => Arc::new(
+                    PrimitiveArray::<TimestampSecondType>::from(timestamps)
+                        .with_timezone_opt(time_zone),
+                ),
+                TimeUnit::Millisecond => Arc::new(
+                    PrimitiveArray::<TimestampMillisecondType>::from(timestamps)
+                        .with_timezone_opt(time_zone),
+                ),
+                TimeUnit::Microsecond => Arc::new(
+                    PrimitiveArray::<TimestampMicrosecondType>::from(timestamps)
+                        .with_timezone_opt(time_zone),
+                ),
+                TimeUnit::Nanosecond => Arc::new(
+                    PrimitiveArray::<TimestampNanosecondType>::from(timestamps)
+                        .with_timezone_opt(time_zone),
+                ),
+            }
+        }
+        DataType::Utf8 => {
+            let mut builder = StringBuilder::new();
+            for value in values {
+                match value {
+                    None => builder.append_null(),
+                    Some(value) => match (value.as_str(), value.as_sym().and_then(|s| s.text())) {
+                        (Some(text), _) | (_, Some(text)) => builder.append_value(text),
+                        _ => return type_mismatch(field, value),
+                    },
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Binary => {
+            let mut builder = BinaryBuilder::new();
+            for value in values {
+                match value {
+                    None => builder.append_null(),
+                    Some(value) => match value.as_bytes() {
+                        Some(bytes) => builder.append_value(bytes),
+                        None => return type_mismatch(field, value),
+                    },
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        data_type => {
+            return illegal_operation(format!(
+                "field '{}' has type {}, which is not yet supported",
+                field.name(),
+                data_type
+            ))
+        }
+    };
+    Ok(array)
+}
+
+fn integer_to_f64(integer: &Integer) -> Option<f64> {
+    match integer {
+        Integer::I64(value) => Some(*value as f64),
+        Integer::BigInt(value) => value.to_f64(),
+    }
+}
+
+// Returns the value as an integer number of units of 10^-scale, if it can be represented that way
+// without losing precision.
+fn decimal_to_i128(value: &OwnedElement, precision: u8, scale: i8) -> Option<i128> {
+    let decimal = match (value.as_decimal(), value.as_integer()) {
+        (Some(decimal), _) => decimal.clone(),
+        (_, Some(Integer::I64(integer))) => Decimal::new(*integer, 0),
+        (_, Some(Integer::BigInt(integer))) => BigDecimal::new(integer.clone(), 0).into(),
+        _ => return None,
+    };
+    if decimal.scale() > scale as i64 {
+        return None;
+    }
+    // Negative zero has no BigDecimal representation, but is numerically equal to zero.
+    let decimal = if decimal.is_zero() {
+        BigDecimal::from(0)
+    } else {
+        BigDecimal::try_from(decimal).ok()?
+    };
+    let (coefficient, _) = decimal.with_scale(scale as i64).into_bigint_and_exponent();
+    let coefficient = coefficient.to_i128()?;
+    (coefficient.unsigned_abs() < 10u128.pow(precision as u32)).then_some(coefficient)
+}
+
+// Returns the number of `unit`s between the Unix epoch and the timestamp, treating timestamps
+// with an unknown offset as UTC.
+fn epoch_time(timestamp: &Timestamp, unit: &TimeUnit) -> Option<i64> {
+    let date_time: DateTime<FixedOffset> = match timestamp.offset() {
+        Some(_) => timestamp.clone().try_into().ok()?,
+        None => {
+            let date_time: NaiveDateTime = timestamp.clone().try_into().ok()?;
+            utc().from_utc_datetime(&date_time)
+        }
+    };
+    let seconds = date_time.timestamp();
+    let nanoseconds = date_time.timestamp_subsec_nanos() as i64;
+    match unit {
+        TimeUnit::Second => Some(seconds),
+        TimeUnit::Millisecond => seconds
+            .checked_mul(1_000)?
+            .checked_add(nanoseconds / 1_000_000),
+        TimeUnit::Microsecond => seconds
+            .checked_mul(1_000_000)?
+            .checked_add(nanoseconds / 1_000),
+        TimeUnit::Nanosecond => seconds.checked_mul(1_000_000_000)?.checked_add(nanoseconds),
+    }
+}
+
+fn utc() -> FixedOffset {
+    FixedOffset::east_opt(0).unwrap()
+}
+
+fn read_value(field: &Field, column: &ArrayRef, index: usize) -> IonResult<OwnedElement> {
+    let any = column.as_any();
+    let element = match field.data_type() {
+        DataType::Boolean => downcast::<BooleanArray>(any).value(index).into(),
+        DataType::Int64 => downcast::<Int64Array>(any).value(index).into(),
+        DataType::Float64 => downcast::<Float64Array>(any).value(index).into(),
+        DataType::Decimal128(_, scale) => {
+            let coefficient = downcast::<Decimal128Array>(any).value(index);
+            let decimal: Decimal = BigDecimal::new(BigInt::from(coefficient), *scale as i64).into();
+            decimal.into()
+        }
+        DataType::Timestamp(unit, time_zone) => {
+            let (time, nanoseconds_per_unit) = match unit {
+                TimeUnit::Second => (
+                    downcast::<PrimitiveArray<TimestampSecondType>>(any).value(index),
+                    1_000_000_000,
+                ),
+                TimeUnit::Millisecond => (
+                    downcast::<PrimitiveArray<TimestampMillisecondType>>(any).value(index),
+                    1_000_000,
+                ),
+                TimeUnit::Microsecond => (
+                    downcast::<PrimitiveArray<TimestampMicrosecondType>>(any).value(index),
+                    1_000,
+                ),
+                TimeUnit::Nanosecond => (
+                    downcast::<PrimitiveArray<TimestampNanosecondType>>(any).value(index),
+                    1,
+                ),
+            };
+            let seconds = time.div_euclid(1_000_000_000 / nanoseconds_per_unit);
+            let nanoseconds =
+                time.rem_euclid(1_000_000_000 / nanoseconds_per_unit) * nanoseconds_per_unit;
+            let date_time = match utc().timestamp_opt(seconds, nanoseconds as u32).single() {
+                Some(date_time) => date_time,
+                None => return decoding_error(format!("timestamp {} is out of range", time)),
+            };
+            // Arrow timestamps with a time zone are relative to UTC; those without one represent
+            // a local time in an unspecified time zone.
+            let timestamp: Timestamp = match time_zone {
+                Some(_) => date_time.into(),
+                None => date_time.naive_utc().into(),
+            };
+            timestamp.into()
+        }
+        DataType::Utf8 => {
+            OwnedValue::String(downcast::<StringArray>(any).value(index).to_owned()).into()
+        }
+        DataType::Binary => {
+            OwnedValue::Blob(downcast::<BinaryArray>(any).value(index).to_vec()).into()
+        }
+        data_type => {
+            return illegal_operation(format!(
+                "field '{}' has type {}, which is not yet supported",
+                field.name(),
+                data_type
+            ))
+        }
+    };
+    Ok(element)
+}
+
+fn downcast<T: 'static>(array: &dyn std::any::Any) -> &T {
+    // RecordBatch guarantees that each column's array matches the data type of its field.
+    array
+        .downcast_ref::<T>()
+        .expect("array type did not match its field's data type")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ion_eq::IonEq;
+    use crate::value::reader::{native_element_reader, ElementReader};
+
+    fn elements(ion: &str) -> Vec<OwnedElement> {
+        native_element_reader().read_all(ion.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn infer_and_round_trip() -> IonResult<()> {
+        let rows = elements(
+            r#"
+            {b: true, i: 1, f: 1, d: 1.5, t: 2022-01-31T12:00:00.000000000Z, s: "a", y: {{aGk=}}}
+            {b: false, i: 2, f: 2.5e0, d: 2, t: 2022-01-31T13:00:00.000000000+01:00, s: b}
+            {n: null}
+            "#,
+        );
+        let schema = infer_schema(&rows)?;
+        let types: Vec<(&str, &DataType, bool)> = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                (
+                    field.name().as_str(),
+                    field.data_type(),
+                    field.is_nullable(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("b", &DataType::Boolean, true),
+                ("d", &DataType::Decimal128(38, 1), true),
+                ("f", &DataType::Float64, true),
+                ("i", &DataType::Int64, true),
+                ("n", &DataType::Null, true),
+                ("s", &DataType::Utf8, true),
+                (
+                    "t",
+                    &DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::from("+00:00"))),
+                    true
+                ),
+                ("y", &DataType::Binary, true),
+            ]
+        );
+
+        let batch = to_record_batch(&rows, Arc::new(schema))?;
+        assert_eq!(batch.num_rows(), 3);
+        let expected = elements(
+            r#"
+            {b: true, i: 1, f: 1e0, d: 1.5, t: 2022-01-31T12:00:00.000000000Z, s: "a", y: {{aGk=}}}
+            {b: false, i: 2, f: 2.5e0, d: 2.0, t: 2022-01-31T12:00:00.000000000Z, s: "b"}
+            {}
+            "#,
+        );
+        assert!(from_record_batch(&batch)?.ion_eq(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_schema() -> IonResult<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("at", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        ]));
+        let rows = elements("{id: 1, at: 1970-01-01T00:00:01.5-00:00, extra: 3} {id: 2}");
+        let batch = to_record_batch(&rows, schema.clone())?;
+        let expected = elements("{id: 1, at: 1970-01-01T00:00:01.500000000-00:00} {id: 2}");
+        assert!(from_record_batch(&batch)?.ion_eq(&expected));
+
+        // A required field is missing.
+        assert!(to_record_batch(&elements("{at: null.timestamp}"), schema.clone()).is_err());
+        // A value does not match its column's type.
+        assert!(to_record_batch(&elements("{id: 1.5}"), schema.clone()).is_err());
+        // Only structs can be converted.
+        assert!(to_record_batch(&elements("[1]"), schema).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn conflicting_types() {
+        assert!(infer_schema(&elements("{a: 1} {a: \"one\"}")).is_err());
+        assert!(infer_schema(&elements("{a: [1]}")).is_err());
+    }
+}
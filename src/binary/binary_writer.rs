@@ -1,37 +1,218 @@
 use crate::binary::raw_binary_writer::{RawBinaryWriter, RawBinaryWriterBuilder};
+use crate::buffer_pool::BufferPool;
+use crate::catalog::Catalog;
+use crate::constants::v1_0;
 use crate::constants::v1_0::system_symbol_ids;
 use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
-use crate::result::{illegal_operation, IonResult};
+use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
+use crate::shared_symbol_table::SharedSymbolTable;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
-use crate::writer::Writer;
-use crate::{Integer, IonType, SymbolTable};
+use crate::value::native_writer::write_element;
+use crate::value::owned::{
+    local_sid_token, text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedSymbolToken,
+    OwnedValue,
+};
+use crate::value::{AnnotationsVec, Element, Sequence, Struct, SymbolToken};
+use crate::writer::{Writer, WriterLimits};
+use crate::{Integer, IonType, Symbol, SymbolTable, SymbolTableImport};
 use delegate::delegate;
 use std::io::Write;
+use std::rc::Rc;
+
+/// Determines when a [BinaryWriter] writes the local symbol tables that declare the symbols it
+/// has encountered.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum SymbolTableFlushPolicy {
+    /// New symbols are declared by a single local symbol table that is written when
+    /// [Writer::flush] is called, ahead of all of the values written since the last flush.
+    #[default]
+    OnFlush,
+    /// Whenever the writer finishes a top-level value that uses new symbols, it writes a local
+    /// symbol table declaring them and then flushes, so that each symbol is declared immediately
+    /// before the first value that uses it.
+    BeforeFirstUse,
+    /// The writer's imports and any symbols provided via [BinaryWriterBuilder::with_symbols] are
+    /// declared by a local symbol table that is written as soon as the writer is built. Symbols
+    /// encountered later are declared as they would be with [SymbolTableFlushPolicy::OnFlush].
+    UpFront,
+}
 
 pub struct BinaryWriterBuilder {
-    // Nothing yet
+    catalog: Option<Rc<dyn Catalog>>,
+    // The name and version of each shared symbol table that the writer's symbol table will import.
+    imports: Vec<(String, usize)>,
+    symbol_table: Option<SymbolTable>,
+    symbols: Vec<String>,
+    symbol_table_flush_policy: SymbolTableFlushPolicy,
+    canonical_encoding: bool,
+    buffer_pool: Option<BufferPool>,
+    limits: WriterLimits,
 }
 
 impl BinaryWriterBuilder {
     pub fn new() -> Self {
-        BinaryWriterBuilder {}
+        BinaryWriterBuilder {
+            catalog: None,
+            imports: Vec::new(),
+            symbol_table: None,
+            symbols: Vec::new(),
+            symbol_table_flush_policy: SymbolTableFlushPolicy::default(),
+            canonical_encoding: false,
+            buffer_pool: None,
+            limits: WriterLimits::default(),
+        }
+    }
+
+    /// Constructs a builder for a writer that enforces [WriterLimits::hardened], so that it
+    /// cannot produce data that a reader using
+    /// [ReaderBuilder::hardened](crate::ReaderBuilder::hardened) would reject. The limits can be
+    /// adjusted using [BinaryWriterBuilder::with_limits].
+    pub fn hardened() -> Self {
+        BinaryWriterBuilder::new().with_limits(WriterLimits::hardened())
+    }
+
+    /// Sets the limits that the writer will enforce on the data it is asked to write. The limit
+    /// on the number of symbols is checked each time a new symbol would be added to the writer's
+    /// symbol table, so a value that needs too many symbols is rejected before it is encoded.
+    pub fn with_limits(mut self, limits: WriterLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Causes the writer to take the scratch buffers that it uses to encode values from `pool`
+    /// and to return them to `pool` when [BinaryWriter::finish] is called, so that writers that
+    /// are built repeatedly (e.g. one per message) don't need to allocate new buffers each time.
+    pub fn with_buffer_pool(mut self, pool: BufferPool) -> Self {
+        self.buffer_pool = Some(pool);
+        self
+    }
+
+    /// When `canonical_encoding` is `true`, the writer produces a deterministic encoding of each
+    /// top-level value so that equivalent data is always written as the same bytes, which is
+    /// useful for signing and content-addressed storage. In this mode:
+    ///
+    /// * Each top-level value is buffered until it is complete and then written in a single pass.
+    /// * Struct fields are written in order of their field names' text. Fields that share a name
+    ///   are ordered by the text Ion representation of their values.
+    /// * New symbols are added to the symbol table in the order in which that sorted traversal
+    ///   first encounters them, so symbol IDs don't depend on the order in which fields were set.
+    /// * Floats are written using the shortest encoding that represents them exactly.
+    ///
+    /// Like the default mode, canonical mode never emits NOP padding and always uses the
+    /// minimal length for integers, decimals, timestamps, and type descriptor lengths.
+    pub fn with_canonical_encoding(mut self, canonical_encoding: bool) -> Self {
+        self.canonical_encoding = canonical_encoding;
+        self
+    }
+
+    /// Sets the symbol table that the writer will start with. Any symbols in the table (other than
+    /// system symbols and imported symbols) are declared by the first local symbol table that the
+    /// writer writes, so values encoded by writers built with copies of the same table use the
+    /// same symbol IDs.
+    pub fn with_symbol_table(mut self, symbol_table: SymbolTable) -> Self {
+        self.symbol_table = Some(symbol_table);
+        self
+    }
+
+    /// Adds `symbols` to the writer's local symbol table (after any imported symbols) before any
+    /// values are written.
+    pub fn with_symbols<I, S>(mut self, symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.symbols.extend(symbols.into_iter().map(Into::into));
+        self
     }
 
-    pub fn build<W: Write>(self, sink: W) -> IonResult<BinaryWriter<W>> {
-        let mut raw_writer = RawBinaryWriterBuilder::new().build(sink)?;
-        let symbol_table_writer = RawBinaryWriterBuilder::new().build(Vec::new())?;
+    /// Sets the [SymbolTableFlushPolicy] that determines when the writer declares new symbols.
+    pub fn with_symbol_table_flush_policy(mut self, policy: SymbolTableFlushPolicy) -> Self {
+        self.symbol_table_flush_policy = policy;
+        self
+    }
+
+    /// Sets the [Catalog] that the writer will use to resolve the shared symbol tables named by
+    /// [BinaryWriterBuilder::with_import].
+    pub fn with_catalog<C: Catalog + 'static>(mut self, catalog: C) -> Self {
+        self.catalog = Some(Rc::new(catalog));
+        self
+    }
+
+    /// Causes the writer's local symbol table to import the shared symbol table with the given
+    /// name and version, which must be available in the writer's [Catalog]. Text found in an
+    /// imported table is encoded using the imported symbol ID instead of being added to the
+    /// local symbol table. Imports are applied in the order in which they were added.
+    pub fn with_import<N: Into<String>>(mut self, name: N, version: usize) -> Self {
+        self.imports.push((name.into(), version));
+        self
+    }
+
+    pub fn build<W: Write>(mut self, sink: W) -> IonResult<BinaryWriter<W>> {
+        let mut symbol_table = self.symbol_table.take().unwrap_or_default();
+        let imports = self.resolve_imports()?;
+        if !imports.is_empty() && symbol_table.len() > v1_0::SYSTEM_SYMBOLS.len() {
+            return illegal_operation(
+                "cannot add imports to a symbol table that already defines symbols",
+            );
+        }
+        for table in imports {
+            symbol_table.import(&table);
+        }
+        let mut raw_writer = RawBinaryWriterBuilder::new()
+            .with_limits(self.limits)
+            .build(sink)?;
+        let mut symbol_table_writer = RawBinaryWriterBuilder::new().build(Vec::new())?;
+        if let Some(pool) = &self.buffer_pool {
+            raw_writer.set_scratch_buffers(pool.take(), pool.take());
+            symbol_table_writer.set_scratch_buffers(pool.take(), pool.take());
+            *symbol_table_writer.output_mut() = pool.take();
+        }
         // TODO: Track whether we've written an IVM and emit it at flush time instead
         raw_writer.write_ion_version_marker(1, 0)?;
-        let binary_writer = BinaryWriter {
+        let mut binary_writer = BinaryWriter {
             raw_writer,
-            symbol_table: Default::default(),
-            num_pending_symbols: 0,
+            imports_written: symbol_table.imports().is_empty(),
+            num_pending_symbols: symbol_table.local_symbols_len(),
+            symbol_table,
+            symbol_table_flush_policy: self.symbol_table_flush_policy,
             symbol_table_writer,
+            canonical: self.canonical_encoding.then(CanonicalBuffer::default),
+            buffer_pool: self.buffer_pool.take(),
+            limits: self.limits,
+            symbol_limit_error: None,
         };
+        for text in &self.symbols {
+            binary_writer.get_or_create_symbol_id(text)?;
+        }
+        if self.symbol_table_flush_policy == SymbolTableFlushPolicy::UpFront {
+            binary_writer.flush()?;
+        }
         Ok(binary_writer)
     }
+
+    fn resolve_imports(&self) -> IonResult<Vec<Rc<SharedSymbolTable>>> {
+        let mut imports = Vec::with_capacity(self.imports.len());
+        for (name, version) in &self.imports {
+            let catalog = self.catalog.as_ref().ok_or_else(|| {
+                illegal_operation_raw(format!(
+                    "cannot import shared symbol table '{}' (version {}); no catalog was configured",
+                    name, version
+                ))
+            })?;
+            let table = catalog
+                .get_table_with_version(name, *version)
+                .ok_or_else(|| {
+                    illegal_operation_raw(format!(
+                        "shared symbol table '{}' (version {}) was not found in the catalog",
+                        name, version
+                    ))
+                })?;
+            imports.push(table);
+        }
+        Ok(imports)
+    }
 }
 
 impl Default for BinaryWriterBuilder {
@@ -50,26 +231,338 @@ pub struct BinaryWriter<W: Write> {
     // The number of symbols that have been added to the in-memory symbol table but
     // whose definitions have not yet been written to the output stream.
     num_pending_symbols: usize,
+    // Whether a local symbol table declaring the symbol table's imports has been written to the
+    // output stream. Once it has, subsequent local symbol tables append to it.
+    imports_written: bool,
+    symbol_table_flush_policy: SymbolTableFlushPolicy,
     // The BinaryWriter uses the `symbol_table_writer` to encode local symbol tables to a buffer
     // and then flush them to output before flushing the contents of the `raw_writer`. This guarantees
     // that any symbols referenced in the `raw_writer`'s contents will be defined in the Ion stream
     // before the reference appears.
     symbol_table_writer: RawBinaryWriter<Vec<u8>>,
+    // In canonical mode, the top-level value currently being written. Set to `None` when
+    // canonical mode is disabled.
+    canonical: Option<CanonicalBuffer>,
+    // The pool to which the writer's scratch buffers are returned by `finish`, if any.
+    buffer_pool: Option<BufferPool>,
+    limits: WriterLimits,
+    // The error raised when `set_annotations` or `set_field_name` could not add a symbol without
+    // exceeding the writer's `max_symbols` limit. It is returned by the next attempt to write a
+    // value, before anything is encoded.
+    symbol_limit_error: Option<IonError>,
+}
+
+// Accumulates the current top-level value in canonical mode so that it can be written in a
+// deterministic order once it is complete.
+#[derive(Default)]
+struct CanonicalBuffer {
+    annotations: AnnotationsVec<OwnedSymbolToken>,
+    field_name: Option<OwnedSymbolToken>,
+    containers: Vec<PartialContainer>,
 }
 
+// A container that has been stepped into but not yet stepped out of in canonical mode.
+struct PartialContainer {
+    ion_type: IonType,
+    annotations: AnnotationsVec<OwnedSymbolToken>,
+    // The container's own field name, if its parent is a struct.
+    field_name: Option<OwnedSymbolToken>,
+    // If the container is a struct, the field name of each of its children.
+    field_names: Vec<OwnedSymbolToken>,
+    children: Vec<OwnedElement>,
+}
+
+impl PartialContainer {
+    fn into_element(self) -> OwnedElement {
+        let value = match self.ion_type {
+            IonType::List => OwnedValue::List(OwnedSequence::new(self.children)),
+            IonType::SExpression => OwnedValue::SExpression(OwnedSequence::new(self.children)),
+            IonType::Struct => OwnedValue::Struct(OwnedStruct::from_iter(
+                self.field_names.into_iter().zip(self.children),
+            )),
+            scalar => unreachable!("{:?} is not a container type", scalar),
+        };
+        OwnedElement::from_parts(self.annotations, value)
+    }
+}
+
+// The children of a container that remain to be written in canonical mode, each paired with its
+// field name (if the container is a struct).
+type CanonicalChildren<'a> = std::vec::IntoIter<(Option<&'a OwnedSymbolToken>, &'a OwnedElement)>;
+
 impl<W: Write> BinaryWriter<W> {
-    fn get_or_create_symbol_id(&mut self, text: &str) -> SymbolId {
+    /// Flushes the writer and returns its sink. If the writer was built with
+    /// [BinaryWriterBuilder::with_buffer_pool], its scratch buffers are returned to the pool.
+    pub fn finish(mut self) -> IonResult<W> {
+        self.flush()?;
+        if let Some(pool) = self.buffer_pool.take() {
+            let buffers = self
+                .raw_writer
+                .take_scratch_buffers()
+                .into_iter()
+                .chain(self.symbol_table_writer.take_scratch_buffers())
+                .chain([std::mem::take(self.symbol_table_writer.output_mut())]);
+            for buffer in buffers {
+                pool.put(buffer);
+            }
+        }
+        Ok(self.raw_writer.into_output())
+    }
+
+    // Returns the sink to which the writer's flushed output has been written.
+    pub(crate) fn output(&self) -> &W {
+        self.raw_writer.output()
+    }
+
+    /// Writes each of the given elements as a top-level value.
+    ///
+    /// Before encoding anything, the writer adds every symbol that the elements use (as field
+    /// names, annotations, or symbol values) to its symbol table. This means that the whole batch
+    /// needs at most one new local symbol table, even when using
+    /// [SymbolTableFlushPolicy::BeforeFirstUse], and that the elements are encoded without
+    /// interleaving symbol table updates.
+    pub fn write_elements<'a, E, I>(&mut self, elements: I) -> IonResult<()>
+    where
+        E: Element + 'a,
+        I: IntoIterator<Item = &'a E>,
+    {
+        let elements: Vec<&E> = elements.into_iter().collect();
+        let mut pending: Vec<&E> = elements.clone();
+        while let Some(element) = pending.pop() {
+            for annotation in element.annotations() {
+                self.intern_token(annotation)?;
+            }
+            if element.is_null() {
+                continue;
+            }
+            match element.ion_type() {
+                IonType::Symbol => self.intern_token(element.as_sym().unwrap())?,
+                IonType::List | IonType::SExpression => {
+                    pending.extend(element.as_sequence().unwrap().iter());
+                }
+                IonType::Struct => {
+                    for (field_name, child) in element.as_struct().unwrap().iter() {
+                        self.intern_token(field_name)?;
+                        pending.push(child);
+                    }
+                }
+                _ => {}
+            }
+        }
+        for element in elements {
+            write_element(self, None, element)?;
+        }
+        Ok(())
+    }
+
+    // Adds the token's text (if any) to the symbol table.
+    fn intern_token<T: SymbolToken>(&mut self, token: &T) -> IonResult<()> {
+        if let Some(text) = token.text() {
+            self.get_or_create_symbol_id(text)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the writer's symbol table, including any symbols that have not yet been declared
+    /// in the output stream.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
+    }
+
+    /// Returns the symbols that have been added to the writer's symbol table but that will not be
+    /// declared in the output stream until the next local symbol table is written.
+    pub fn pending_symbols(&self) -> &[Option<Symbol>] {
+        let start = self.symbol_table.len() - self.num_pending_symbols;
+        self.symbol_table.symbols_tail(start)
+    }
+
+    // Called after each value is written. If the writer is at the top level and the value used new
+    // symbols, this applies the writer's SymbolTableFlushPolicy.
+    fn end_value(&mut self) -> IonResult<()> {
+        if self.symbol_table_flush_policy == SymbolTableFlushPolicy::BeforeFirstUse
+            && self.num_pending_symbols > 0
+            && self.raw_writer.depth() == 0
+        {
+            return self.flush();
+        }
+        Ok(())
+    }
+
+    // Converts a symbol token to an owned token for the canonical buffer, resolving symbol IDs to
+    // their text where the symbol table defines it. Returns `None` if the symbol ID is undefined.
+    fn owned_token<A: AsRawSymbolTokenRef>(&self, token: A) -> Option<OwnedSymbolToken> {
+        match token.as_raw_symbol_token_ref() {
+            RawSymbolTokenRef::SymbolId(symbol_id) => {
+                if !self.symbol_table.sid_is_valid(symbol_id) {
+                    return None;
+                }
+                Some(match self.symbol_table.text_for(symbol_id) {
+                    Some(text) => text_token(text),
+                    None => local_sid_token(symbol_id),
+                })
+            }
+            RawSymbolTokenRef::Text(text) => Some(text_token(text)),
+        }
+    }
+
+    // In canonical mode, adds a completed value to the buffered container that is currently open.
+    // If no container is open, the value is a complete top-level value and is written out.
+    fn buffer_value(&mut self, value: OwnedValue) -> IonResult<()> {
+        let buffer = self.canonical.as_mut().unwrap();
+        let element = OwnedElement::from_parts(std::mem::take(&mut buffer.annotations), value);
+        let field_name = buffer.field_name.take();
+        self.buffer_element(field_name, element)
+    }
+
+    fn buffer_element(
+        &mut self,
+        field_name: Option<OwnedSymbolToken>,
+        element: OwnedElement,
+    ) -> IonResult<()> {
+        let buffer = self.canonical.as_mut().unwrap();
+        if let Some(parent) = buffer.containers.last_mut() {
+            if parent.ion_type == IonType::Struct {
+                let field_name = field_name.ok_or_else(|| {
+                    illegal_operation_raw("values inside a struct must have a field name")
+                })?;
+                parent.field_names.push(field_name);
+            }
+            parent.children.push(element);
+            return Ok(());
+        }
+        // Write the value with canonical mode switched off so that the Writer methods below
+        // encode it directly instead of buffering it again.
+        let buffer = self.canonical.take();
+        let result = self.write_canonical_element(&element);
+        self.canonical = buffer;
+        result
+    }
+
+    // Writes a complete top-level `element`, sorting the fields of each struct. Like
+    // `native_writer::write_element`, containers are traversed using an explicit stack.
+    fn write_canonical_element(&mut self, element: &OwnedElement) -> IonResult<()> {
+        let mut parents: Vec<CanonicalChildren> = Vec::new();
+        if let Some(children) = self.write_canonical_value(None, element)? {
+            parents.push(children);
+        }
+        while let Some(children) = parents.last_mut() {
+            match children.next() {
+                Some((field_name, child)) => {
+                    if let Some(grandchildren) = self.write_canonical_value(field_name, child)? {
+                        parents.push(grandchildren);
+                    }
+                }
+                None => {
+                    parents.pop();
+                    self.step_out()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_canonical_value<'a>(
+        &mut self,
+        field_name: Option<&OwnedSymbolToken>,
+        element: &'a OwnedElement,
+    ) -> IonResult<Option<CanonicalChildren<'a>>> {
+        if let Some(field_name) = field_name {
+            self.set_field_name(raw_token(field_name));
+        }
+        let annotations: Vec<RawSymbolTokenRef> = element.annotations().map(raw_token).collect();
+        self.set_annotations(annotations);
+
+        if element.is_null() {
+            self.write_null(element.ion_type())?;
+            return Ok(None);
+        }
+        match element.ion_type() {
+            IonType::Float => {
+                let value = element.as_f64().unwrap();
+                // Use the 4-byte encoding whenever it round-trips exactly. Zero is left to
+                // write_f64, which preserves the sign of negative zero.
+                if value != 0f64 && (value.is_nan() || (value as f32) as f64 == value) {
+                    self.write_f32(value as f32)?;
+                } else {
+                    self.write_f64(value)?;
+                }
+            }
+            IonType::Symbol => self.write_symbol(raw_token(element.as_sym().unwrap()))?,
+            IonType::List | IonType::SExpression => {
+                self.step_in(element.ion_type())?;
+                let children: Vec<_> = element
+                    .as_sequence()
+                    .unwrap()
+                    .iter()
+                    .map(|child| (None, child))
+                    .collect();
+                return Ok(Some(children.into_iter()));
+            }
+            IonType::Struct => {
+                self.step_in(IonType::Struct)?;
+                let mut fields: Vec<_> = element
+                    .as_struct()
+                    .unwrap()
+                    .iter()
+                    .map(|(field_name, child)| (Some(field_name), child))
+                    .collect();
+                fields.sort_by_cached_key(|(field_name, child)| {
+                    (field_name.map(token_sort_key), child.to_string())
+                });
+                return Ok(Some(fields.into_iter()));
+            }
+            IonType::Boolean => self.write_bool(element.as_bool().unwrap())?,
+            IonType::Integer => self.write_integer(element.as_integer().unwrap())?,
+            IonType::Decimal => self.write_decimal(element.as_decimal().unwrap())?,
+            IonType::Timestamp => self.write_timestamp(element.as_timestamp().unwrap())?,
+            IonType::String => self.write_string(element.as_str().unwrap())?,
+            IonType::Clob => self.write_clob(element.as_bytes().unwrap())?,
+            IonType::Blob => self.write_blob(element.as_bytes().unwrap())?,
+            IonType::Null => unreachable!("element has IonType::Null but is_null() was false"),
+        }
+        Ok(None)
+    }
+
+    fn get_or_create_symbol_id(&mut self, text: &str) -> IonResult<SymbolId> {
         if let Some(symbol_id) = self.symbol_table.sid_for(&text) {
             // If the provided text is in the symbol table, use the associated symbol ID...
-            symbol_id
+            Ok(symbol_id)
         } else {
-            // ...otherwise, add it to the symbol table and return the new symbol ID.
+            // ...otherwise, add it to the symbol table and return the new symbol ID. The limit is
+            // checked first so that the symbol table never grows beyond it; a symbol table that
+            // did could never be flushed.
+            self.limits.check_symbols(self.symbol_table.len() + 1)?;
             self.num_pending_symbols += 1;
-            self.symbol_table.intern(text)
+            Ok(self.symbol_table.intern(text))
+        }
+    }
+
+    // Like `get_or_create_symbol_id`, but for methods that cannot return an error. If the symbol
+    // cannot be added, the error is saved and returned by the next value that is written.
+    fn get_or_defer_symbol_id(&mut self, text: &str) -> Option<SymbolId> {
+        match self.get_or_create_symbol_id(text) {
+            Ok(symbol_id) => Some(symbol_id),
+            Err(error) => {
+                self.symbol_limit_error.get_or_insert(error);
+                None
+            }
+        }
+    }
+
+    // Returns the error (if any) saved by `get_or_defer_symbol_id`, discarding the annotations
+    // that were set for the value that would have been written.
+    fn check_symbol_limit(&mut self) -> IonResult<()> {
+        match self.symbol_limit_error.take() {
+            Some(error) => {
+                self.raw_writer.clear_annotations();
+                Err(error)
+            }
+            None => Ok(()),
         }
     }
 
     fn write_symbol_table_for_pending_symbols(&mut self) -> IonResult<()> {
+        self.limits.check_symbols(self.symbol_table.len())?;
         let pending_symbols_starting_index = self.symbol_table.len() - self.num_pending_symbols;
         let pending_symbols = self
             .symbol_table
@@ -81,8 +574,13 @@ impl<W: Write> BinaryWriter<W> {
 
         self.symbol_table_writer
             .set_field_name(system_symbol_ids::IMPORTS);
-        self.symbol_table_writer
-            .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        if self.imports_written {
+            self.symbol_table_writer
+                .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        } else {
+            write_imports(&mut self.symbol_table_writer, self.symbol_table.imports())?;
+            self.imports_written = true;
+        }
 
         self.symbol_table_writer
             .set_field_name(system_symbol_ids::SYMBOLS);
@@ -120,6 +618,19 @@ impl<W: Write> Writer for BinaryWriter<W> {
         A: AsRawSymbolTokenRef,
         I: IntoIterator<Item = A>,
     {
+        if self.canonical.is_some() {
+            let tokens: AnnotationsVec<OwnedSymbolToken> = annotations
+                .into_iter()
+                .map(|annotation| {
+                    self.owned_token(annotation)
+                        .expect("Cannot set an undefined symbol ID as annotation.")
+                })
+                .collect();
+            if let Some(buffer) = self.canonical.as_mut() {
+                buffer.annotations = tokens;
+            }
+            return;
+        }
         for annotation in annotations {
             let symbol_id = match annotation.as_raw_symbol_token_ref() {
                 RawSymbolTokenRef::SymbolId(symbol_id) => {
@@ -132,13 +643,22 @@ impl<W: Write> Writer for BinaryWriter<W> {
                         );
                     }
                 }
-                RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text),
+                RawSymbolTokenRef::Text(text) => match self.get_or_defer_symbol_id(text) {
+                    Some(symbol_id) => symbol_id,
+                    None => continue,
+                },
             };
             self.raw_writer.add_annotation(symbol_id);
         }
     }
 
     fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
+        if self.canonical.is_some() {
+            let token = self.owned_token(value).ok_or_else(|| {
+                illegal_operation_raw("Cannot write an undefined symbol ID as a symbol value.")
+            })?;
+            return self.buffer_value(OwnedValue::Symbol(token));
+        }
         let symbol_id = match value.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(symbol_id) => {
                 if self.symbol_table.sid_is_valid(symbol_id) {
@@ -150,12 +670,23 @@ impl<W: Write> Writer for BinaryWriter<W> {
                     ));
                 }
             }
-            RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text),
+            RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text)?,
         };
-        self.raw_writer.write_symbol(symbol_id)
+        self.check_symbol_limit()?;
+        self.raw_writer.write_symbol(symbol_id)?;
+        self.end_value()
     }
 
     fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) {
+        if self.canonical.is_some() {
+            let token = self
+                .owned_token(name)
+                .expect("Cannot set an undefined symbol ID as field name.");
+            if let Some(buffer) = self.canonical.as_mut() {
+                buffer.field_name = Some(token);
+            }
+            return;
+        }
         let text = match name.as_raw_symbol_token_ref() {
             RawSymbolTokenRef::SymbolId(symbol_id) => {
                 if self.symbol_table.sid_is_valid(symbol_id) {
@@ -167,50 +698,221 @@ impl<W: Write> Writer for BinaryWriter<W> {
                     );
                 }
             }
-            RawSymbolTokenRef::Text(text) => self.get_or_create_symbol_id(text),
+            RawSymbolTokenRef::Text(text) => match self.get_or_defer_symbol_id(text) {
+                Some(symbol_id) => symbol_id,
+                None => return,
+            },
         };
         self.raw_writer.set_field_name(text);
     }
 
     fn flush(&mut self) -> IonResult<()> {
         // Check to see if there are any pending symbols.
-        if self.num_pending_symbols > 0 {
+        if self.num_pending_symbols > 0 || !self.imports_written {
             self.write_symbol_table_for_pending_symbols()?;
             self.num_pending_symbols = 0;
         }
         self.raw_writer.flush()
     }
 
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Null(ion_type));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_null(ion_type)?;
+        self.end_value()
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Boolean(value));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_bool(value)?;
+        self.end_value()
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Integer(Integer::I64(value)));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_i64(value)?;
+        self.end_value()
+    }
+
+    fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Integer(value.clone()));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_integer(value)?;
+        self.end_value()
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Float(value as f64));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_f32(value)?;
+        self.end_value()
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Float(value));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_f64(value)?;
+        self.end_value()
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Decimal(value.clone()));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_decimal(value)?;
+        self.end_value()
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Timestamp(value.clone()));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_timestamp(value)?;
+        self.end_value()
+    }
+
+    fn write_string<A: AsRef<str>>(&mut self, value: A) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::String(value.as_ref().to_string()));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_string(value)?;
+        self.end_value()
+    }
+
+    fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Clob(value.as_ref().to_vec()));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_clob(value)?;
+        self.end_value()
+    }
+
+    fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        if self.canonical.is_some() {
+            return self.buffer_value(OwnedValue::Blob(value.as_ref().to_vec()));
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.write_blob(value)?;
+        self.end_value()
+    }
+
+    fn step_in(&mut self, container_type: IonType) -> IonResult<()> {
+        if let Some(buffer) = self.canonical.as_mut() {
+            if !container_type.is_container() {
+                return illegal_operation("Cannot step into a scalar Ion type.");
+            }
+            let container = PartialContainer {
+                ion_type: container_type,
+                annotations: std::mem::take(&mut buffer.annotations),
+                field_name: buffer.field_name.take(),
+                field_names: Vec::new(),
+                children: Vec::new(),
+            };
+            buffer.containers.push(container);
+            return Ok(());
+        }
+        self.check_symbol_limit()?;
+        self.raw_writer.step_in(container_type)
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if let Some(buffer) = self.canonical.as_mut() {
+            let mut container = buffer
+                .containers
+                .pop()
+                .ok_or_else(|| illegal_operation_raw("Cannot step out of the top level."))?;
+            let field_name = container.field_name.take();
+            return self.buffer_element(field_name, container.into_element());
+        }
+        self.raw_writer.step_out()?;
+        self.end_value()
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        match &self.canonical {
+            Some(buffer) => buffer.containers.last().map(|container| container.ion_type),
+            None => self.raw_writer.parent_type(),
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match &self.canonical {
+            Some(buffer) => buffer.containers.len(),
+            None => self.raw_writer.depth(),
+        }
+    }
+
     delegate! {
         to self.raw_writer {
             fn ion_version(&self) -> (u8, u8);
             fn write_ion_version_marker(&mut self, major: u8, minor: u8) -> IonResult<()>;
-            fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
-            fn write_bool(&mut self, value: bool) -> IonResult<()>;
-            fn write_i64(&mut self, value: i64) -> IonResult<()>;
-            fn write_integer(&mut self, value: &Integer) -> IonResult<()>;
-            fn write_f32(&mut self, value: f32) -> IonResult<()>;
-            fn write_f64(&mut self, value: f64) -> IonResult<()>;
-            fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
-            fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()>;
-            fn write_string<A: AsRef<str>>(&mut self, value: A) -> IonResult<()>;
-            fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()>;
-            fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()>;
-            fn step_in(&mut self, container_type: IonType) -> IonResult<()>;
-            fn parent_type(&self) -> Option<IonType>;
-            fn depth(&self) -> usize;
-            fn step_out(&mut self) -> IonResult<()>;
         }
     }
 }
 
+fn raw_token(token: &OwnedSymbolToken) -> RawSymbolTokenRef<'_> {
+    match (token.text(), token.local_sid()) {
+        (Some(text), _) => RawSymbolTokenRef::Text(text),
+        (None, Some(symbol_id)) => RawSymbolTokenRef::SymbolId(symbol_id),
+        (None, None) => unreachable!("canonical buffer tokens have either text or a symbol ID"),
+    }
+}
+
+// Orders symbol tokens with text by that text, followed by tokens with unknown text by symbol ID.
+fn token_sort_key(token: &OwnedSymbolToken) -> (bool, String, SymbolId) {
+    match token.text() {
+        Some(text) => (false, text.to_string(), 0),
+        None => (true, String::new(), token.local_sid().unwrap_or_default()),
+    }
+}
+
+// Writes a local symbol table's list of shared symbol table imports.
+fn write_imports(
+    writer: &mut RawBinaryWriter<Vec<u8>>,
+    imports: &[SymbolTableImport],
+) -> IonResult<()> {
+    writer.step_in(IonType::List)?;
+    for import in imports {
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name(system_symbol_ids::NAME);
+        writer.write_string(import.name())?;
+        writer.set_field_name(system_symbol_ids::VERSION);
+        writer.write_i64(import.version() as i64)?;
+        writer.set_field_name(system_symbol_ids::MAX_ID);
+        writer.write_i64(import.max_id() as i64)?;
+        writer.step_out()?;
+    }
+    writer.step_out()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::reader::ReaderBuilder;
     use crate::stream_reader::StreamReader;
 
-    use crate::StreamItem::Value;
+    use crate::catalog::MapCatalog;
+    use crate::reader::SymbolPolicy;
+    use crate::value::reader::{native_element_reader, ElementReader};
+    use crate::StreamItem::{self, Value};
 
     #[test]
     fn intern_field_names() -> IonResult<()> {
@@ -268,4 +970,306 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn import_shared_symbol_tables() -> IonResult<()> {
+        let fruit = vec![Some("apple".to_string()), None, Some("cherry".to_string())];
+        let catalog = Rc::new(
+            MapCatalog::new()
+                .with_table(SharedSymbolTable::new("fruit", 1, vec![])?)
+                .with_table(SharedSymbolTable::new("fruit", 2, fruit)?),
+        );
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_catalog(catalog.clone())
+            .with_import("fruit", 2)
+            .build(&mut buffer)?;
+        binary_writer.write_symbol("cherry")?;
+        binary_writer.write_symbol("date")?;
+        binary_writer.write_symbol(11)?;
+        binary_writer.flush()?;
+        binary_writer.write_symbol("elderberry")?;
+        binary_writer.flush()?;
+        drop(binary_writer);
+
+        // Without the imported table, the text of its symbols is unknown.
+        let mut reader = ReaderBuilder::new().build(buffer.clone())?;
+        assert_eq!(Value(IonType::Symbol), reader.next()?);
+        assert!(reader.read_symbol().is_err());
+
+        let mut reader = ReaderBuilder::new()
+            .with_catalog(catalog)
+            .with_symbol_policy(SymbolPolicy::SidText)
+            .build(buffer)?;
+        for expected in ["cherry", "date", "$11", "elderberry"] {
+            assert_eq!(Value(IonType::Symbol), reader.next()?);
+            assert_eq!(expected, reader.read_symbol()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unresolved_imports() {
+        assert!(BinaryWriterBuilder::new()
+            .with_import("fruit", 1)
+            .build(Vec::new())
+            .is_err());
+        assert!(BinaryWriterBuilder::new()
+            .with_catalog(MapCatalog::new())
+            .with_import("fruit", 1)
+            .build(Vec::new())
+            .is_err());
+    }
+
+    // Returns the symbols declared by each of the local symbol tables in `data`.
+    fn declared_symbols(data: &[u8]) -> IonResult<Vec<Vec<String>>> {
+        let mut reader = ReaderBuilder::new().build(data)?;
+        let mut declared = Vec::new();
+        let mut previous_max_id = reader.max_id();
+        loop {
+            let item = reader.next()?;
+            if reader.max_id() != previous_max_id {
+                let symbols = reader.symbol_table().symbols_tail(previous_max_id + 1);
+                declared.push(
+                    symbols
+                        .iter()
+                        .map(|symbol| symbol.as_ref().unwrap().to_string())
+                        .collect(),
+                );
+                previous_max_id = reader.max_id();
+            }
+            if item == StreamItem::Nothing {
+                return Ok(declared);
+            }
+        }
+    }
+
+    fn write_with_policy(
+        policy: SymbolTableFlushPolicy,
+        expected_pending_symbols: usize,
+    ) -> IonResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_symbols(["foo"])
+            .with_symbol_table_flush_policy(policy)
+            .build(&mut buffer)?;
+        assert_eq!(binary_writer.symbol_table().sid_for(&"foo"), Some(10));
+        binary_writer.write_symbol("foo")?;
+        binary_writer.step_in(IonType::List)?;
+        binary_writer.write_symbol("bar")?;
+        assert_eq!(
+            binary_writer.pending_symbols().len(),
+            expected_pending_symbols
+        );
+        binary_writer.step_out()?;
+        binary_writer.write_symbol("baz")?;
+        binary_writer.write_i64(1)?;
+        binary_writer.flush()?;
+        assert!(binary_writer.pending_symbols().is_empty());
+        drop(binary_writer);
+        Ok(buffer)
+    }
+
+    #[test]
+    fn symbol_table_flush_policies() -> IonResult<()> {
+        use SymbolTableFlushPolicy::*;
+        let declared = declared_symbols(&write_with_policy(OnFlush, 2)?)?;
+        assert_eq!(declared, vec![vec!["foo", "bar", "baz"]]);
+        // No values precede the second symbol table, so the reader processes both at once.
+        let declared = declared_symbols(&write_with_policy(UpFront, 1)?)?;
+        assert_eq!(declared, vec![vec!["foo", "bar", "baz"]]);
+        let mut buffer = Vec::new();
+        let binary_writer = BinaryWriterBuilder::new()
+            .with_symbols(["foo"])
+            .with_symbol_table_flush_policy(UpFront)
+            .build(&mut buffer)?;
+        drop(binary_writer);
+        assert_eq!(declared_symbols(&buffer)?, vec![vec!["foo"]]);
+        let declared = declared_symbols(&write_with_policy(BeforeFirstUse, 1)?)?;
+        assert_eq!(declared, vec![vec!["foo"], vec!["bar"], vec!["baz"]]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_elements_declares_symbols_up_front() -> IonResult<()> {
+        let elements = native_element_reader().read_all(b"foo::{a: bar} [baz, {b: 1}] 2")?;
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_symbol_table_flush_policy(SymbolTableFlushPolicy::BeforeFirstUse)
+            .build(&mut buffer)?;
+        binary_writer.write_elements(&elements)?;
+        binary_writer.flush()?;
+        drop(binary_writer);
+        let mut declared = declared_symbols(&buffer)?;
+        assert_eq!(declared.len(), 1);
+        declared[0].sort();
+        assert_eq!(declared[0], vec!["a", "b", "bar", "baz", "foo"]);
+        assert_eq!(native_element_reader().read_all(&buffer)?, elements);
+        Ok(())
+    }
+
+    #[test]
+    fn share_symbol_table_between_writers() -> IonResult<()> {
+        let mut symbol_table = SymbolTable::new();
+        symbol_table.intern("foo");
+        symbol_table.add_placeholder();
+        symbol_table.intern("bar");
+
+        let mut outputs = Vec::new();
+        for text in ["bar", "foo"] {
+            let mut buffer = Vec::new();
+            let mut binary_writer = BinaryWriterBuilder::new()
+                .with_symbol_table(symbol_table.clone())
+                .build(&mut buffer)?;
+            assert_eq!(binary_writer.pending_symbols().len(), 3);
+            binary_writer.write_symbol(text)?;
+            binary_writer.write_symbol(12)?;
+            binary_writer.flush()?;
+            drop(binary_writer);
+            outputs.push(buffer);
+        }
+
+        for (output, expected) in outputs.iter().zip(["bar", "foo"]) {
+            let mut reader = ReaderBuilder::new().build(output.as_slice())?;
+            assert_eq!(Value(IonType::Symbol), reader.next()?);
+            assert_eq!(expected, reader.read_symbol()?);
+            assert_eq!(Value(IonType::Symbol), reader.next()?);
+            assert_eq!("bar", reader.read_symbol()?);
+            assert_eq!(reader.symbol_table().symbols_tail(10).len(), 3);
+        }
+
+        // A symbol table that already defines symbols cannot have imports added to it.
+        let catalog = MapCatalog::new().with_table(SharedSymbolTable::new("fruit", 1, vec![])?);
+        assert!(BinaryWriterBuilder::new()
+            .with_catalog(catalog)
+            .with_import("fruit", 1)
+            .with_symbol_table(symbol_table)
+            .build(Vec::new())
+            .is_err());
+        Ok(())
+    }
+
+    // Writes a struct containing the given fields in order using a canonical writer.
+    fn write_canonical_struct(fields: &[(&str, f64)]) -> IonResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_canonical_encoding(true)
+            .build(&mut buffer)?;
+        binary_writer.set_annotations(["point"]);
+        binary_writer.step_in(IonType::Struct)?;
+        assert_eq!(binary_writer.depth(), 1);
+        for (name, value) in fields {
+            binary_writer.set_field_name(*name);
+            binary_writer.write_f64(*value)?;
+        }
+        binary_writer.step_out()?;
+        binary_writer.flush()?;
+        drop(binary_writer);
+        Ok(buffer)
+    }
+
+    #[test]
+    fn canonical_encoding_is_independent_of_field_order() -> IonResult<()> {
+        let forward = write_canonical_struct(&[("x", 1.5), ("y", 0.1), ("x", -2.0)])?;
+        let backward = write_canonical_struct(&[("x", -2.0), ("y", 0.1), ("x", 1.5)])?;
+        assert_eq!(forward, backward);
+
+        let mut reader = ReaderBuilder::new().build(forward)?;
+        assert_eq!(Value(IonType::Struct), reader.next()?);
+        assert_eq!(reader.annotations().next().unwrap()?, "point");
+        reader.step_in()?;
+        let mut fields = Vec::new();
+        while let Value(_) = reader.next()? {
+            fields.push((reader.field_name()?.to_string(), reader.read_f64()?));
+        }
+        assert_eq!(
+            fields,
+            vec![
+                ("x".to_string(), -2.0),
+                ("x".to_string(), 1.5),
+                ("y".to_string(), 0.1)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_encoding_uses_shortest_float() -> IonResult<()> {
+        let bytes = write_canonical_struct(&[("a", 1.5), ("b", 0.1)])?;
+        // 1.5 is exactly representable as an f32; 0.1 is not.
+        let short = [0x44, 0x3F, 0xC0, 0x00, 0x00];
+        assert!(bytes.windows(short.len()).any(|window| window == short));
+        let long = 0.1f64.to_be_bytes();
+        assert!(bytes.windows(long.len()).any(|window| window == long));
+        Ok(())
+    }
+
+    #[test]
+    fn writer_limits() -> IonResult<()> {
+        let limits = WriterLimits::new()
+            .with_max_depth(1)
+            .with_max_annotations(1)
+            .with_max_value_length(3);
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_limits(limits)
+            .build(&mut buffer)?;
+        binary_writer.step_in(IonType::List)?;
+        assert!(binary_writer.step_in(IonType::List).is_err());
+        binary_writer.step_out()?;
+        binary_writer.set_annotations(["a", "b"]);
+        assert!(binary_writer.write_i64(1).is_err());
+        binary_writer.write_string("abc")?;
+        assert!(binary_writer.write_string("abcd").is_err());
+        assert!(binary_writer.write_blob([0u8; 4]).is_err());
+
+        // The system symbol table defines 10 symbols, so a single new symbol is allowed.
+        let mut buffer = Vec::new();
+        let mut binary_writer = BinaryWriterBuilder::new()
+            .with_limits(WriterLimits::new().with_max_symbols(11))
+            .build(&mut buffer)?;
+        binary_writer.write_symbol("foo")?;
+        assert!(binary_writer.write_symbol("bar").is_err());
+        binary_writer.set_annotations(["baz"]);
+        assert!(binary_writer.write_i64(1).is_err());
+        binary_writer.step_in(IonType::Struct)?;
+        binary_writer.set_field_name("qux");
+        assert!(binary_writer.write_i64(2).is_err());
+        binary_writer.step_out()?;
+        // Rejected values leave the writer usable.
+        binary_writer.set_annotations(["foo"]);
+        binary_writer.write_symbol("foo")?;
+        binary_writer.flush()?;
+        drop(binary_writer);
+
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        let elements: Vec<OwnedElement> = reader.elements().collect::<IonResult<_>>()?;
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements[1].as_struct().unwrap().iter().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn buffers_are_returned_to_pool() -> IonResult<()> {
+        let pool = BufferPool::new(8);
+        for _ in 0..2 {
+            let mut buffer = Vec::new();
+            let mut binary_writer = BinaryWriterBuilder::new()
+                .with_buffer_pool(pool.clone())
+                .build(&mut buffer)?;
+            assert!(pool.is_empty());
+            binary_writer.step_in(IonType::Struct)?;
+            binary_writer.set_field_name("foo");
+            binary_writer.write_symbol("bar")?;
+            binary_writer.step_out()?;
+            binary_writer.finish()?;
+            // The encoding and flush buffers of both the value writer and the symbol table
+            // writer, plus the symbol table writer's output.
+            assert_eq!(pool.len(), 5);
+
+            let mut reader = ReaderBuilder::new().build(buffer)?;
+            assert_eq!(Value(IonType::Struct), reader.next()?);
+        }
+        Ok(())
+    }
 }
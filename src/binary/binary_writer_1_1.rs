@@ -0,0 +1,680 @@
+use std::io::Write;
+
+use num_bigint::BigInt;
+
+use crate::binary::constants::v1_1::IVM;
+use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
+use crate::result::{illegal_operation, illegal_operation_raw, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+use crate::value::native_writer::write_element;
+use crate::value::owned::OwnedElement;
+use crate::value::Element;
+use crate::writer::Writer;
+
+// E-expressions whose macro address is less than this are encoded using a single opcode byte.
+const MAX_MACROS: usize = 64;
+
+/// A template that a [BinaryWriter1_1] can invoke to write a value without encoding all of it.
+///
+/// The template is written as-is into the macro table of the encoding directive at the
+/// beginning of the stream, so it must be expressed in the Ion 1.1 template definition language.
+/// Each parameter accepts exactly one argument, which may be any value.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::value::reader::{native_element_reader, ElementReader};
+/// use ion_rs::Macro;
+///
+/// let template = native_element_reader().read_one(b"{x: (%x), y: (%y)}")?;
+/// let point = Macro::new("point", ["x", "y"], template);
+/// assert_eq!(point.parameters(), &["x", "y"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Macro {
+    name: String,
+    parameters: Vec<String>,
+    template: OwnedElement,
+}
+
+impl Macro {
+    pub fn new<N, I, P>(name: N, parameters: I, template: OwnedElement) -> Macro
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = P>,
+        P: Into<String>,
+    {
+        Macro {
+            name: name.into(),
+            parameters: parameters.into_iter().map(Into::into).collect(),
+            template,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn parameters(&self) -> &[String] {
+        &self.parameters
+    }
+
+    pub fn template(&self) -> &OwnedElement {
+        &self.template
+    }
+}
+
+/// Configures and constructs new instances of [BinaryWriter1_1].
+#[derive(Debug, Clone, Default)]
+pub struct BinaryWriter1_1Builder {
+    macros: Vec<Macro>,
+    delimited_containers: bool,
+}
+
+impl BinaryWriter1_1Builder {
+    pub fn new() -> Self {
+        BinaryWriter1_1Builder::default()
+    }
+
+    /// Adds `definition` to the macro table that the writer declares at the beginning of the
+    /// stream, making it available to [BinaryWriter1_1::invoke_macro]. Up to 64 macros can be
+    /// defined.
+    pub fn with_macro(mut self, definition: Macro) -> Self {
+        self.macros.push(definition);
+        self
+    }
+
+    /// Sets whether containers are written using delimited encodings, which end with a marker
+    /// instead of beginning with their length. Length-prefixed containers are usually a little
+    /// smaller, and are written by default.
+    pub fn with_delimited_containers(mut self, delimited: bool) -> Self {
+        self.delimited_containers = delimited;
+        self
+    }
+
+    pub fn build<W: Write>(self, sink: W) -> IonResult<BinaryWriter1_1<W>> {
+        if self.macros.len() > MAX_MACROS {
+            return illegal_operation(format!(
+                "cannot define {} macros; at most {} are supported",
+                self.macros.len(),
+                MAX_MACROS
+            ));
+        }
+        for (index, definition) in self.macros.iter().enumerate() {
+            if self.macros[..index]
+                .iter()
+                .any(|other| other.name == definition.name)
+            {
+                return illegal_operation(format!(
+                    "macro '{}' is defined more than once",
+                    definition.name
+                ));
+            }
+        }
+        let mut writer = BinaryWriter1_1 {
+            sink,
+            buffer: Vec::new(),
+            containers: Vec::new(),
+            annotations: Vec::new(),
+            num_annotations: 0,
+            field_name: None,
+            argument_depth: None,
+            macros: self.macros,
+            delimited_containers: self.delimited_containers,
+        };
+        writer.write_ion_version_marker(1, 1)?;
+        Ok(writer)
+    }
+}
+
+// A container that the writer has stepped into. Its children are encoded into `body`, which
+// is copied into the parent once the container's length is known.
+#[derive(Debug)]
+struct Container {
+    ion_type: IonType,
+    body: Vec<u8>,
+    // Whether any fields have been written to this struct.
+    has_fields: bool,
+}
+
+/// A [Writer] that encodes values using binary Ion 1.1.
+///
+/// Ion 1.1 support is in its early stages. Field names, annotations, and symbol values are always
+/// encoded as inline text (or as symbol IDs, if they are provided that way), so no symbol tables
+/// are written. Timestamps cannot be written yet.
+///
+/// Repetitive values can be written more compactly by defining [Macro]s using
+/// [BinaryWriter1_1Builder::with_macro] and invoking them with [BinaryWriter1_1::invoke_macro].
+/// The macro table is declared at the beginning of the stream by an `$ion_encoding` directive.
+/// Note that this crate's readers cannot yet expand macro invocations.
+pub struct BinaryWriter1_1<W: Write> {
+    sink: W,
+    // Encoded top-level values that have not been written to the sink yet.
+    buffer: Vec<u8>,
+    containers: Vec<Container>,
+    // The pending annotations, each encoded as a FlexSym.
+    annotations: Vec<u8>,
+    num_annotations: usize,
+    // The pending field name, encoded as a FlexSym.
+    field_name: Option<Vec<u8>>,
+    // The depth at which the arguments of an e-expression are being written, if any.
+    argument_depth: Option<usize>,
+    macros: Vec<Macro>,
+    delimited_containers: bool,
+}
+
+impl<W: Write> BinaryWriter1_1<W> {
+    /// Writes an e-expression that invokes the macro called `name`, passing one argument for each
+    /// of its parameters. Inside a struct, the e-expression uses the pending field name.
+    pub fn invoke_macro<E: Element>(&mut self, name: &str, arguments: &[E]) -> IonResult<()> {
+        let address = self
+            .macros
+            .iter()
+            .position(|definition| definition.name == name)
+            .ok_or_else(|| {
+                illegal_operation_raw(format!("no macro named '{}' is defined", name))
+            })?;
+        let num_parameters = self.macros[address].parameters.len();
+        if arguments.len() != num_parameters {
+            return illegal_operation(format!(
+                "macro '{}' expects {} arguments, but {} were provided",
+                name,
+                num_parameters,
+                arguments.len()
+            ));
+        }
+        if self.num_annotations > 0 {
+            return illegal_operation("macro invocations cannot be annotated");
+        }
+        self.write_prefix()?;
+        self.output().push(address as u8);
+
+        let depth = self.depth();
+        let outer_argument_depth = self.argument_depth.replace(depth);
+        let result = arguments
+            .iter()
+            .try_for_each(|argument| write_element(self, None, argument));
+        self.argument_depth = outer_argument_depth;
+        result
+    }
+
+    // Returns the buffer to which the next value should be written.
+    fn output(&mut self) -> &mut Vec<u8> {
+        match self.containers.last_mut() {
+            Some(container) => &mut container.body,
+            None => &mut self.buffer,
+        }
+    }
+
+    // Writes the pending field name (if the writer is in a struct) and annotations.
+    fn write_prefix(&mut self) -> IonResult<()> {
+        let field_name = self.field_name.take();
+        let in_arguments = self.argument_depth == Some(self.containers.len());
+        match self.containers.last_mut() {
+            Some(container) if container.ion_type == IonType::Struct && !in_arguments => {
+                let field_name = field_name.ok_or_else(|| {
+                    illegal_operation_raw("values inside a struct must have a field name")
+                })?;
+                // Length-prefixed structs begin by encoding field names as symbol IDs; a field name
+                // of `0` switches them to encoding field names as FlexSyms.
+                if !container.has_fields && !self.delimited_containers {
+                    container.body.push(0x01);
+                }
+                container.has_fields = true;
+                container.body.extend_from_slice(&field_name);
+            }
+            _ => {}
+        }
+        let num_annotations = std::mem::take(&mut self.num_annotations);
+        let annotations = std::mem::take(&mut self.annotations);
+        let output = self.output();
+        match num_annotations {
+            0 => {}
+            1 => output.push(0xE7),
+            2 => output.push(0xE8),
+            _ => {
+                output.push(0xE9);
+                write_flex_uint(output, annotations.len());
+            }
+        }
+        output.extend_from_slice(&annotations);
+        Ok(())
+    }
+
+    // Writes the pending field name and annotations followed by a value.
+    fn write_value<F: FnOnce(&mut Vec<u8>)>(&mut self, encode: F) -> IonResult<()> {
+        self.write_prefix()?;
+        encode(self.output());
+        Ok(())
+    }
+
+    // Writes the macro table declaring the writer's macros.
+    fn write_encoding_directive(&mut self) -> IonResult<()> {
+        let macros = std::mem::take(&mut self.macros);
+        let result = (|| {
+            self.set_annotations(["$ion_encoding"]);
+            self.step_in(IonType::SExpression)?;
+            self.step_in(IonType::SExpression)?;
+            self.write_symbol("macro_table")?;
+            for definition in &macros {
+                self.step_in(IonType::SExpression)?;
+                self.write_symbol("macro")?;
+                self.write_symbol(definition.name.as_str())?;
+                self.step_in(IonType::SExpression)?;
+                for parameter in &definition.parameters {
+                    self.write_symbol(parameter.as_str())?;
+                }
+                self.step_out()?;
+                write_element(self, None, &definition.template)?;
+                self.step_out()?;
+            }
+            self.step_out()?;
+            self.step_out()
+        })();
+        self.macros = macros;
+        result
+    }
+}
+
+// Writes `value` as a FlexUInt: a little-endian integer in which the number of trailing zeros in
+// the first byte indicates how many more bytes follow it.
+fn write_flex_uint(output: &mut Vec<u8>, value: usize) {
+    let bits = (usize::BITS - value.leading_zeros()).max(1) as usize;
+    let length = bits.div_ceil(7);
+    let encoded = ((value as u64) << length) | (1 << (length - 1));
+    output.extend_from_slice(&encoded.to_le_bytes()[..length]);
+}
+
+// Writes `value` as a FlexInt, the signed counterpart of a FlexUInt.
+fn write_flex_int(output: &mut Vec<u8>, value: i64) {
+    let bits = (64 - (value ^ (value >> 63)).leading_zeros() + 1) as usize;
+    let length = bits.div_ceil(7);
+    let encoded = ((value << length) as u64) | (1 << (length - 1));
+    output.extend_from_slice(&encoded.to_le_bytes()[..length]);
+}
+
+// Writes a symbol as a FlexSym: a FlexInt that is either a positive symbol ID or the negated
+// length of the inline text that follows it. Zero introduces an escape byte.
+fn write_flex_sym(output: &mut Vec<u8>, symbol: RawSymbolTokenRef) {
+    match symbol {
+        RawSymbolTokenRef::SymbolId(0) => output.extend_from_slice(&[0x01, 0x60]),
+        RawSymbolTokenRef::SymbolId(symbol_id) => write_flex_int(output, symbol_id as i64),
+        RawSymbolTokenRef::Text("") => output.extend_from_slice(&[0x01, 0x90]),
+        RawSymbolTokenRef::Text(text) => {
+            write_flex_int(output, -(text.len() as i64));
+            output.extend_from_slice(text.as_bytes());
+        }
+    }
+}
+
+// Writes a value whose opcode encodes lengths up to 15 (`short_opcode` + length), or whose
+// `long_opcode` is followed by a FlexUInt length.
+fn write_with_length(output: &mut Vec<u8>, short_opcode: u8, long_opcode: u8, body: &[u8]) {
+    if body.len() <= 15 {
+        output.push(short_opcode + body.len() as u8);
+    } else {
+        output.push(long_opcode);
+        write_flex_uint(output, body.len());
+    }
+    output.extend_from_slice(body);
+}
+
+// Returns the shortest little-endian two's complement encoding of `value`. Zero is encoded
+// using no bytes at all.
+fn fixed_int_bytes(value: i64) -> ([u8; 8], usize) {
+    let length = match value {
+        0 => 0,
+        _ => ((64 - (value ^ (value >> 63)).leading_zeros() + 1) as usize).div_ceil(8),
+    };
+    (value.to_le_bytes(), length)
+}
+
+impl<W: Write> Writer for BinaryWriter1_1<W> {
+    fn ion_version(&self) -> (u8, u8) {
+        (1, 1)
+    }
+
+    /// Writes an Ion 1.1 version marker followed by the writer's macro table, if it has one.
+    /// Version markers can only be written at the top level.
+    fn write_ion_version_marker(&mut self, major: u8, minor: u8) -> IonResult<()> {
+        if (major, minor) != (1, 1) {
+            return illegal_operation("BinaryWriter1_1 can only write Ion 1.1 version markers");
+        }
+        if self.depth() > 0 {
+            return illegal_operation("version markers can only be written at the top level");
+        }
+        self.buffer.extend_from_slice(&IVM);
+        if !self.macros.is_empty() {
+            self.write_encoding_directive()?;
+        }
+        Ok(())
+    }
+
+    fn supports_text_symbol_tokens(&self) -> bool {
+        true
+    }
+
+    fn set_annotations<I, A>(&mut self, annotations: I)
+    where
+        A: AsRawSymbolTokenRef,
+        I: IntoIterator<Item = A>,
+    {
+        self.annotations.clear();
+        self.num_annotations = 0;
+        for annotation in annotations {
+            write_flex_sym(&mut self.annotations, annotation.as_raw_symbol_token_ref());
+            self.num_annotations += 1;
+        }
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        let type_code = match ion_type {
+            IonType::Null => return self.write_value(|output| output.push(0xEA)),
+            IonType::Boolean => 0x00,
+            IonType::Integer => 0x01,
+            IonType::Float => 0x02,
+            IonType::Decimal => 0x03,
+            IonType::Timestamp => 0x04,
+            IonType::String => 0x05,
+            IonType::Symbol => 0x06,
+            IonType::Blob => 0x07,
+            IonType::Clob => 0x08,
+            IonType::List => 0x09,
+            IonType::SExpression => 0x0A,
+            IonType::Struct => 0x0B,
+        };
+        self.write_value(|output| output.extend_from_slice(&[0xEB, type_code]))
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        self.write_value(|output| output.push(if value { 0x6E } else { 0x6F }))
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        let (bytes, length) = fixed_int_bytes(value);
+        self.write_value(|output| {
+            output.push(0x60 + length as u8);
+            output.extend_from_slice(&bytes[..length]);
+        })
+    }
+
+    fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+        match value {
+            Integer::I64(value) => self.write_i64(*value),
+            Integer::BigInt(value) => {
+                let bytes = value.to_signed_bytes_le();
+                self.write_value(|output| {
+                    if bytes.len() <= 8 {
+                        output.push(0x60 + bytes.len() as u8);
+                    } else {
+                        output.push(0xF6);
+                        write_flex_uint(output, bytes.len());
+                    }
+                    output.extend_from_slice(&bytes);
+                })
+            }
+        }
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        self.write_value(|output| {
+            output.push(0x6C);
+            output.extend_from_slice(&value.to_le_bytes());
+        })
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        self.write_value(|output| {
+            if value == 0.0 && value.is_sign_positive() {
+                output.push(0x6A);
+            } else {
+                output.push(0x6D);
+                output.extend_from_slice(&value.to_le_bytes());
+            }
+        })
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        if value.coefficient.is_negative_zero() {
+            return illegal_operation("negative zero decimals cannot be written in Ion 1.1 yet");
+        }
+        let coefficient: BigInt = value.coefficient.clone().try_into()?;
+        let mut body = Vec::new();
+        if !(coefficient == BigInt::from(0) && value.exponent == 0) {
+            write_flex_int(&mut body, value.exponent);
+            if coefficient != BigInt::from(0) {
+                body.extend_from_slice(&coefficient.to_signed_bytes_le());
+            }
+        }
+        self.write_value(|output| write_with_length(output, 0x70, 0xF7, &body))
+    }
+
+    fn write_timestamp(&mut self, _value: &Timestamp) -> IonResult<()> {
+        illegal_operation("Ion 1.1 timestamps cannot be written yet")
+    }
+
+    fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
+        self.write_value(|output| match value.as_raw_symbol_token_ref() {
+            RawSymbolTokenRef::SymbolId(symbol_id) if symbol_id < 256 => {
+                output.extend_from_slice(&[0xE1, symbol_id as u8])
+            }
+            RawSymbolTokenRef::SymbolId(symbol_id) if symbol_id < 65_792 => {
+                output.push(0xE2);
+                output.extend_from_slice(&((symbol_id - 256) as u16).to_le_bytes());
+            }
+            RawSymbolTokenRef::SymbolId(symbol_id) => {
+                output.push(0xE3);
+                write_flex_uint(output, symbol_id - 65_792);
+            }
+            RawSymbolTokenRef::Text(text) => write_with_length(output, 0xA0, 0xFA, text.as_bytes()),
+        })
+    }
+
+    fn write_string<A: AsRef<str>>(&mut self, value: A) -> IonResult<()> {
+        self.write_value(|output| write_with_length(output, 0x90, 0xF9, value.as_ref().as_bytes()))
+    }
+
+    fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        let value = value.as_ref();
+        self.write_value(|output| {
+            output.push(0xFF);
+            write_flex_uint(output, value.len());
+            output.extend_from_slice(value);
+        })
+    }
+
+    fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        let value = value.as_ref();
+        self.write_value(|output| {
+            output.push(0xFE);
+            write_flex_uint(output, value.len());
+            output.extend_from_slice(value);
+        })
+    }
+
+    fn step_in(&mut self, container_type: IonType) -> IonResult<()> {
+        if !container_type.is_container() {
+            return illegal_operation("Cannot step into a scalar Ion type.");
+        }
+        self.write_prefix()?;
+        self.containers.push(Container {
+            ion_type: container_type,
+            body: Vec::new(),
+            has_fields: false,
+        });
+        Ok(())
+    }
+
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) {
+        let mut field_name = Vec::new();
+        write_flex_sym(&mut field_name, name.as_raw_symbol_token_ref());
+        self.field_name = Some(field_name);
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        self.containers.last().map(|container| container.ion_type)
+    }
+
+    fn depth(&self) -> usize {
+        self.containers.len()
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        let container = match self.containers.pop() {
+            Some(container) => container,
+            None => return illegal_operation("Cannot step out of the top level."),
+        };
+        let (short_opcode, long_opcode, delimited_opcode) = match container.ion_type {
+            IonType::List => (0xB0, 0xFB, 0xF1),
+            IonType::SExpression => (0xC0, 0xFC, 0xF2),
+            _ => (0xD0, 0xFD, 0xF3),
+        };
+        let delimited = self.delimited_containers;
+        let output = self.output();
+        if delimited {
+            output.push(delimited_opcode);
+            output.extend_from_slice(&container.body);
+            if container.ion_type == IonType::Struct {
+                // A FlexSym escape followed by the delimited end opcode.
+                output.push(0x01);
+            }
+            output.push(0xF0);
+        } else {
+            write_with_length(output, short_opcode, long_opcode, &container.body);
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> IonResult<()> {
+        if self.depth() > 0 {
+            return illegal_operation("Cannot flush while the writer is inside a container.");
+        }
+        self.sink.write_all(&self.buffer)?;
+        self.buffer.clear();
+        self.sink.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ion_eq::IonEq;
+    use crate::value::reader::{native_element_reader, ElementReader};
+    use crate::ReaderBuilder;
+    use rstest::*;
+
+    fn element(ion: &str) -> OwnedElement {
+        native_element_reader().read_one(ion.as_bytes()).unwrap()
+    }
+
+    #[rstest]
+    #[case::length_prefixed(false)]
+    #[case::delimited(true)]
+    fn round_trip(#[case] delimited: bool) -> IonResult<()> {
+        let ion = r#"
+            null null.struct true false 0 -1000 123456789012345678901234567890 0e0 -2.5e0
+            0d0 1.25 -7d20 123456789012345678901234567890d-5 "" "hello" '' foo
+            "a much longer string that does not fit in a short opcode" {{aGk=}} {{"hi"}}
+            a::b::c::[1, (2 three), {}, {four: 4, 'five six': x::y::[], seven: {eight: 8}}]
+        "#;
+        let expected = native_element_reader().read_all(ion.as_bytes())?;
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriter1_1Builder::new()
+            .with_delimited_containers(delimited)
+            .build(&mut buffer)?;
+        for value in &expected {
+            write_element(&mut writer, None, value)?;
+        }
+        writer.flush()?;
+        drop(writer);
+
+        let mut reader = ReaderBuilder::new().with_ion_1_1(true).build(buffer)?;
+        let actual = reader.elements().collect::<IonResult<Vec<_>>>()?;
+        assert!(actual.ion_eq(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn encodings() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriter1_1Builder::new().build(&mut buffer)?;
+        writer.set_annotations(["a"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("b");
+        writer.write_i64(-1)?;
+        writer.step_out()?;
+        writer.write_symbol(300)?;
+        writer.flush()?;
+        drop(writer);
+        assert_eq!(
+            buffer,
+            vec![
+                0xE0, 0x01, 0x01, 0xEA, // IVM
+                0xE7, 0xFF, 0x61, // a::
+                0xD5, 0x01, 0xFF, 0x62, 0x61, 0xFF, // {b: -1}
+                0xE2, 0x2C, 0x00, // $300
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invoke_macros() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriter1_1Builder::new()
+            .with_macro(Macro::new("greet", [] as [&str; 0], element("\"hello\"")))
+            .with_macro(Macro::new(
+                "point",
+                ["x", "y"],
+                element("{x: (%x), y: (%y)}"),
+            ))
+            .build(&mut buffer)?;
+        writer.invoke_macro("point", &[element("1"), element("2")])?;
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("p");
+        writer.invoke_macro("point", &[element("3"), element("{z: 4}")])?;
+        writer.step_out()?;
+        assert!(writer.invoke_macro("point", &[element("1")]).is_err());
+        assert!(writer.invoke_macro::<OwnedElement>("line", &[]).is_err());
+        writer.flush()?;
+        drop(writer);
+
+        let directive_end = buffer.len() - 18;
+        let expected_directive = native_element_reader().read_one(
+            br#"$ion_encoding::((macro_table
+                (macro greet () "hello")
+                (macro point (x y) {x: (%x), y: (%y)})))"#,
+        )?;
+        let mut reader = ReaderBuilder::new()
+            .with_ion_1_1(true)
+            .build(&buffer[..directive_end])?;
+        let directive = reader.elements().next().unwrap()?;
+        assert!(directive.ion_eq(&expected_directive));
+
+        assert_eq!(
+            &buffer[directive_end..],
+            &[
+                0x01, 0x61, 0x01, 0x61, 0x02, // (:point 1 2)
+                0xDC, // {
+                0x01, 0xFF, 0x70, // p:
+                0x01, 0x61, 0x03, // (:point 3
+                0xD5, 0x01, 0xFF, 0x7A, 0x61, 0x04, // {z: 4})
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn macro_table_limits() {
+        let definition = Macro::new("m", ["x"], element("(%x)"));
+        let builder = BinaryWriter1_1Builder::new()
+            .with_macro(definition.clone())
+            .with_macro(definition);
+        assert!(builder.build(Vec::new()).is_err());
+    }
+}
@@ -9,3 +9,9 @@ pub(crate) mod v1_0 {
         pub const VAR_UINT: u8 = 14;
     }
 }
+
+/// Constants for Ion v1.1
+pub(crate) mod v1_1 {
+    /// Ion Version Marker byte sequence
+    pub(crate) const IVM: [u8; 4] = [0xE0, 0x01, 0x01, 0xEA];
+}
@@ -4,17 +4,19 @@
 //! data stream.
 
 pub mod binary_writer;
+pub mod binary_writer_1_1;
 pub(crate) mod constants;
 pub mod decimal;
-mod header;
+pub(crate) mod header;
 pub mod int;
 mod nibbles;
 pub(crate) mod raw_binary_reader;
+pub(crate) mod raw_binary_reader_1_1;
 pub mod raw_binary_writer;
 pub mod timestamp;
 mod type_code;
 pub mod uint;
 mod var_int;
-mod var_uint;
+pub(crate) mod var_uint;
 
 pub use type_code::IonTypeCode;
@@ -2,11 +2,12 @@ use bytes::BigEndian;
 use bytes::ByteOrder;
 use delegate::delegate;
 use std::fmt::Display;
+use std::mem;
 
 use crate::raw_reader::RawStreamItem;
 use crate::{
     binary::{
-        constants::v1_0::length_codes,
+        constants::v1_0::{length_codes, IVM},
         header::{create_header_byte_jump_table, Header},
         int::DecodedInt,
         uint::DecodedUInt,
@@ -15,6 +16,7 @@ use crate::{
         IonTypeCode,
     },
     data_source::IonDataSource,
+    position::Position,
     result::{decoding_error, illegal_operation, illegal_operation_raw, IonResult},
     types::{IonType, SymbolId},
 };
@@ -24,7 +26,7 @@ use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::{decoding_error_raw, IonError};
 use crate::stream_reader::StreamReader;
 use crate::types::decimal::Decimal;
-use crate::types::integer::{IntAccess, Integer};
+use crate::types::integer::{IntAccess, Integer, UInteger};
 use crate::types::timestamp::Timestamp;
 use num_traits::Zero;
 use std::ops::Range;
@@ -223,6 +225,8 @@ where
     cursor: CursorState,
     // A jump table of pre-parsed header bytes
     header_cache: Vec<IonResult<Option<Header>>>,
+    // Whether the text of strings is checked for invalid UTF-8 when it is read
+    validate_utf8: bool,
 }
 
 /* CursorState is broken out from the BinaryIonCursor struct to allow it to be cloned
@@ -237,6 +241,10 @@ pub struct CursorState {
     ion_version: (u8, u8),
     // How many bytes we've read from our data source
     bytes_read: usize,
+    // The offset at which the most recent top-level item began
+    top_level_offset: usize,
+    // Set when `resynchronize()` has consumed an IVM that `next()` has not yet returned
+    has_pending_ivm: bool,
     // How deeply nested the cursor is at the moment
     depth: usize,
     // The number of values that have been read at the current depth
@@ -288,108 +296,8 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
     // `next()` resembles `Iterator::next`, generating a clippy warning.
     #[allow(clippy::should_implement_trait)]
     fn next(&mut self) -> IonResult<RawStreamItem> {
-        // Skip the remaining bytes of the current value, if any.
-        let _ = self.skip_current_value()?;
-
-        if let Some(parent) = self.cursor.parents.last() {
-            // If the cursor is nested inside a parent object, don't attempt to read beyond the end of
-            // the parent. Users can call '.step_out()' to progress beyond the container.
-            if self.cursor.bytes_read >= parent.value_end_exclusive() {
-                return Ok(self.set_current_item(RawStreamItem::Nothing));
-            }
-        }
-
-        // If we're in a struct, read the field id that must precede each value.
-        self.cursor.value.field_id = if self.cursor.is_in_struct {
-            Some(RawSymbolToken::SymbolId(self.read_field_id()?))
-        } else {
-            self.cursor.value.field_id_length = 0;
-            None
-        };
-
-        // Pull the next byte from the data source and interpret it as a value header
-        let mut header = match self.read_next_value_header()? {
-            Some(header) => header,
-            None => return Ok(self.set_current_item(RawStreamItem::Nothing)),
-        };
-        self.cursor.value.header = header;
-
-        // Skip over consecutive NOP padding, but don't handle nulls
-        if header.is_nop() {
-            let number_of_bytes = self.read_standard_length()?;
-
-            // If we're in a container, validate that the NOP pad doesn't overrun the container end
-            if let Some(parent) = self.cursor.parents.last() {
-                // The NOP padding described starts on the byte *after* the NOP header
-                let nop_offset = self.cursor.bytes_read;
-                let nop_range = (nop_offset)..(nop_offset + number_of_bytes);
-                let container_range = parent.value_range();
-
-                if nop_range.end > container_range.end {
-                    // This NOP is malformed, let's assemble data for error reporting
-                    return decoding_error(&format!(
-                        "{bytes}-byte NOP padding on byte range {nop_range:?} is {over} \
-                        byte{s} past container content range {container_range:?}",
-                        bytes = number_of_bytes,
-                        nop_range = nop_range,
-                        over = nop_range.end - container_range.end,
-                        s = if number_of_bytes == 1 { "" } else { "s" },
-                        container_range = container_range,
-                    ));
-                }
-            }
-
-            self.skip_bytes(number_of_bytes)?;
-
-            //TODO: Find a way to do this non-recursively when we clean up/refactor next()
-            return self.next();
-        }
-
-        self.clear_annotations();
-        let mut expected_annotated_value_length = None;
-        if header.ion_type_code == IonTypeCode::Annotation {
-            if header.length_code == 0 {
-                // This is actually the first byte in an Ion Version Marker
-                let ivm = self.read_ivm()?;
-                return Ok(self.set_current_item(ivm));
-            }
-            // We've found an annotated value. Read all of the annotation symbols leading
-            // up to the value
-            expected_annotated_value_length = Some(self.read_annotations()?);
-            // Now read the next header representing the value itself.
-            header = match self.read_next_value_header()? {
-                Some(header) => header,
-                None => return Ok(self.set_current_item(RawStreamItem::Nothing)),
-            };
-            if header.is_nop() {
-                return decoding_error(&format!(
-                    "The annotation wrapper starting at byte {} contains NOP padding, which is illegal.",
-                    self.cursor.bytes_read
-                ));
-            }
-            self.cursor.value.header = header;
-        }
-
-        let _ = self.process_header_by_type_code(header)?;
-
-        self.cursor.index_at_depth += 1;
-        self.cursor.value.index_at_depth = self.cursor.index_at_depth;
-
-        // If this value had an annotations wrapper, make sure the length of the value we just
-        // found is the expected size.
-        if let Some(expected_length) = expected_annotated_value_length {
-            let value = &self.cursor.value;
-            let encoded_length = value.header_length() + value.value_length();
-            if encoded_length != expected_length {
-                return decoding_error(format!(
-                    "annotations wrapper expected a {}-byte value, but found a {}-byte {}",
-                    expected_length, encoded_length, value.ion_type
-                ));
-            }
-        }
-
-        let item = RawStreamItem::nullable_value(self.cursor.value.ion_type, self.is_null());
-        Ok(self.set_current_item(item))
+        let result = self.next_item();
+        self.located(result)
     }
 
     fn current(&self) -> Self::Item {
@@ -408,15 +316,17 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
         self.cursor.value.is_null
     }
 
-    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'a> {
-        let num_annotations = self.cursor.value.number_of_annotations as usize;
-        if num_annotations == 0 {
-            return Box::new(EMPTY_SLICE_RAW_SYMBOL_TOKEN.iter().cloned().map(Ok));
+    fn annotation(&self, index: usize) -> IonResult<Self::Symbol> {
+        let num_annotations = self.number_of_annotations();
+        if index >= num_annotations {
+            return illegal_operation(format!(
+                "annotation index {} is out of bounds; the current value has {} annotations",
+                index, num_annotations
+            ));
         }
-        let end = self.cursor.annotations.len();
-        let start = end - num_annotations;
-        let annotations = &self.cursor.annotations[start..end];
-        Box::new(annotations.iter().cloned().map(Ok))
+        // The current value's annotations are at the end of the annotations stack.
+        let start = self.cursor.annotations.len() - num_annotations;
+        Ok(self.cursor.annotations[start + index].clone())
     }
 
     fn has_annotations(&self) -> bool {
@@ -447,44 +357,18 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
     }
 
     fn read_bool(&mut self) -> IonResult<bool> {
-        read_safety_checks!(self, IonType::Boolean);
-
-        // No reading from the stream occurs -- the header contains all of the information we need.
-        let representation = self.cursor.value.header.length_code;
-
-        match representation {
-            0 => Ok(false),
-            1 => Ok(true),
-            _ => decoding_error(&format!(
-                "Found a boolean value with an illegal representation: {}",
-                representation
-            )),
-        }
+        let result = self.decode_bool();
+        self.located(result)
     }
 
     fn read_integer(&mut self) -> IonResult<Integer> {
-        read_safety_checks!(self, IonType::Integer);
-
-        let uint = self.read_value_as_uint()?;
-        let value = Integer::from(uint);
-
-        use self::IonTypeCode::*;
-        let value = match (self.cursor.value.header.ion_type_code, value) {
-            (PositiveInteger, integer) => integer,
-            (NegativeInteger, integer) if integer.is_zero() => {
-                return decoding_error("found a negative integer (typecode=3) with a value of 0");
-            }
-            (NegativeInteger, integer) => -integer,
-            itc => unreachable!("Unexpected IonTypeCode: {:?}", itc),
-        };
-
-        Ok(value)
+        let result = self.decode_integer();
+        self.located(result)
     }
 
     fn read_i64(&mut self) -> IonResult<i64> {
-        self.read_integer()?
-            .as_i64()
-            .ok_or_else(|| decoding_error_raw("integer was too large to fit in an i64"))
+        let result = self.decode_i64();
+        self.located(result)
     }
 
     fn read_f32(&mut self) -> IonResult<f32> {
@@ -495,49 +379,34 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
     }
 
     fn read_f64(&mut self) -> IonResult<f64> {
-        read_safety_checks!(self, IonType::Float);
-
-        let number_of_bytes = self.cursor.value.value_length;
-
-        self.read_slice(number_of_bytes, |buffer: &[u8]| {
-            let value = match number_of_bytes {
-                0 => 0f64,
-                4 => f64::from(BigEndian::read_f32(buffer)),
-                8 => BigEndian::read_f64(buffer),
-                _ => {
-                    return decoding_error(&format!(
-                        "Encountered an illegal value for a Float length: {}",
-                        number_of_bytes
-                    ))
-                }
-            };
-            Ok(value)
-        })
+        let result = self.decode_f64();
+        self.located(result)
     }
 
     fn read_decimal(&mut self) -> IonResult<Decimal> {
-        read_safety_checks!(self, IonType::Decimal);
-
-        if self.cursor.value.value_length == 0 {
-            return Ok(Decimal::new(0i32, 0i64));
-        }
+        let result = self.decode_decimal();
+        self.located(result)
+    }
 
-        let exponent_var_int = self.read_var_int()?;
-        let coefficient_size_in_bytes =
-            self.cursor.value.value_length - exponent_var_int.size_in_bytes();
+    fn read_string(&mut self) -> IonResult<String> {
+        self.map_string(|s| s.to_owned())
+    }
 
-        let exponent = exponent_var_int.value() as i64;
-        let coefficient = self.read_int(coefficient_size_in_bytes)?;
+    fn read_str(&mut self) -> IonResult<&str> {
+        read_safety_checks!(self, IonType::String);
 
-        if coefficient.is_negative_zero() {
-            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        // The data source's input buffer may be refilled (or may not contain the whole string
+        // to begin with), so copy the string's bytes into the reader's reusable buffer instead.
+        let length_in_bytes = self.cursor.value.value_length;
+        if self.buffer.len() < length_in_bytes {
+            self.buffer.resize(length_in_bytes, 0);
         }
+        self.data_source
+            .read_exact(&mut self.buffer[..length_in_bytes])?;
+        self.cursor.bytes_read += length_in_bytes;
 
-        Ok(Decimal::new(coefficient, exponent))
-    }
-
-    fn read_string(&mut self) -> IonResult<String> {
-        self.map_string(|s| s.to_owned())
+        let result = decode_utf8(&self.buffer[..length_in_bytes], self.validate_utf8);
+        self.located(result)
     }
 
     fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
@@ -545,15 +414,9 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
         Self: Sized,
         F: FnOnce(&str) -> U,
     {
-        self.map_string_bytes(|buffer| match std::str::from_utf8(buffer) {
-            Ok(utf8_text) => Ok(f(utf8_text)),
-            Err(utf8_error) => {
-                return decoding_error(&format!(
-                    "The requested string was not valid UTF-8: {:?}",
-                    utf8_error
-                ))
-            }
-        })?
+        let validate_utf8 = self.validate_utf8;
+        let result = self.map_string_bytes(|buffer| decode_utf8(buffer, validate_utf8).map(f));
+        self.located(result.and_then(|decoded| decoded))
     }
 
     fn map_string_bytes<F, U>(&mut self, f: F) -> IonResult<U>
@@ -569,11 +432,8 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
 
     #[inline(always)]
     fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
-        read_safety_checks!(self, IonType::Symbol);
-
-        let uint = self.read_value_as_uint()?;
-        let symbol_id = usize::try_from(uint.value())?;
-        Ok(RawSymbolToken::SymbolId(symbol_id))
+        let result = self.decode_symbol();
+        self.located(result)
     }
 
     fn read_blob(&mut self) -> IonResult<Vec<u8>> {
@@ -606,145 +466,78 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
         self.read_slice(number_of_bytes, |buffer: &[u8]| Ok(f(buffer)))
     }
 
-    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
-        read_safety_checks!(self, IonType::Timestamp);
+    fn read_blob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        read_safety_checks!(self, IonType::Blob);
+        let result = self.read_value_chunks(chunk_handler);
+        self.located(result)
+    }
 
-        let datetime_start_offset = self.cursor.bytes_read;
+    fn read_clob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        read_safety_checks!(self, IonType::Clob);
+        let result = self.read_value_chunks(chunk_handler);
+        self.located(result)
+    }
 
-        let offset = self.read_var_int()?;
-        let is_known_offset = !offset.is_negative_zero();
-        let offset_minutes = offset.value() as i32;
-        let year = self.read_var_uint()?.value() as u32;
+    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
+        let result = self.decode_timestamp();
+        self.located(result)
+    }
 
-        // Year precision
+    #[inline]
+    fn step_in(&mut self) -> IonResult<()> {
+        use self::IonType::*;
+        use std::mem;
+        self.cursor.is_in_struct = match self.cursor.value.ion_type {
+            Struct => true,
+            List | SExpression => false,
+            _ => panic!("You cannot step into a(n) {:?}", self.cursor.value.ion_type),
+        };
+        self.cursor.parents.push(EncodedValue::default());
+        // We've just push()ed a value onto the `parents` Vec, so it's safe to call
+        // last_mut().unwrap() below.
+        mem::swap(
+            &mut self.cursor.value,
+            self.cursor.parents.last_mut().unwrap(),
+        );
+        self.cursor.depth += 1;
+        self.cursor.index_at_depth = 0;
+        Ok(())
+    }
 
-        let builder = Timestamp::with_year(year);
-        if self.finished_reading_value() {
-            let timestamp = builder.build()?;
-            return Ok(timestamp);
-        }
+    #[inline]
+    fn step_out(&mut self) -> IonResult<()> {
+        use std::mem;
 
-        // Month precision
+        // Clear annotations belonging to the current value before we step out.
+        self.clear_annotations();
 
-        let month = self.read_var_uint()?.value() as u32;
-        let builder = builder.with_month(month);
-        if self.finished_reading_value() {
-            let timestamp = builder.build()?;
-            return Ok(timestamp);
-        }
+        // EncodedLevel is a fairly large struct. Using Vec::pop() to remove the last item causes
+        // the last EncodedLevel to be moved to the stack before it's swap()ped into
+        // self.cursor.value, which is surprisingly expensive. As an optimization, we can get
+        // an in-place reference to the last parent in the Vec and perform the swap() there.
+        // We then truncate the Vec to discard the old value that was swapped into the last
+        // Vec position.
 
-        // Day precision
+        // Get an in-place handle to parent
+        let parent = self
+            .cursor
+            .parents
+            .last_mut()
+            .ok_or_else(|| illegal_operation_raw("You cannot step out of the root level."))?;
 
-        let day = self.read_var_uint()?.value() as u32;
-        let builder = builder.with_day(day);
-        if self.finished_reading_value() {
-            let timestamp = builder.build()?;
-            return Ok(timestamp);
-        }
+        // We're stepping out of the container, so we need to skip to the end of it.
+        let value_end_excl = parent.value_end_exclusive();
+        let bytes_read = self.cursor.bytes_read;
+        let bytes_to_skip = value_end_excl - bytes_read;
 
-        // Hour-and-minute precision
-
-        let hour = self.read_var_uint()?.value() as u32;
-        if self.finished_reading_value() {
-            return decoding_error("timestamps with an hour must also specify a minute");
-        }
-        let minute = self.read_var_uint()?.value() as u32;
-        let builder = builder.with_hour_and_minute(hour, minute);
-        if self.finished_reading_value() {
-            let timestamp = if is_known_offset {
-                builder.build_utc_fields_at_offset(offset_minutes)
-            } else {
-                builder.build_at_unknown_offset()
-            }?;
-            return Ok(timestamp);
-        }
-
-        // Second precision
-
-        let second = self.read_var_uint()?.value() as u32;
-        let builder = builder.with_second(second);
-        if self.finished_reading_value() {
-            let timestamp = if is_known_offset {
-                builder.build_utc_fields_at_offset(offset_minutes)
-            } else {
-                builder.build_at_unknown_offset()
-            }?;
-            return Ok(timestamp);
-        }
-
-        // Fractional second precision
-
-        let subsecond_exponent = self.read_var_int()?.value();
-        // The remaining bytes represent the coefficient. We need to determine how many bytes
-        // we've read to know how many remain.
-        let value_bytes_read = self.cursor.bytes_read - datetime_start_offset;
-        let coefficient_size_in_bytes = self.cursor.value.value_length - value_bytes_read;
-        let subsecond_coefficient = if coefficient_size_in_bytes == 0 {
-            DecodedInt::zero()
-        } else {
-            self.read_int(coefficient_size_in_bytes)?
-        };
-
-        let builder = builder
-            .with_fractional_seconds(Decimal::new(subsecond_coefficient, subsecond_exponent));
-        let timestamp = if is_known_offset {
-            builder.build_utc_fields_at_offset(offset_minutes)
-        } else {
-            builder.build_at_unknown_offset()
-        }?;
-
-        Ok(timestamp)
-    }
-
-    #[inline]
-    fn step_in(&mut self) -> IonResult<()> {
-        use self::IonType::*;
-        use std::mem;
-        self.cursor.is_in_struct = match self.cursor.value.ion_type {
-            Struct => true,
-            List | SExpression => false,
-            _ => panic!("You cannot step into a(n) {:?}", self.cursor.value.ion_type),
-        };
-        self.cursor.parents.push(EncodedValue::default());
-        // We've just push()ed a value onto the `parents` Vec, so it's safe to call
-        // last_mut().unwrap() below.
-        mem::swap(
-            &mut self.cursor.value,
-            self.cursor.parents.last_mut().unwrap(),
-        );
-        self.cursor.depth += 1;
-        self.cursor.index_at_depth = 0;
-        Ok(())
-    }
-
-    #[inline]
-    fn step_out(&mut self) -> IonResult<()> {
-        use std::mem;
-
-        // Clear annotations belonging to the current value before we step out.
-        self.clear_annotations();
-
-        // EncodedLevel is a fairly large struct. Using Vec::pop() to remove the last item causes
-        // the last EncodedLevel to be moved to the stack before it's swap()ped into
-        // self.cursor.value, which is surprisingly expensive. As an optimization, we can get
-        // an in-place reference to the last parent in the Vec and perform the swap() there.
-        // We then truncate the Vec to discard the old value that was swapped into the last
-        // Vec position.
-
-        // Get an in-place handle to parent
-        let parent = self
-            .cursor
-            .parents
-            .last_mut()
-            .ok_or_else(|| illegal_operation_raw("You cannot step out of the root level."))?;
-
-        // We're stepping out of the container, so we need to skip to the end of it.
-        let value_end_excl = parent.value_end_exclusive();
-        let bytes_read = self.cursor.bytes_read;
-        let bytes_to_skip = value_end_excl - bytes_read;
-
-        // Set the parent as the current value
-        mem::swap(&mut self.cursor.value, parent);
+        // Set the parent as the current value
+        mem::swap(&mut self.cursor.value, parent);
 
         // Drop the last entry in the parents Vec in-place to avoid another move.
         let len_without_last = self.cursor.parents.len() - 1;
@@ -770,10 +563,75 @@ impl<R: IonDataSource> StreamReader for RawBinaryReader<R> {
     fn depth(&self) -> usize {
         self.cursor.depth
     }
+
+    fn resynchronize(&mut self) -> IonResult<Range<usize>> {
+        // Return to the top level, abandoning any containers that were in progress.
+        self.cursor.current_item = RawStreamItem::Nothing;
+        self.cursor.has_pending_ivm = false;
+        self.cursor.depth = 0;
+        self.cursor.index_at_depth = 0;
+        self.cursor.is_in_struct = false;
+        self.cursor.value = Default::default();
+        self.cursor.parents.clear();
+        self.cursor.annotations.clear();
+
+        // Binary Ion offers no reliable value boundaries other than an IVM, which also resets the
+        // symbol table. Discard bytes until the next IVM or the end of the stream.
+        let start = self.cursor.top_level_offset.min(self.cursor.bytes_read);
+        let mut matched = 0;
+        while let Some(byte) = self.next_byte()? {
+            matched = match byte {
+                byte if byte == IVM[matched] => matched + 1,
+                byte if byte == IVM[0] => 1,
+                _ => 0,
+            };
+            if matched == IVM.len() {
+                self.cursor.has_pending_ivm = true;
+                let ivm_offset = self.cursor.bytes_read - IVM.len();
+                self.cursor.top_level_offset = ivm_offset;
+                return Ok(start..ivm_offset);
+            }
+        }
+        self.cursor.top_level_offset = self.cursor.bytes_read;
+        Ok(start..self.cursor.bytes_read)
+    }
+
+    fn declared_value_length(&self) -> Option<usize> {
+        match self.cursor.current_item {
+            RawStreamItem::Value(_) | RawStreamItem::Null(_) => {
+                Some(self.cursor.value.value_length)
+            }
+            _ => None,
+        }
+    }
+
+    fn value_end_offset(&self) -> Option<usize> {
+        match self.cursor.current_item {
+            RawStreamItem::Value(_) | RawStreamItem::Null(_) => {
+                Some(self.cursor.value.value_end_exclusive())
+            }
+            _ => None,
+        }
+    }
+
+    fn position(&self) -> Position {
+        use RawStreamItem::*;
+        let offset = match self.cursor.current_item {
+            // An IVM is always 4 bytes long and the cursor is positioned just beyond it.
+            VersionMarker(_, _) => self.cursor.bytes_read - IVM.len(),
+            Value(_) | Null(_) => {
+                let value = &self.cursor.value;
+                value
+                    .annotations_offset()
+                    .unwrap_or_else(|| value.header_offset())
+            }
+            Nothing => self.cursor.bytes_read,
+        };
+        Position::with_offset(offset)
+    }
 }
 
 const EMPTY_SLICE_U8: &[u8] = &[];
-const EMPTY_SLICE_RAW_SYMBOL_TOKEN: &[RawSymbolToken] = &[];
 
 /// Additional functionality that's only available if the data source is in-memory, such as a
 /// Vec<u8> or &[u8]).
@@ -791,102 +649,461 @@ where
             pub fn annotations_offset(&self) -> Option<usize>;
             pub fn annotations_range(&self) -> Option<Range<usize>>;
 
-            pub fn header_length(&self) -> usize;
-            pub fn header_offset(&self) -> usize;
-            pub fn header_range(&self) -> Range<usize>;
+            pub fn header_length(&self) -> usize;
+            pub fn header_offset(&self) -> usize;
+            pub fn header_range(&self) -> Range<usize>;
+
+            pub fn value_length(&self) -> usize;
+            pub fn value_offset(&self) -> usize;
+            pub fn value_range(&self) -> Range<usize>;
+        }
+    }
+
+    /// Returns a slice containing the entirety of this encoded value, including its field ID
+    /// (if present), its annotations (if present), its header, and the encoded value itself.
+    /// Calling this function does not advance the cursor.
+    pub fn raw_bytes(&self) -> Option<&[u8]> {
+        self.ion_type()?;
+        let start: usize;
+        if let Some(field_id_offset) = self.cursor.value.field_id_offset() {
+            start = field_id_offset;
+        } else if let Some(annotations_offset) = self.cursor.value.annotations_offset() {
+            start = annotations_offset;
+        } else {
+            start = self.cursor.value.header_offset;
+        }
+        let end = self.cursor.value.value_end_exclusive();
+        let bytes = self.data_source.get_ref().as_ref();
+        Some(&bytes[start..end])
+    }
+
+    /// Returns a slice containing the current value's header's raw bytes without advancing the
+    /// cursor. Includes the type descriptor byte and any bytes used to represent the `length`
+    /// field.
+    pub fn raw_header_bytes(&self) -> Option<&[u8]> {
+        self.ion_type()?;
+        let bytes = self.data_source.get_ref().as_ref();
+        Some(&bytes[self.cursor.value.header_range()])
+    }
+
+    /// Returns a slice containing the current value's raw bytes (not including its field ID,
+    /// annotations, or type descriptor byte) without advancing the cursor.
+    pub fn raw_value_bytes(&self) -> Option<&[u8]> {
+        self.ion_type()?;
+        let bytes = self.data_source.get_ref().as_ref();
+        Some(&bytes[self.cursor.value.value_range()])
+    }
+
+    /// Returns a slice containing the current value's raw field ID bytes (if present) without
+    /// advancing the cursor.
+    pub fn raw_field_id_bytes(&self) -> Option<&[u8]> {
+        self.ion_type()?;
+        if let Some(range) = self.cursor.value.field_id_range() {
+            let bytes = self.data_source.get_ref().as_ref();
+            return Some(&bytes[range]);
+        }
+        None
+    }
+
+    /// Returns a slice containing the current value's annotations (if any) without advancing the
+    /// cursor.
+    pub fn raw_annotations_bytes(&self) -> Option<&[u8]> {
+        self.ion_type()?;
+        if let Some(range) = self.cursor.value.annotations_range() {
+            let bytes = self.data_source.get_ref().as_ref();
+            return Some(&bytes[range]);
+        }
+        None
+    }
+}
+
+impl<R> RawBinaryReader<R>
+where
+    R: IonDataSource,
+{
+    pub fn new(data_source: R) -> Self {
+        Self::with_buffer_capacity(data_source, 4096)
+    }
+
+    /// Like [RawBinaryReader::new], but sizes the reader's scratch buffer to hold `capacity`
+    /// bytes. The scratch buffer is used to assemble values that span more than one of the data
+    /// source's reads; it grows as needed to hold larger values.
+    pub fn with_buffer_capacity(data_source: R, capacity: usize) -> Self {
+        RawBinaryReader {
+            data_source,
+            buffer: vec![0; capacity],
+            cursor: CursorState {
+                current_item: RawStreamItem::Nothing,
+                ion_version: (1, 0),
+                bytes_read: 0,
+                top_level_offset: 0,
+                has_pending_ivm: false,
+                depth: 0,
+                index_at_depth: 0,
+                is_in_struct: false,
+                value: Default::default(),
+                parents: Vec::new(),
+                annotations: Vec::new(),
+            },
+            header_cache: create_header_byte_jump_table(),
+            validate_utf8: true,
+        }
+    }
+
+    /// Configures the reader to skip UTF-8 validation when the text of a string is read. This
+    /// removes a significant cost when reading data that is known to be well-formed, such as
+    /// data that was produced by this library's own writers.
+    ///
+    /// Text is only decoded when a string is read, so strings that are skipped are never
+    /// validated regardless of this setting.
+    ///
+    /// # Safety
+    ///
+    /// Every string in the input must be valid UTF-8. Reading a string that is not results in
+    /// undefined behavior.
+    pub unsafe fn with_unchecked_utf8(mut self) -> Self {
+        self.validate_utf8 = false;
+        self
+    }
+
+    /// Helper method to record the [RawStreamItem] over which the reader is currently
+    /// positioned before returning from [next].
+    fn set_current_item(&mut self, item: RawStreamItem) -> RawStreamItem {
+        self.cursor.current_item = item;
+        item
+    }
+
+    /// Attaches the reader's current offset to any decoding error in the provided `result`.
+    fn located<T>(&self, result: IonResult<T>) -> IonResult<T> {
+        result.map_err(|error| error.with_position(Position::with_offset(self.cursor.bytes_read)))
+    }
+
+    fn next_item(&mut self) -> IonResult<RawStreamItem> {
+        if self.cursor.has_pending_ivm {
+            // `resynchronize()` already consumed this IVM's bytes; surface it now.
+            self.cursor.has_pending_ivm = false;
+            self.cursor.ion_version = (1, 0);
+            return Ok(self.set_current_item(RawStreamItem::VersionMarker(1, 0)));
+        }
+
+        // Skip the remaining bytes of the current value, if any.
+        let _ = self.skip_current_value()?;
+
+        if self.cursor.depth == 0 {
+            self.cursor.top_level_offset = self.cursor.bytes_read;
+        }
+
+        if let Some(parent) = self.cursor.parents.last() {
+            // If the cursor is nested inside a parent object, don't attempt to read beyond the end of
+            // the parent. Users can call '.step_out()' to progress beyond the container.
+            if self.cursor.bytes_read >= parent.value_end_exclusive() {
+                return Ok(self.set_current_item(RawStreamItem::Nothing));
+            }
+        }
+
+        // If we're in a struct, read the field id that must precede each value.
+        self.cursor.value.field_id = if self.cursor.is_in_struct {
+            Some(RawSymbolToken::SymbolId(self.read_field_id()?))
+        } else {
+            self.cursor.value.field_id_length = 0;
+            None
+        };
+
+        // Pull the next byte from the data source and interpret it as a value header
+        let mut header = match self.read_next_value_header()? {
+            Some(header) => header,
+            None => return Ok(self.set_current_item(RawStreamItem::Nothing)),
+        };
+        self.cursor.value.header = header;
+
+        // Skip over consecutive NOP padding, but don't handle nulls
+        if header.is_nop() {
+            let number_of_bytes = self.read_standard_length()?;
+
+            // If we're in a container, validate that the NOP pad doesn't overrun the container end
+            if let Some(parent) = self.cursor.parents.last() {
+                // The NOP padding described starts on the byte *after* the NOP header
+                let nop_offset = self.cursor.bytes_read;
+                let nop_range = (nop_offset)..(nop_offset + number_of_bytes);
+                let container_range = parent.value_range();
+
+                if nop_range.end > container_range.end {
+                    // This NOP is malformed, let's assemble data for error reporting
+                    return decoding_error(&format!(
+                        "{bytes}-byte NOP padding on byte range {nop_range:?} is {over} \
+                        byte{s} past container content range {container_range:?}",
+                        bytes = number_of_bytes,
+                        nop_range = nop_range,
+                        over = nop_range.end - container_range.end,
+                        s = if number_of_bytes == 1 { "" } else { "s" },
+                        container_range = container_range,
+                    ));
+                }
+            }
+
+            self.skip_bytes(number_of_bytes)?;
+
+            //TODO: Find a way to do this non-recursively when we clean up/refactor next()
+            return self.next_item();
+        }
+
+        self.clear_annotations();
+        let mut expected_annotated_value_length = None;
+        if header.ion_type_code == IonTypeCode::Annotation {
+            if header.length_code == 0 {
+                // This is actually the first byte in an Ion Version Marker
+                let ivm = self.read_ivm()?;
+                return Ok(self.set_current_item(ivm));
+            }
+            // We've found an annotated value. Read all of the annotation symbols leading
+            // up to the value
+            expected_annotated_value_length = Some(self.read_annotations()?);
+            // Now read the next header representing the value itself.
+            header = match self.read_next_value_header()? {
+                Some(header) => header,
+                None => return Ok(self.set_current_item(RawStreamItem::Nothing)),
+            };
+            if header.is_nop() {
+                return decoding_error(&format!(
+                    "The annotation wrapper starting at byte {} contains NOP padding, which is illegal.",
+                    self.cursor.bytes_read
+                ));
+            }
+            self.cursor.value.header = header;
+        }
+
+        let _ = self.process_header_by_type_code(header)?;
+
+        self.cursor.index_at_depth += 1;
+        self.cursor.value.index_at_depth = self.cursor.index_at_depth;
+
+        // If this value had an annotations wrapper, make sure the length of the value we just
+        // found is the expected size.
+        if let Some(expected_length) = expected_annotated_value_length {
+            let value = &self.cursor.value;
+            let encoded_length = value.header_length() + value.value_length();
+            if encoded_length != expected_length {
+                return decoding_error(format!(
+                    "annotations wrapper expected a {}-byte value, but found a {}-byte {}",
+                    expected_length, encoded_length, value.ion_type
+                ));
+            }
+        }
+
+        let item = RawStreamItem::nullable_value(self.cursor.value.ion_type, self.is_null());
+        Ok(self.set_current_item(item))
+    }
+
+    fn decode_bool(&mut self) -> IonResult<bool> {
+        read_safety_checks!(self, IonType::Boolean);
+
+        // No reading from the stream occurs -- the header contains all of the information we need.
+        let representation = self.cursor.value.header.length_code;
+
+        match representation {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => decoding_error(&format!(
+                "Found a boolean value with an illegal representation: {}",
+                representation
+            )),
+        }
+    }
+
+    fn decode_integer(&mut self) -> IonResult<Integer> {
+        read_safety_checks!(self, IonType::Integer);
+
+        // Most integers fit in a u64, which can be decoded without the bookkeeping that
+        // arbitrarily large UInts require.
+        let value = if self.cursor.value.value_length <= mem::size_of::<u64>() {
+            Integer::from(UInteger::U64(self.read_value_as_u64()?))
+        } else {
+            Integer::from(self.read_value_as_uint()?)
+        };
+
+        use self::IonTypeCode::*;
+        let value = match (self.cursor.value.header.ion_type_code, value) {
+            (PositiveInteger, integer) => integer,
+            (NegativeInteger, integer) if integer.is_zero() => {
+                return decoding_error("found a negative integer (typecode=3) with a value of 0");
+            }
+            (NegativeInteger, integer) => -integer,
+            itc => unreachable!("Unexpected IonTypeCode: {:?}", itc),
+        };
+
+        Ok(value)
+    }
+
+    fn decode_i64(&mut self) -> IonResult<i64> {
+        read_safety_checks!(self, IonType::Integer);
+
+        if self.cursor.value.value_length > mem::size_of::<u64>() {
+            // The encoding may be padded with leading zeros, so the value could still fit.
+            return self.decode_integer().and_then(|integer| {
+                integer
+                    .as_i64()
+                    .ok_or_else(|| decoding_error_raw("integer was too large to fit in an i64"))
+            });
+        }
+
+        let magnitude = self.read_value_as_u64()?;
+        let value = match self.cursor.value.header.ion_type_code {
+            IonTypeCode::PositiveInteger => i64::try_from(magnitude).ok(),
+            IonTypeCode::NegativeInteger if magnitude == 0 => {
+                return decoding_error("found a negative integer (typecode=3) with a value of 0");
+            }
+            // The magnitude of i64::MIN is one greater than i64::MAX, so it wraps to itself.
+            IonTypeCode::NegativeInteger if magnitude <= i64::MIN.unsigned_abs() => {
+                Some((magnitude as i64).wrapping_neg())
+            }
+            IonTypeCode::NegativeInteger => None,
+            itc => unreachable!("Unexpected IonTypeCode: {:?}", itc),
+        };
+        value.ok_or_else(|| decoding_error_raw("integer was too large to fit in an i64"))
+    }
+
+    fn decode_f64(&mut self) -> IonResult<f64> {
+        read_safety_checks!(self, IonType::Float);
+
+        let number_of_bytes = self.cursor.value.value_length;
+
+        self.read_slice(number_of_bytes, |buffer: &[u8]| {
+            let value = match number_of_bytes {
+                0 => 0f64,
+                4 => f64::from(BigEndian::read_f32(buffer)),
+                8 => BigEndian::read_f64(buffer),
+                _ => {
+                    return decoding_error(&format!(
+                        "Encountered an illegal value for a Float length: {}",
+                        number_of_bytes
+                    ))
+                }
+            };
+            Ok(value)
+        })
+    }
+
+    fn decode_decimal(&mut self) -> IonResult<Decimal> {
+        read_safety_checks!(self, IonType::Decimal);
+
+        if self.cursor.value.value_length == 0 {
+            return Ok(Decimal::new(0i32, 0i64));
+        }
+
+        let exponent_var_int = self.read_var_int()?;
+        let coefficient_size_in_bytes =
+            self.cursor.value.value_length - exponent_var_int.size_in_bytes();
+
+        let exponent = exponent_var_int.value() as i64;
+        let coefficient = self.read_int(coefficient_size_in_bytes)?;
+
+        if coefficient.is_negative_zero() {
+            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        }
+
+        Ok(Decimal::new(coefficient, exponent))
+    }
+
+    fn decode_symbol(&mut self) -> IonResult<RawSymbolToken> {
+        read_safety_checks!(self, IonType::Symbol);
+
+        let symbol_id = if self.cursor.value.value_length <= mem::size_of::<u64>() {
+            usize::try_from(self.read_value_as_u64()?)
+                .map_err(|_| decoding_error_raw("found a symbol ID that was too large"))?
+        } else {
+            usize::try_from(self.read_value_as_uint()?.value())?
+        };
+        Ok(RawSymbolToken::SymbolId(symbol_id))
+    }
+
+    fn decode_timestamp(&mut self) -> IonResult<Timestamp> {
+        read_safety_checks!(self, IonType::Timestamp);
+
+        let datetime_start_offset = self.cursor.bytes_read;
+
+        let offset = self.read_var_int()?;
+        let is_known_offset = !offset.is_negative_zero();
+        let offset_minutes = offset.value() as i32;
+        let year = self.read_var_uint()?.value() as u32;
+
+        // Year precision
 
-            pub fn value_length(&self) -> usize;
-            pub fn value_offset(&self) -> usize;
-            pub fn value_range(&self) -> Range<usize>;
+        let builder = Timestamp::with_year(year);
+        if self.finished_reading_value() {
+            let timestamp = builder.build()?;
+            return Ok(timestamp);
         }
-    }
 
-    /// Returns a slice containing the entirety of this encoded value, including its field ID
-    /// (if present), its annotations (if present), its header, and the encoded value itself.
-    /// Calling this function does not advance the cursor.
-    pub fn raw_bytes(&self) -> Option<&[u8]> {
-        self.ion_type()?;
-        let start: usize;
-        if let Some(field_id_offset) = self.cursor.value.field_id_offset() {
-            start = field_id_offset;
-        } else if let Some(annotations_offset) = self.cursor.value.annotations_offset() {
-            start = annotations_offset;
-        } else {
-            start = self.cursor.value.header_offset;
-        }
-        let end = self.cursor.value.value_end_exclusive();
-        let bytes = self.data_source.get_ref().as_ref();
-        Some(&bytes[start..end])
-    }
+        // Month precision
 
-    /// Returns a slice containing the current value's header's raw bytes without advancing the
-    /// cursor. Includes the type descriptor byte and any bytes used to represent the `length`
-    /// field.
-    pub fn raw_header_bytes(&self) -> Option<&[u8]> {
-        self.ion_type()?;
-        let bytes = self.data_source.get_ref().as_ref();
-        Some(&bytes[self.cursor.value.header_range()])
-    }
+        let month = self.read_var_uint()?.value() as u32;
+        let builder = builder.with_month(month);
+        if self.finished_reading_value() {
+            let timestamp = builder.build()?;
+            return Ok(timestamp);
+        }
 
-    /// Returns a slice containing the current value's raw bytes (not including its field ID,
-    /// annotations, or type descriptor byte) without advancing the cursor.
-    pub fn raw_value_bytes(&self) -> Option<&[u8]> {
-        self.ion_type()?;
-        let bytes = self.data_source.get_ref().as_ref();
-        Some(&bytes[self.cursor.value.value_range()])
-    }
+        // Day precision
 
-    /// Returns a slice containing the current value's raw field ID bytes (if present) without
-    /// advancing the cursor.
-    pub fn raw_field_id_bytes(&self) -> Option<&[u8]> {
-        self.ion_type()?;
-        if let Some(range) = self.cursor.value.field_id_range() {
-            let bytes = self.data_source.get_ref().as_ref();
-            return Some(&bytes[range]);
+        let day = self.read_var_uint()?.value() as u32;
+        let builder = builder.with_day(day);
+        if self.finished_reading_value() {
+            let timestamp = builder.build()?;
+            return Ok(timestamp);
         }
-        None
-    }
 
-    /// Returns a slice containing the current value's annotations (if any) without advancing the
-    /// cursor.
-    pub fn raw_annotations_bytes(&self) -> Option<&[u8]> {
-        self.ion_type()?;
-        if let Some(range) = self.cursor.value.annotations_range() {
-            let bytes = self.data_source.get_ref().as_ref();
-            return Some(&bytes[range]);
+        // Hour-and-minute precision
+
+        let hour = self.read_var_uint()?.value() as u32;
+        if self.finished_reading_value() {
+            return decoding_error("timestamps with an hour must also specify a minute");
+        }
+        let minute = self.read_var_uint()?.value() as u32;
+        let builder = builder.with_hour_and_minute(hour, minute);
+        if self.finished_reading_value() {
+            let timestamp = if is_known_offset {
+                builder.build_utc_fields_at_offset(offset_minutes)
+            } else {
+                builder.build_at_unknown_offset()
+            }?;
+            return Ok(timestamp);
         }
-        None
-    }
-}
 
-impl<R> RawBinaryReader<R>
-where
-    R: IonDataSource,
-{
-    pub fn new(data_source: R) -> Self {
-        RawBinaryReader {
-            data_source,
-            buffer: vec![0; 4096],
-            cursor: CursorState {
-                current_item: RawStreamItem::Nothing,
-                ion_version: (1, 0),
-                bytes_read: 0,
-                depth: 0,
-                index_at_depth: 0,
-                is_in_struct: false,
-                value: Default::default(),
-                parents: Vec::new(),
-                annotations: Vec::new(),
-            },
-            header_cache: create_header_byte_jump_table(),
+        // Second precision
+
+        let second = self.read_var_uint()?.value() as u32;
+        let builder = builder.with_second(second);
+        if self.finished_reading_value() {
+            let timestamp = if is_known_offset {
+                builder.build_utc_fields_at_offset(offset_minutes)
+            } else {
+                builder.build_at_unknown_offset()
+            }?;
+            return Ok(timestamp);
         }
-    }
 
-    /// Helper method to record the [RawStreamItem] over which the reader is currently
-    /// positioned before returning from [next].
-    fn set_current_item(&mut self, item: RawStreamItem) -> RawStreamItem {
-        self.cursor.current_item = item;
-        item
+        // Fractional second precision
+
+        let subsecond_exponent = self.read_var_int()?.value();
+        // The remaining bytes represent the coefficient. We need to determine how many bytes
+        // we've read to know how many remain.
+        let value_bytes_read = self.cursor.bytes_read - datetime_start_offset;
+        let coefficient_size_in_bytes = self.cursor.value.value_length - value_bytes_read;
+        let subsecond_coefficient = if coefficient_size_in_bytes == 0 {
+            DecodedInt::zero()
+        } else {
+            self.read_int(coefficient_size_in_bytes)?
+        };
+
+        let builder = builder
+            .with_fractional_seconds(Decimal::new(subsecond_coefficient, subsecond_exponent));
+        let timestamp = if is_known_offset {
+            builder.build_utc_fields_at_offset(offset_minutes)
+        } else {
+            builder.build_at_unknown_offset()
+        }?;
+
+        Ok(timestamp)
     }
 
     pub fn is_null(&self) -> bool {
@@ -952,6 +1169,18 @@ where
         self.read_uint(number_of_bytes)
     }
 
+    // Like `read_value_as_uint`, but for values that are no more than 8 bytes long. The bytes are
+    // decoded in place when the data source has buffered them.
+    #[inline(always)]
+    fn read_value_as_u64(&mut self) -> IonResult<u64> {
+        let number_of_bytes = self.cursor.value.value_length;
+        self.read_slice(number_of_bytes, |bytes: &[u8]| {
+            Ok(bytes
+                .iter()
+                .fold(0u64, |magnitude, &byte| (magnitude << 8) | u64::from(byte)))
+        })
+    }
+
     #[inline(always)]
     fn read_uint(&mut self, number_of_bytes: usize) -> IonResult<DecodedUInt> {
         let uint = DecodedUInt::read(&mut self.data_source, number_of_bytes)?;
@@ -1067,7 +1296,10 @@ where
 
     fn next_byte(&mut self) -> IonResult<Option<u8>> {
         let byte = self.data_source.next_byte();
-        self.cursor.bytes_read += 1;
+        // Only count the byte if there was one to read; EOF does not advance the cursor.
+        if let Ok(Some(_)) = byte {
+            self.cursor.bytes_read += 1;
+        }
         byte
     }
 
@@ -1099,6 +1331,16 @@ where
     }
 
     fn read_field_id(&mut self) -> IonResult<SymbolId> {
+        // Field IDs below 128 are encoded as a single byte with the VarUInt end flag set. Most
+        // streams only use field IDs in this range.
+        if let Some(&byte) = self.data_source.fill_buf()?.first() {
+            if byte & 0x80 != 0 {
+                self.data_source.consume(1);
+                self.cursor.bytes_read += 1;
+                self.cursor.value.field_id_length = 1;
+                return Ok((byte & 0x7F) as SymbolId);
+            }
+        }
         let var_uint = self.read_var_uint()?;
         let field_id = var_uint.value();
         self.cursor.value.field_id_length = var_uint.size_in_bytes() as u8;
@@ -1158,6 +1400,28 @@ where
         Ok(())
     }
 
+    /// Passes the current value's bytes to `chunk_handler` in the largest chunks that the data
+    /// source's input buffer can provide without copying. Returns the length of the value.
+    fn read_value_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        let length_in_bytes = self.cursor.value.value_length;
+        let mut bytes_remaining = length_in_bytes;
+        while bytes_remaining > 0 {
+            let buffer = self.data_source.fill_buf()?;
+            if buffer.is_empty() {
+                return decoding_error("Unexpected end of stream.");
+            }
+            let chunk_length = bytes_remaining.min(buffer.len());
+            chunk_handler(&buffer[..chunk_length])?;
+            self.data_source.consume(chunk_length);
+            self.cursor.bytes_read += chunk_length;
+            bytes_remaining -= chunk_length;
+        }
+        Ok(length_in_bytes)
+    }
+
     /// See IonDataSource#read_slice.
     fn read_slice<T, F>(&mut self, number_of_bytes: usize, slice_processor: F) -> IonResult<T>
     where
@@ -1180,12 +1444,28 @@ where
     }
 }
 
+/// Interprets `bytes` as UTF-8 text. If `validate` is false, the caller must have guaranteed (by
+/// way of [RawBinaryReader::with_unchecked_utf8]) that `bytes` is valid UTF-8.
+fn decode_utf8(bytes: &[u8], validate: bool) -> IonResult<&str> {
+    if !validate {
+        // SAFETY: `with_unchecked_utf8` requires the input's strings to be valid UTF-8.
+        return Ok(unsafe { std::str::from_utf8_unchecked(bytes) });
+    }
+    std::str::from_utf8(bytes).map_err(|utf8_error| {
+        decoding_error_raw(format!(
+            "The requested string was not valid UTF-8: {:?}",
+            utf8_error
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::io;
 
     use crate::binary::constants::v1_0::IVM;
     use crate::binary::raw_binary_reader::RawBinaryReader;
+    use crate::position::Position;
     use crate::raw_reader::{RawStreamItem, RawStreamItem::*};
     use crate::raw_symbol_token::local_sid_token;
     use crate::result::{IonError, IonResult};
@@ -1301,6 +1581,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_i64_limits() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x28, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // i64::MAX
+            0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // i64::MIN
+            0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x05, // 5, padded to 9 bytes
+            0x28, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // i64::MAX + 1
+            0x31, 0x00, // Negative zero
+        ]);
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.read_i64()?, i64::MAX);
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.read_i64()?, i64::MIN);
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.read_i64()?, 5);
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert!(cursor.read_i64().is_err());
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert!(cursor.read_i64().is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_read_f64_zero() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x40]);
@@ -1437,6 +1740,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_str() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x83, 0x66, 0x6f, 0x6f, 0x83, 0x62, 0x61, 0x72]);
+        assert_eq!(cursor.next()?, Value(IonType::String));
+        assert_eq!(cursor.read_str()?, "foo");
+        assert_eq!(cursor.next()?, Value(IonType::String));
+        assert_eq!(cursor.read_str()?, "bar");
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_str_invalid_utf8() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x82, 0xC3, 0x28]);
+        assert_eq!(cursor.next()?, Value(IonType::String));
+        assert!(matches!(
+            cursor.read_str(),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_read_string_foo_twice_fails() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x83, 0x66, 0x6f, 0x6f]);
@@ -1479,6 +1803,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_blob_chunks() -> IonResult<()> {
+        let mut data = ion_data(&[0xAE, 0x8A]); // 10-byte blob
+        data.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        // Use a tiny input buffer so the blob has to be handed over in several chunks.
+        let mut cursor =
+            RawBinaryReader::new(io::BufReader::with_capacity(4, io::Cursor::new(data)));
+        assert_eq!(cursor.next()?, VersionMarker(1, 0));
+        assert_eq!(cursor.next()?, Value(IonType::Blob));
+        let mut chunks: Vec<Vec<u8>> = Vec::new();
+        let length = cursor.read_blob_chunks(&mut |chunk| {
+            chunks.push(chunk.to_vec());
+            Ok(())
+        })?;
+        assert_eq!(length, 10);
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(cursor.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_clob_into() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x93, 0x61, 0x62, 0x63, 0x20]);
+        assert_eq!(cursor.next()?, Value(IonType::Clob));
+        let mut sink: Vec<u8> = Vec::new();
+        assert_eq!(cursor.read_clob_into(&mut sink)?, 3);
+        assert_eq!(sink, b"abc".to_vec());
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        Ok(())
+    }
+
     #[test]
     fn test_read_list_empty() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0xB0]);
@@ -1567,6 +1923,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_struct_multi_byte_field_id() -> IonResult<()> {
+        // {$200: 1, $10: 0}
+        let mut cursor = ion_cursor_for(&[
+            0xD6, // 6-byte struct
+            0x01, 0xC8, // Field ID 200
+            0x21, 0x01, // Integer 1
+            0x8A, // Field ID 10
+            0x20, // Integer 0
+        ]);
+        assert_eq!(cursor.next()?, Value(IonType::Struct));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.field_name()?, local_sid_token(200));
+        assert_eq!(cursor.read_i64()?, 1i64);
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.field_name()?, local_sid_token(10));
+        assert_eq!(cursor.read_i64()?, 0i64);
+        assert_eq!(cursor.next()?, Nothing);
+        cursor.step_out()?;
+        Ok(())
+    }
+
     #[test]
     fn test_read_list_in_struct() -> IonResult<()> {
         // Note: technically invalid Ion because the symbol IDs referenced are never added to the
@@ -1920,4 +2299,75 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_position() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x20, // [4] single octet int, 0
+            0xE4, // [5] 4-byte annotations envelope
+            0x81, // [6] * Annotations themselves take 1 byte
+            0x84, // [7] * Annotation w/SID $4 ("name")
+            0x21, // [8] int with a 1-byte value...
+            0x01, // [9] ...1
+        ]);
+        assert_eq!(cursor.position(), Position::with_offset(0));
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.position(), Position::with_offset(4));
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        // The position of an annotated value is the beginning of its annotations wrapper.
+        assert_eq!(cursor.position(), Position::with_offset(5));
+        assert_eq!(cursor.next()?, Nothing);
+        assert_eq!(cursor.position(), Position::with_offset(10));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decoding_error_position() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x20, // [4] single octet int, 0
+            0x31, // [5] negative int with a 1-byte value...
+            0x00, // [6] ...0, which is illegal
+        ]);
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        let error = cursor.read_integer().unwrap_err();
+        assert_eq!(error.position(), Some(Position::with_offset(7)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resynchronize() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x20, // [4] single octet int, 0
+            0xF0, // [5] reserved type code
+            0x01, // [6] garbage
+            0x02, // [7] garbage
+            0xE0, 0x01, 0x00, 0xEA, // [8-11] IVM
+            0x21, // [12] int with a 1-byte value...
+            0x07, // [13] ...7
+        ]);
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert!(matches!(cursor.next(), Err(IonError::DecodingError { .. })));
+        assert_eq!(cursor.resynchronize()?, 5..8);
+        assert_eq!(cursor.next()?, VersionMarker(1, 0));
+        assert_eq!(cursor.position(), Position::with_offset(8));
+        assert_eq!(cursor.next()?, Value(IonType::Integer));
+        assert_eq!(cursor.read_i64()?, 7);
+        assert_eq!(cursor.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resynchronize_at_end_of_stream() -> IonResult<()> {
+        // A struct claiming to be 4 bytes long that contains a 1-byte field ID and a
+        // (reserved) type descriptor.
+        let mut cursor = ion_cursor_for(&[0xD4, 0x84, 0xF0, 0x00, 0x00]);
+        assert_eq!(cursor.next()?, Value(IonType::Struct));
+        cursor.step_in()?;
+        assert!(matches!(cursor.next(), Err(IonError::DecodingError { .. })));
+        assert_eq!(cursor.resynchronize()?, 4..9);
+        assert_eq!(cursor.depth(), 0);
+        assert_eq!(cursor.next()?, Nothing);
+        Ok(())
+    }
 }
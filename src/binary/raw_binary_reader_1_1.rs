@@ -0,0 +1,981 @@
+use std::ops::Range;
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::binary::constants::v1_1::IVM;
+use crate::constants::v1_0::SYSTEM_SYMBOLS;
+use crate::position::Position;
+use crate::raw_reader::RawStreamItem;
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonResult};
+use crate::stream_reader::StreamReader;
+use crate::types::coefficient::{Coefficient, Sign};
+use crate::types::decimal::Decimal;
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+
+/// Information about the value over which the reader is currently positioned.
+#[derive(Clone, Debug)]
+struct EncodedValue {
+    ion_type: IonType,
+    is_null: bool,
+    opcode: u8,
+    field_name: Option<RawSymbolToken>,
+    annotations: Vec<RawSymbolToken>,
+    // The offset of the value's annotations (if any) or its opcode.
+    offset: usize,
+    // The value's encoded representation, excluding its opcode and length. For containers,
+    // this spans the encoded children (but not the end marker of a delimited container).
+    body: Range<usize>,
+    // The offset of the first byte following the value.
+    end: usize,
+}
+
+/// A container that the reader has stepped into.
+#[derive(Clone, Copy, Debug)]
+struct Container {
+    ion_type: IonType,
+    delimited: bool,
+    // Whether the struct's field names are encoded as FlexSyms rather than as FlexUInt symbol
+    // IDs. Length-prefixed structs begin in symbol ID mode; a field name of `0` switches them
+    // to FlexSym mode for the remainder of the struct.
+    flex_sym_fields: bool,
+    body_end: usize,
+    end: usize,
+}
+
+// The next item found at a given depth.
+enum Item {
+    End,
+    Nop { next: usize, flex_sym_fields: bool },
+    VersionMarker(u8, u8),
+    Value(EncodedValue, bool),
+}
+
+// A symbol encoded as a FlexSym.
+enum FlexSym {
+    Symbol(RawSymbolToken),
+    // The escape sequence that ends a delimited struct.
+    End,
+}
+
+/// A [RawReader](crate::RawReader) for binary Ion 1.1 data.
+///
+/// Ion 1.1 support is in its early stages. This reader understands the Ion 1.1 opcodes for
+/// nulls, booleans, integers, floats, decimals, strings, symbols (including symbols with inline
+/// text), blobs, clobs, and length-prefixed and delimited containers, as well as annotations
+/// encoded as symbol IDs or inline text. Timestamps and macro invocations (e-expressions) are not
+/// yet supported and produce a decoding error. Symbol IDs are resolved the same way as in Ion 1.0.
+///
+/// The reader holds the complete stream in memory, and cannot read streams that switch back to
+/// Ion 1.0 part of the way through. Most applications should use a
+/// [ReaderBuilder](crate::ReaderBuilder) with [ion 1.1 support](crate::ReaderBuilder::with_ion_1_1)
+/// enabled rather than constructing this reader directly.
+pub struct RawBinaryReader1_1 {
+    data: Vec<u8>,
+    // The offset of the next item to read at the current depth.
+    offset: usize,
+    // The offset of the most recent IVM.
+    ivm_offset: usize,
+    current: RawStreamItem,
+    value: Option<EncodedValue>,
+    parents: Vec<Container>,
+}
+
+impl RawBinaryReader1_1 {
+    pub fn new(data: Vec<u8>) -> RawBinaryReader1_1 {
+        RawBinaryReader1_1 {
+            data,
+            offset: 0,
+            ivm_offset: 0,
+            current: RawStreamItem::Nothing,
+            value: None,
+            parents: Vec::new(),
+        }
+    }
+
+    fn located<T>(&self, result: IonResult<T>) -> IonResult<T> {
+        result.map_err(|error| error.with_position(self.position()))
+    }
+
+    fn expect_value(&self, ion_type: IonType) -> IonResult<&EncodedValue> {
+        match &self.value {
+            Some(value) if value.ion_type == ion_type && !value.is_null => Ok(value),
+            _ => illegal_operation(format!(
+                "expected the reader to be positioned on a non-null {}, but the current item is {:?}",
+                ion_type, self.current
+            )),
+        }
+    }
+
+    fn body(&self, ion_type: IonType) -> IonResult<&[u8]> {
+        let value = self.expect_value(ion_type)?;
+        Ok(&self.data[value.body.clone()])
+    }
+
+    fn advance(&mut self) -> IonResult<RawStreamItem> {
+        if let Some(value) = self.value.take() {
+            self.offset = value.end;
+        }
+        loop {
+            let parent = self.parents.last().copied();
+            match self.parse_item(self.offset, parent)? {
+                Item::End => {
+                    self.current = RawStreamItem::Nothing;
+                    return Ok(self.current);
+                }
+                Item::Nop {
+                    next,
+                    flex_sym_fields,
+                } => {
+                    self.offset = next;
+                    self.set_flex_sym_fields(flex_sym_fields);
+                }
+                Item::VersionMarker(1, 1) => {
+                    self.ivm_offset = self.offset;
+                    self.offset += IVM.len();
+                    self.current = RawStreamItem::VersionMarker(1, 1);
+                    return Ok(self.current);
+                }
+                Item::VersionMarker(major, minor) => {
+                    return decoding_error(format!(
+                        "cannot switch to Ion v{}.{} in the middle of a binary Ion 1.1 stream",
+                        major, minor
+                    ));
+                }
+                Item::Value(value, flex_sym_fields) => {
+                    self.set_flex_sym_fields(flex_sym_fields);
+                    self.current = RawStreamItem::nullable_value(value.ion_type, value.is_null);
+                    self.value = Some(value);
+                    return Ok(self.current);
+                }
+            }
+        }
+    }
+
+    fn set_flex_sym_fields(&mut self, flex_sym_fields: bool) {
+        if let Some(parent) = self.parents.last_mut() {
+            parent.flex_sym_fields = flex_sym_fields;
+        }
+    }
+
+    // Reads the field name (if the parent is a struct), annotations, and header of the item at
+    // `offset`. The returned value's children are not read, but delimited containers are scanned
+    // to find where they end.
+    fn parse_item(&self, mut offset: usize, parent: Option<Container>) -> IonResult<Item> {
+        let body_end = parent.map_or(self.data.len(), |p| p.body_end);
+        if offset >= body_end {
+            return Ok(Item::End);
+        }
+        let is_delimited = matches!(parent, Some(p) if p.delimited);
+        let mut flex_sym_fields = matches!(parent, Some(p) if p.flex_sym_fields);
+        let mut field_name = None;
+        if parent.map(|p| p.ion_type) == Some(IonType::Struct) {
+            if !flex_sym_fields {
+                let (symbol_id, length) = self.flex_uint(offset)?;
+                offset += length;
+                match symbol_id {
+                    0 => flex_sym_fields = true,
+                    symbol_id => field_name = Some(RawSymbolToken::SymbolId(symbol_id)),
+                }
+            }
+            if field_name.is_none() {
+                let (symbol, length) = self.flex_sym(offset)?;
+                match symbol {
+                    FlexSym::Symbol(symbol) => field_name = Some(symbol),
+                    FlexSym::End if is_delimited => return Ok(Item::End),
+                    FlexSym::End => {
+                        return decoding_error(
+                            "found a delimited end marker in a length-prefixed struct",
+                        )
+                    }
+                }
+                offset += length;
+            }
+        }
+
+        let value_offset = offset;
+        let mut opcode = self.byte_at(offset)?;
+        match opcode {
+            // A field whose value is a NOP pad is omitted from the struct.
+            0xEC => {
+                return Ok(Item::Nop {
+                    next: offset + 1,
+                    flex_sym_fields,
+                })
+            }
+            0xED => {
+                let (length, length_length) = self.flex_uint(offset + 1)?;
+                let next = offset + 1 + length_length + length;
+                if next > body_end {
+                    return decoding_error("NOP padding extends beyond the end of its container");
+                }
+                return Ok(Item::Nop {
+                    next,
+                    flex_sym_fields,
+                });
+            }
+            0xF0 if is_delimited => return Ok(Item::End),
+            0xE0 if parent.is_none() => {
+                let marker = self.bytes_at(offset, IVM.len())?;
+                if marker[3] != 0xEA {
+                    return decoding_error(format!(
+                        "Illegal IVM: 0xE0 0x{:X} 0x{:X} 0x{:X}",
+                        marker[1], marker[2], marker[3]
+                    ));
+                }
+                return Ok(Item::VersionMarker(marker[1], marker[2]));
+            }
+            _ => {}
+        }
+
+        let mut annotations = Vec::new();
+        if let 0xE4..=0xE9 = opcode {
+            offset = self.read_annotations(offset, &mut annotations)?;
+            opcode = self.byte_at(offset)?;
+            if let 0xE0 | 0xE4..=0xE9 | 0xEC | 0xED | 0xF0 = opcode {
+                return decoding_error(format!(
+                    "annotations must be followed by a value, found opcode 0x{:02X}",
+                    opcode
+                ));
+            }
+        }
+
+        let (ion_type, is_null, body, end) = self.parse_value(offset, opcode)?;
+        if end > body_end {
+            return decoding_error("value extends beyond the end of its container");
+        }
+        let value = EncodedValue {
+            ion_type,
+            is_null,
+            opcode,
+            field_name,
+            annotations,
+            offset: value_offset,
+            body,
+            end,
+        };
+        Ok(Item::Value(value, flex_sym_fields))
+    }
+
+    // Reads the annotations sequence that begins with the opcode at `offset` into `annotations`,
+    // returning the offset of the annotated value.
+    fn read_annotations(
+        &self,
+        offset: usize,
+        annotations: &mut Vec<RawSymbolToken>,
+    ) -> IonResult<usize> {
+        let opcode = self.data[offset];
+        let mut cursor = offset + 1;
+        // Opcodes 0xE4-0xE6 encode symbol IDs, while 0xE7-0xE9 encode FlexSyms. The third opcode
+        // in each group is followed by the length of the sequence in bytes.
+        let end = match opcode {
+            0xE6 | 0xE9 => {
+                let (length, length_length) = self.flex_uint(cursor)?;
+                cursor += length_length;
+                Some(cursor + length)
+            }
+            _ => None,
+        };
+        let count = match opcode {
+            0xE4 | 0xE7 => 1,
+            0xE5 | 0xE8 => 2,
+            _ => usize::MAX,
+        };
+        while annotations.len() < count && !matches!(end, Some(end) if cursor >= end) {
+            if opcode < 0xE7 {
+                let (symbol_id, length) = self.flex_uint(cursor)?;
+                annotations.push(RawSymbolToken::SymbolId(symbol_id));
+                cursor += length;
+            } else {
+                match self.flex_sym(cursor)? {
+                    (FlexSym::Symbol(symbol), length) => {
+                        annotations.push(symbol);
+                        cursor += length;
+                    }
+                    (FlexSym::End, _) => {
+                        return decoding_error("found a delimited end marker in an annotation")
+                    }
+                }
+            }
+        }
+        if matches!(end, Some(end) if cursor != end) {
+            return decoding_error("annotations sequence did not match its declared length");
+        }
+        Ok(cursor)
+    }
+
+    // Returns the type, nullness, body, and end offset of the value whose opcode is at `offset`.
+    fn parse_value(
+        &self,
+        offset: usize,
+        opcode: u8,
+    ) -> IonResult<(IonType, bool, Range<usize>, usize)> {
+        use IonType::*;
+        let mut body_start = offset + 1;
+        let low_nibble = (opcode & 0x0F) as usize;
+        let (ion_type, length) = match opcode {
+            0x00..=0x5F | 0xEF | 0xF4 | 0xF5 => {
+                return decoding_error("Ion 1.1 macro invocations are not supported")
+            }
+            0x60..=0x68 => (Integer, (opcode - 0x60) as usize),
+            0x6A => (Float, 0),
+            0x6B => (Float, 2),
+            0x6C => (Float, 4),
+            0x6D => (Float, 8),
+            0x6E | 0x6F => (Boolean, 0),
+            0x70..=0x7F => (Decimal, low_nibble),
+            0x80..=0x8F | 0xF8 => {
+                return decoding_error("Ion 1.1 timestamps are not yet supported")
+            }
+            0x90..=0x9F => (String, low_nibble),
+            0xA0..=0xAF => (Symbol, low_nibble),
+            0xB0..=0xBF => (List, low_nibble),
+            0xC0..=0xCF => (SExpression, low_nibble),
+            0xD0..=0xDF => (Struct, low_nibble),
+            0xE1 | 0xEE => (Symbol, 1),
+            0xE2 => (Symbol, 2),
+            0xE3 => (Symbol, self.flex_uint(body_start)?.1),
+            0xEA => return Ok((Null, true, body_start..body_start, body_start)),
+            0xEB => {
+                let ion_type = match self.byte_at(body_start)? {
+                    0x00 => Boolean,
+                    0x01 => Integer,
+                    0x02 => Float,
+                    0x03 => Decimal,
+                    0x04 => Timestamp,
+                    0x05 => String,
+                    0x06 => Symbol,
+                    0x07 => Blob,
+                    0x08 => Clob,
+                    0x09 => List,
+                    0x0A => SExpression,
+                    0x0B => Struct,
+                    other => return decoding_error(format!("invalid typed null: 0x{:02X}", other)),
+                };
+                return Ok((
+                    ion_type,
+                    true,
+                    body_start + 1..body_start + 1,
+                    body_start + 1,
+                ));
+            }
+            0xF1 => return self.parse_delimited(offset, List),
+            0xF2 => return self.parse_delimited(offset, SExpression),
+            0xF3 => return self.parse_delimited(offset, Struct),
+            0xF6 | 0xF7 | 0xF9..=0xFF => {
+                let (length, length_length) = self.flex_uint(body_start)?;
+                body_start += length_length;
+                let ion_type = match opcode {
+                    0xF6 => Integer,
+                    0xF7 => Decimal,
+                    0xF9 => String,
+                    0xFA => Symbol,
+                    0xFB => List,
+                    0xFC => SExpression,
+                    0xFD => Struct,
+                    0xFE => Blob,
+                    _ => Clob,
+                };
+                (ion_type, length)
+            }
+            _ => return decoding_error(format!("invalid opcode: 0x{:02X}", opcode)),
+        };
+        let end = body_start + length;
+        if end > self.data.len() {
+            return self.incomplete(end);
+        }
+        Ok((ion_type, false, body_start..end, end))
+    }
+
+    // Scans the children of the delimited container whose opcode is at `offset` to find its end.
+    fn parse_delimited(
+        &self,
+        offset: usize,
+        ion_type: IonType,
+    ) -> IonResult<(IonType, bool, Range<usize>, usize)> {
+        let mut container = Container {
+            ion_type,
+            delimited: true,
+            flex_sym_fields: ion_type == IonType::Struct,
+            body_end: usize::MAX,
+            end: usize::MAX,
+        };
+        let body_start = offset + 1;
+        let mut cursor = body_start;
+        loop {
+            match self.parse_item(cursor, Some(container))? {
+                Item::End => break,
+                Item::Nop {
+                    next,
+                    flex_sym_fields,
+                } => {
+                    cursor = next;
+                    container.flex_sym_fields = flex_sym_fields;
+                }
+                Item::Value(value, flex_sym_fields) => {
+                    cursor = value.end;
+                    container.flex_sym_fields = flex_sym_fields;
+                }
+                Item::VersionMarker(_, _) => {
+                    unreachable!("IVMs are only recognized at the top level")
+                }
+            }
+        }
+        let end_marker_length = match ion_type {
+            IonType::Struct => self.flex_sym(cursor)?.1,
+            _ => 1,
+        };
+        Ok((
+            ion_type,
+            false,
+            body_start..cursor,
+            cursor + end_marker_length,
+        ))
+    }
+
+    fn incomplete<T>(&self, offset: usize) -> IonResult<T> {
+        decoding_error(format!(
+            "unexpected end of data; needed {} bytes but only {} are available",
+            offset,
+            self.data.len()
+        ))
+    }
+
+    fn byte_at(&self, offset: usize) -> IonResult<u8> {
+        match self.data.get(offset) {
+            Some(byte) => Ok(*byte),
+            None => self.incomplete(offset + 1),
+        }
+    }
+
+    fn bytes_at(&self, offset: usize, length: usize) -> IonResult<&[u8]> {
+        match self.data.get(offset..offset + length) {
+            Some(bytes) => Ok(bytes),
+            None => self.incomplete(offset + length),
+        }
+    }
+
+    // Reads the little-endian bytes of a FlexUInt or FlexInt at `offset`, returning them along
+    // with the encoding's length. The number of trailing zeros in the first byte indicates how
+    // many more bytes follow it.
+    fn flex_bytes(&self, offset: usize) -> IonResult<(u64, usize)> {
+        let first = self.byte_at(offset)?;
+        if first == 0 {
+            return decoding_error(
+                "FlexUInt and FlexInt values wider than 56 bits are not supported",
+            );
+        }
+        let length = first.trailing_zeros() as usize + 1;
+        let mut buffer = [0u8; 8];
+        buffer[..length].copy_from_slice(self.bytes_at(offset, length)?);
+        Ok((u64::from_le_bytes(buffer), length))
+    }
+
+    fn flex_uint(&self, offset: usize) -> IonResult<(usize, usize)> {
+        let (bytes, length) = self.flex_bytes(offset)?;
+        Ok(((bytes >> length) as usize, length))
+    }
+
+    fn flex_int(&self, offset: usize) -> IonResult<(i64, usize)> {
+        let (bytes, length) = self.flex_bytes(offset)?;
+        // Sign-extend the encoded bytes before discarding the length bits.
+        let unused_bits = 64 - 8 * length as u32;
+        let value = ((bytes << unused_bits) as i64) >> unused_bits >> length;
+        Ok((value, length))
+    }
+
+    fn flex_sym(&self, offset: usize) -> IonResult<(FlexSym, usize)> {
+        let (value, length) = self.flex_int(offset)?;
+        match value {
+            0 => {
+                let symbol = match self.byte_at(offset + length)? {
+                    0xF0 => FlexSym::End,
+                    0x90 => FlexSym::Symbol(RawSymbolToken::Text(String::new())),
+                    address @ 0x60..=0x7F => system_symbol(address - 0x60)?,
+                    other => {
+                        return decoding_error(format!("invalid FlexSym escape: 0x{:02X}", other))
+                    }
+                };
+                Ok((symbol, length + 1))
+            }
+            symbol_id if symbol_id > 0 => Ok((
+                FlexSym::Symbol(RawSymbolToken::SymbolId(symbol_id as usize)),
+                length,
+            )),
+            negative_length => {
+                let text_length = negative_length.unsigned_abs() as usize;
+                let text = utf8(self.bytes_at(offset + length, text_length)?)?;
+                Ok((
+                    FlexSym::Symbol(RawSymbolToken::Text(text.to_owned())),
+                    length + text_length,
+                ))
+            }
+        }
+    }
+}
+
+// Returns the system symbol with the specified address. Only the system symbols that Ion 1.1
+// shares with Ion 1.0 are currently supported.
+fn system_symbol(address: u8) -> IonResult<FlexSym> {
+    if address as usize >= SYSTEM_SYMBOLS.len() {
+        return decoding_error(format!("unsupported Ion 1.1 system symbol: {}", address));
+    }
+    Ok(FlexSym::Symbol(RawSymbolToken::SymbolId(address as usize)))
+}
+
+fn utf8(bytes: &[u8]) -> IonResult<&str> {
+    std::str::from_utf8(bytes)
+        .map_err(|error| decoding_error_raw(format!("invalid UTF-8 in text: {:?}", error)))
+}
+
+// Decodes a little-endian two's complement integer of any width. An empty slice is zero.
+fn fixed_int(bytes: &[u8]) -> Integer {
+    if bytes.len() > 8 {
+        let value = BigInt::from_signed_bytes_le(bytes);
+        return match value.to_i64() {
+            Some(value) => Integer::I64(value),
+            None => Integer::BigInt(value),
+        };
+    }
+    let mut buffer = match bytes.last() {
+        Some(byte) if byte & 0x80 != 0 => [0xFFu8; 8],
+        _ => [0u8; 8],
+    };
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    Integer::I64(i64::from_le_bytes(buffer))
+}
+
+// Converts an IEEE-754 half precision float to an f64.
+fn f16_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1f64 } else { 1f64 };
+    let exponent = ((bits >> 10) & 0x1F) as i32;
+    let fraction = (bits & 0x3FF) as f64;
+    match exponent {
+        0 => sign * fraction * 2f64.powi(-24),
+        0x1F if fraction == 0.0 => sign * f64::INFINITY,
+        0x1F => f64::NAN,
+        _ => sign * (1.0 + fraction / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
+
+impl StreamReader for RawBinaryReader1_1 {
+    type Item = RawStreamItem;
+    type Symbol = RawSymbolToken;
+
+    fn ion_version(&self) -> (u8, u8) {
+        (1, 1)
+    }
+
+    fn next(&mut self) -> IonResult<RawStreamItem> {
+        let result = self.advance();
+        self.located(result)
+    }
+
+    fn current(&self) -> RawStreamItem {
+        self.current
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        self.value.as_ref().map(|value| value.ion_type)
+    }
+
+    fn annotation(&self, index: usize) -> IonResult<RawSymbolToken> {
+        let num_annotations = self.number_of_annotations();
+        if index >= num_annotations {
+            return illegal_operation(format!(
+                "annotation index {} is out of bounds; the current value has {} annotations",
+                index, num_annotations
+            ));
+        }
+        Ok(self.value.as_ref().unwrap().annotations[index].clone())
+    }
+
+    fn number_of_annotations(&self) -> usize {
+        self.value
+            .as_ref()
+            .map_or(0, |value| value.annotations.len())
+    }
+
+    fn field_name(&self) -> IonResult<RawSymbolToken> {
+        match self.value.as_ref().and_then(|value| value.field_name.as_ref()) {
+            Some(field_name) => Ok(field_name.clone()),
+            None => illegal_operation(format!(
+                "field_name() can only be called when the reader is positioned inside a struct; current parent: {:?}",
+                self.parent_type()
+            )),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(&self.value, Some(value) if value.is_null)
+    }
+
+    fn read_null(&mut self) -> IonResult<IonType> {
+        match self.current {
+            RawStreamItem::Null(ion_type) => Ok(ion_type),
+            _ => illegal_operation("the reader is not positioned on a null"),
+        }
+    }
+
+    fn read_bool(&mut self) -> IonResult<bool> {
+        Ok(self.expect_value(IonType::Boolean)?.opcode == 0x6E)
+    }
+
+    fn read_i64(&mut self) -> IonResult<i64> {
+        match self.read_integer()? {
+            Integer::I64(value) => Ok(value),
+            Integer::BigInt(_) => decoding_error("integer was too large to fit in an i64"),
+        }
+    }
+
+    fn read_integer(&mut self) -> IonResult<Integer> {
+        Ok(fixed_int(self.body(IonType::Integer)?))
+    }
+
+    fn read_f32(&mut self) -> IonResult<f32> {
+        Ok(self.read_f64()? as f32)
+    }
+
+    fn read_f64(&mut self) -> IonResult<f64> {
+        let body = self.body(IonType::Float)?;
+        Ok(match body.len() {
+            0 => 0f64,
+            2 => f16_to_f64(u16::from_le_bytes([body[0], body[1]])),
+            4 => f32::from_le_bytes(body.try_into().unwrap()) as f64,
+            _ => f64::from_le_bytes(body.try_into().unwrap()),
+        })
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Decimal> {
+        let body = self.expect_value(IonType::Decimal)?.body.clone();
+        if body.is_empty() {
+            return Ok(Decimal::new(0, 0));
+        }
+        let (exponent, exponent_length) = self.flex_int(body.start)?;
+        if body.start + exponent_length > body.end {
+            return decoding_error("decimal exponent extends beyond the end of the value");
+        }
+        let coefficient = match fixed_int(&self.data[body.start + exponent_length..body.end]) {
+            Integer::I64(value) => Coefficient::from(value),
+            Integer::BigInt(value) => {
+                let sign = match value.sign() {
+                    num_bigint::Sign::Minus => Sign::Negative,
+                    _ => Sign::Positive,
+                };
+                Coefficient::new(sign, value.magnitude().clone())
+            }
+        };
+        Ok(Decimal::new(coefficient, exponent))
+    }
+
+    fn read_string(&mut self) -> IonResult<String> {
+        self.read_str().map(String::from)
+    }
+
+    fn read_str(&mut self) -> IonResult<&str> {
+        utf8(self.body(IonType::String)?)
+    }
+
+    fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&str) -> U,
+    {
+        self.read_str().map(f)
+    }
+
+    fn map_string_bytes<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.body(IonType::String).map(f)
+    }
+
+    fn read_symbol(&mut self) -> IonResult<RawSymbolToken> {
+        let value = self.expect_value(IonType::Symbol)?;
+        let body = &self.data[value.body.clone()];
+        let symbol_id = match value.opcode {
+            0xE1 => body[0] as usize,
+            0xE2 => u16::from_le_bytes([body[0], body[1]]) as usize + 256,
+            0xE3 => self.flex_uint(value.body.start)?.0 + 65_792,
+            0xEE => match system_symbol(body[0])? {
+                FlexSym::Symbol(symbol) => return Ok(symbol),
+                FlexSym::End => unreachable!("system symbols are never end markers"),
+            },
+            _ => return Ok(RawSymbolToken::Text(utf8(body)?.to_owned())),
+        };
+        Ok(RawSymbolToken::SymbolId(symbol_id))
+    }
+
+    fn read_blob(&mut self) -> IonResult<Vec<u8>> {
+        self.map_blob(|bytes| bytes.to_vec())
+    }
+
+    fn map_blob<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.body(IonType::Blob).map(f)
+    }
+
+    fn read_clob(&mut self) -> IonResult<Vec<u8>> {
+        self.map_clob(|bytes| bytes.to_vec())
+    }
+
+    fn map_clob<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.body(IonType::Clob).map(f)
+    }
+
+    fn read_blob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        let body = self.body(IonType::Blob)?;
+        chunk_handler(body)?;
+        Ok(body.len())
+    }
+
+    fn read_clob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        let body = self.body(IonType::Clob)?;
+        chunk_handler(body)?;
+        Ok(body.len())
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
+        // Timestamps are rejected by `next()`, so the reader is never positioned on one.
+        illegal_operation("Ion 1.1 timestamps are not yet supported")
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        let value = match self.value.take() {
+            Some(value) if value.ion_type.is_container() && !value.is_null => value,
+            other => {
+                self.value = other;
+                return illegal_operation(format!(
+                    "cannot step into the current item: {:?}",
+                    self.current
+                ));
+            }
+        };
+        self.parents.push(Container {
+            ion_type: value.ion_type,
+            delimited: matches!(value.opcode, 0xF1..=0xF3),
+            flex_sym_fields: value.opcode == 0xF3,
+            body_end: value.body.end,
+            end: value.end,
+        });
+        self.offset = value.body.start;
+        self.current = RawStreamItem::Nothing;
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        let parent = match self.parents.pop() {
+            Some(parent) => parent,
+            None => return illegal_operation("Cannot step out of the top level."),
+        };
+        self.offset = parent.end;
+        self.value = None;
+        self.current = RawStreamItem::Nothing;
+        Ok(())
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        self.parents.last().map(|parent| parent.ion_type)
+    }
+
+    fn depth(&self) -> usize {
+        self.parents.len()
+    }
+
+    fn position(&self) -> Position {
+        let offset = match (&self.current, &self.value) {
+            (RawStreamItem::VersionMarker(_, _), _) => self.ivm_offset,
+            (_, Some(value)) => value.offset,
+            _ => self.offset,
+        };
+        Position::with_offset(offset)
+    }
+
+    fn value_end_offset(&self) -> Option<usize> {
+        self.value.as_ref().map(|value| value.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw_reader::RawStreamItem::*;
+
+    fn reader(body: &[u8]) -> RawBinaryReader1_1 {
+        let mut data = IVM.to_vec();
+        data.extend_from_slice(body);
+        let mut reader = RawBinaryReader1_1::new(data);
+        assert_eq!(reader.next(), Ok(VersionMarker(1, 1)));
+        reader
+    }
+
+    #[test]
+    fn read_scalars() -> IonResult<()> {
+        let mut reader = reader(&[
+            0xEA, // null.null
+            0xEB, 0x01, // null.int
+            0x6E, // true
+            0x60, // 0
+            0x62, 0x18, 0xFC, // -1000
+            0x6A, // 0e0
+            0x6B, 0x00, 0x3C, // 1.0 (half precision)
+            0x6C, 0x00, 0x00, 0x20, 0x40, // 2.5 (single precision)
+            0x72, 0xFD, 0x19, // 25d-2
+            0x93, 0x66, 0x6F, 0x6F, // "foo"
+            0xA3, 0x62, 0x61, 0x72, // bar
+            0xE1, 0x04, // $4
+            0xEE, 0x05, // system symbol $5
+            0xFE, 0x05, 0x68, 0x69, // {{aGk=}}
+        ]);
+        assert_eq!(reader.next()?, Null(IonType::Null));
+        assert_eq!(reader.next()?, Null(IonType::Integer));
+        assert_eq!(reader.next()?, Value(IonType::Boolean));
+        assert!(reader.read_bool()?);
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 0);
+        reader.next()?;
+        assert_eq!(reader.read_i64()?, -1000);
+        assert_eq!(reader.next()?, Value(IonType::Float));
+        assert_eq!(reader.read_f64()?, 0.0);
+        reader.next()?;
+        assert_eq!(reader.read_f64()?, 1.0);
+        reader.next()?;
+        assert_eq!(reader.read_f64()?, 2.5);
+        assert_eq!(reader.next()?, Value(IonType::Decimal));
+        assert_eq!(reader.read_decimal()?, Decimal::new(25, -2));
+        assert_eq!(reader.next()?, Value(IonType::String));
+        assert_eq!(reader.read_str()?, "foo");
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(
+            reader.read_symbol()?,
+            RawSymbolToken::Text("bar".to_string())
+        );
+        reader.next()?;
+        assert_eq!(reader.read_symbol()?, RawSymbolToken::SymbolId(4));
+        reader.next()?;
+        assert_eq!(reader.read_symbol()?, RawSymbolToken::SymbolId(5));
+        assert_eq!(reader.next()?, Value(IonType::Blob));
+        assert_eq!(reader.read_blob()?, b"hi".to_vec());
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn read_annotations() -> IonResult<()> {
+        let mut reader = reader(&[
+            0xE5, 0x09, 0x0B, // $4::$5::
+            0x61, 0x07, // 7
+            0xE8, 0xFF, 0x61, 0x0B, // a::$5::
+            0x61, 0x08, // 8
+        ]);
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.annotation(0)?, RawSymbolToken::SymbolId(4));
+        assert_eq!(reader.annotation(1)?, RawSymbolToken::SymbolId(5));
+        assert_eq!(reader.read_i64()?, 7);
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        let annotations: Vec<RawSymbolToken> = reader.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(
+            annotations,
+            vec![
+                RawSymbolToken::Text("a".to_string()),
+                RawSymbolToken::SymbolId(5)
+            ]
+        );
+        assert_eq!(reader.read_i64()?, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn read_containers() -> IonResult<()> {
+        let mut reader = reader(&[
+            0xF1, // [
+            0x61, 0x01, // 1,
+            0xF2, 0x61, 0x02, 0xF0, // (2),
+            0xEC, // NOP
+            0xB2, 0x61, 0x03, // [3]
+            0xF0, // ]
+            0xD8, // { (symbol ID field names)
+            0x09, 0x61, 0x04, // $4: 4,
+            0x01, 0xFF, 0x61, 0x61, 0x05, // a: 5
+            0xF3, // { (FlexSym field names)
+            0xFF, 0x62, 0xEC, // b: NOP (omitted)
+            0xFF, 0x63, 0xE1, 0x04, // c: $4
+            0x01, 0xF0, // }
+            0x6F, // false
+        ]);
+        assert_eq!(reader.next()?, Value(IonType::List));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.next()?, Value(IonType::SExpression));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 2);
+        assert_eq!(reader.next()?, Nothing);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Value(IonType::List));
+        assert_eq!(reader.next()?, Nothing);
+        reader.step_out()?;
+
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.field_name()?, RawSymbolToken::SymbolId(4));
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.field_name()?, RawSymbolToken::Text("a".to_string()));
+        assert_eq!(reader.read_i64()?, 5);
+        assert_eq!(reader.next()?, Nothing);
+        reader.step_out()?;
+
+        // Skip over the delimited struct without stepping into it.
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        assert_eq!(reader.next()?, Value(IonType::Boolean));
+        assert!(!reader.read_bool()?);
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn read_delimited_struct() -> IonResult<()> {
+        let mut reader = reader(&[0xF3, 0xFF, 0x62, 0xEC, 0xFF, 0x63, 0xE1, 0x04, 0x01, 0xF0]);
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.field_name()?, RawSymbolToken::Text("c".to_string()));
+        assert_eq!(reader.next()?, Nothing);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_encodings() {
+        for body in [
+            &[0x80, 0x35][..], // short timestamp
+            &[0x00],           // macro invocation
+            &[0x69],           // reserved opcode
+            &[0xF1, 0x61],     // unterminated list
+        ] {
+            assert!(reader(body).next().is_err(), "{:02X?}", body);
+        }
+        let mut reader = reader(&[0xE0, 0x01, 0x00, 0xEA]);
+        assert!(reader.next().is_err());
+    }
+}
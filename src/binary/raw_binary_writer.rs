@@ -14,7 +14,7 @@ use crate::result::{illegal_operation, IonResult};
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::{ContainerType, SymbolId};
-use crate::writer::Writer;
+use crate::writer::{Writer, WriterLimits};
 use crate::{Integer, IonType};
 
 use super::decimal::DecimalBinaryEncoder;
@@ -22,12 +22,20 @@ use super::timestamp::TimestampBinaryEncoder;
 use super::uint;
 
 pub struct RawBinaryWriterBuilder {
-    // Nothing yet
+    limits: WriterLimits,
 }
 
 impl RawBinaryWriterBuilder {
     pub fn new() -> Self {
-        RawBinaryWriterBuilder {}
+        RawBinaryWriterBuilder {
+            limits: WriterLimits::default(),
+        }
+    }
+
+    /// Sets the limits that the writer will enforce on the data it is asked to write.
+    pub fn with_limits(mut self, limits: WriterLimits) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Creates a new RawBinaryWriter that will write its encoded output to the provided
@@ -48,6 +56,7 @@ impl RawBinaryWriterBuilder {
             num_annotations_current_value: 0,
             field_id: None,
             contiguous_encoding: Vec::with_capacity(INITIAL_ENCODING_BUFFER_CAPACITY),
+            limits: self.limits,
         };
 
         // Currently, this method cannot fail. However, the other builder APIs return an
@@ -116,6 +125,10 @@ struct EncodingLevel {
     // Index of the IoRange for this container's type descriptor and length. When the writer
     // steps out of this level, the type descriptor IoRange will be retrieved and populated.
     td_io_range_index: usize,
+    // The number of bytes that have been encoded in this level so far, including the headers and
+    // annotations of nested containers. Keeping a running total means that stepping out of a
+    // container does not require revisiting the IoRanges of everything nested inside of it.
+    encoded_length: usize,
 }
 
 impl EncodingLevel {
@@ -130,17 +143,9 @@ impl EncodingLevel {
             field_id,
             num_annotations,
             td_io_range_index,
+            encoded_length: 0,
         }
     }
-
-    // Visits all of the IoRanges belonging to this EncodingLevel and notes their total length.
-    // This length will be written out as a length prefix for the container.
-    fn calculate_final_size(&self, io_ranges: &mut [Range<usize>]) -> usize {
-        io_ranges[self.td_io_range_index..]
-            .iter()
-            .map(|r| r.len())
-            .sum()
-    }
 }
 
 /// A system-level streaming binary Ion writer. This writer does not provide symbol table
@@ -170,6 +175,7 @@ pub struct RawBinaryWriter<W: Write> {
     // Scratch space for the flush() method to rearrange the contents of `buffer` before writing
     // the data to `out`.
     contiguous_encoding: Vec<u8>,
+    limits: WriterLimits,
 }
 
 // The largest possible 'L' (length) value that can be written directly in a type descriptor byte.
@@ -220,6 +226,14 @@ impl<W: Write> RawBinaryWriter<W> {
             .last_mut()
             .expect("io_ranges unexpectedly empty.");
         last_range.end += number_of_bytes;
+        self.add_encoded_length(number_of_bytes);
+    }
+
+    // Adds `number_of_bytes` to the encoded length of the current level.
+    #[inline]
+    fn add_encoded_length(&mut self, number_of_bytes: usize) {
+        // `self.levels` always has at least one value: the top level.
+        self.levels.last_mut().unwrap().encoded_length += number_of_bytes;
     }
 
     // Handles before-and-after tasks common to writing all non-container values, like encoding
@@ -228,6 +242,7 @@ impl<W: Write> RawBinaryWriter<W> {
         &mut self,
         mut write_fn: impl FnMut(&mut Vec<u8>) -> IonResult<()>,
     ) -> IonResult<()> {
+        self.check_annotations_limit()?;
         // If we're in a struct, encode the field ID first.
         if self.is_in_struct() {
             let field_id = self.expect_field_id()? as u64;
@@ -271,6 +286,13 @@ impl<W: Write> RawBinaryWriter<W> {
             value_io_range.len(),
         )?;
 
+        self.add_encoded_length(
+            header_io_range.len()
+                + annotations_seq_length_io_range.len()
+                + annotations_seq_io_range.len()
+                + value_io_range.len(),
+        );
+
         // Push the IO ranges in the correct order so the encoded bytes will be written in the
         // correct order when the user calls `flush()`.
         self.io_ranges.extend_from_slice(&[
@@ -374,6 +396,18 @@ impl<W: Write> RawBinaryWriter<W> {
         }
     }
 
+    // Returns an error (and discards the annotations) if the current value has more annotations
+    // than the writer's limits allow.
+    fn check_annotations_limit(&mut self) -> IonResult<()> {
+        let result = self
+            .limits
+            .check_annotations(self.num_annotations_current_value as usize);
+        if result.is_err() {
+            self.clear_annotations();
+        }
+        result
+    }
+
     #[inline]
     pub fn has_annotations(&self) -> bool {
         self.num_annotations_current_value > 0
@@ -437,12 +471,13 @@ impl<W: Write> RawBinaryWriter<W> {
     }
 
     // When step_out() is called and the container has been written, this function uses the encoded
-    // length to write the container's annotations wrapper.
+    // length to write the container's annotations wrapper. Returns the length of the wrapper, not
+    // including the container itself.
     fn encode_container_annotations(
         &mut self,
         td_io_range_index: usize,
         container_size: usize,
-    ) -> IonResult<()> {
+    ) -> IonResult<usize> {
         // Create IoRanges that will ultimately point to the encoded components of the annotations
         // wrapper for the value.
         let mut header_io_range: Range<usize> = 0..0;
@@ -457,6 +492,10 @@ impl<W: Write> RawBinaryWriter<W> {
             container_size,
         )?;
 
+        let wrapper_length = header_io_range.len()
+            + annotations_seq_length_io_range.len()
+            + annotations_seq_io_range.len();
+
         // Populate each of the reserved annotation IO ranges using the results from above.
         let header_io_range_index = td_io_range_index - IO_RANGES_PER_ANNOTATION_WRAPPER;
         let _ = mem::replace(&mut self.io_ranges[header_io_range_index], header_io_range);
@@ -473,7 +512,7 @@ impl<W: Write> RawBinaryWriter<W> {
             annotations_seq_io_range,
         );
 
-        Ok(())
+        Ok(wrapper_length)
     }
 
     /// Returns a reference to the underlying io::Write implementation.
@@ -488,6 +527,33 @@ impl<W: Write> RawBinaryWriter<W> {
         &mut self.out
     }
 
+    // Consumes the writer, returning its io::Write implementation. Any data that has not been
+    // flushed is discarded.
+    pub(crate) fn into_output(self) -> W {
+        self.out
+    }
+
+    // Replaces the writer's scratch buffers with `buffer` and `contiguous_encoding` so that their
+    // existing capacity can be reused. This must be called before anything has been written.
+    pub(crate) fn set_scratch_buffers(
+        &mut self,
+        mut buffer: Vec<u8>,
+        mut contiguous_encoding: Vec<u8>,
+    ) {
+        buffer.clear();
+        contiguous_encoding.clear();
+        self.buffer = buffer;
+        self.contiguous_encoding = contiguous_encoding;
+    }
+
+    // Removes the writer's scratch buffers, leaving it unable to write anything else.
+    pub(crate) fn take_scratch_buffers(&mut self) -> [Vec<u8>; 2] {
+        [
+            mem::take(&mut self.buffer),
+            mem::take(&mut self.contiguous_encoding),
+        ]
+    }
+
     fn reserve_io_ranges_for_annotations(&mut self) {
         // Annotations type descriptor and wrapper length
         self.push_empty_io_range();
@@ -690,6 +756,7 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
     }
 
     fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        self.limits.check_value_length(value.as_ref().len())?;
         self.write_scalar(|enc_buffer| {
             let text: &str = value.as_ref();
             let encoded_length = text.len(); // The number of utf8 bytes
@@ -709,6 +776,7 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
     }
 
     fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        self.limits.check_value_length(value.as_ref().len())?;
         self.write_scalar(|enc_buffer| {
             let bytes: &[u8] = value.as_ref();
             // The clob type descriptor's high nibble is type code 9
@@ -717,6 +785,7 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
     }
 
     fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        self.limits.check_value_length(value.as_ref().len())?;
         self.write_scalar(|enc_buffer| {
             let bytes: &[u8] = value.as_ref();
             // The blob type descriptor's high nibble is type code 10
@@ -734,6 +803,8 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
             Struct => ContainerType::Struct,
             _ => return illegal_operation("Cannot step into a scalar Ion type."),
         };
+        self.limits.check_depth(self.depth() + 1)?;
+        self.check_annotations_limit()?;
 
         // If this is a field in a struct, encode the field ID at the end of the last IO range.
         if self.is_in_struct() {
@@ -809,7 +880,7 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
         let container = self.levels.pop().unwrap();
         self.num_annotations_current_value = container.num_annotations;
         self.field_id = container.field_id;
-        let container_size = container.calculate_final_size(&mut self.io_ranges);
+        let container_size = container.encoded_length;
 
         use crate::types::ContainerType::*;
         let mut type_descriptor: u8 = match container.container_type {
@@ -847,9 +918,12 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
 
         // If this container had annotations, retrieve the IO ranges that were reserved to store
         // them and use them to encode the annotations wrapper.
+        let mut encoded_length = container_size;
         if container.num_annotations > 0 {
-            self.encode_container_annotations(container.td_io_range_index, container_size)?;
+            encoded_length +=
+                self.encode_container_annotations(container.td_io_range_index, container_size)?;
         }
+        self.add_encoded_length(encoded_length);
 
         // Create an empty IO Range that will hold the bytes of any scalar values that will follow
         // now that we've stepped out.
@@ -871,8 +945,10 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
         // Instead, its io_range represents the bytes of any leading scalar values.
 
         // For each io_range in order, copy the specified bytes into a contiguous buffer that
-        // we'll write to output.
-
+        // we'll write to output. The top level's encoded length is the size of that buffer.
+        let top_level = &mut self.levels[0];
+        self.contiguous_encoding
+            .reserve(mem::take(&mut top_level.encoded_length));
         for io_range in self.io_ranges.drain(..) {
             self.contiguous_encoding
                 .extend_from_slice(&self.buffer[io_range]);
@@ -887,6 +963,8 @@ impl<'a, W: Write> Writer for RawBinaryWriter<W> {
         self.out.write_all(self.contiguous_encoding.as_slice())?;
 
         self.contiguous_encoding.clear();
+        // Everything in the encoding buffer has been written, so it can be reused.
+        self.buffer.clear();
         self.push_empty_io_range();
 
         Ok(())
@@ -1477,4 +1555,30 @@ mod writer_tests {
             },
         )
     }
+
+    #[test]
+    fn binary_writer_annotated_nested_containers() -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = RawBinaryWriterBuilder::new().build(&mut buffer)?;
+        for _ in 0..2 {
+            // $10::[$11::[1], 2]
+            writer.add_annotation(10);
+            writer.step_in(IonType::List)?;
+            writer.add_annotation(11);
+            writer.step_in(IonType::List)?;
+            writer.write_i64(1)?;
+            writer.step_out()?;
+            writer.write_i64(2)?;
+            writer.step_out()?;
+            writer.flush()?;
+            // Flushing makes the encoding buffer available for reuse.
+            assert!(writer.buffer.is_empty());
+        }
+        drop(writer);
+        let expected: &[u8] = &[
+            0xEB, 0x81, 0x8A, 0xB8, 0xE5, 0x81, 0x8B, 0xB2, 0x21, 0x01, 0x21, 0x02,
+        ];
+        assert_eq!(buffer, [expected, expected].concat());
+        Ok(())
+    }
 }
@@ -0,0 +1,108 @@
+use std::sync::{Arc, Mutex};
+
+/// A thread-safe pool of byte buffers that readers and writers can reuse instead of allocating
+/// new ones. Cloning a `BufferPool` produces another handle to the same pool.
+///
+/// Services that encode or decode one message per request can share a pool between requests so
+/// that the scratch space used for each message keeps its capacity from one request to the
+/// next. A [BinaryWriter](crate::BinaryWriter) built with
+/// [BinaryWriterBuilder::with_buffer_pool](crate::BinaryWriterBuilder::with_buffer_pool) takes its
+/// encoding buffers from the pool and returns them when
+/// [BinaryWriter::finish](crate::BinaryWriter::finish) is called. Buffers can also be taken
+/// explicitly, for example to hold a message while it is being read:
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{BufferPool, ReaderBuilder, StreamReader};
+/// use std::io::Read;
+///
+/// let pool = BufferPool::new(8);
+/// let mut message = pool.take();
+/// "{greeting: \"hello\"}".as_bytes().read_to_end(&mut message)?;
+/// let mut reader = ReaderBuilder::new().build(message.as_slice())?;
+/// reader.next()?;
+/// drop(reader);
+/// pool.put(message);
+/// assert_eq!(pool.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BufferPool {
+    buffers: Arc<Mutex<Vec<Vec<u8>>>>,
+    max_buffers: usize,
+}
+
+impl BufferPool {
+    /// Constructs an empty pool that will retain at most `max_buffers` idle buffers. Buffers
+    /// returned to a full pool are freed.
+    pub fn new(max_buffers: usize) -> BufferPool {
+        BufferPool {
+            buffers: Arc::new(Mutex::new(Vec::new())),
+            max_buffers,
+        }
+    }
+
+    /// Removes an empty buffer from the pool, allocating a new one if the pool has none.
+    pub fn take(&self) -> Vec<u8> {
+        self.lock().pop().unwrap_or_default()
+    }
+
+    /// Clears `buffer` and returns it to the pool so that its capacity can be reused.
+    pub fn put(&self, mut buffer: Vec<u8>) {
+        // Buffers that never allocated have nothing to offer.
+        if buffer.capacity() == 0 {
+            return;
+        }
+        buffer.clear();
+        let mut buffers = self.lock();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+
+    /// Returns the number of idle buffers in the pool.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the pool has no idle buffers.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Vec<u8>>> {
+        // The pool's contents are always valid, so a panic in another thread while it held the
+        // lock does not prevent the pool from being used.
+        self.buffers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for BufferPool {
+    fn default() -> Self {
+        BufferPool::new(64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffers_are_reused() {
+        let pool = BufferPool::new(1);
+        let mut buffer = pool.take();
+        buffer.extend_from_slice(b"hello");
+        let capacity = buffer.capacity();
+        pool.put(buffer);
+        pool.put(Vec::with_capacity(16));
+        assert_eq!(pool.len(), 1);
+
+        let buffer = pool.clone().take();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.capacity(), capacity);
+        assert!(pool.is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::result::{decoding_error, IonResult};
+use crate::shared_symbol_table::SharedSymbolTable;
+
+/// A collection of [SharedSymbolTable]s that readers consult when a stream's local symbol table
+/// imports a shared table by name and version.
+pub trait Catalog {
+    /// Returns the shared symbol table with the given name and the highest version available,
+    /// if any.
+    fn get_table(&self, name: &str) -> Option<Rc<SharedSymbolTable>>;
+
+    /// Returns the shared symbol table with the given name and exactly the given version, if any.
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<Rc<SharedSymbolTable>>;
+}
+
+// Allows a single catalog to be shared by several readers.
+impl<C: Catalog + ?Sized> Catalog for Rc<C> {
+    fn get_table(&self, name: &str) -> Option<Rc<SharedSymbolTable>> {
+        (**self).get_table(name)
+    }
+
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<Rc<SharedSymbolTable>> {
+        (**self).get_table_with_version(name, version)
+    }
+}
+
+/// A [Catalog] that stores its shared symbol tables in memory.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{Catalog, MapCatalog, SharedSymbolTable};
+///
+/// let mut catalog = MapCatalog::new();
+/// catalog.insert_table(SharedSymbolTable::new("fruit", 1, vec![Some("apple".into())])?);
+/// catalog.insert_table(SharedSymbolTable::new("fruit", 2, vec![Some("banana".into())])?);
+/// assert_eq!(catalog.get_table("fruit").unwrap().version(), 2);
+/// assert_eq!(catalog.get_table_with_version("fruit", 1).unwrap().version(), 1);
+/// assert!(catalog.get_table_with_version("fruit", 3).is_none());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MapCatalog {
+    tables_by_name: HashMap<String, BTreeMap<usize, Rc<SharedSymbolTable>>>,
+}
+
+impl MapCatalog {
+    pub fn new() -> MapCatalog {
+        MapCatalog::default()
+    }
+
+    /// Adds `table` to the catalog, replacing any table that has the same name and version.
+    pub fn insert_table(&mut self, table: SharedSymbolTable) {
+        self.tables_by_name
+            .entry(table.name().to_owned())
+            .or_default()
+            .insert(table.version(), Rc::new(table));
+    }
+
+    /// Adds `table` to the catalog and returns it, allowing calls to be chained.
+    pub fn with_table(mut self, table: SharedSymbolTable) -> MapCatalog {
+        self.insert_table(table);
+        self
+    }
+
+    /// Removes the table with the given name and version from the catalog, returning it if it
+    /// was present.
+    pub fn remove_table(&mut self, name: &str, version: usize) -> Option<Rc<SharedSymbolTable>> {
+        let versions = self.tables_by_name.get_mut(name)?;
+        let table = versions.remove(&version);
+        if versions.is_empty() {
+            self.tables_by_name.remove(name);
+        }
+        table
+    }
+
+    /// Returns the number of tables (counting each version separately) in the catalog.
+    pub fn len(&self) -> usize {
+        self.tables_by_name.values().map(BTreeMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables_by_name.is_empty()
+    }
+}
+
+impl Catalog for MapCatalog {
+    fn get_table(&self, name: &str) -> Option<Rc<SharedSymbolTable>> {
+        let (_version, table) = self.tables_by_name.get(name)?.iter().next_back()?;
+        Some(table.clone())
+    }
+
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<Rc<SharedSymbolTable>> {
+        self.tables_by_name.get(name)?.get(&version).cloned()
+    }
+}
+
+// The extension of the files that a FileSystemCatalog loads shared symbol tables from.
+const TABLE_FILE_EXTENSION: &str = ".ion";
+
+/// A [Catalog] that loads shared symbol tables from a directory of Ion files.
+///
+/// Each version of a table is stored in its own file named `<name>.<version>.ion` (for example,
+/// `com.example.fruit.2.ion`), which may hold either text or binary Ion. See
+/// [SharedSymbolTable::from_ion] for the expected contents. Tables are loaded the first time they
+/// are requested and cached for the lifetime of the catalog.
+///
+/// The [Catalog] methods treat a table that cannot be loaded as missing; use
+/// [FileSystemCatalog::load_table] to see why a table could not be loaded.
+#[derive(Debug)]
+pub struct FileSystemCatalog {
+    directory: PathBuf,
+    tables: RefCell<HashMap<(String, usize), Rc<SharedSymbolTable>>>,
+}
+
+impl FileSystemCatalog {
+    pub fn new<P: Into<PathBuf>>(directory: P) -> FileSystemCatalog {
+        FileSystemCatalog {
+            directory: directory.into(),
+            tables: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the path of the file that holds the given version of the named table.
+    pub fn path_for(&self, name: &str, version: usize) -> PathBuf {
+        self.directory
+            .join(format!("{}.{}{}", name, version, TABLE_FILE_EXTENSION))
+    }
+
+    /// Loads the given version of the named table, returning `Ok(None)` if the directory does
+    /// not contain a file for it. Returns an error if the file could not be read or did not
+    /// contain a shared symbol table with the expected name and version.
+    pub fn load_table(
+        &self,
+        name: &str,
+        version: usize,
+    ) -> IonResult<Option<Rc<SharedSymbolTable>>> {
+        let key = (name.to_owned(), version);
+        if let Some(table) = self.tables.borrow().get(&key) {
+            return Ok(Some(table.clone()));
+        }
+        let path = self.path_for(name, version);
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        let table = SharedSymbolTable::from_ion(data)?;
+        if table.name() != name || table.version() != version {
+            return decoding_error(format!(
+                "{} contained version {} of shared symbol table '{}'",
+                path.display(),
+                table.version(),
+                table.name()
+            ));
+        }
+        let table = Rc::new(table);
+        self.tables.borrow_mut().insert(key, table.clone());
+        Ok(Some(table))
+    }
+
+    /// Returns the versions of the named table that the directory contains files for, in
+    /// ascending order.
+    pub fn versions(&self, name: &str) -> IonResult<Vec<usize>> {
+        let prefix = format!("{}.", name);
+        let mut versions = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let file_name = entry?.file_name();
+            let version = file_name
+                .to_str()
+                .and_then(|file_name| file_name.strip_prefix(&prefix))
+                .and_then(|rest| rest.strip_suffix(TABLE_FILE_EXTENSION))
+                .and_then(|version| version.parse::<usize>().ok());
+            match version {
+                Some(version) if version > 0 => versions.push(version),
+                _ => {}
+            }
+        }
+        versions.sort_unstable();
+        Ok(versions)
+    }
+}
+
+impl Catalog for FileSystemCatalog {
+    fn get_table(&self, name: &str) -> Option<Rc<SharedSymbolTable>> {
+        let versions = self.versions(name).ok()?;
+        versions
+            .into_iter()
+            .rev()
+            .find_map(|version| self.load_table(name, version).ok().flatten())
+    }
+
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<Rc<SharedSymbolTable>> {
+        self.load_table(name, version).ok().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::IonResult;
+
+    fn table(name: &str, version: usize) -> IonResult<SharedSymbolTable> {
+        SharedSymbolTable::new(name, version, vec![Some(format!("{}{}", name, version))])
+    }
+
+    #[test]
+    fn map_catalog_lookups() -> IonResult<()> {
+        let mut catalog = MapCatalog::new()
+            .with_table(table("fruit", 2)?)
+            .with_table(table("fruit", 10)?)
+            .with_table(table("vegetables", 1)?);
+        assert_eq!(catalog.len(), 3);
+        assert_eq!(catalog.get_table("fruit").unwrap().version(), 10);
+        assert_eq!(
+            catalog
+                .get_table_with_version("fruit", 2)
+                .unwrap()
+                .symbols(),
+            &[Some("fruit2".to_string())]
+        );
+        assert!(catalog.get_table_with_version("fruit", 3).is_none());
+        assert!(catalog.get_table("grains").is_none());
+
+        assert!(catalog.remove_table("fruit", 10).is_some());
+        assert_eq!(catalog.get_table("fruit").unwrap().version(), 2);
+        assert!(catalog.remove_table("fruit", 2).is_some());
+        assert!(catalog.get_table("fruit").is_none());
+        assert!(catalog.remove_table("vegetables", 1).is_some());
+        assert!(catalog.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn file_system_catalog() -> IonResult<()> {
+        let directory = std::env::temp_dir().join(format!("ion-rs-catalog-{}", std::process::id()));
+        fs::create_dir_all(&directory)?;
+        let write_table = |file_name: &str, ion: &str| fs::write(directory.join(file_name), ion);
+        write_table(
+            "fruit.1.ion",
+            r#"$ion_shared_symbol_table::{name: "fruit", version: 1, symbols: ["apple"]}"#,
+        )?;
+        write_table(
+            "fruit.2.ion",
+            r#"$ion_shared_symbol_table::{name: "fruit", version: 2, symbols: ["banana"]}"#,
+        )?;
+        write_table(
+            "fruit.3.ion",
+            r#"$ion_shared_symbol_table::{name: "vegetables", version: 3}"#,
+        )?;
+        write_table("fruit.salad.ion", "")?;
+
+        let catalog = FileSystemCatalog::new(&directory);
+        assert_eq!(catalog.versions("fruit")?, vec![1, 2, 3]);
+        // Version 3's file is mislabeled, so the catalog falls back to version 2.
+        assert!(catalog.load_table("fruit", 3).is_err());
+        assert_eq!(catalog.get_table("fruit").unwrap().version(), 2);
+        let apple = catalog.get_table_with_version("fruit", 1).unwrap();
+        assert_eq!(apple.symbols(), &[Some("apple".to_string())]);
+        assert!(catalog.load_table("fruit", 4)?.is_none());
+        assert!(catalog.get_table("grains").is_none());
+
+        // Tables that have already been loaded are cached.
+        fs::remove_dir_all(&directory)?;
+        assert!(Rc::ptr_eq(
+            &catalog.get_table_with_version("fruit", 1).unwrap(),
+            &apple
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,423 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::ion_eq::IonEq;
+use crate::result::IonResult;
+use crate::value::native_writer::write_element;
+use crate::value::owned::OwnedElement;
+use crate::value::reader::{native_element_reader, ElementReader};
+use crate::value::{Element, Sequence, SymbolToken};
+use crate::writer::Writer;
+use crate::{BinaryWriterBuilder, TextWriterBuilder};
+
+/// The kind of check that a [ConformanceRunner] performs on a test file, which is determined by
+/// the directory that contains it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TestCategory {
+    /// Files in `good/` must be read successfully, and must survive a round trip through the text
+    /// and binary writers.
+    Good,
+    /// Files in `bad/` must fail to be read.
+    Bad,
+    /// Each top-level sequence in a file in `good/equivs/` contains values that must all be
+    /// equivalent to one another.
+    Equivs,
+    /// Each top-level sequence in a file in `good/non-equivs/` contains values that must each be
+    /// equivalent only to themselves.
+    NonEquivs,
+}
+
+/// The outcome of checking a single test file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestOutcome {
+    Passed,
+    /// The file did not meet the expectations of its [TestCategory]. The message describes why.
+    Failed(String),
+    /// The file was on the runner's skip list.
+    Skipped,
+}
+
+/// The result of checking a single test file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    path: PathBuf,
+    category: TestCategory,
+    outcome: TestOutcome,
+}
+
+impl TestResult {
+    /// The path of the test file, relative to the runner's root directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn category(&self) -> TestCategory {
+        self.category
+    }
+
+    pub fn outcome(&self) -> &TestOutcome {
+        &self.outcome
+    }
+}
+
+/// The results of a [ConformanceRunner::run], ordered by path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConformanceReport {
+    results: Vec<TestResult>,
+}
+
+impl ConformanceReport {
+    pub fn results(&self) -> &[TestResult] {
+        &self.results
+    }
+
+    pub fn num_passed(&self) -> usize {
+        self.count(|outcome| *outcome == TestOutcome::Passed)
+    }
+
+    pub fn num_failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, TestOutcome::Failed(_)))
+    }
+
+    pub fn num_skipped(&self) -> usize {
+        self.count(|outcome| *outcome == TestOutcome::Skipped)
+    }
+
+    /// Returns the results of the files that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &TestResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.outcome, TestOutcome::Failed(_)))
+    }
+
+    /// Returns `true` if no files failed.
+    pub fn is_success(&self) -> bool {
+        self.num_failed() == 0
+    }
+
+    fn count<F: Fn(&TestOutcome) -> bool>(&self, predicate: F) -> usize {
+        self.results
+            .iter()
+            .filter(|result| predicate(&result.outcome))
+            .count()
+    }
+}
+
+impl Display for ConformanceReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for result in self.failures() {
+            if let TestOutcome::Failed(message) = &result.outcome {
+                writeln!(
+                    f,
+                    "FAILED {} ({:?}): {}",
+                    result.path.display(),
+                    result.category,
+                    message
+                )?;
+            }
+        }
+        write!(
+            f,
+            "{} passed, {} failed, {} skipped",
+            self.num_passed(),
+            self.num_failed(),
+            self.num_skipped()
+        )
+    }
+}
+
+/// Runs the test vectors from the [ion-tests](https://github.com/amazon-ion/ion-tests) suite
+/// against this crate's native reader and writers.
+///
+/// The runner is given the suite's `iontestdata` directory, and checks every `.ion` and `.10n`
+/// file in its `good/` and `bad/` subdirectories as described by [TestCategory]. Files are
+/// checked independently; a reader or writer that panics on a file causes that file to fail
+/// rather than ending the run.
+///
+/// ```no_run
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::ConformanceRunner;
+///
+/// let report = ConformanceRunner::new("ion-tests/iontestdata")
+///     .with_skip_list(["good/utf16.ion", "good/utf32.ion"])
+///     .run()?;
+/// println!("{}", report);
+/// assert!(report.is_success());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConformanceRunner {
+    root: PathBuf,
+    skip_list: Vec<PathBuf>,
+}
+
+impl ConformanceRunner {
+    pub fn new<P: Into<PathBuf>>(root: P) -> ConformanceRunner {
+        ConformanceRunner {
+            root: root.into(),
+            skip_list: Vec::new(),
+        }
+    }
+
+    /// Adds files that should not be checked, specified as paths relative to the root directory.
+    /// Skipped files are still included in the report.
+    pub fn with_skip_list<I, P>(mut self, paths: I) -> ConformanceRunner
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        self.skip_list.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Checks every test file, returning an error only if the test files could not be listed.
+    pub fn run(&self) -> IonResult<ConformanceReport> {
+        let mut paths = Vec::new();
+        for directory in ["good", "bad"] {
+            collect_test_files(&self.root, Path::new(directory), &mut paths)?;
+        }
+        paths.sort();
+
+        let results = paths
+            .into_iter()
+            .map(|path| {
+                let category = category_for(&path);
+                let outcome = if self.skip_list.contains(&path) {
+                    TestOutcome::Skipped
+                } else {
+                    check_file(&self.root.join(&path), category)
+                };
+                TestResult {
+                    path,
+                    category,
+                    outcome,
+                }
+            })
+            .collect();
+        Ok(ConformanceReport { results })
+    }
+}
+
+// Adds the paths (relative to `root`) of the test files in `directory` and its subdirectories.
+fn collect_test_files(root: &Path, directory: &Path, paths: &mut Vec<PathBuf>) -> IonResult<()> {
+    let full_path = root.join(directory);
+    if !full_path.is_dir() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(full_path)? {
+        let path = directory.join(entry?.file_name());
+        if root.join(&path).is_dir() {
+            collect_test_files(root, &path, paths)?;
+        } else if matches!(
+            path.extension().and_then(|extension| extension.to_str()),
+            Some("ion" | "10n")
+        ) {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn category_for(path: &Path) -> TestCategory {
+    if path.starts_with("bad") {
+        TestCategory::Bad
+    } else if path.starts_with("good/equivs") {
+        TestCategory::Equivs
+    } else if path.starts_with("good/non-equivs") {
+        TestCategory::NonEquivs
+    } else {
+        TestCategory::Good
+    }
+}
+
+fn check_file(path: &Path, category: TestCategory) -> TestOutcome {
+    let data = match fs::read(path) {
+        Ok(data) => data,
+        Err(error) => return TestOutcome::Failed(format!("could not read the file: {}", error)),
+    };
+    let result = catch_unwind(AssertUnwindSafe(|| match category {
+        TestCategory::Good => check_good(&data),
+        TestCategory::Bad => check_bad(&data),
+        TestCategory::Equivs => check_groups(&data, true),
+        TestCategory::NonEquivs => check_groups(&data, false),
+    }));
+    match result {
+        Ok(Ok(())) => TestOutcome::Passed,
+        Ok(Err(message)) => TestOutcome::Failed(message),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            TestOutcome::Failed(format!("panicked: {}", message))
+        }
+    }
+}
+
+fn read_all(data: &[u8]) -> Result<Vec<OwnedElement>, String> {
+    native_element_reader()
+        .read_all(data)
+        .map_err(|error| format!("could not read the file: {}", error))
+}
+
+fn check_good(data: &[u8]) -> Result<(), String> {
+    let elements = read_all(data)?;
+    let text = round_trip(&elements, false)
+        .map_err(|error| format!("text round trip failed: {}", error))?;
+    let binary =
+        round_trip(&text, true).map_err(|error| format!("binary round trip failed: {}", error))?;
+    if !elements.ion_eq(&binary) {
+        return Err("values changed after round trips through text and binary".to_string());
+    }
+    Ok(())
+}
+
+// Writes `elements` as text or binary Ion, then reads them back.
+fn round_trip(elements: &[OwnedElement], binary: bool) -> IonResult<Vec<OwnedElement>> {
+    let mut buffer = Vec::new();
+    if binary {
+        write_all(BinaryWriterBuilder::new().build(&mut buffer)?, elements)?;
+    } else {
+        write_all(TextWriterBuilder::new().build(&mut buffer)?, elements)?;
+    }
+    native_element_reader().read_all(&buffer)
+}
+
+fn write_all<W: Writer>(mut writer: W, elements: &[OwnedElement]) -> IonResult<()> {
+    for element in elements {
+        write_element(&mut writer, None, element)?;
+    }
+    writer.flush()
+}
+
+fn check_bad(data: &[u8]) -> Result<(), String> {
+    match native_element_reader().read_all(data) {
+        Ok(elements) => Err(format!(
+            "the file was read successfully, producing {} values",
+            elements.len()
+        )),
+        Err(_) => Ok(()),
+    }
+}
+
+// Checks that the values in each top-level sequence are (or, if `equivalent` is false, are not)
+// equivalent to one another. Sequences annotated with `embedded_documents` contain strings of Ion
+// text; each string is compared as a stream.
+fn check_groups(data: &[u8], equivalent: bool) -> Result<(), String> {
+    for (group_index, group) in read_all(data)?.iter().enumerate() {
+        let sequence = group
+            .as_sequence()
+            .ok_or_else(|| format!("group #{} is not a list or s-expression", group_index))?;
+        let is_embedded = group
+            .annotations()
+            .any(|annotation| annotation.text() == Some("embedded_documents"));
+        let members: Vec<Vec<OwnedElement>> = if is_embedded {
+            sequence
+                .iter()
+                .map(|document| match document.as_str() {
+                    Some(text) => read_all(text.as_bytes()),
+                    None => Err(format!("group #{} contains a non-string", group_index)),
+                })
+                .collect::<Result<_, _>>()?
+        } else {
+            sequence.iter().map(|value| vec![value.clone()]).collect()
+        };
+        for (this_index, this) in members.iter().enumerate() {
+            for (that_index, that) in members.iter().enumerate() {
+                let expected = equivalent || this_index == that_index;
+                if this.ion_eq(that) != expected {
+                    return Err(format!(
+                        "in group #{}, values #{} and #{} were {}",
+                        group_index,
+                        this_index,
+                        that_index,
+                        if expected {
+                            "not equivalent"
+                        } else {
+                            "equivalent"
+                        }
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_test_vectors() -> IonResult<()> {
+        let root = std::env::temp_dir().join(format!("ion-rs-conformance-{}", std::process::id()));
+        let write = |path: &str, contents: &[u8]| -> IonResult<()> {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, contents)?;
+            Ok(())
+        };
+        write("good/values.ion", b"1 {a: [b, \"c\"]} 2.5e0")?;
+        write("good/binary.10n", &[0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01])?;
+        write("good/skipped.ion", b"[")?;
+        write("good/notes.txt", b"not a test file")?;
+        write(
+            "good/equivs/ints.ion",
+            b"(1 0x1 0b1) embedded_documents::(\"a\" \"'a'\")",
+        )?;
+        write("good/non-equivs/ints.ion", b"(1 2) (1 1)")?;
+        write("bad/unterminated.ion", b"{a: 1")?;
+        write("bad/valid.ion", b"1")?;
+
+        let report = ConformanceRunner::new(&root)
+            .with_skip_list(["good/skipped.ion"])
+            .run()?;
+        fs::remove_dir_all(&root)?;
+
+        let outcomes: Vec<(&Path, TestCategory, bool)> = report
+            .results()
+            .iter()
+            .map(|result| {
+                let passed = *result.outcome() == TestOutcome::Passed;
+                (result.path(), result.category(), passed)
+            })
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (Path::new("bad/unterminated.ion"), TestCategory::Bad, true),
+                (Path::new("bad/valid.ion"), TestCategory::Bad, false),
+                (Path::new("good/binary.10n"), TestCategory::Good, true),
+                (
+                    Path::new("good/equivs/ints.ion"),
+                    TestCategory::Equivs,
+                    true
+                ),
+                (
+                    Path::new("good/non-equivs/ints.ion"),
+                    TestCategory::NonEquivs,
+                    false
+                ),
+                (Path::new("good/skipped.ion"), TestCategory::Good, false),
+                (Path::new("good/values.ion"), TestCategory::Good, true),
+            ]
+        );
+        assert_eq!(
+            (
+                report.num_passed(),
+                report.num_failed(),
+                report.num_skipped()
+            ),
+            (4, 2, 1)
+        );
+        assert!(!report.is_success());
+        assert!(report
+            .to_string()
+            .ends_with("4 passed, 2 failed, 1 skipped"));
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use std::io::{Read, Write};
+
+use crate::reader::ReaderBuilder;
+use crate::result::IonResult;
+use crate::transform::TransformPipeline;
+use crate::value::writer::{Format, TextKind};
+use crate::writer::Writer;
+use crate::{BinaryWriterBuilder, TextWriterBuilder};
+
+/// Reads the Ion stream in `input` (which may be text or binary) and writes the same data to
+/// `output` in the requested [Format].
+///
+/// Symbol tables in the input are resolved as it is read, so symbol IDs in a binary input are
+/// written to text output as their text, and text input is written to binary output using a new
+/// local symbol table. Input streams made up of several segments (each starting with an Ion
+/// version marker or a local symbol table that doesn't append to the previous one) are converted
+/// as a single stream. Nested containers are converted without recursion, so deeply nested input
+/// cannot overflow the stack.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{convert, Format, TextKind};
+///
+/// let mut binary = Vec::new();
+/// convert("{greeting: hello}".as_bytes(), &mut binary, Format::Binary)?;
+/// assert_eq!(&binary[..4], &[0xE0, 0x01, 0x00, 0xEA]);
+///
+/// let mut text = Vec::new();
+/// convert(binary.as_slice(), &mut text, Format::Text(TextKind::Compact))?;
+/// assert_eq!(String::from_utf8(text).unwrap().trim(), "{greeting: hello}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn convert<R: Read, W: Write>(input: R, output: W, to: Format) -> IonResult<()> {
+    match to {
+        Format::Binary => transcode(input, BinaryWriterBuilder::new().build(output)?),
+        Format::Text(TextKind::Compact) => {
+            transcode(input, TextWriterBuilder::new().build(output)?)
+        }
+        Format::Text(TextKind::Pretty) => {
+            transcode(input, TextWriterBuilder::pretty().build(output)?)
+        }
+    }
+}
+
+fn transcode<R: Read, W: Writer>(input: R, mut writer: W) -> IonResult<()> {
+    let mut reader = ReaderBuilder::new().build_from_read(input)?;
+    TransformPipeline::new().run(&mut reader, &mut writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::StreamItem;
+    use crate::stream_reader::StreamReader;
+    use crate::types::IonType;
+    use crate::value::owned::OwnedElement;
+
+    fn elements(data: &[u8]) -> IonResult<Vec<OwnedElement>> {
+        ReaderBuilder::new().build(data)?.elements().collect()
+    }
+
+    #[test]
+    fn round_trip_multi_segment_stream() -> IonResult<()> {
+        let text = r#"
+            $ion_1_0 foo::bar
+            $ion_symbol_table::{symbols: ["quux"]} $10
+            $ion_1_0 {a: [1, 2.5, 3e0, "four"], b: (five)}
+        "#;
+        let mut binary = Vec::new();
+        convert(text.as_bytes(), &mut binary, Format::Binary)?;
+        let mut pretty = Vec::new();
+        convert(
+            binary.as_slice(),
+            &mut pretty,
+            Format::Text(TextKind::Pretty),
+        )?;
+        let mut compact = Vec::new();
+        convert(
+            pretty.as_slice(),
+            &mut compact,
+            Format::Text(TextKind::Compact),
+        )?;
+
+        let expected = elements(text.as_bytes())?;
+        assert_eq!(expected.len(), 3);
+        assert_eq!(elements(&binary)?, expected);
+        assert_eq!(elements(&pretty)?, expected);
+        assert_eq!(elements(&compact)?, expected);
+        assert!(String::from_utf8(compact).unwrap().contains("quux"));
+        Ok(())
+    }
+
+    #[test]
+    fn convert_deeply_nested_stream() -> IonResult<()> {
+        let depth = 100_000;
+        let text = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let mut binary = Vec::new();
+        convert(text.as_bytes(), &mut binary, Format::Binary)?;
+        let mut compact = Vec::new();
+        convert(
+            binary.as_slice(),
+            &mut compact,
+            Format::Text(TextKind::Compact),
+        )?;
+
+        for data in [binary, compact] {
+            let mut reader = ReaderBuilder::new().build(data)?;
+            let mut levels = 0;
+            while reader.next()? == StreamItem::Value(IonType::List) {
+                reader.step_in()?;
+                levels += 1;
+            }
+            assert_eq!(levels, depth);
+        }
+        Ok(())
+    }
+}
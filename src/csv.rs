@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+
+use crate::position::Position;
+use crate::result::{decoding_error_raw, illegal_operation, IonError, IonResult};
+use crate::types::integer::Integer;
+use crate::types::IonType;
+use crate::value::reader::{native_element_reader, ElementReader};
+use crate::value::Element;
+use crate::writer::Writer;
+
+/// The Ion type that a [CsvConverter] writes for the values in a column.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Write the text of each value as a string. This is the default.
+    #[default]
+    String,
+    /// Write the text of each value as a symbol.
+    Symbol,
+    /// Parse each value as a base-10 integer of any size.
+    Integer,
+    /// Parse each value as a 64-bit float. `nan`, `inf`, and `-inf` are accepted.
+    Float,
+    /// Parse each value as a decimal. Either `e` or Ion's `d` may introduce an exponent.
+    Decimal,
+    /// Parse each value as `true` or `false`, ignoring case.
+    Boolean,
+    /// Parse each value as an Ion timestamp, such as `2022-01-31T12:00Z`.
+    Timestamp,
+}
+
+/// Configures and constructs new instances of [CsvConverter].
+#[derive(Debug, Clone)]
+pub struct CsvConverterBuilder {
+    delimiter: char,
+    has_header_row: bool,
+    column_names: Option<Vec<String>>,
+    column_types: HashMap<String, ColumnType>,
+}
+
+impl CsvConverterBuilder {
+    pub fn new() -> CsvConverterBuilder {
+        CsvConverterBuilder {
+            delimiter: ',',
+            has_header_row: true,
+            column_names: None,
+            column_types: HashMap::new(),
+        }
+    }
+
+    /// Sets the character that separates values in a row. The default is a comma; use `'\t'` to
+    /// read TSV.
+    pub fn with_delimiter(mut self, delimiter: char) -> CsvConverterBuilder {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the first row of the input contains the names of the columns. The default is
+    /// `true`. If column names are also provided using [CsvConverterBuilder::with_column_names],
+    /// the header row is skipped.
+    pub fn with_header_row(mut self, has_header_row: bool) -> CsvConverterBuilder {
+        self.has_header_row = has_header_row;
+        self
+    }
+
+    /// Sets the names of the columns, which are used as the field names of each struct.
+    pub fn with_column_names<I, S>(mut self, names: I) -> CsvConverterBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.column_names = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the Ion type of the values in the column called `name`. Columns without a type are
+    /// written as strings.
+    pub fn with_column_type<S: Into<String>>(
+        mut self,
+        name: S,
+        column_type: ColumnType,
+    ) -> CsvConverterBuilder {
+        self.column_types.insert(name.into(), column_type);
+        self
+    }
+
+    pub fn build(self) -> IonResult<CsvConverter> {
+        if self.delimiter == '"' || self.delimiter == '\n' || self.delimiter == '\r' {
+            return illegal_operation(format!(
+                "{:?} cannot be used as a delimiter",
+                self.delimiter
+            ));
+        }
+        if self.column_names.is_none() && !self.has_header_row {
+            return illegal_operation(
+                "column names must be provided when the input does not have a header row",
+            );
+        }
+        Ok(CsvConverter {
+            delimiter: self.delimiter,
+            has_header_row: self.has_header_row,
+            column_names: self.column_names,
+            column_types: self.column_types,
+        })
+    }
+}
+
+impl Default for CsvConverterBuilder {
+    fn default() -> Self {
+        CsvConverterBuilder::new()
+    }
+}
+
+/// Converts delimited text, such as CSV or TSV, into a stream of Ion structs.
+///
+/// Each row of the input is written as a struct whose field names are the names of the columns.
+/// Values are written as strings unless a [ColumnType] was specified for their column. Empty
+/// values in columns of other types are written as typed nulls (for example, `null.int`).
+///
+/// Values may be enclosed in double quotes, in which case they can contain delimiters, line
+/// breaks, and pairs of double quotes (`""`) that each represent a single double quote. Rows may
+/// end with either `\n` or `\r\n`.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{ColumnType, CsvConverterBuilder, TextWriterBuilder};
+///
+/// let csv = "name,age\nAlice,34\n\"Smith, Bob\",\n";
+/// let converter = CsvConverterBuilder::new()
+///     .with_column_type("age", ColumnType::Integer)
+///     .build()?;
+/// let mut buffer = Vec::new();
+/// let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+/// assert_eq!(converter.convert(csv.as_bytes(), &mut writer)?, 2);
+/// drop(writer);
+/// assert_eq!(
+///     String::from_utf8(buffer).unwrap().trim(),
+///     r#"{name: "Alice", age: 34} {name: "Smith, Bob", age: null.int}"#
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvConverter {
+    delimiter: char,
+    has_header_row: bool,
+    column_names: Option<Vec<String>>,
+    column_types: HashMap<String, ColumnType>,
+}
+
+impl CsvConverter {
+    /// Reads every row from `input` and writes it to `writer` as a struct, returning the number of
+    /// rows that were written. If the writer is at the top level when the input is exhausted, it
+    /// is flushed.
+    pub fn convert<R: BufRead, W: Writer>(&self, input: R, writer: &mut W) -> IonResult<usize> {
+        let mut rows = RowReader {
+            input,
+            delimiter: self.delimiter,
+            line: 0,
+            offset: 0,
+            row_position: Position::with_offset(0).with_line_and_column(1, 1),
+        };
+        let header_row = if self.has_header_row {
+            rows.next_row()?
+        } else {
+            None
+        };
+        let column_names = match (&self.column_names, header_row) {
+            (Some(names), _) => names.clone(),
+            (None, Some(names)) => names,
+            (None, None) => Vec::new(),
+        };
+        let column_types: Vec<ColumnType> = column_names
+            .iter()
+            .map(|name| self.column_types.get(name).copied().unwrap_or_default())
+            .collect();
+
+        let mut rows_written = 0;
+        while let Some(row) = rows.next_row()? {
+            if row.len() != column_names.len() {
+                return Err(rows.error(format!(
+                    "expected {} values, but the row contains {}",
+                    column_names.len(),
+                    row.len()
+                )));
+            }
+            writer.step_in(IonType::Struct)?;
+            for ((name, column_type), value) in column_names.iter().zip(&column_types).zip(&row) {
+                writer.set_field_name(name.as_str());
+                write_value(writer, *column_type, value)
+                    .map_err(|message| rows.error(format!("column '{}': {}", name, message)))?;
+            }
+            writer.step_out()?;
+            rows_written += 1;
+        }
+        if writer.depth() == 0 {
+            writer.flush()?;
+        }
+        Ok(rows_written)
+    }
+}
+
+// Writes `text` as a value of the specified type. Conversion failures are reported as messages
+// that the caller can add a position to.
+fn write_value<W: Writer>(
+    writer: &mut W,
+    column_type: ColumnType,
+    text: &str,
+) -> Result<(), String> {
+    let ion_type = match column_type {
+        ColumnType::String => return writer.write_string(text).map_err(|error| error.to_string()),
+        ColumnType::Symbol => IonType::Symbol,
+        ColumnType::Integer => IonType::Integer,
+        ColumnType::Float => IonType::Float,
+        ColumnType::Decimal => IonType::Decimal,
+        ColumnType::Boolean => IonType::Boolean,
+        ColumnType::Timestamp => IonType::Timestamp,
+    };
+    let text = text.trim();
+    let invalid = || format!("'{}' is not a valid {}", text, ion_type);
+    let result = if text.is_empty() {
+        writer.write_null(ion_type)
+    } else {
+        match column_type {
+            ColumnType::Symbol => writer.write_symbol(text),
+            ColumnType::Integer => {
+                let text = text.strip_prefix('+').unwrap_or(text);
+                let value = match i64::from_str(text) {
+                    Ok(value) => Integer::I64(value),
+                    Err(_) => Integer::BigInt(BigInt::from_str(text).map_err(|_| invalid())?),
+                };
+                writer.write_integer(&value)
+            }
+            ColumnType::Float => writer.write_f64(f64::from_str(text).map_err(|_| invalid())?),
+            ColumnType::Boolean => match text.to_ascii_lowercase().as_str() {
+                "true" => writer.write_bool(true),
+                "false" => writer.write_bool(false),
+                _ => return Err(invalid()),
+            },
+            ColumnType::Decimal => {
+                // Ion text uses `d` to introduce a decimal's exponent; `e` would make it a float.
+                let mut ion_text = text.replace(['e', 'E'], "d");
+                // Without a decimal point or an exponent, Ion text would be read as an integer.
+                if !ion_text.contains(['.', 'd']) {
+                    ion_text.push_str("d0");
+                }
+                match native_element_reader().read_one(ion_text.as_bytes()) {
+                    Ok(element) if element.ion_type() == IonType::Decimal && !element.is_null() => {
+                        writer.write_decimal(element.as_decimal().unwrap())
+                    }
+                    _ => return Err(invalid()),
+                }
+            }
+            ColumnType::Timestamp => match native_element_reader().read_one(text.as_bytes()) {
+                Ok(element) if element.ion_type() == IonType::Timestamp && !element.is_null() => {
+                    writer.write_timestamp(element.as_timestamp().unwrap())
+                }
+                _ => return Err(invalid()),
+            },
+            ColumnType::String => unreachable!("strings were written above"),
+        }
+    };
+    result.map_err(|error| error.to_string())
+}
+
+// Reads rows of delimited text, handling quoted values that span multiple lines.
+struct RowReader<R: BufRead> {
+    input: R,
+    delimiter: char,
+    // The number of lines and bytes read so far.
+    line: usize,
+    offset: usize,
+    // The position at which the most recent row began.
+    row_position: Position,
+}
+
+impl<R: BufRead> RowReader<R> {
+    fn error(&self, message: String) -> IonError {
+        decoding_error_raw(message).with_position(self.row_position)
+    }
+
+    fn read_line(&mut self, line: &mut String) -> IonResult<usize> {
+        let bytes_read = self.input.read_line(line)?;
+        if bytes_read > 0 {
+            self.line += 1;
+            self.offset += bytes_read;
+        }
+        Ok(bytes_read)
+    }
+
+    fn next_row(&mut self) -> IonResult<Option<Vec<String>>> {
+        let mut line = String::new();
+        // Skip blank lines between rows.
+        loop {
+            line.clear();
+            self.row_position =
+                Position::with_offset(self.offset).with_line_and_column(self.line + 1, 1);
+            if self.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+
+        let mut values = Vec::new();
+        let mut value = String::new();
+        let mut in_quotes = false;
+        // Whether the current value began with a quote, in which case only a delimiter or the
+        // end of the row may follow its closing quote.
+        let mut was_quoted = false;
+        loop {
+            let mut chars = line.chars().peekable();
+            while let Some(c) = chars.next() {
+                if in_quotes {
+                    if c != '"' {
+                        value.push(c);
+                    } else if chars.peek() == Some(&'"') {
+                        chars.next();
+                        value.push('"');
+                    } else {
+                        in_quotes = false;
+                    }
+                } else if c == self.delimiter {
+                    values.push(std::mem::take(&mut value));
+                    was_quoted = false;
+                } else if c == '\n' || (c == '\r' && chars.peek() == Some(&'\n')) {
+                    break;
+                } else if was_quoted {
+                    return Err(self.error(format!(
+                        "unexpected {:?} after a quoted value; expected {:?}",
+                        c, self.delimiter
+                    )));
+                } else if c == '"' && value.is_empty() {
+                    in_quotes = true;
+                    was_quoted = true;
+                } else {
+                    value.push(c);
+                }
+            }
+            if !in_quotes {
+                values.push(value);
+                return Ok(Some(values));
+            }
+            // The quoted value continues on the next line.
+            line.clear();
+            if self.read_line(&mut line)? == 0 {
+                return Err(self.error("the input ended inside a quoted value".to_string()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ion_eq::IonEq;
+    use crate::value::owned::OwnedElement;
+    use crate::TextWriterBuilder;
+
+    fn convert(converter: &CsvConverter, input: &str) -> IonResult<Vec<OwnedElement>> {
+        let mut buffer = Vec::new();
+        let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+        converter.convert(input.as_bytes(), &mut writer)?;
+        drop(writer);
+        native_element_reader().read_all(&buffer)
+    }
+
+    fn expect(converter: &CsvConverter, input: &str, expected: &str) {
+        let actual = convert(converter, input).unwrap();
+        let expected = native_element_reader()
+            .read_all(expected.as_bytes())
+            .unwrap();
+        assert!(actual.ion_eq(&expected), "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn convert_with_type_hints() -> IonResult<()> {
+        let converter = CsvConverterBuilder::new()
+            .with_column_type("id", ColumnType::Integer)
+            .with_column_type("price", ColumnType::Decimal)
+            .with_column_type("ratio", ColumnType::Float)
+            .with_column_type("active", ColumnType::Boolean)
+            .with_column_type("updated", ColumnType::Timestamp)
+            .with_column_type("kind", ColumnType::Symbol)
+            .build()?;
+        expect(
+            &converter,
+            "id,name,price,ratio,active,updated,kind\r\n\
+             1,\"Smith, \"\"Bob\"\"\",1.50,0.5,TRUE,2022-01-31T12:00Z,a\r\n\
+             \n\
+             99999999999999999999,\"two\nlines\",2e3,,false,,\r\n",
+            r#"
+            {id: 1, name: "Smith, \"Bob\"", price: 1.50, ratio: 0.5e0, active: true,
+             updated: 2022-01-31T12:00Z, kind: a}
+            {id: 99999999999999999999, name: "two\nlines", price: 2d3, ratio: null.float,
+             active: false, updated: null.timestamp, kind: null.symbol}
+            "#,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_tsv_with_column_names() -> IonResult<()> {
+        let converter = CsvConverterBuilder::new()
+            .with_delimiter('\t')
+            .with_header_row(false)
+            .with_column_names(["a", "b"])
+            .with_column_type("b", ColumnType::Integer)
+            .build()?;
+        expect(
+            &converter,
+            "x,y\t1\n\t2",
+            r#"{a: "x,y", b: 1} {a: "", b: 2}"#,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_input() -> IonResult<()> {
+        let converter = CsvConverterBuilder::new()
+            .with_column_type("n", ColumnType::Integer)
+            .build()?;
+        let error = convert(&converter, "n,s\n1,a\nx,b\n").unwrap_err();
+        assert_eq!(
+            error.position().and_then(|position| position.line()),
+            Some(3)
+        );
+        assert!(convert(&converter, "n,s\n1\n").is_err());
+        assert!(convert(&converter, "n,s\n1,\"a\n").is_err());
+        assert!(convert(&converter, "n,s\n1,\"a\"b\n").is_err());
+        assert!(CsvConverterBuilder::new()
+            .with_header_row(false)
+            .build()
+            .is_err());
+        Ok(())
+    }
+}
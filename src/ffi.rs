@@ -0,0 +1,1036 @@
+//! A C-compatible interface for reading and writing Ion, for embedding this implementation in
+//! components that are not written in Rust.
+//!
+//! Readers and writers are exposed as opaque handles that are created by `ion_reader_new` and
+//! `ion_writer_new` and must be released by `ion_reader_free` and `ion_writer_free`. Functions
+//! that can fail return an [IonStatus]; when the status is [IonStatus::Error], a description of
+//! the failure can be fetched with `ion_reader_last_error` or `ion_writer_last_error`.
+//!
+//! Text and byte values are returned as a pointer and a length that refer to memory owned by the
+//! handle. They remain valid until the next call that uses the same handle.
+//!
+//! Enumerations are passed from C as `uint32_t` and validated before use. No function lets a
+//! Rust panic unwind into the caller; if one occurs, the function returns [IonStatus::Panicked]
+//! (or null) and the handle involved should be freed.
+
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use crate::convert::convert;
+use crate::reader::{Reader, ReaderBuilder, StreamItem};
+use crate::result::{illegal_operation, IonResult};
+use crate::stream_reader::StreamReader;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+use crate::value::writer::{Format, TextKind};
+use crate::writer::Writer;
+use crate::{BinaryWriter, BinaryWriterBuilder, Symbol, TextWriter, TextWriterBuilder};
+
+/// The result of an FFI call.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IonStatus {
+    Ok = 0,
+    /// The operation failed. The handle's last error describes the failure.
+    Error = 1,
+    /// A required pointer argument was null.
+    NullArgument = 2,
+    /// The operation panicked. The handle may be left in an inconsistent state.
+    Panicked = 3,
+}
+
+/// The type of a value, or [IonValueType::Nothing] if a reader has no current value. Functions
+/// that take a type accept its discriminant as a `u32`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IonValueType {
+    Nothing = 0,
+    Null = 1,
+    Bool = 2,
+    Int = 3,
+    Float = 4,
+    Decimal = 5,
+    Timestamp = 6,
+    Symbol = 7,
+    String = 8,
+    Clob = 9,
+    Blob = 10,
+    List = 11,
+    SExp = 12,
+    Struct = 13,
+}
+
+impl From<IonType> for IonValueType {
+    fn from(ion_type: IonType) -> Self {
+        match ion_type {
+            IonType::Null => IonValueType::Null,
+            IonType::Boolean => IonValueType::Bool,
+            IonType::Integer => IonValueType::Int,
+            IonType::Float => IonValueType::Float,
+            IonType::Decimal => IonValueType::Decimal,
+            IonType::Timestamp => IonValueType::Timestamp,
+            IonType::Symbol => IonValueType::Symbol,
+            IonType::String => IonValueType::String,
+            IonType::Clob => IonValueType::Clob,
+            IonType::Blob => IonValueType::Blob,
+            IonType::List => IonValueType::List,
+            IonType::SExpression => IonValueType::SExp,
+            IonType::Struct => IonValueType::Struct,
+        }
+    }
+}
+
+impl IonValueType {
+    // Converts the discriminant of an IonValueType received from C to the corresponding IonType.
+    fn ion_type(value_type: u32) -> IonResult<IonType> {
+        Ok(match value_type {
+            1 => IonType::Null,
+            2 => IonType::Boolean,
+            3 => IonType::Integer,
+            4 => IonType::Float,
+            5 => IonType::Decimal,
+            6 => IonType::Timestamp,
+            7 => IonType::Symbol,
+            8 => IonType::String,
+            9 => IonType::Clob,
+            10 => IonType::Blob,
+            11 => IonType::List,
+            12 => IonType::SExpression,
+            13 => IonType::Struct,
+            0 => return illegal_operation("IonValueType::Nothing has no Ion type"),
+            _ => return illegal_operation(format!("{} is not a valid IonValueType", value_type)),
+        })
+    }
+}
+
+/// The encoding produced by an FFI writer. `ion_writer_new` accepts its discriminant as a `u32`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IonWriterFormat {
+    Binary = 0,
+    Text = 1,
+    PrettyText = 2,
+}
+
+impl IonWriterFormat {
+    fn from_u32(format: u32) -> Option<IonWriterFormat> {
+        match format {
+            0 => Some(IonWriterFormat::Binary),
+            1 => Some(IonWriterFormat::Text),
+            2 => Some(IonWriterFormat::PrettyText),
+            _ => None,
+        }
+    }
+}
+
+// Runs `body`, returning `on_panic` instead of unwinding into the caller if it panics.
+fn guard<T, F: FnOnce() -> T>(on_panic: T, body: F) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(on_panic)
+}
+
+// Records the outcome of `result` in `last_error` and converts it to an IonStatus.
+fn record(last_error: &mut Option<CString>, result: IonResult<()>) -> IonStatus {
+    match result {
+        Ok(()) => IonStatus::Ok,
+        Err(error) => {
+            // Interior NUL bytes would truncate the message, so they are replaced.
+            let message = error.to_string().replace('\0', " ");
+            *last_error = CString::new(message).ok();
+            IonStatus::Error
+        }
+    }
+}
+
+fn last_error_ptr(last_error: &Option<CString>) -> *const c_char {
+    last_error
+        .as_ref()
+        .map(|message| message.as_ptr())
+        .unwrap_or(ptr::null())
+}
+
+// Reads `len` bytes starting at `data`, treating a null pointer with a length of zero as empty.
+// Returns `None` if `data` is null but `len` is not zero.
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    if data.is_null() {
+        return if len == 0 { Some(&[]) } else { None };
+    }
+    Some(slice::from_raw_parts(data, len))
+}
+
+fn text(bytes: &[u8]) -> IonResult<&str> {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Ok(text),
+        Err(_) => illegal_operation("text arguments must be valid UTF-8"),
+    }
+}
+
+// Parses the text Ion representation of a single decimal or timestamp.
+fn parse_scalar<T, F>(text: &str, read: F) -> IonResult<T>
+where
+    F: FnOnce(&mut Reader<'_>) -> IonResult<T>,
+{
+    let mut reader = ReaderBuilder::new().build(text)?;
+    reader.next()?;
+    read(&mut reader)
+}
+
+/// An FFI handle for reading a stream of text or binary Ion.
+pub struct IonReaderHandle {
+    reader: Reader<'static>,
+    // Holds the most recently returned text or bytes so that callers can borrow them.
+    buffer: Vec<u8>,
+    last_error: Option<CString>,
+}
+
+impl IonReaderHandle {
+    fn run<F: FnOnce(&mut IonReaderHandle) -> IonResult<()>>(&mut self, f: F) -> IonStatus {
+        let result = f(self);
+        record(&mut self.last_error, result)
+    }
+
+    // Stores `data` in the handle's buffer and returns a pointer/length pair referring to it.
+    unsafe fn lend(&mut self, data: Vec<u8>, out_data: *mut *const u8, out_len: *mut usize) {
+        self.buffer = data;
+        *out_data = self.buffer.as_ptr();
+        *out_len = self.buffer.len();
+    }
+}
+
+/// Creates a reader over a copy of the `len` bytes at `data`, which may be text or binary Ion.
+/// Returns null if the reader could not be created.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_new(data: *const u8, len: usize) -> *mut IonReaderHandle {
+    guard(ptr::null_mut(), || {
+        let input = match bytes(data, len) {
+            Some(input) => input,
+            None => return ptr::null_mut(),
+        };
+        match ReaderBuilder::new().build(input.to_vec()) {
+            Ok(reader) => Box::into_raw(Box::new(IonReaderHandle {
+                reader,
+                buffer: Vec::new(),
+                last_error: None,
+            })),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+/// Releases a reader created by `ion_reader_new`. Passing null has no effect.
+///
+/// # Safety
+///
+/// `reader` must be null or a handle returned by `ion_reader_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_free(reader: *mut IonReaderHandle) {
+    guard((), || {
+        if !reader.is_null() {
+            drop(Box::from_raw(reader));
+        }
+    })
+}
+
+/// Returns a description of the most recent error encountered by `reader`, or null if there
+/// hasn't been one. The string is owned by the reader.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_last_error(reader: *const IonReaderHandle) -> *const c_char {
+    guard(ptr::null(), || match reader.as_ref() {
+        Some(reader) => last_error_ptr(&reader.last_error),
+        None => ptr::null(),
+    })
+}
+
+/// Advances the reader to the next value at the current depth and stores its type in
+/// `out_type`. If there are no more values at the current depth, the type is
+/// [IonValueType::Nothing]. A typed null (e.g. `null.int`) is reported as its type; use
+/// `ion_reader_is_null` to detect it.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new` and `out_type` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_next(
+    reader: *mut IonReaderHandle,
+    out_type: *mut IonValueType,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        let reader = match reader.as_mut() {
+            Some(reader) => reader,
+            None => return IonStatus::NullArgument,
+        };
+        if out_type.is_null() {
+            return IonStatus::NullArgument;
+        }
+        reader.run(|handle| {
+            *out_type = match handle.reader.next()? {
+                StreamItem::Value(ion_type) | StreamItem::Null(ion_type) => ion_type.into(),
+                StreamItem::Nothing => IonValueType::Nothing,
+            };
+            Ok(())
+        })
+    })
+}
+
+/// Stores whether the reader's current value is null in `out_value`.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new` and `out_value` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_is_null(
+    reader: *mut IonReaderHandle,
+    out_value: *mut bool,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        match (reader.as_mut(), out_value.is_null()) {
+            (Some(reader), false) => {
+                *out_value = reader.reader.is_null();
+                IonStatus::Ok
+            }
+            _ => IonStatus::NullArgument,
+        }
+    })
+}
+
+/// Stores the reader's current depth in `out_depth`.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new` and `out_depth` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_depth(
+    reader: *mut IonReaderHandle,
+    out_depth: *mut usize,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        match (reader.as_mut(), out_depth.is_null()) {
+            (Some(reader), false) => {
+                *out_depth = reader.reader.depth();
+                IonStatus::Ok
+            }
+            _ => IonStatus::NullArgument,
+        }
+    })
+}
+
+/// Steps into the container on which the reader is positioned.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_step_in(reader: *mut IonReaderHandle) -> IonStatus {
+    guard(IonStatus::Panicked, || match reader.as_mut() {
+        Some(reader) => reader.run(|handle| handle.reader.step_in()),
+        None => IonStatus::NullArgument,
+    })
+}
+
+/// Steps out of the current container, skipping any of its values that haven't been read.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_step_out(reader: *mut IonReaderHandle) -> IonStatus {
+    guard(IonStatus::Panicked, || match reader.as_mut() {
+        Some(reader) => reader.run(|handle| handle.reader.step_out()),
+        None => IonStatus::NullArgument,
+    })
+}
+
+// Defines an FFI function that reads a scalar of a fixed-size type from the current value.
+macro_rules! read_scalar_fn {
+    ($(#[$doc:meta])* $name:ident, $ty:ty, $method:ident) => {
+        $(#[$doc])*
+        ///
+        /// # Safety
+        ///
+        /// `reader` must be a live handle returned by `ion_reader_new` and `out_value` must be
+        /// writable.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(reader: *mut IonReaderHandle, out_value: *mut $ty) -> IonStatus {
+            guard(IonStatus::Panicked, || {
+                let reader = match reader.as_mut() {
+                    Some(reader) => reader,
+                    None => return IonStatus::NullArgument,
+                };
+                if out_value.is_null() {
+                    return IonStatus::NullArgument;
+                }
+                reader.run(|handle| {
+                    *out_value = handle.reader.$method()?;
+                    Ok(())
+                })
+            })
+        }
+    };
+}
+
+read_scalar_fn!(
+    /// Reads the current bool value into `out_value`.
+    ion_reader_read_bool,
+    bool,
+    read_bool
+);
+read_scalar_fn!(
+    /// Reads the current int value into `out_value`. Fails if the value does not fit in an i64.
+    ion_reader_read_i64,
+    i64,
+    read_i64
+);
+read_scalar_fn!(
+    /// Reads the current float value into `out_value`.
+    ion_reader_read_f64,
+    f64,
+    read_f64
+);
+
+// Defines an FFI function that lends the caller the text or bytes of the current value.
+macro_rules! read_bytes_fn {
+    ($(#[$doc:meta])* $name:ident, |$reader:ident| $read:expr) => {
+        $(#[$doc])*
+        ///
+        /// # Safety
+        ///
+        /// `reader` must be a live handle returned by `ion_reader_new`. `out_data` and `out_len`
+        /// must be writable.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            reader: *mut IonReaderHandle,
+            out_data: *mut *const u8,
+            out_len: *mut usize,
+        ) -> IonStatus {
+            guard(IonStatus::Panicked, || {
+                let reader = match reader.as_mut() {
+                    Some(reader) => reader,
+                    None => return IonStatus::NullArgument,
+                };
+                if out_data.is_null() || out_len.is_null() {
+                    return IonStatus::NullArgument;
+                }
+                reader.run(|handle| {
+                    let $reader = &mut handle.reader;
+                    let data: Vec<u8> = $read;
+                    handle.lend(data, out_data, out_len);
+                    Ok(())
+                })
+            })
+        }
+    };
+}
+
+read_bytes_fn!(
+    /// Lends the caller the UTF-8 text of the current string value.
+    ion_reader_read_string,
+    |reader| reader.read_str()?.as_bytes().to_vec()
+);
+read_bytes_fn!(
+    /// Lends the caller the UTF-8 text of the current symbol value.
+    ion_reader_read_symbol,
+    |reader| symbol_bytes(reader.read_symbol()?)
+);
+read_bytes_fn!(
+    /// Lends the caller the bytes of the current blob or clob value.
+    ion_reader_read_lob,
+    |reader| match reader.ion_type() {
+        Some(IonType::Clob) => reader.read_clob()?,
+        _ => reader.read_blob()?,
+    }
+);
+read_bytes_fn!(
+    /// Lends the caller the text Ion representation (e.g. `125d-2`) of the current decimal value.
+    ion_reader_read_decimal,
+    |reader| text_repr(|writer| writer.write_decimal(&reader.read_decimal()?))?
+);
+read_bytes_fn!(
+    /// Lends the caller the text Ion representation (e.g. `2022-06-01T12:00Z`) of the current
+    /// timestamp value.
+    ion_reader_read_timestamp,
+    |reader| text_repr(|writer| writer.write_timestamp(&reader.read_timestamp()?))?
+);
+read_bytes_fn!(
+    /// Lends the caller the UTF-8 text of the current value's field name.
+    ion_reader_field_name,
+    |reader| symbol_bytes(reader.field_name()?)
+);
+
+// Returns the text Ion representation of the single value written by `write`.
+fn text_repr<F>(write: F) -> IonResult<Vec<u8>>
+where
+    F: FnOnce(&mut TextWriter<&mut Vec<u8>>) -> IonResult<()>,
+{
+    let mut buffer = Vec::new();
+    let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+    write(&mut writer)?;
+    writer.flush()?;
+    drop(writer);
+    let len = buffer.trim_ascii_end().len();
+    buffer.truncate(len);
+    Ok(buffer)
+}
+
+fn symbol_bytes(symbol: Symbol) -> Vec<u8> {
+    symbol.as_ref().as_bytes().to_vec()
+}
+
+/// Stores the number of annotations on the current value in `out_count`.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new` and `out_count` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_annotation_count(
+    reader: *mut IonReaderHandle,
+    out_count: *mut usize,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        let reader = match reader.as_mut() {
+            Some(reader) => reader,
+            None => return IonStatus::NullArgument,
+        };
+        if out_count.is_null() {
+            return IonStatus::NullArgument;
+        }
+        reader.run(|handle| {
+            *out_count = handle.reader.annotations().count();
+            Ok(())
+        })
+    })
+}
+
+/// Lends the caller the UTF-8 text of the current value's annotation at `index`.
+///
+/// # Safety
+///
+/// `reader` must be a live handle returned by `ion_reader_new`. `out_data` and `out_len` must be
+/// writable.
+#[no_mangle]
+pub unsafe extern "C" fn ion_reader_annotation(
+    reader: *mut IonReaderHandle,
+    index: usize,
+    out_data: *mut *const u8,
+    out_len: *mut usize,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        let reader = match reader.as_mut() {
+            Some(reader) => reader,
+            None => return IonStatus::NullArgument,
+        };
+        if out_data.is_null() || out_len.is_null() {
+            return IonStatus::NullArgument;
+        }
+        reader.run(|handle| {
+            let annotation = match handle.reader.annotations().nth(index) {
+                Some(annotation) => annotation?,
+                None => {
+                    return illegal_operation(format!("the value has no annotation #{}", index))
+                }
+            };
+            let data = symbol_bytes(annotation);
+            handle.lend(data, out_data, out_len);
+            Ok(())
+        })
+    })
+}
+
+/// An FFI handle for writing a stream of Ion.
+pub struct IonWriterHandle {
+    // Values are always encoded as binary Ion and converted to the requested format when the
+    // writer is finished.
+    writer: BinaryWriter<Vec<u8>>,
+    format: IonWriterFormat,
+    annotations: Vec<String>,
+    output: Vec<u8>,
+    last_error: Option<CString>,
+}
+
+impl IonWriterHandle {
+    fn run<F: FnOnce(&mut IonWriterHandle) -> IonResult<()>>(&mut self, f: F) -> IonStatus {
+        let result = f(self);
+        record(&mut self.last_error, result)
+    }
+
+    // Applies any pending annotations to the next value, then writes it with `write`.
+    fn write_value<F>(&mut self, write: F) -> IonStatus
+    where
+        F: FnOnce(&mut BinaryWriter<Vec<u8>>) -> IonResult<()>,
+    {
+        self.run(|handle| {
+            let annotations = std::mem::take(&mut handle.annotations);
+            handle.writer.set_annotations(&annotations);
+            write(&mut handle.writer)
+        })
+    }
+}
+
+/// Creates a writer that will encode values in the given [IonWriterFormat]. Returns null if
+/// `format` is not a valid format or if the writer could not be created.
+#[no_mangle]
+pub extern "C" fn ion_writer_new(format: u32) -> *mut IonWriterHandle {
+    guard(ptr::null_mut(), || {
+        let format = match IonWriterFormat::from_u32(format) {
+            Some(format) => format,
+            None => return ptr::null_mut(),
+        };
+        match BinaryWriterBuilder::new().build(Vec::new()) {
+            Ok(writer) => Box::into_raw(Box::new(IonWriterHandle {
+                writer,
+                format,
+                annotations: Vec::new(),
+                output: Vec::new(),
+                last_error: None,
+            })),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+/// Releases a writer created by `ion_writer_new`. Passing null has no effect.
+///
+/// # Safety
+///
+/// `writer` must be null or a handle returned by `ion_writer_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_free(writer: *mut IonWriterHandle) {
+    guard((), || {
+        if !writer.is_null() {
+            drop(Box::from_raw(writer));
+        }
+    })
+}
+
+/// Returns a description of the most recent error encountered by `writer`, or null if there
+/// hasn't been one. The string is owned by the writer.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_last_error(writer: *const IonWriterHandle) -> *const c_char {
+    guard(ptr::null(), || match writer.as_ref() {
+        Some(writer) => last_error_ptr(&writer.last_error),
+        None => ptr::null(),
+    })
+}
+
+/// Sets the field name of the next value, which must be written inside a struct. Fails if the
+/// writer is not inside a struct.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new` and `data` must point to `len`
+/// readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_set_field_name(
+    writer: *mut IonWriterHandle,
+    data: *const u8,
+    len: usize,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        let (writer, data) = match (writer.as_mut(), bytes(data, len)) {
+            (Some(writer), Some(data)) => (writer, data),
+            _ => return IonStatus::NullArgument,
+        };
+        writer.run(|handle| {
+            if handle.writer.parent_type() != Some(IonType::Struct) {
+                return illegal_operation("field names can only be set inside a struct");
+            }
+            handle.writer.set_field_name(text(data)?);
+            Ok(())
+        })
+    })
+}
+
+/// Adds an annotation to the next value.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new` and `data` must point to `len`
+/// readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_add_annotation(
+    writer: *mut IonWriterHandle,
+    data: *const u8,
+    len: usize,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        let (writer, data) = match (writer.as_mut(), bytes(data, len)) {
+            (Some(writer), Some(data)) => (writer, data),
+            _ => return IonStatus::NullArgument,
+        };
+        writer.run(|handle| {
+            handle.annotations.push(text(data)?.to_string());
+            Ok(())
+        })
+    })
+}
+
+/// Writes a null of the given [IonValueType].
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_null(
+    writer: *mut IonWriterHandle,
+    value_type: u32,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || match writer.as_mut() {
+        Some(writer) => writer.write_value(|w| w.write_null(IonValueType::ion_type(value_type)?)),
+        None => IonStatus::NullArgument,
+    })
+}
+
+/// Writes a bool.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_bool(
+    writer: *mut IonWriterHandle,
+    value: bool,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || match writer.as_mut() {
+        Some(writer) => writer.write_value(|w| w.write_bool(value)),
+        None => IonStatus::NullArgument,
+    })
+}
+
+/// Writes an int.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_i64(
+    writer: *mut IonWriterHandle,
+    value: i64,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || match writer.as_mut() {
+        Some(writer) => writer.write_value(|w| w.write_i64(value)),
+        None => IonStatus::NullArgument,
+    })
+}
+
+/// Writes a float.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_write_f64(
+    writer: *mut IonWriterHandle,
+    value: f64,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || match writer.as_mut() {
+        Some(writer) => writer.write_value(|w| w.write_f64(value)),
+        None => IonStatus::NullArgument,
+    })
+}
+
+// Defines an FFI function that writes a value taken from a caller-provided buffer.
+macro_rules! write_bytes_fn {
+    ($(#[$doc:meta])* $name:ident, |$writer:ident, $data:ident| $write:expr) => {
+        $(#[$doc])*
+        ///
+        /// # Safety
+        ///
+        /// `writer` must be a live handle returned by `ion_writer_new` and `data` must point to
+        /// `len` readable bytes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            writer: *mut IonWriterHandle,
+            data: *const u8,
+            len: usize,
+        ) -> IonStatus {
+            guard(IonStatus::Panicked, || {
+                let (writer, $data) = match (writer.as_mut(), bytes(data, len)) {
+                    (Some(writer), Some(data)) => (writer, data),
+                    _ => return IonStatus::NullArgument,
+                };
+                writer.write_value(|$writer| $write)
+            })
+        }
+    };
+}
+
+write_bytes_fn!(
+    /// Writes a string from `len` bytes of UTF-8 text.
+    ion_writer_write_string,
+    |writer, data| writer.write_string(text(data)?)
+);
+write_bytes_fn!(
+    /// Writes a symbol from `len` bytes of UTF-8 text.
+    ion_writer_write_symbol,
+    |writer, data| writer.write_symbol(text(data)?)
+);
+write_bytes_fn!(
+    /// Writes a blob containing `len` bytes.
+    ion_writer_write_blob,
+    |writer, data| writer.write_blob(data)
+);
+write_bytes_fn!(
+    /// Writes a clob containing `len` bytes.
+    ion_writer_write_clob,
+    |writer, data| writer.write_clob(data)
+);
+write_bytes_fn!(
+    /// Writes a decimal given its text Ion representation (e.g. `1.25`).
+    ion_writer_write_decimal,
+    |writer, data| {
+        let value: Decimal = parse_scalar(text(data)?, |reader| reader.read_decimal())?;
+        writer.write_decimal(&value)
+    }
+);
+write_bytes_fn!(
+    /// Writes a timestamp given its text Ion representation (e.g. `2022-06-01T12:00Z`).
+    ion_writer_write_timestamp,
+    |writer, data| {
+        let value: Timestamp = parse_scalar(text(data)?, |reader| reader.read_timestamp())?;
+        writer.write_timestamp(&value)
+    }
+);
+
+/// Steps into a new container of the given [IonValueType].
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_step_in(
+    writer: *mut IonWriterHandle,
+    container_type: u32,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || match writer.as_mut() {
+        Some(writer) => writer.write_value(|w| w.step_in(IonValueType::ion_type(container_type)?)),
+        None => IonStatus::NullArgument,
+    })
+}
+
+/// Steps out of the current container.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_step_out(writer: *mut IonWriterHandle) -> IonStatus {
+    guard(IonStatus::Panicked, || match writer.as_mut() {
+        Some(writer) => writer.run(|handle| handle.writer.step_out()),
+        None => IonStatus::NullArgument,
+    })
+}
+
+/// Flushes the writer, which must be at the top level, and lends the caller everything that it
+/// has encoded so far in the writer's format.
+///
+/// # Safety
+///
+/// `writer` must be a live handle returned by `ion_writer_new`. `out_data` and `out_len` must be
+/// writable.
+#[no_mangle]
+pub unsafe extern "C" fn ion_writer_finish(
+    writer: *mut IonWriterHandle,
+    out_data: *mut *const u8,
+    out_len: *mut usize,
+) -> IonStatus {
+    guard(IonStatus::Panicked, || {
+        let writer = match writer.as_mut() {
+            Some(writer) => writer,
+            None => return IonStatus::NullArgument,
+        };
+        if out_data.is_null() || out_len.is_null() {
+            return IonStatus::NullArgument;
+        }
+        writer.run(|handle| {
+            if handle.writer.depth() > 0 {
+                return illegal_operation("cannot finish a writer that is inside a container");
+            }
+            handle.writer.flush()?;
+            let binary = handle.writer.output().as_slice();
+            handle.output.clear();
+            match handle.format {
+                IonWriterFormat::Binary => handle.output.extend_from_slice(binary),
+                IonWriterFormat::Text => {
+                    convert(binary, &mut handle.output, Format::Text(TextKind::Compact))?
+                }
+                IonWriterFormat::PrettyText => {
+                    convert(binary, &mut handle.output, Format::Text(TextKind::Pretty))?
+                }
+            }
+            *out_data = handle.output.as_ptr();
+            *out_len = handle.output.len();
+            Ok(())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    unsafe fn lent_text<'a>(data: *const u8, len: usize) -> &'a str {
+        std::str::from_utf8(slice::from_raw_parts(data, len)).unwrap()
+    }
+
+    #[test]
+    fn write_then_read() {
+        unsafe {
+            let writer = ion_writer_new(IonWriterFormat::Text as u32);
+            assert!(!writer.is_null());
+            assert_eq!(
+                ion_writer_add_annotation(writer, b"point".as_ptr(), 5),
+                IonStatus::Ok
+            );
+            assert_eq!(
+                ion_writer_step_in(writer, IonValueType::Struct as u32),
+                IonStatus::Ok
+            );
+            assert_eq!(
+                ion_writer_set_field_name(writer, b"x".as_ptr(), 1),
+                IonStatus::Ok
+            );
+            assert_eq!(ion_writer_write_i64(writer, 3), IonStatus::Ok);
+            assert_eq!(
+                ion_writer_set_field_name(writer, b"y".as_ptr(), 1),
+                IonStatus::Ok
+            );
+            assert_eq!(
+                ion_writer_write_decimal(writer, b"2.5".as_ptr(), 3),
+                IonStatus::Ok
+            );
+            assert_eq!(ion_writer_step_out(writer), IonStatus::Ok);
+            assert_eq!(
+                ion_writer_write_string(writer, b"hi".as_ptr(), 2),
+                IonStatus::Ok
+            );
+
+            let mut data = ptr::null();
+            let mut len = 0;
+            assert_eq!(
+                ion_writer_finish(writer, &mut data, &mut len),
+                IonStatus::Ok
+            );
+            let output = slice::from_raw_parts(data, len).to_vec();
+            ion_writer_free(writer);
+
+            let reader = ion_reader_new(output.as_ptr(), output.len());
+            assert!(!reader.is_null());
+            let mut value_type = IonValueType::Nothing;
+            assert_eq!(ion_reader_next(reader, &mut value_type), IonStatus::Ok);
+            assert_eq!(value_type, IonValueType::Struct);
+            let mut count = 0;
+            assert_eq!(
+                ion_reader_annotation_count(reader, &mut count),
+                IonStatus::Ok
+            );
+            assert_eq!(count, 1);
+            assert_eq!(
+                ion_reader_annotation(reader, 0, &mut data, &mut len),
+                IonStatus::Ok
+            );
+            assert_eq!(lent_text(data, len), "point");
+
+            assert_eq!(ion_reader_step_in(reader), IonStatus::Ok);
+            assert_eq!(ion_reader_next(reader, &mut value_type), IonStatus::Ok);
+            assert_eq!(value_type, IonValueType::Int);
+            assert_eq!(
+                ion_reader_field_name(reader, &mut data, &mut len),
+                IonStatus::Ok
+            );
+            assert_eq!(lent_text(data, len), "x");
+            let mut int_value = 0;
+            assert_eq!(ion_reader_read_i64(reader, &mut int_value), IonStatus::Ok);
+            assert_eq!(int_value, 3);
+            assert_eq!(ion_reader_next(reader, &mut value_type), IonStatus::Ok);
+            assert_eq!(value_type, IonValueType::Decimal);
+            assert_eq!(
+                ion_reader_read_decimal(reader, &mut data, &mut len),
+                IonStatus::Ok
+            );
+            assert_eq!(lent_text(data, len), "25d-1");
+            assert_eq!(ion_reader_step_out(reader), IonStatus::Ok);
+
+            assert_eq!(ion_reader_next(reader, &mut value_type), IonStatus::Ok);
+            assert_eq!(value_type, IonValueType::String);
+            assert_eq!(
+                ion_reader_read_string(reader, &mut data, &mut len),
+                IonStatus::Ok
+            );
+            assert_eq!(lent_text(data, len), "hi");
+            assert_eq!(ion_reader_next(reader, &mut value_type), IonStatus::Ok);
+            assert_eq!(value_type, IonValueType::Nothing);
+            ion_reader_free(reader);
+        }
+    }
+
+    #[test]
+    fn errors_are_reported() {
+        unsafe {
+            let reader = ion_reader_new(b"\"hi\"".as_ptr(), 4);
+            let mut value_type = IonValueType::Nothing;
+            assert_eq!(ion_reader_next(reader, &mut value_type), IonStatus::Ok);
+            assert!(ion_reader_last_error(reader).is_null());
+            let mut value = false;
+            assert_eq!(ion_reader_read_bool(reader, &mut value), IonStatus::Error);
+            let message = CStr::from_ptr(ion_reader_last_error(reader));
+            assert!(!message.to_bytes().is_empty());
+            assert_eq!(
+                ion_reader_next(reader, ptr::null_mut()),
+                IonStatus::NullArgument
+            );
+            ion_reader_free(reader);
+        }
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        unsafe {
+            assert!(ion_writer_new(3).is_null());
+            let writer = ion_writer_new(IonWriterFormat::Binary as u32);
+            // Setting a field name outside of a struct is an error rather than a panic.
+            assert_eq!(
+                ion_writer_set_field_name(writer, b"x".as_ptr(), 1),
+                IonStatus::Error
+            );
+            assert_eq!(ion_writer_write_null(writer, 14), IonStatus::Error);
+            assert_eq!(ion_writer_step_in(writer, u32::MAX), IonStatus::Error);
+            // A null pointer is only accepted with a length of zero.
+            assert_eq!(
+                ion_writer_write_string(writer, ptr::null(), 3),
+                IonStatus::NullArgument
+            );
+            assert_eq!(
+                ion_writer_write_blob(writer, ptr::null(), 3),
+                IonStatus::NullArgument
+            );
+            assert_eq!(
+                ion_writer_add_annotation(writer, ptr::null(), 1),
+                IonStatus::NullArgument
+            );
+            assert_eq!(ion_writer_write_blob(writer, ptr::null(), 0), IonStatus::Ok);
+            ion_writer_free(writer);
+        }
+    }
+
+    #[test]
+    fn panics_do_not_unwind() {
+        assert_eq!(
+            guard(IonStatus::Panicked, || panic!("oops")),
+            IonStatus::Panicked
+        );
+    }
+}
@@ -0,0 +1,373 @@
+#![cfg(feature = "ion_c")]
+
+use std::mem::ManuallyDrop;
+
+use ion_c_sys::reader::{IonCReader, IonCReaderHandle};
+use ion_c_sys::result::Position as IonCPosition;
+
+use crate::position::Position;
+use crate::raw_reader::RawStreamItem;
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{illegal_operation, illegal_operation_raw, IonResult};
+use crate::stream_reader::StreamReader;
+use crate::types::decimal::Decimal;
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+
+/// A [RawReader](crate::RawReader) implementation backed by ion-c.
+///
+/// ion-c resolves symbol tables itself, so every field name, annotation, and symbol value that
+/// this reader surfaces is [RawSymbolToken::Text] and local symbol tables are never surfaced as
+/// values. It is normally constructed by a [ReaderBuilder](crate::ReaderBuilder) that has been
+/// configured to use [ReaderBackend::IonC](crate::ReaderBackend::IonC).
+pub struct IonCRawReader {
+    // ion-c reads directly from `input`, which is freed after the handle is closed in `drop`.
+    handle: ManuallyDrop<IonCReaderHandle<'static>>,
+    input: *mut [u8],
+    current: RawStreamItem,
+    field_name: Option<String>,
+    annotations: Vec<String>,
+    // Holds the text of the current string value so that `read_str` can return a reference to it.
+    text: String,
+    parents: Vec<IonType>,
+}
+
+impl IonCRawReader {
+    /// Constructs an ion-c backed reader over `input`, which may be either text or binary Ion.
+    pub fn new(input: Vec<u8>) -> IonResult<IonCRawReader> {
+        let input = Box::into_raw(input.into_boxed_slice());
+        // SAFETY: `input` is a heap allocation that does not move when this reader does and is
+        // only freed in `drop`, after the handle that refers to it has been closed.
+        let handle = match IonCReaderHandle::try_from(unsafe { &*input }) {
+            Ok(handle) => handle,
+            Err(error) => {
+                // SAFETY: `input` came from `Box::into_raw` above and is not referenced elsewhere.
+                drop(unsafe { Box::from_raw(input) });
+                return Err(error.into());
+            }
+        };
+        Ok(IonCRawReader {
+            handle: ManuallyDrop::new(handle),
+            input,
+            current: RawStreamItem::Nothing,
+            field_name: None,
+            annotations: Vec::new(),
+            text: String::new(),
+            parents: Vec::new(),
+        })
+    }
+
+    fn expect_type(&self, expected: IonType, operation: &str) -> IonResult<()> {
+        match self.current {
+            RawStreamItem::Value(ion_type) if ion_type == expected => Ok(()),
+            _ => illegal_operation(format!(
+                "type mismatch: {} was called but the reader is positioned on {:?}",
+                operation, self.current
+            )),
+        }
+    }
+
+    fn read_lob(&mut self, expected: IonType, operation: &str) -> IonResult<Vec<u8>> {
+        self.expect_type(expected, operation)?;
+        Ok(self.handle.read_bytes()?)
+    }
+}
+
+impl Drop for IonCRawReader {
+    fn drop(&mut self) {
+        // SAFETY: the handle is never used again, and closing it first guarantees that ion-c no
+        // longer refers to `input` when `input` is freed.
+        unsafe {
+            ManuallyDrop::drop(&mut self.handle);
+            drop(Box::from_raw(self.input));
+        }
+    }
+}
+
+impl StreamReader for IonCRawReader {
+    type Item = RawStreamItem;
+    type Symbol = RawSymbolToken;
+
+    fn ion_version(&self) -> (u8, u8) {
+        (1, 0)
+    }
+
+    fn next(&mut self) -> IonResult<Self::Item> {
+        self.field_name = None;
+        self.annotations.clear();
+        let ionc_type = self.handle.next()?;
+        if ionc_type == ion_c_sys::ION_TYPE_EOF {
+            self.current = RawStreamItem::Nothing;
+            return Ok(RawStreamItem::Nothing);
+        }
+        let ion_type: IonType = ionc_type.try_into()?;
+        if self.parent_type() == Some(IonType::Struct) {
+            self.field_name = Some(self.handle.get_field_name()?.as_str().to_owned());
+        }
+        for annotation in self.handle.get_annotations()?.iter() {
+            self.annotations.push((*annotation).to_owned());
+        }
+        self.current = if self.handle.is_null()? {
+            RawStreamItem::Null(ion_type)
+        } else {
+            RawStreamItem::Value(ion_type)
+        };
+        Ok(self.current)
+    }
+
+    fn current(&self) -> Self::Item {
+        self.current
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        match self.current {
+            RawStreamItem::Value(ion_type) | RawStreamItem::Null(ion_type) => Some(ion_type),
+            _ => None,
+        }
+    }
+
+    fn annotation(&self, index: usize) -> IonResult<Self::Symbol> {
+        self.annotations
+            .get(index)
+            .map(|text| RawSymbolToken::Text(text.clone()))
+            .ok_or_else(|| {
+                illegal_operation_raw(format!(
+                    "annotation index {} is out of bounds; the current value has {} annotations",
+                    index,
+                    self.annotations.len()
+                ))
+            })
+    }
+
+    fn number_of_annotations(&self) -> usize {
+        self.annotations.len()
+    }
+
+    fn field_name(&self) -> IonResult<Self::Symbol> {
+        match self.field_name.as_ref() {
+            Some(text) => Ok(RawSymbolToken::Text(text.clone())),
+            None => illegal_operation(
+                "field_name() can only be called when the reader is positioned inside a struct",
+            ),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self.current, RawStreamItem::Null(_))
+    }
+
+    fn read_null(&mut self) -> IonResult<IonType> {
+        match self.current {
+            RawStreamItem::Null(ion_type) => Ok(ion_type),
+            _ => illegal_operation("read_null() was called but the current value is not a null"),
+        }
+    }
+
+    fn read_bool(&mut self) -> IonResult<bool> {
+        self.expect_type(IonType::Boolean, "read_bool")?;
+        Ok(self.handle.read_bool()?)
+    }
+
+    fn read_i64(&mut self) -> IonResult<i64> {
+        self.expect_type(IonType::Integer, "read_i64")?;
+        Ok(self.handle.read_i64()?)
+    }
+
+    fn read_integer(&mut self) -> IonResult<Integer> {
+        self.expect_type(IonType::Integer, "read_integer")?;
+        if let Ok(value) = self.handle.read_i64() {
+            return Ok(Integer::I64(value));
+        }
+        Ok(Integer::BigInt(self.handle.read_bigint()?))
+    }
+
+    fn read_f32(&mut self) -> IonResult<f32> {
+        self.read_f64().map(|value| value as f32)
+    }
+
+    fn read_f64(&mut self) -> IonResult<f64> {
+        self.expect_type(IonType::Float, "read_f64")?;
+        Ok(self.handle.read_f64()?)
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Decimal> {
+        self.expect_type(IonType::Decimal, "read_decimal")?;
+        Ok(self.handle.read_bigdecimal()?.into())
+    }
+
+    fn read_string(&mut self) -> IonResult<String> {
+        self.read_str().map(|text| text.to_owned())
+    }
+
+    fn read_str(&mut self) -> IonResult<&str> {
+        self.expect_type(IonType::String, "read_str")?;
+        self.text.clear();
+        self.text.push_str(self.handle.read_string()?.as_str());
+        Ok(&self.text)
+    }
+
+    fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&str) -> U,
+    {
+        self.read_str().map(f)
+    }
+
+    fn map_string_bytes<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.read_str().map(|text| f(text.as_bytes()))
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
+        self.expect_type(IonType::Symbol, "read_symbol")?;
+        let text = self.handle.read_string()?.as_str().to_owned();
+        Ok(RawSymbolToken::Text(text))
+    }
+
+    fn read_blob(&mut self) -> IonResult<Vec<u8>> {
+        self.read_lob(IonType::Blob, "read_blob")
+    }
+
+    fn map_blob<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.read_blob().map(|bytes| f(&bytes))
+    }
+
+    fn read_clob(&mut self) -> IonResult<Vec<u8>> {
+        self.read_lob(IonType::Clob, "read_clob")
+    }
+
+    fn map_clob<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.read_clob().map(|bytes| f(&bytes))
+    }
+
+    // ion-c materializes lobs in a single call, so they are surfaced as a single chunk.
+    fn read_blob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        let bytes = self.read_blob()?;
+        chunk_handler(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    fn read_clob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        let bytes = self.read_clob()?;
+        chunk_handler(&bytes)?;
+        Ok(bytes.len())
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
+        self.expect_type(IonType::Timestamp, "read_timestamp")?;
+        Ok(self.handle.read_datetime()?.into())
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        match self.current {
+            RawStreamItem::Value(ion_type) if ion_type.is_container() => {
+                self.handle.step_in()?;
+                self.parents.push(ion_type);
+                self.current = RawStreamItem::Nothing;
+                Ok(())
+            }
+            _ => illegal_operation(format!(
+                "cannot step_in() while the reader is positioned on {:?}",
+                self.current
+            )),
+        }
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if self.parents.is_empty() {
+            return illegal_operation("cannot step_out() of the top level");
+        }
+        self.handle.step_out()?;
+        self.parents.pop();
+        self.current = RawStreamItem::Nothing;
+        self.field_name = None;
+        self.annotations.clear();
+        Ok(())
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        self.parents.last().copied()
+    }
+
+    fn depth(&self) -> usize {
+        self.parents.len()
+    }
+
+    fn position(&self) -> Position {
+        match self.handle.pos() {
+            Ok(IonCPosition::Offset(bytes)) => Position::with_offset(bytes as usize),
+            Ok(IonCPosition::OffsetLineColumn(bytes, line_column)) => {
+                Position::with_offset(bytes as usize)
+                    .with_line_and_column(line_column.0 as usize, line_column.1 as usize)
+            }
+            _ => Position::with_offset(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::{ReaderBackend, ReaderBuilder, StreamItem};
+
+    #[test]
+    fn read_with_ion_c_backend() -> IonResult<()> {
+        let ion_data = "foo::{a: 1, b: [true, \"hi\"], c: null.int}";
+        let mut reader = ReaderBuilder::new()
+            .with_backend(ReaderBackend::IonC)
+            .build(ion_data)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Struct));
+        assert_eq!(reader.annotations().next().unwrap()?, "foo");
+        reader.step_in()?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.field_name()?, "a");
+        assert_eq!(reader.read_i64()?, 1);
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::List));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Boolean));
+        assert!(reader.read_bool()?);
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert_eq!(reader.read_str()?, "hi");
+        reader.step_out()?;
+        assert_eq!(reader.next()?, StreamItem::Null(IonType::Integer));
+        assert_eq!(reader.field_name()?, "c");
+        reader.step_out()?;
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn backends_agree() -> IonResult<()> {
+        let ion_data = "{a: 1, b: [2.5, 3e0, 2022-01-01T]} (c d) {{aGVsbG8=}}";
+        let native = ReaderBuilder::new()
+            .build(ion_data)?
+            .elements()
+            .collect::<IonResult<Vec<_>>>()?;
+        let ion_c = ReaderBuilder::new()
+            .with_backend(ReaderBackend::IonC)
+            .build(ion_data)?
+            .elements()
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(native, ion_c);
+        Ok(())
+    }
+}
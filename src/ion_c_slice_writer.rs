@@ -0,0 +1,288 @@
+#![cfg(feature = "ion_c")]
+
+use bigdecimal::BigDecimal;
+use ion_c_sys::result::IonCResult;
+use ion_c_sys::timestamp::IonDateTime;
+use ion_c_sys::writer::{
+    IonCAnnotationsFieldWriter, IonCValueWriter, IonCWriter, IonCWriterHandle,
+};
+
+use crate::raw_symbol_token_ref::{AsRawSymbolTokenRef, RawSymbolTokenRef};
+use crate::result::{illegal_operation, IonResult};
+use crate::symbol_table::SymbolTable;
+use crate::types::decimal::Decimal;
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+use crate::value::ion_c_writer::writer_options;
+use crate::value::writer::Format;
+use crate::writer::Writer;
+
+/// A [Writer] implementation backed by ion-c that encodes values into a caller-provided buffer.
+///
+/// ion-c manages its own symbol tables, so field names, annotations, and symbol values are always
+/// provided to it as text. Symbol IDs are only accepted if they refer to system symbols.
+///
+/// The writer cannot grow its buffer; writing more data than the buffer can hold results in an
+/// error. Call [IonCSliceWriter::finish] to retrieve the encoded data.
+pub struct IonCSliceWriter<'a> {
+    // Raw pointer to the buffer that the ion-c writer borrows, retained so that `finish` can
+    // return the encoded data once the writer has been closed.
+    data: *const u8,
+    writer: IonCWriterHandle<'a>,
+    bytes_written: usize,
+    symbol_table: SymbolTable,
+    annotations: Vec<String>,
+    field_name: Option<String>,
+    parents: Vec<IonType>,
+}
+
+impl<'a> IonCSliceWriter<'a> {
+    /// Constructs a writer that will encode values into `buffer` using the specified format.
+    pub fn new(buffer: &'a mut [u8], format: Format) -> IonResult<IonCSliceWriter<'a>> {
+        let data = buffer.as_ptr();
+        let mut options = writer_options(format);
+        let writer = IonCWriterHandle::new_buf(buffer, &mut options)?;
+        Ok(IonCSliceWriter {
+            data,
+            writer,
+            bytes_written: 0,
+            symbol_table: SymbolTable::new(),
+            annotations: Vec::new(),
+            field_name: None,
+            parents: Vec::new(),
+        })
+    }
+
+    /// Flushes any pending data and closes the writer, returning the portion of the buffer
+    /// that contains the encoded stream. The writer must be at the top level.
+    pub fn finish(mut self) -> IonResult<&'a [u8]> {
+        self.flush()?;
+        let data = self.data;
+        let len = self.bytes_written;
+        drop(self);
+        // SAFETY: the writer (and with it, ion-c's borrow of the buffer) has been dropped, so the
+        // first `len` bytes of the buffer can be handed back for the rest of its lifetime.
+        Ok(unsafe { std::slice::from_raw_parts(data, len) })
+    }
+
+    fn text_for<A: AsRawSymbolTokenRef>(&self, token: &A, usage: &str) -> String {
+        match token.as_raw_symbol_token_ref() {
+            RawSymbolTokenRef::SymbolId(symbol_id) => {
+                if let Some(text) = self.symbol_table.text_for(symbol_id) {
+                    text.to_owned()
+                } else {
+                    panic!(
+                        "Cannot use symbol ID ${} as {}; it is undefined.",
+                        symbol_id, usage
+                    );
+                }
+            }
+            RawSymbolTokenRef::Text(text) => text.to_owned(),
+        }
+    }
+
+    // Writes a value using `applier`, decorating it with any pending annotations and field name.
+    fn write_value<F>(&mut self, applier: F) -> IonResult<()>
+    where
+        F: Fn(&mut IonCWriterHandle<'a>) -> IonCResult<()>,
+    {
+        let annotations: Vec<&str> = self.annotations.iter().map(String::as_str).collect();
+        let field_name = match self.parent_type() {
+            Some(IonType::Struct) => self.field_name.as_deref(),
+            _ => None,
+        };
+        self.writer
+            .write_annotations_and_field(annotations.as_slice(), field_name, applier)?;
+        self.annotations.clear();
+        self.field_name = None;
+        Ok(())
+    }
+}
+
+impl<'a> Writer for IonCSliceWriter<'a> {
+    fn ion_version(&self) -> (u8, u8) {
+        (1, 0)
+    }
+
+    /// ion-c emits version markers itself. Calling this method flushes the writer, which ends
+    /// the current symbol table context; values written afterwards begin a new one.
+    fn write_ion_version_marker(&mut self, major: u8, minor: u8) -> IonResult<()> {
+        if major != 1 || minor != 0 {
+            return illegal_operation("Only Ion 1.0 is supported.");
+        }
+        self.flush()
+    }
+
+    fn supports_text_symbol_tokens(&self) -> bool {
+        true
+    }
+
+    fn set_annotations<I, A>(&mut self, annotations: I)
+    where
+        A: AsRawSymbolTokenRef,
+        I: IntoIterator<Item = A>,
+    {
+        self.annotations.clear();
+        for annotation in annotations {
+            let text = self.text_for(&annotation, "an annotation");
+            self.annotations.push(text);
+        }
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        self.write_value(|writer| writer.write_null(ion_type.into()))
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        self.write_value(|writer| writer.write_bool(value))
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        self.write_value(|writer| writer.write_i64(value))
+    }
+
+    fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+        match value {
+            Integer::I64(value) => self.write_i64(*value),
+            Integer::BigInt(value) => self.write_value(|writer| writer.write_bigint(value)),
+        }
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        self.write_f64(value as f64)
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        self.write_value(|writer| writer.write_f64(value))
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        let value: BigDecimal = value.clone().try_into()?;
+        self.write_value(|writer| writer.write_bigdecimal(&value))
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        let value: IonDateTime = value.clone().try_into()?;
+        self.write_value(|writer| writer.write_datetime(&value))
+    }
+
+    fn write_symbol<A: AsRawSymbolTokenRef>(&mut self, value: A) -> IonResult<()> {
+        let text = match value.as_raw_symbol_token_ref() {
+            RawSymbolTokenRef::SymbolId(symbol_id) => {
+                if let Some(text) = self.symbol_table.text_for(symbol_id) {
+                    text.to_owned()
+                } else {
+                    return illegal_operation(format!(
+                        "Cannot write symbol ID ${} as a symbol value; it is undefined.",
+                        symbol_id
+                    ));
+                }
+            }
+            RawSymbolTokenRef::Text(text) => text.to_owned(),
+        };
+        self.write_value(|writer| writer.write_symbol(&text))
+    }
+
+    fn write_string<A: AsRef<str>>(&mut self, value: A) -> IonResult<()> {
+        self.write_value(|writer| writer.write_string(value.as_ref()))
+    }
+
+    fn write_clob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        self.write_value(|writer| writer.write_clob(value.as_ref()))
+    }
+
+    fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        self.write_value(|writer| writer.write_blob(value.as_ref()))
+    }
+
+    fn step_in(&mut self, container_type: IonType) -> IonResult<()> {
+        match container_type {
+            IonType::List | IonType::SExpression | IonType::Struct => {}
+            _ => return illegal_operation("Cannot step into a scalar Ion type."),
+        }
+        self.write_value(|writer| writer.start_container(container_type.into()))?;
+        self.parents.push(container_type);
+        Ok(())
+    }
+
+    fn set_field_name<A: AsRawSymbolTokenRef>(&mut self, name: A) {
+        self.field_name = Some(self.text_for(&name, "a field name"));
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        self.parents.last().copied()
+    }
+
+    fn depth(&self) -> usize {
+        self.parents.len()
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if self.parents.pop().is_none() {
+            return illegal_operation("Cannot step out of the top level.");
+        }
+        self.writer.finish_container()?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> IonResult<()> {
+        if self.depth() > 0 {
+            return illegal_operation("Cannot flush while the writer is inside a container.");
+        }
+        self.bytes_written += self.writer.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ion_eq::IonEq;
+    use crate::value::reader::{native_element_reader, ElementReader};
+    use crate::value::writer::{Binary, Compact, Text};
+    use rstest::*;
+
+    #[rstest]
+    #[case::text(Text(Compact))]
+    #[case::binary(Binary)]
+    fn write_values(#[case] format: Format) -> IonResult<()> {
+        let mut buffer = vec![0u8; 1024];
+        let mut writer = IonCSliceWriter::new(&mut buffer, format)?;
+        writer.set_annotations(["foo", "bar"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("a");
+        writer.write_i64(1)?;
+        writer.set_field_name("b");
+        writer.step_in(IonType::List)?;
+        writer.write_bool(true)?;
+        writer.write_string("hi")?;
+        writer.set_annotations([1]);
+        writer.write_symbol("baz")?;
+        writer.write_null(IonType::Integer)?;
+        writer.step_out()?;
+        writer.set_field_name(4);
+        writer.write_f64(2.5)?;
+        writer.step_out()?;
+        writer.write_blob(b"hello")?;
+        let output = writer.finish()?;
+
+        let actual = native_element_reader().read_all(output)?;
+        let expected = native_element_reader().read_all(
+            br#"foo::bar::{a: 1, b: [true, "hi", '$ion'::baz, null.int], name: 2.5e0} {{aGVsbG8=}}"#,
+        )?;
+        assert!(actual.ion_eq(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_flush_inside_container() -> IonResult<()> {
+        let mut buffer = vec![0u8; 128];
+        let mut writer = IonCSliceWriter::new(&mut buffer, Binary)?;
+        writer.step_in(IonType::List)?;
+        assert!(writer.flush().is_err());
+        writer.step_out()?;
+        writer.flush()?;
+        Ok(())
+    }
+}
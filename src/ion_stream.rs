@@ -0,0 +1,245 @@
+use std::mem;
+
+use crate::result::IonResult;
+use crate::types::IonType;
+use crate::value::native_writer::write_element;
+use crate::value::owned::{OwnedElement, OwnedValue};
+use crate::value::{Element, Sequence, Struct, SymbolToken};
+use crate::writer::Writer;
+
+/// Combinators for processing a stream of top-level elements, such as the iterators returned by
+/// [ElementReader](crate::value::reader::ElementReader).
+///
+/// Errors from the underlying iterator are passed through each combinator unchanged, so a
+/// pipeline can be built from any number of combinators and its errors handled once at the end.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::value::reader::{element_reader, ElementReader};
+/// use ion_rs::{IonStream, TextWriterBuilder};
+///
+/// let data = b"event::{user: \"alice\"} metric::{value: 5} event::{user: \"bob\"}";
+/// let mut buffer = Vec::new();
+/// let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+/// let written = element_reader()
+///     .iterate_over(data)?
+///     .filter_annotated("event")
+///     .map_struct_field("user", |_user| String::from("redacted").into())
+///     .write_to(&mut writer)?;
+/// drop(writer);
+/// assert_eq!(written, 2);
+/// assert_eq!(
+///     String::from_utf8(buffer).unwrap().trim(),
+///     "event::{user: \"redacted\"} event::{user: \"redacted\"}"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub trait IonStream: Iterator<Item = IonResult<OwnedElement>> + Sized {
+    /// Keeps only the elements that are annotated with `annotation`.
+    fn filter_annotated<A: Into<String>>(self, annotation: A) -> FilterAnnotated<Self> {
+        FilterAnnotated {
+            elements: self,
+            annotation: annotation.into(),
+        }
+    }
+
+    /// Replaces the value of each field named `field_name` in each top-level struct with the
+    /// result of calling `mapper` on it. Elements that are not structs, or that do not have the
+    /// field, are passed through unchanged.
+    fn map_struct_field<A, F>(self, field_name: A, mapper: F) -> MapStructField<Self, F>
+    where
+        A: Into<String>,
+        F: FnMut(OwnedElement) -> OwnedElement,
+    {
+        MapStructField {
+            elements: self,
+            field_name: field_name.into(),
+            mapper,
+        }
+    }
+
+    /// Passes elements through until their combined size would exceed `limit` bytes, then ends
+    /// the stream. Each element's size is the approximate amount of memory it uses, as measured
+    /// by [ReaderLimits::with_max_materialized_bytes](crate::ReaderLimits::with_max_materialized_bytes).
+    fn take_bytes(self, limit: usize) -> TakeBytes<Self> {
+        TakeBytes {
+            elements: self,
+            remaining: Some(limit),
+        }
+    }
+
+    /// Writes each element to `writer` as a top-level value, returning the number of elements
+    /// that were written. Stops at the first error, whether it comes from the stream or from the
+    /// writer. If the writer is at the top level when all of the elements have been written, it
+    /// is flushed.
+    fn write_to<W: Writer>(self, writer: &mut W) -> IonResult<usize> {
+        let mut count = 0;
+        for element in self {
+            write_element(writer, None, &element?)?;
+            count += 1;
+        }
+        if writer.depth() == 0 {
+            writer.flush()?;
+        }
+        Ok(count)
+    }
+}
+
+impl<I: Iterator<Item = IonResult<OwnedElement>>> IonStream for I {}
+
+/// See [IonStream::filter_annotated].
+pub struct FilterAnnotated<I> {
+    elements: I,
+    annotation: String,
+}
+
+impl<I: Iterator<Item = IonResult<OwnedElement>>> Iterator for FilterAnnotated<I> {
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let annotation = self.annotation.as_str();
+        self.elements.find(|result| match result {
+            Ok(element) => element.annotations().any(|a| a.text() == Some(annotation)),
+            Err(_) => true,
+        })
+    }
+}
+
+/// See [IonStream::map_struct_field].
+pub struct MapStructField<I, F> {
+    elements: I,
+    field_name: String,
+    mapper: F,
+}
+
+impl<I, F> Iterator for MapStructField<I, F>
+where
+    I: Iterator<Item = IonResult<OwnedElement>>,
+    F: FnMut(OwnedElement) -> OwnedElement,
+{
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut element = match self.elements.next()? {
+            Ok(element) => element,
+            error => return Some(error),
+        };
+        if let Some(structure) = element.as_struct_mut() {
+            for value in structure.get_all_mut(&self.field_name) {
+                let placeholder = OwnedElement::from(OwnedValue::Null(IonType::Null));
+                *value = (self.mapper)(mem::replace(value, placeholder));
+            }
+        }
+        Some(Ok(element))
+    }
+}
+
+/// See [IonStream::take_bytes].
+pub struct TakeBytes<I> {
+    elements: I,
+    // The number of bytes left in the budget, or `None` once the budget has been exceeded.
+    remaining: Option<usize>,
+}
+
+impl<I: Iterator<Item = IonResult<OwnedElement>>> Iterator for TakeBytes<I> {
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+        let element = match self.elements.next()? {
+            Ok(element) => element,
+            error => return Some(error),
+        };
+        self.remaining = remaining.checked_sub(approximate_size(&element));
+        self.remaining?;
+        Some(Ok(element))
+    }
+}
+
+// Returns the approximate amount of memory that `element` uses, counting the size of an element
+// for each nested value plus the length of its text or bytes, field name, and annotations.
+fn approximate_size(element: &OwnedElement) -> usize {
+    let mut size = 0usize;
+    let mut pending: Vec<(Option<&str>, &OwnedElement)> = vec![(None, element)];
+    while let Some((field_name, element)) = pending.pop() {
+        let metadata_length: usize = field_name.map_or(0, str::len)
+            + element
+                .annotations()
+                .map(|annotation| annotation.text().map_or(0, str::len))
+                .sum::<usize>();
+        let payload_length = match element.ion_type() {
+            IonType::String => element.as_str().map_or(0, str::len),
+            IonType::Symbol => element.as_sym().and_then(|s| s.text()).map_or(0, str::len),
+            IonType::Clob | IonType::Blob => element.as_bytes().map_or(0, <[u8]>::len),
+            IonType::List | IonType::SExpression => {
+                if let Some(sequence) = element.as_sequence() {
+                    pending.extend(sequence.iter().map(|child| (None, child)));
+                }
+                0
+            }
+            IonType::Struct => {
+                if let Some(structure) = element.as_struct() {
+                    pending.extend(
+                        structure
+                            .iter()
+                            .map(|(field_name, child)| (field_name.text(), child)),
+                    );
+                }
+                0
+            }
+            _ => 0,
+        };
+        size =
+            size.saturating_add(mem::size_of::<OwnedElement>() + metadata_length + payload_length);
+    }
+    size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::reader::{native_element_reader, ElementReader};
+    use crate::{Integer, TextWriterBuilder};
+
+    fn stream(text: &'static str) -> impl Iterator<Item = IonResult<OwnedElement>> {
+        native_element_reader()
+            .iterate_over(text.as_bytes())
+            .unwrap()
+    }
+
+    #[test]
+    fn filter_and_map() -> IonResult<()> {
+        let elements: Vec<OwnedElement> = stream("a::{x: 1} b::{x: 2} a::{x: 3, x: 4} a::5")
+            .filter_annotated("a")
+            .map_struct_field("x", |x| match x.as_integer() {
+                Some(Integer::I64(value)) => OwnedElement::from(value * 10),
+                _ => x,
+            })
+            .collect::<IonResult<_>>()?;
+        let expected = native_element_reader().read_all(b"a::{x: 10} a::{x: 30, x: 40} a::5")?;
+        assert_eq!(elements, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn take_bytes() -> IonResult<()> {
+        let element_size = mem::size_of::<OwnedElement>();
+        let count = stream("1 2 3 4").take_bytes(element_size * 2).count();
+        assert_eq!(count, 2);
+        // A list of two integers is three elements.
+        let count = stream("[1, 2] 3").take_bytes(element_size * 3).count();
+        assert_eq!(count, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn write_to_stops_at_errors() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+        assert!(stream("1 2 {").write_to(&mut writer).is_err());
+        let count = stream("1 2 3").write_to(&mut writer)?;
+        assert_eq!(count, 3);
+        Ok(())
+    }
+}
@@ -17,6 +17,8 @@ macro_rules! try_to {
 
 pub mod result;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
 pub mod binary;
 pub mod data_source;
 pub mod raw_reader;
@@ -24,43 +26,94 @@ pub mod text;
 pub mod types;
 pub mod value;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
+mod buffer_pool;
+mod catalog;
+mod conformance;
 pub mod constants;
+mod convert;
+mod csv;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "ion_c")]
+mod ion_c_raw_reader;
+#[cfg(feature = "ion_c")]
+mod ion_c_slice_writer;
 pub mod ion_eq;
+mod ion_stream;
+mod path_extractor;
+mod position;
 mod raw_symbol_token;
 mod raw_symbol_token_ref;
 mod reader;
+mod reader_stats;
+#[cfg(feature = "schema")]
+mod schema;
+mod shared_symbol_table;
 mod stream_reader;
 mod symbol;
 mod symbol_table;
 mod system_reader;
+mod transform;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 mod writer;
 
+pub use buffer_pool::BufferPool;
+pub use catalog::{Catalog, FileSystemCatalog, MapCatalog};
+pub use conformance::{
+    ConformanceReport, ConformanceRunner, TestCategory, TestOutcome, TestResult,
+};
+pub use convert::convert;
+pub use csv::{ColumnType, CsvConverter, CsvConverterBuilder};
 pub use data_source::IonDataSource;
+#[cfg(feature = "ion_c")]
+pub use ion_c_raw_reader::IonCRawReader;
+#[cfg(feature = "ion_c")]
+pub use ion_c_slice_writer::IonCSliceWriter;
+pub use path_extractor::{PathComponent, PathExtractor, PathExtractorBuilder, SearchPath};
+pub use position::Position;
 pub use raw_symbol_token::RawSymbolToken;
 pub use raw_symbol_token_ref::RawSymbolTokenRef;
+#[cfg(feature = "schema")]
+pub use schema::{Schema, Violation};
+pub use shared_symbol_table::SharedSymbolTable;
 
 pub use symbol::Symbol;
-pub use symbol_table::SymbolTable;
+pub use symbol_table::{SymbolTable, SymbolTableImport};
 
 pub use types::decimal::Decimal;
 pub use types::integer::Integer;
-pub use types::timestamp::Timestamp;
+pub use types::timestamp::{LeapSecondPolicy, Timestamp};
 pub use types::IonType;
 
-pub use binary::binary_writer::{BinaryWriter, BinaryWriterBuilder};
+pub use binary::binary_writer::{BinaryWriter, BinaryWriterBuilder, SymbolTableFlushPolicy};
+pub use binary::binary_writer_1_1::{BinaryWriter1_1, BinaryWriter1_1Builder, Macro};
 pub use text::text_writer::{TextWriter, TextWriterBuilder};
-pub use writer::Writer;
+pub use writer::{Writer, WriterLimits};
 
 pub use binary::raw_binary_reader::RawBinaryReader;
+pub use binary::raw_binary_reader_1_1::RawBinaryReader1_1;
 pub use binary::raw_binary_writer::RawBinaryWriter;
+pub use ion_stream::{FilterAnnotated, IonStream, MapStructField, TakeBytes};
 pub use raw_reader::{RawReader, RawStreamItem};
 pub use reader::StreamItem;
-pub use reader::{Reader, ReaderBuilder, UserReader};
-pub use stream_reader::StreamReader;
+pub use reader::{
+    Reader, ReaderBackend, ReaderBuilder, ReaderFormat, ReaderLimits, SymbolPolicy,
+    SymbolTableChange, TrailingDataPolicy, UserReader,
+};
+pub use reader_stats::ReaderStats;
+pub use stream_reader::{Annotations, StreamReader};
 pub use system_reader::{SystemReader, SystemStreamItem};
-pub use text::raw_text_reader::RawTextReader;
+pub use text::raw_json_reader::{JsonNumberPolicy, RawJsonReader};
+pub use text::raw_text_reader::{RawTextReader, Utf8Policy};
 pub use text::raw_text_writer::RawTextWriter;
 pub use text::raw_text_writer::RawTextWriterBuilder;
+pub use text::tokenizer::{Token, TokenKind, Tokenizer};
+pub use transform::{TransformAction, TransformPipeline};
+pub use value::writer::{Format, TextKind};
 
 pub use result::IonError;
 pub use result::IonResult;
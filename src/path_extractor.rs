@@ -0,0 +1,336 @@
+use std::str::FromStr;
+
+use crate::raw_reader::RawReader;
+use crate::reader::{ReaderBuilder, StreamItem, UserReader};
+use crate::result::{illegal_operation, illegal_operation_raw, IonResult};
+use crate::stream_reader::StreamReader;
+use crate::types::IonType;
+
+/// A single step in a [SearchPath].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathComponent {
+    /// Matches the struct field with the given name.
+    Field(String),
+    /// Matches the child value at the given position in any container.
+    Index(usize),
+    /// Matches every child value of any container.
+    Wildcard,
+}
+
+impl PathComponent {
+    fn matches(&self, field_name: Option<&str>, index: usize) -> bool {
+        match self {
+            PathComponent::Field(name) => field_name == Some(name.as_str()),
+            PathComponent::Index(position) => *position == index,
+            PathComponent::Wildcard => true,
+        }
+    }
+}
+
+/// A sequence of [PathComponent]s describing the location of values within each top-level value
+/// of a stream. An empty path matches the top-level values themselves.
+///
+/// Search paths can be parsed from their Ion text representation: an s-expression in which
+/// symbols and strings are field names, integers are indexes, and the symbol `*` is a wildcard.
+/// For example, `(foo bar 2)` matches the third child of field `bar` within field `foo`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchPath {
+    components: Vec<PathComponent>,
+}
+
+impl SearchPath {
+    pub fn new(components: Vec<PathComponent>) -> SearchPath {
+        SearchPath { components }
+    }
+
+    pub fn components(&self) -> &[PathComponent] {
+        &self.components
+    }
+}
+
+impl From<Vec<PathComponent>> for SearchPath {
+    fn from(components: Vec<PathComponent>) -> Self {
+        SearchPath::new(components)
+    }
+}
+
+impl FromStr for SearchPath {
+    type Err = crate::IonError;
+
+    fn from_str(text: &str) -> IonResult<SearchPath> {
+        let mut reader = ReaderBuilder::new().build(text)?;
+        match reader.next()? {
+            StreamItem::Value(IonType::SExpression) => {}
+            _ => return illegal_operation("search paths must be written as an s-expression"),
+        }
+        let mut components = Vec::new();
+        reader.step_in()?;
+        while let StreamItem::Value(ion_type) = reader.next()? {
+            let component = match ion_type {
+                IonType::Symbol => match reader.read_symbol()?.as_ref() {
+                    "*" => PathComponent::Wildcard,
+                    name => PathComponent::Field(name.to_owned()),
+                },
+                IonType::String => PathComponent::Field(reader.read_string()?),
+                IonType::Integer => {
+                    let index = reader.read_i64()?;
+                    let index = usize::try_from(index).map_err(|_| {
+                        illegal_operation_raw(format!("invalid search path index: {}", index))
+                    })?;
+                    PathComponent::Index(index)
+                }
+                other => {
+                    return illegal_operation(format!(
+                        "search path components must be symbols, strings, or integers; found {:?}",
+                        other
+                    ))
+                }
+            };
+            components.push(component);
+        }
+        reader.step_out()?;
+        if reader.next()? != StreamItem::Nothing {
+            return illegal_operation("search path text must contain a single s-expression");
+        }
+        Ok(SearchPath::new(components))
+    }
+}
+
+type Callback<'a, R> = Box<dyn FnMut(&mut UserReader<R>) -> IonResult<()> + 'a>;
+
+struct Registration<'a, R: RawReader> {
+    path: SearchPath,
+    callback: Callback<'a, R>,
+}
+
+/// Configures and constructs new instances of [PathExtractor].
+pub struct PathExtractorBuilder<'a, R: RawReader> {
+    registrations: Vec<Registration<'a, R>>,
+}
+
+impl<'a, R: RawReader> PathExtractorBuilder<'a, R> {
+    pub fn new() -> PathExtractorBuilder<'a, R> {
+        PathExtractorBuilder {
+            registrations: Vec::new(),
+        }
+    }
+
+    /// Registers a callback that will be invoked with the reader positioned on each value that
+    /// matches `path`. When the callback returns, the reader must be at the same depth as the
+    /// matched value; the callback may read the value or step into and back out of it, but must
+    /// not advance the reader past it. A callback that steps into a container prevents longer
+    /// paths from matching values inside of that container.
+    pub fn with_search_path<P, F>(mut self, path: P, callback: F) -> PathExtractorBuilder<'a, R>
+    where
+        P: Into<SearchPath>,
+        F: FnMut(&mut UserReader<R>) -> IonResult<()> + 'a,
+    {
+        self.registrations.push(Registration {
+            path: path.into(),
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    pub fn build(self) -> PathExtractor<'a, R> {
+        PathExtractor {
+            registrations: self.registrations,
+        }
+    }
+}
+
+impl<'a, R: RawReader> Default for PathExtractorBuilder<'a, R> {
+    fn default() -> Self {
+        PathExtractorBuilder::new()
+    }
+}
+
+/// Surfaces only the values in a stream that match a set of registered [SearchPath]s.
+///
+/// Containers are only stepped into if a registered path could match one of their descendants;
+/// all other values are skipped without being read or materialized.
+pub struct PathExtractor<'a, R: RawReader> {
+    registrations: Vec<Registration<'a, R>>,
+}
+
+impl<'a, R: RawReader> PathExtractor<'a, R> {
+    /// Advances `reader` over each of the remaining values at its current depth, invoking the
+    /// registered callbacks for every value that matches a search path.
+    pub fn match_reader(&mut self, reader: &mut UserReader<R>) -> IonResult<()> {
+        let candidates: Vec<usize> = (0..self.registrations.len()).collect();
+        while let Some((ion_type, is_null)) = next_value(reader)? {
+            self.match_value(reader, ion_type, is_null, 0, &candidates)?;
+        }
+        Ok(())
+    }
+
+    // Invokes the callbacks of any candidate paths that end at the current value, then steps
+    // into the value if any of the remaining candidates could match one of its children.
+    fn match_value(
+        &mut self,
+        reader: &mut UserReader<R>,
+        ion_type: IonType,
+        is_null: bool,
+        path_depth: usize,
+        candidates: &[usize],
+    ) -> IonResult<()> {
+        let mut unfinished = Vec::new();
+        for &index in candidates {
+            if self.registrations[index].path.components.len() == path_depth {
+                self.invoke_callback(reader, index)?;
+            } else {
+                unfinished.push(index);
+            }
+        }
+        if unfinished.is_empty() || is_null || !ion_type.is_container() {
+            return Ok(());
+        }
+
+        let matches_field = unfinished.iter().any(|&index| {
+            matches!(
+                self.registrations[index].path.components[path_depth],
+                PathComponent::Field(_)
+            )
+        });
+        reader.step_in()?;
+        let mut child_index = 0;
+        while let Some((child_type, child_is_null)) = next_value(reader)? {
+            let field_name = if matches_field && ion_type == IonType::Struct {
+                Some(reader.field_name()?)
+            } else {
+                None
+            };
+            let matching: Vec<usize> = unfinished
+                .iter()
+                .copied()
+                .filter(|&index| {
+                    self.registrations[index].path.components[path_depth]
+                        .matches(field_name.as_deref(), child_index)
+                })
+                .collect();
+            if !matching.is_empty() {
+                self.match_value(reader, child_type, child_is_null, path_depth + 1, &matching)?;
+            }
+            child_index += 1;
+        }
+        reader.step_out()
+    }
+
+    fn invoke_callback(&mut self, reader: &mut UserReader<R>, index: usize) -> IonResult<()> {
+        let depth = reader.depth();
+        (self.registrations[index].callback)(reader)?;
+        if reader.depth() != depth {
+            return illegal_operation(
+                "path extractor callbacks must leave the reader at the depth of the matched value",
+            );
+        }
+        Ok(())
+    }
+}
+
+// Advances to the next value at the current depth, returning its type and whether it is null.
+fn next_value<R: RawReader>(reader: &mut UserReader<R>) -> IonResult<Option<(IonType, bool)>> {
+    Ok(match reader.next()? {
+        StreamItem::Value(ion_type) => Some((ion_type, false)),
+        StreamItem::Null(ion_type) => Some((ion_type, true)),
+        StreamItem::Nothing => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Reader;
+    use std::cell::RefCell;
+
+    fn search(path: &str) -> SearchPath {
+        path.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_search_path() -> IonResult<()> {
+        let path: SearchPath = r#"(foo "bar baz" 2 *)"#.parse()?;
+        assert_eq!(
+            path.components(),
+            &[
+                PathComponent::Field("foo".to_string()),
+                PathComponent::Field("bar baz".to_string()),
+                PathComponent::Index(2),
+                PathComponent::Wildcard,
+            ]
+        );
+        assert!("()".parse::<SearchPath>()?.components().is_empty());
+        assert!("[foo]".parse::<SearchPath>().is_err());
+        assert!("(foo -1)".parse::<SearchPath>().is_err());
+        assert!("(foo 1.5)".parse::<SearchPath>().is_err());
+        assert!("(foo) (bar)".parse::<SearchPath>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn extract_matching_values() -> IonResult<()> {
+        let ion_data = r#"
+            {foo: {bar: [1, 2, 3]}, baz: 4}
+            {foo: {bar: [5, 6]}, quux: {bar: [7, 8, 9]}}
+            {foo: null.struct}
+            10
+        "#;
+        let indexed = RefCell::new(Vec::new());
+        let wildcard = RefCell::new(Vec::new());
+        let top_level = RefCell::new(0);
+        let mut extractor = PathExtractorBuilder::new()
+            .with_search_path(search("(foo bar 2)"), |reader: &mut Reader| {
+                indexed.borrow_mut().push(reader.read_i64()?);
+                Ok(())
+            })
+            .with_search_path(search("(* bar 0)"), |reader: &mut Reader| {
+                wildcard.borrow_mut().push(reader.read_i64()?);
+                Ok(())
+            })
+            .with_search_path(SearchPath::default(), |_: &mut Reader| {
+                *top_level.borrow_mut() += 1;
+                Ok(())
+            })
+            .build();
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        extractor.match_reader(&mut reader)?;
+        drop(extractor);
+
+        assert_eq!(indexed.into_inner(), vec![3]);
+        assert_eq!(wildcard.into_inner(), vec![1, 5, 7]);
+        assert_eq!(top_level.into_inner(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn callback_may_step_into_matched_value() -> IonResult<()> {
+        let mut sums = Vec::new();
+        let mut extractor = PathExtractorBuilder::new()
+            .with_search_path(search("(values)"), |reader: &mut Reader| {
+                let mut sum = 0;
+                reader.step_in()?;
+                while reader.next()? != StreamItem::Nothing {
+                    sum += reader.read_i64()?;
+                }
+                reader.step_out()?;
+                sums.push(sum);
+                Ok(())
+            })
+            .build();
+        let mut reader = ReaderBuilder::new().build("{values: [1, 2]} {values: (3 4)}")?;
+        extractor.match_reader(&mut reader)?;
+        drop(extractor);
+        assert_eq!(sums, vec![3, 7]);
+        Ok(())
+    }
+
+    #[test]
+    fn callback_must_not_change_depth() -> IonResult<()> {
+        let mut extractor = PathExtractorBuilder::new()
+            .with_search_path(search("(foo)"), |reader: &mut Reader| reader.step_in())
+            .build();
+        let mut reader = ReaderBuilder::new().build("{foo: [1]}")?;
+        assert!(extractor.match_reader(&mut reader).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::{Display, Formatter};
+
+/// Describes a location within an Ion stream.
+///
+/// Every position has a byte offset from the beginning of the stream. Positions produced by text
+/// readers also carry the (1-based) line and column at which the location can be found.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Position {
+    byte_offset: usize,
+    line_column: Option<(usize, usize)>,
+}
+
+impl Position {
+    /// Creates a new `Position` representing the provided byte offset.
+    pub fn with_offset(byte_offset: usize) -> Self {
+        Position {
+            byte_offset,
+            line_column: None,
+        }
+    }
+
+    /// Adds the provided (1-based) line and column information to this `Position`.
+    pub fn with_line_and_column(mut self, line: usize, column: usize) -> Self {
+        self.line_column = Some((line, column));
+        self
+    }
+
+    /// Returns the number of bytes between the beginning of the stream and this `Position`.
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// If available, returns the (1-based) `(line, column)` pair for this `Position`. Positions
+    /// produced by readers of binary Ion will always return `None`.
+    pub fn line_column(&self) -> Option<(usize, usize)> {
+        self.line_column
+    }
+
+    /// If available, returns the (1-based) line number for this `Position`.
+    pub fn line(&self) -> Option<usize> {
+        self.line_column.map(|(line, _column)| line)
+    }
+
+    /// If available, returns the (1-based) column number for this `Position`.
+    pub fn column(&self) -> Option<usize> {
+        self.line_column.map(|(_line, column)| column)
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.line_column {
+            Some((line, column)) => write!(
+                f,
+                "line {}, column {} (byte offset {})",
+                line, column, self.byte_offset
+            ),
+            None => write!(f, "byte offset {}", self.byte_offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_offset_only() {
+        let position = Position::with_offset(42);
+        assert_eq!(position.line_column(), None);
+        assert_eq!(position.to_string(), "byte offset 42");
+    }
+
+    #[test]
+    fn display_line_and_column() {
+        let position = Position::with_offset(42).with_line_and_column(3, 7);
+        assert_eq!(position.line(), Some(3));
+        assert_eq!(position.column(), Some(7));
+        assert_eq!(position.to_string(), "line 3, column 7 (byte offset 42)");
+    }
+}
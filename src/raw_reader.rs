@@ -1,8 +1,10 @@
+use crate::position::Position;
 use crate::raw_symbol_token::RawSymbolToken;
 use crate::stream_reader::StreamReader;
 use crate::types::IonType;
 use crate::{Decimal, Integer, IonResult, Timestamp};
 use std::fmt::{Display, Formatter};
+use std::ops::Range;
 
 /// `RawReader` is a shorthand for a [Reader](crate::Reader) implementation that returns [RawStreamItem]s and
 /// uses [RawSymbolToken] to represent its field names, annotations, and symbol values.
@@ -42,8 +44,12 @@ impl<R: RawReader + ?Sized> StreamReader for Box<R> {
         (**self).ion_type()
     }
 
-    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'a> {
-        (**self).annotations()
+    fn annotation(&self, index: usize) -> IonResult<Self::Symbol> {
+        (**self).annotation(index)
+    }
+
+    fn number_of_annotations(&self) -> usize {
+        (**self).number_of_annotations()
     }
 
     fn field_name(&self) -> IonResult<Self::Symbol> {
@@ -86,12 +92,16 @@ impl<R: RawReader + ?Sized> StreamReader for Box<R> {
         (**self).read_string()
     }
 
+    fn read_str(&mut self) -> IonResult<&str> {
+        (**self).read_str()
+    }
+
     fn map_string<F, U>(&mut self, _f: F) -> IonResult<U>
     where
         Self: Sized,
         F: FnOnce(&str) -> U,
     {
-        todo!("Cannot use `map_string` via dynamic dispatch. Use `read_string` instead. See: https://github.com/amzn/ion-rust/issues/335")
+        todo!("Cannot use `map_string` via dynamic dispatch. Use `read_str` instead. See: https://github.com/amzn/ion-rust/issues/335")
     }
 
     fn map_string_bytes<F, U>(&mut self, _f: F) -> IonResult<U>
@@ -130,6 +140,20 @@ impl<R: RawReader + ?Sized> StreamReader for Box<R> {
         todo!("Cannot use `map_clob` via dynamic dispatch. Use `read_clob` instead. See: https://github.com/amzn/ion-rust/issues/335")
     }
 
+    fn read_blob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        (**self).read_blob_chunks(chunk_handler)
+    }
+
+    fn read_clob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        (**self).read_clob_chunks(chunk_handler)
+    }
+
     fn read_timestamp(&mut self) -> IonResult<Timestamp> {
         (**self).read_timestamp()
     }
@@ -149,6 +173,22 @@ impl<R: RawReader + ?Sized> StreamReader for Box<R> {
     fn depth(&self) -> usize {
         (**self).depth()
     }
+
+    fn position(&self) -> Position {
+        (**self).position()
+    }
+
+    fn value_end_offset(&self) -> Option<usize> {
+        (**self).value_end_offset()
+    }
+
+    fn declared_value_length(&self) -> Option<usize> {
+        (**self).declared_value_length()
+    }
+
+    fn resynchronize(&mut self) -> IonResult<Range<usize>> {
+        (**self).resynchronize()
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -1,39 +1,451 @@
 use std::io;
 use std::io::Read;
 use std::ops::Range;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
 
 use delegate::delegate;
 
 use crate::binary::constants::v1_0::IVM;
+use crate::catalog::Catalog;
 use crate::constants::v1_0::system_symbol_ids;
+#[cfg(feature = "ion_c")]
+use crate::ion_c_raw_reader::IonCRawReader;
+use crate::position::Position;
 use crate::raw_reader::{RawReader, RawStreamItem};
 use crate::raw_symbol_token::RawSymbolToken;
-use crate::result::{decoding_error, decoding_error_raw, IonResult};
+use crate::reader_stats::ReaderStats;
+use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonError, IonResult};
 use crate::stream_reader::StreamReader;
 use crate::symbol::Symbol;
-use crate::symbol_table::SymbolTable;
+use crate::symbol_table::{SymbolTable, SymbolTableImport};
 use crate::text::ion_data_source::ToIonDataSource;
+use crate::text::raw_json_reader::JsonNumberPolicy;
+use crate::text::raw_text_reader::Utf8Policy;
 use crate::types::decimal::Decimal;
 use crate::types::integer::Integer;
-use crate::types::timestamp::Timestamp;
-use crate::{IonType, RawBinaryReader, RawTextReader};
+use crate::types::timestamp::{LeapSecondPolicy, Timestamp};
+use crate::types::SymbolId;
+use crate::value::native_reader::FieldNameCache;
+use crate::value::owned;
+use crate::value::owned::OwnedSymbolToken;
+use crate::{IonType, RawBinaryReader, RawBinaryReader1_1, RawJsonReader, RawTextReader};
+
+/// The Ion encoding that a [ReaderBuilder] should expect its input to use.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum ReaderFormat {
+    /// Inspect the beginning of the input for a binary Ion version marker. If one is found, the
+    /// input is read as binary Ion; otherwise, it is read as text Ion. This is the default.
+    #[default]
+    Auto,
+    /// Read the input as text Ion without inspecting it first.
+    Text,
+    /// Read the input as binary Ion. The input is only inspected first if Ion 1.1 support was
+    /// enabled using [ReaderBuilder::with_ion_1_1], in order to determine its version.
+    Binary,
+    /// Read the input as JSON, converting it to the Ion data model using a [RawJsonReader].
+    /// Numbers are converted as specified by [ReaderBuilder::with_json_number_policy]. The
+    /// complete input is loaded into memory before reading begins.
+    Json,
+}
+
+/// The implementation that a [ReaderBuilder] should use to parse its input.
+///
+/// Backends can also be parsed from their names (`"native"` or `"ion-c"`), which allows
+/// applications to choose between them at runtime using a configuration setting.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum ReaderBackend {
+    /// This crate's own text and binary readers. This is the default.
+    #[default]
+    Native,
+    /// The ion-c reader. ion-c detects the input's encoding itself, so the builder's
+    /// [ReaderFormat] and buffer capacity settings are ignored, and the complete input is loaded
+    /// into memory before reading begins.
+    #[cfg(feature = "ion_c")]
+    IonC,
+}
+
+impl FromStr for ReaderBackend {
+    type Err = IonError;
+
+    fn from_str(name: &str) -> IonResult<ReaderBackend> {
+        match name {
+            "native" => Ok(ReaderBackend::Native),
+            #[cfg(feature = "ion_c")]
+            "ion-c" | "ion_c" => Ok(ReaderBackend::IonC),
+            _ => illegal_operation(format!("unsupported reader backend: '{}'", name)),
+        }
+    }
+}
+
+/// Upper bounds on the resources that a [Reader] will spend on its input. Input that exceeds
+/// one of these limits causes the reader to return a decoding error instead of processing it.
+///
+/// By default, no limits are enforced.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ReaderLimits {
+    max_depth: Option<usize>,
+    max_annotations: Option<usize>,
+    max_symbols: Option<usize>,
+    max_value_length: Option<usize>,
+    max_materialized_nodes: Option<usize>,
+    max_materialized_bytes: Option<usize>,
+}
+
+impl ReaderLimits {
+    /// Constructs a `ReaderLimits` that does not limit the reader in any way.
+    pub fn new() -> ReaderLimits {
+        ReaderLimits::default()
+    }
+
+    /// Constructs a `ReaderLimits` with conservative limits that are suitable for reading
+    /// untrusted input:
+    ///
+    /// * Containers can be nested at most 64 levels deep.
+    /// * Values can have at most 32 annotations.
+    /// * The symbol table can hold at most 100,000 symbols.
+    /// * Strings, blobs, and clobs can be at most 16 MiB long. When reading binary Ion, the length
+    ///   declared in a value's header is checked before any of its contents are read.
+    pub fn hardened() -> ReaderLimits {
+        ReaderLimits::new()
+            .with_max_depth(HARDENED_MAX_DEPTH)
+            .with_max_annotations(HARDENED_MAX_ANNOTATIONS)
+            .with_max_symbols(HARDENED_MAX_SYMBOLS)
+            .with_max_value_length(HARDENED_MAX_VALUE_LENGTH)
+    }
+
+    /// Limits how deeply the reader can step into nested containers.
+    pub fn with_max_depth(mut self, max_depth: usize) -> ReaderLimits {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Limits the number of annotations that a single value can have.
+    pub fn with_max_annotations(mut self, max_annotations: usize) -> ReaderLimits {
+        self.max_annotations = Some(max_annotations);
+        self
+    }
+
+    /// Limits the number of symbols (including the system symbols) that the reader's symbol
+    /// table can hold.
+    ///
+    /// Without this limit, the shared symbol tables imported by a single local symbol table can
+    /// still contribute at most 1,000,000 symbols; the `max_id` of an import can declare any
+    /// number of symbols without providing their text, so it is never left unbounded.
+    pub fn with_max_symbols(mut self, max_symbols: usize) -> ReaderLimits {
+        self.max_symbols = Some(max_symbols);
+        self
+    }
+
+    /// Limits the length in bytes of any string, blob, or clob that is read.
+    pub fn with_max_value_length(mut self, max_value_length: usize) -> ReaderLimits {
+        self.max_value_length = Some(max_value_length);
+        self
+    }
+
+    /// Limits the number of values (counting the value itself and every value nested inside of
+    /// it) that can be materialized as a single element, for example by
+    /// [UserReader::elements]. This does not affect the streaming API.
+    pub fn with_max_materialized_nodes(mut self, max_materialized_nodes: usize) -> ReaderLimits {
+        self.max_materialized_nodes = Some(max_materialized_nodes);
+        self
+    }
+
+    /// Limits the approximate number of bytes of memory that materializing a single element can
+    /// use. Each value nested inside of the element counts the size of an element, plus the
+    /// length of its text or bytes, field name, and annotations. When reading binary Ion, the
+    /// length declared in a string, blob, or clob's header is checked before any of its contents
+    /// are read. This does not affect the streaming API.
+    pub fn with_max_materialized_bytes(mut self, max_materialized_bytes: usize) -> ReaderLimits {
+        self.max_materialized_bytes = Some(max_materialized_bytes);
+        self
+    }
+
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    pub fn max_annotations(&self) -> Option<usize> {
+        self.max_annotations
+    }
+
+    pub fn max_symbols(&self) -> Option<usize> {
+        self.max_symbols
+    }
+
+    pub fn max_value_length(&self) -> Option<usize> {
+        self.max_value_length
+    }
+
+    pub fn max_materialized_nodes(&self) -> Option<usize> {
+        self.max_materialized_nodes
+    }
+
+    pub fn max_materialized_bytes(&self) -> Option<usize> {
+        self.max_materialized_bytes
+    }
+}
+
+// The limits used by `ReaderLimits::hardened` and `WriterLimits::hardened`.
+pub(crate) const HARDENED_MAX_DEPTH: usize = 64;
+pub(crate) const HARDENED_MAX_ANNOTATIONS: usize = 32;
+pub(crate) const HARDENED_MAX_SYMBOLS: usize = 100_000;
+pub(crate) const HARDENED_MAX_VALUE_LENGTH: usize = 16 * 1024 * 1024;
+
+// The maximum number of symbols that a local symbol table's imports can contribute when no
+// `max_symbols` limit has been configured.
+const DEFAULT_MAX_IMPORTED_SYMBOLS: usize = 1_000_000;
+
+// Returns a decoding error if `actual` is greater than `limit`.
+fn check_limit(limit: Option<usize>, actual: usize, description: &str) -> IonResult<()> {
+    match limit {
+        Some(limit) if actual > limit => decoding_error(format!(
+            "{} ({}) exceeds the reader's configured limit of {}",
+            description, actual, limit
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Determines how a [Reader] surfaces symbol IDs that are defined in the symbol table but whose
+/// text is unknown. (For example: `$0`, or a symbol table entry that was not a string.)
+/// Symbol IDs that are not defined in the symbol table at all are always an error.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum SymbolPolicy {
+    /// Return a decoding error when a symbol with unknown text is encountered. This is the
+    /// default.
+    #[default]
+    Error,
+    /// Surface symbols with unknown text as text of the form `$<sid>`, like `$0`. This applies to
+    /// symbol values, field names, and annotations alike, including those that refer to the
+    /// symbols of shared symbol tables that are missing from the reader's [Catalog].
+    SidText,
+}
+
+/// Determines what a [Reader] does when it encounters invalid data at the top level of a stream,
+/// such as garbage following a sequence of valid values.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum TrailingDataPolicy {
+    /// Return the error; the rest of the stream cannot be read. This is the default.
+    #[default]
+    Fail,
+    /// Stop reading at the error as though the stream had ended there, so that only the values
+    /// preceding it are surfaced. The error is available afterwards via
+    /// [UserReader::trailing_data_error].
+    Truncate,
+    /// Return the error, then [resynchronize](StreamReader::resynchronize) so that reading can
+    /// continue with the next top-level value that can be found. If the reader cannot
+    /// resynchronize, it stops reading as it would using [TrailingDataPolicy::Truncate].
+    Resynchronize,
+}
+
+/// Describes how a reader's symbol table changed. See [ReaderBuilder::with_symbol_table_handler].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolTableChange {
+    /// An Ion version marker reset the symbol table to contain only the system symbols.
+    Reset,
+    /// A local symbol table replaced the symbol table's contents.
+    Replaced,
+    /// A local symbol table appended symbols to the existing symbol table.
+    Appended,
+}
+
+type SymbolTableHandler = Box<dyn FnMut(SymbolTableChange, &SymbolTable)>;
 
 /// Configures and constructs new instances of [Reader].
-pub struct ReaderBuilder {}
+pub struct ReaderBuilder {
+    backend: ReaderBackend,
+    format: ReaderFormat,
+    buffer_capacity: Option<usize>,
+    limits: ReaderLimits,
+    symbol_policy: SymbolPolicy,
+    catalog: Option<Rc<dyn Catalog>>,
+    collect_stats: bool,
+    trailing_data_policy: TrailingDataPolicy,
+    utf8_policy: Utf8Policy,
+    invalid_utf8_handler: Option<Box<dyn FnMut(Position)>>,
+    unchecked_utf8: bool,
+    symbol_table_handler: Option<SymbolTableHandler>,
+    ion_1_1: bool,
+    json_number_policy: JsonNumberPolicy,
+    leap_second_policy: LeapSecondPolicy,
+}
 
 impl ReaderBuilder {
     /// Constructs a [ReaderBuilder] pre-populated with common default settings.
     pub fn new() -> ReaderBuilder {
         ReaderBuilder {
-            // Eventually, this will contain settings like a `Catalog` implementation.
+            backend: ReaderBackend::default(),
+            format: ReaderFormat::default(),
+            // Each reader implementation picks its own default buffer size.
+            buffer_capacity: None,
+            limits: ReaderLimits::default(),
+            symbol_policy: SymbolPolicy::default(),
+            catalog: None,
+            collect_stats: false,
+            trailing_data_policy: TrailingDataPolicy::default(),
+            utf8_policy: Utf8Policy::default(),
+            invalid_utf8_handler: None,
+            unchecked_utf8: false,
+            symbol_table_handler: None,
+            ion_1_1: false,
+            json_number_policy: JsonNumberPolicy::default(),
+            leap_second_policy: LeapSecondPolicy::default(),
         }
     }
 
-    /// Applies the specified settings to a new instance of `Reader`. This process involves
-    /// reading some data from the beginning of `input` to detect whether its content is
-    /// text or binary Ion. If this read operation fails, `build` will return an `Err`
-    /// describing the problem it encountered.
+    /// Constructs a [ReaderBuilder] that is configured for reading untrusted input. The reader
+    /// enforces [ReaderLimits::hardened] and, as by default, returns an error when it encounters
+    /// invalid UTF-8 or undefined symbols. The limits can be adjusted using
+    /// [ReaderBuilder::with_limits].
+    pub fn hardened() -> ReaderBuilder {
+        ReaderBuilder::new().with_limits(ReaderLimits::hardened())
+    }
+
+    /// Sets the implementation that will be used to parse the input.
+    pub fn with_backend(mut self, backend: ReaderBackend) -> ReaderBuilder {
+        self.backend = backend;
+        self
+    }
+
+    /// Sets the Ion encoding that the input is expected to use. By default, the builder will
+    /// detect the encoding by inspecting the beginning of the input.
+    pub fn with_format(mut self, format: ReaderFormat) -> ReaderBuilder {
+        self.format = format;
+        self
+    }
+
+    /// Sets the initial capacity (in bytes) of the reader's input buffer. Buffers grow as needed
+    /// to hold values (or lines of text) that are larger than this.
+    pub fn with_buffer_capacity(mut self, capacity: usize) -> ReaderBuilder {
+        self.buffer_capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the resource limits that the reader will enforce.
+    pub fn with_limits(mut self, limits: ReaderLimits) -> ReaderBuilder {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets how the reader surfaces symbols whose text is unknown.
+    pub fn with_symbol_policy(mut self, symbol_policy: SymbolPolicy) -> ReaderBuilder {
+        self.symbol_policy = symbol_policy;
+        self
+    }
+
+    /// Sets the [Catalog] that the reader will use to resolve shared symbol table imports.
+    /// Without a catalog, streams that import shared symbol tables cannot be read.
+    pub fn with_catalog<C: Catalog + 'static>(mut self, catalog: C) -> ReaderBuilder {
+        self.catalog = Some(Rc::new(catalog));
+        self
+    }
+
+    /// Sets whether the reader will collect [ReaderStats] describing the data that it processes.
+    /// Statistics collection is disabled by default.
+    pub fn with_stats(mut self, enabled: bool) -> ReaderBuilder {
+        self.collect_stats = enabled;
+        self
+    }
+
+    /// Sets what the reader will do when it encounters invalid data at the top level of the
+    /// stream. By default, the error is returned and the rest of the stream cannot be read.
+    pub fn with_trailing_data_policy(mut self, policy: TrailingDataPolicy) -> ReaderBuilder {
+        self.trailing_data_policy = policy;
+        self
+    }
+
+    /// Sets how a text reader handles input that is not valid UTF-8. By default, invalid UTF-8
+    /// is an error. This setting has no effect on binary readers.
+    pub fn with_utf8_policy(mut self, policy: Utf8Policy) -> ReaderBuilder {
+        self.utf8_policy = policy;
+        self
+    }
+
+    /// Sets a handler that a text reader using [Utf8Policy::Lossy] will invoke for each line of
+    /// input that contains invalid UTF-8. The handler is passed the location of the first invalid
+    /// byte on the line, measured from the beginning of the input.
+    pub fn with_invalid_utf8_handler<F>(mut self, handler: F) -> ReaderBuilder
+    where
+        F: FnMut(Position) + 'static,
+    {
+        self.invalid_utf8_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Configures a binary reader to skip UTF-8 validation when the text of a string (including
+    /// the symbol text in a local symbol table) is read. Validation is a significant part of the
+    /// cost of reading binary data that is known to be well-formed, such as data that was written
+    /// by this library. This setting has no effect on text readers.
+    ///
+    /// Text is only decoded when a string is read, so the strings in values that are skipped are
+    /// never validated regardless of this setting.
+    ///
+    /// # Safety
+    ///
+    /// Every string in the input must be valid UTF-8. Reading a string that is not results in
+    /// undefined behavior.
+    pub unsafe fn with_unchecked_utf8(mut self) -> ReaderBuilder {
+        self.unchecked_utf8 = true;
+        self
+    }
+
+    /// Sets a handler that the reader will invoke each time its symbol table changes, passing it
+    /// a description of the change and the new symbol table. Ion version markers (including the
+    /// one that begins each binary stream) always reset the symbol table, even if it only held
+    /// the system symbols.
+    pub fn with_symbol_table_handler<F>(mut self, handler: F) -> ReaderBuilder
+    where
+        F: FnMut(SymbolTableChange, &SymbolTable) + 'static,
+    {
+        self.symbol_table_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets whether the reader accepts Ion 1.1 data. Ion 1.1 support is incomplete and disabled
+    /// by default; when it is enabled, text streams may contain the `$ion_1_1` version marker and
+    /// binary streams that begin with the Ion 1.1 version marker are read using a
+    /// [RawBinaryReader1_1], which describes the subset of the binary encoding that is supported.
+    /// Binary Ion 1.1 streams are loaded into memory in their entirety before they are read.
+    pub fn with_ion_1_1(mut self, enabled: bool) -> ReaderBuilder {
+        self.ion_1_1 = enabled;
+        self
+    }
+
+    /// Sets how a reader using the [Json](ReaderFormat::Json) format converts numbers that have a
+    /// fractional part or an exponent. By default, they are read as decimals.
+    pub fn with_json_number_policy(mut self, policy: JsonNumberPolicy) -> ReaderBuilder {
+        self.json_number_policy = policy;
+        self
+    }
+
+    /// Sets how a text reader handles timestamps with a leap second, such as
+    /// `2016-12-31T23:59:60Z`. By default, reading one fails. This setting only affects text
+    /// readers.
+    pub fn with_leap_second_policy(mut self, policy: LeapSecondPolicy) -> ReaderBuilder {
+        self.leap_second_policy = policy;
+        self
+    }
+
+    /// Applies the specified settings to a new instance of `Reader`. Unless a format was
+    /// specified, this process involves reading some data from the beginning of `input` to
+    /// detect whether its content is text or binary Ion. If this read operation fails, `build`
+    /// will return an `Err` describing the problem it encountered.
     pub fn build<'a, I: 'a + ToIonDataSource>(self, input: I) -> IonResult<Reader<'a>> {
+        #[cfg(feature = "ion_c")]
+        if self.backend == ReaderBackend::IonC {
+            return self.make_ion_c_reader(input);
+        }
+
+        match self.format {
+            ReaderFormat::Text => return Ok(self.make_text_reader(input)),
+            ReaderFormat::Json => return self.make_json_reader(input),
+            // The version of a binary stream can only be determined by inspecting its IVM.
+            ReaderFormat::Binary if !self.ion_1_1 => return Ok(self.make_binary_reader(input)),
+            _ => {}
+        }
+
         // Convert the provided input into an implementation of `BufRead`
         let mut input = input.to_ion_data_source();
         // Stack-allocated buffer to hold the first four bytes from input
@@ -56,8 +468,12 @@ impl ReaderBuilder {
                 // is short-lived. Instead we'll make a statically owned copy of the bytes that
                 // we can move into the reader.
                 let owned_header = Vec::from(&header[..total_bytes_read]);
-                // The file was too short to be binary Ion. Construct a text Reader.
-                return Ok(Self::make_text_reader(owned_header));
+                // The file was too short to be binary Ion. Unless binary was requested,
+                // construct a text Reader.
+                if self.format == ReaderFormat::Binary {
+                    return Ok(self.make_binary_reader(owned_header));
+                }
+                return Ok(self.make_text_reader(owned_header));
             }
             total_bytes_read += bytes_read;
         }
@@ -68,7 +484,13 @@ impl ReaderBuilder {
             [0xe0, 0x01, 0x00, 0xea] => {
                 // Binary Ion v1.0
                 let full_input = io::Cursor::new(header).chain(input);
-                Ok(Self::make_binary_reader(full_input))
+                Ok(self.make_binary_reader(full_input))
+            }
+            [0xe0, 0x01, 0x01, 0xea] if self.ion_1_1 => {
+                // Binary Ion v1.1
+                let mut data = header.to_vec();
+                input.read_to_end(&mut data)?;
+                Ok(self.make_reader(Box::new(RawBinaryReader1_1::new(data))))
             }
             [0xe0, major, minor, 0xea] => {
                 // Binary Ion v{major}.{minor}
@@ -77,27 +499,86 @@ impl ReaderBuilder {
                     major, minor
                 ))
             }
+            _ if self.format == ReaderFormat::Binary => {
+                let full_input = io::Cursor::new(header).chain(input);
+                Ok(self.make_binary_reader(full_input))
+            }
             _ => {
                 // It's not binary, assume it's text
                 let full_input = io::Cursor::new(header).chain(input);
-                Ok(Self::make_text_reader(full_input))
+                Ok(self.make_text_reader(full_input))
             }
         }
     }
 
-    fn make_text_reader<'a, I: 'a + ToIonDataSource>(data: I) -> Reader<'a> {
-        let raw_reader = Box::new(RawTextReader::new(data));
-        Reader {
-            raw_reader,
-            symbol_table: SymbolTable::new(),
+    /// Like [ReaderBuilder::build], but accepts any implementation of [io::Read] (a socket, a
+    /// decompressor, a pipe, etc). The input will be wrapped in an [io::BufReader] so that text
+    /// and binary readers can both consume it incrementally without loading the complete stream
+    /// into memory.
+    pub fn build_from_read<'a, R: 'a + Read>(self, input: R) -> IonResult<Reader<'a>> {
+        let input = match self.buffer_capacity {
+            Some(capacity) => io::BufReader::with_capacity(capacity, input),
+            None => io::BufReader::new(input),
+        };
+        self.build(input)
+    }
+
+    fn make_text_reader<'a, I: 'a + ToIonDataSource>(mut self, data: I) -> Reader<'a> {
+        let mut raw_reader = match self.buffer_capacity {
+            Some(capacity) => RawTextReader::with_buffer_capacity(data, capacity),
+            None => RawTextReader::new(data),
+        }
+        .with_utf8_policy(self.utf8_policy)
+        .with_ion_1_1(self.ion_1_1)
+        .with_leap_second_policy(self.leap_second_policy);
+        if let Some(handler) = self.invalid_utf8_handler.take() {
+            raw_reader = raw_reader.with_invalid_utf8_handler(handler);
+        }
+        self.make_reader(Box::new(raw_reader))
+    }
+
+    fn make_binary_reader<'a, I: 'a + ToIonDataSource>(self, data: I) -> Reader<'a> {
+        let data_source = data.to_ion_data_source();
+        let mut raw_reader = match self.buffer_capacity {
+            Some(capacity) => RawBinaryReader::with_buffer_capacity(data_source, capacity),
+            None => RawBinaryReader::new(data_source),
+        };
+        if self.unchecked_utf8 {
+            // SAFETY: The caller of `with_unchecked_utf8` guaranteed that the input's strings
+            // are valid UTF-8.
+            raw_reader = unsafe { raw_reader.with_unchecked_utf8() };
         }
+        self.make_reader(Box::new(raw_reader))
     }
 
-    fn make_binary_reader<'a, I: 'a + ToIonDataSource>(data: I) -> Reader<'a> {
-        let raw_reader = Box::new(RawBinaryReader::new(data.to_ion_data_source()));
+    fn make_json_reader<'a, I: 'a + ToIonDataSource>(self, data: I) -> IonResult<Reader<'a>> {
+        let mut input = Vec::new();
+        data.to_ion_data_source().read_to_end(&mut input)?;
+        let raw_reader = RawJsonReader::with_number_policy(input, self.json_number_policy);
+        Ok(self.make_reader(Box::new(raw_reader)))
+    }
+
+    #[cfg(feature = "ion_c")]
+    fn make_ion_c_reader<'a, I: 'a + ToIonDataSource>(self, data: I) -> IonResult<Reader<'a>> {
+        let mut input = Vec::new();
+        data.to_ion_data_source().read_to_end(&mut input)?;
+        let raw_reader = IonCRawReader::new(input)?;
+        Ok(self.make_reader(Box::new(raw_reader)))
+    }
+
+    fn make_reader<'a>(self, raw_reader: Box<dyn RawReader + 'a>) -> Reader<'a> {
         Reader {
             raw_reader,
             symbol_table: SymbolTable::new(),
+            limits: self.limits,
+            symbol_policy: self.symbol_policy,
+            catalog: self.catalog,
+            is_peeked: false,
+            stats: self.collect_stats.then(ReaderStats::default),
+            trailing_data_policy: self.trailing_data_policy,
+            trailing_data_error: None,
+            symbol_table_handler: self.symbol_table_handler,
+            field_names: FieldNameCache::default(),
         }
     }
 }
@@ -119,6 +600,18 @@ pub type Reader<'a> = UserReader<Box<dyn RawReader + 'a>>;
 pub struct UserReader<R: RawReader> {
     raw_reader: R,
     symbol_table: SymbolTable,
+    limits: ReaderLimits,
+    symbol_policy: SymbolPolicy,
+    catalog: Option<Rc<dyn Catalog>>,
+    // Set when `peek()` has advanced to an item that the next call to `next()` should return.
+    is_peeked: bool,
+    stats: Option<ReaderStats>,
+    trailing_data_policy: TrailingDataPolicy,
+    // Set when the reader has stopped reading because of an error at the top level.
+    trailing_data_error: Option<IonError>,
+    symbol_table_handler: Option<SymbolTableHandler>,
+    // Shares the text of field names between the structs that the reader materializes.
+    field_names: FieldNameCache,
 }
 
 /// Stream components that an application-level [Reader] implementation may encounter.
@@ -145,10 +638,123 @@ impl<R: RawReader> UserReader<R> {
         self.raw_reader.field_name()
     }
 
+    /// If statistics collection was enabled using [ReaderBuilder::with_stats], returns
+    /// [ReaderStats] describing the data that the reader has processed so far. Otherwise,
+    /// returns `None`.
+    pub fn stats(&self) -> Option<ReaderStats> {
+        let mut stats = self.stats?;
+        stats.set_bytes_consumed(self.position().byte_offset());
+        Some(stats)
+    }
+
+    /// If the reader stopped reading because of invalid data at the top level of the stream (see
+    /// [TrailingDataPolicy]), returns the error that it encountered. Otherwise, returns `None`.
+    pub fn trailing_data_error(&self) -> Option<&IonError> {
+        self.trailing_data_error.as_ref()
+    }
+
+    /// Applies the reader's [TrailingDataPolicy] to an error encountered while reading a
+    /// top-level value. Returns `Ok(())` if the reader has stopped reading.
+    pub(crate) fn recover_from(&mut self, error: IonError) -> IonResult<()> {
+        match self.trailing_data_policy {
+            TrailingDataPolicy::Fail => Err(error),
+            TrailingDataPolicy::Truncate => {
+                self.trailing_data_error = Some(error);
+                Ok(())
+            }
+            TrailingDataPolicy::Resynchronize => {
+                if self.resynchronize().is_err() {
+                    self.trailing_data_error = Some(error);
+                    return Ok(());
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns the next item at the current depth without consuming it: the following call to
+    /// [StreamReader::next] will return the same item rather than advancing. Calling `peek`
+    /// repeatedly returns the same item.
+    ///
+    /// Peeking moves the reader onto the upcoming item, so its type, null-ness, annotations,
+    /// and field name can be inspected (and it can even be read) before deciding whether to step
+    /// in, skip it, or hand the reader off to another component. Once the reader has peeked, the
+    /// value that was current before the call can no longer be read. Stepping in or out of a
+    /// container discards the peeked state.
+    pub fn peek(&mut self) -> IonResult<StreamItem> {
+        if self.is_peeked {
+            return Ok(self.current());
+        }
+        let item = self.next()?;
+        self.is_peeked = true;
+        Ok(item)
+    }
+
+    /// Returns the current field's name as a symbol token. Unlike [StreamReader::field_name],
+    /// this succeeds even if the field name is a symbol ID whose text is unknown; the token
+    /// carries the symbol ID (if the field name was encoded as one) and the text (if known).
+    ///
+    /// Symbol IDs that are not defined in the current symbol table are still an error.
+    pub fn field_name_symbol(&self) -> IonResult<OwnedSymbolToken> {
+        let raw_token = self.raw_reader.field_name()?;
+        self.symbol_token_for(raw_token)
+    }
+
+    /// Returns an iterator over the current value's annotations as symbol tokens. Unlike
+    /// [StreamReader::annotations], annotations whose text is unknown are returned as tokens
+    /// that only carry a symbol ID. See [Self::field_name_symbol] for details.
+    pub fn annotations_symbols(&self) -> impl Iterator<Item = IonResult<OwnedSymbolToken>> + '_ {
+        self.raw_reader
+            .annotations()
+            .map(move |raw_token| self.symbol_token_for(raw_token?))
+    }
+
+    fn symbol_token_for(&self, raw_token: RawSymbolToken) -> IonResult<OwnedSymbolToken> {
+        match raw_token {
+            RawSymbolToken::SymbolId(sid) => {
+                if !self.symbol_table.sid_is_valid(sid) {
+                    return Err(decoding_error_raw(format!(
+                        "found symbol ID ${}, which is not defined",
+                        sid
+                    ))
+                    .with_position(self.position()));
+                }
+                let text = self.symbol_table.text_for(sid).map(Arc::from);
+                Ok(OwnedSymbolToken::new(text, Some(sid), None))
+            }
+            RawSymbolToken::Text(text) => Ok(owned::text_token(text)),
+        }
+    }
+
+    /// Deserializes the value over which the reader is currently positioned into a `T` without
+    /// first materializing it as an element. This allows applications to skim a stream using the
+    /// streaming API and only fully decode the values that they're interested in.
+    ///
+    /// Calling this method consumes the current value; see [crate::serde::Deserializer] for
+    /// details of how Ion values are mapped onto the serde data model.
+    #[cfg(feature = "serde")]
+    pub fn read_as<T: serde::de::DeserializeOwned>(&mut self) -> IonResult<T> {
+        self.read_as_with_options(crate::serde::DeserializerOptions::default())
+    }
+
+    /// Like [Self::read_as], but using the specified options to control which type coercions
+    /// are allowed.
+    #[cfg(feature = "serde")]
+    pub fn read_as_with_options<T: serde::de::DeserializeOwned>(
+        &mut self,
+        options: crate::serde::DeserializerOptions,
+    ) -> IonResult<T> {
+        let mut deserializer = crate::serde::Deserializer::new(self).with_options(options);
+        let result = T::deserialize(&mut deserializer);
+        // If deserialization failed, the reader is still positioned over the offending value.
+        result.map_err(|error| error.with_position(self.position()))
+    }
+
     fn read_symbol_table(&mut self) -> IonResult<()> {
         self.raw_reader.step_in()?;
 
         let mut is_append = false;
+        let mut imported_symbols = vec![];
         let mut new_symbols = vec![];
 
         // It's illegal for a symbol table to have multiple `symbols` or `imports` fields.
@@ -169,19 +775,38 @@ impl<R: RawReader> UserReader<R> {
                 }
             };
 
-            let field_id = self
-                .raw_reader
-                .field_name()
-                .expect("No field ID found inside $ion_symbol_table struct.");
+            let field_id = self.raw_reader.field_name().map_err(|_| {
+                decoding_error_raw("found a value without a field name in a $ion_symbol_table")
+            })?;
             match (field_id, ion_type) {
                 // The field name is either SID 6 or the text 'imports' and the
                 // field value is a non-null List
                 (symbol, IonType::List)
                     if symbol.matches(system_symbol_ids::IMPORTS, "imports") =>
                 {
-                    // TODO: SST imports. This implementation only supports local symbol
-                    //       table imports and appends.
-                    return decoding_error("importing shared symbol tables is not yet supported");
+                    if has_found_imports_field {
+                        return decoding_error("symbol table had multiple 'imports' fields");
+                    }
+                    has_found_imports_field = true;
+                    self.raw_reader.step_in()?;
+                    loop {
+                        match self.raw_reader.next()? {
+                            RawStreamItem::Value(IonType::Struct) => {
+                                if let Some(import) = self.read_import()? {
+                                    let already_imported = imported_symbols
+                                        .iter()
+                                        .map(|(_, _, symbols): &(_, _, Vec<_>)| symbols.len())
+                                        .sum();
+                                    let symbols = self.resolve_import(&import, already_imported)?;
+                                    imported_symbols.push((import.0, import.1, symbols));
+                                }
+                            }
+                            RawStreamItem::Nothing => break,
+                            // Import list entries that are not structs are ignored.
+                            _ => continue,
+                        }
+                    }
+                    self.raw_reader.step_out()?;
                 }
                 // The field name is either SID 6 or the text 'imports' and the
                 // field value is a non-null symbol
@@ -214,6 +839,11 @@ impl<R: RawReader> UserReader<R> {
                         match self.raw_reader.next()? {
                             Value(IonType::String) => {
                                 new_symbols.push(Some(self.raw_reader.read_string()?));
+                                check_limit(
+                                    self.limits.max_symbols,
+                                    new_symbols.len(),
+                                    "number of local symbols",
+                                )?;
                             }
                             Value(_) | Null(_) => {
                                 // If we encounter a non-string or null, add a placeholder
@@ -227,73 +857,271 @@ impl<R: RawReader> UserReader<R> {
                     }
                     self.raw_reader.step_out()?;
                 }
-                something_else => {
-                    unimplemented!("No support for {:?}", something_else);
-                }
+                // Other fields, including `imports` and `symbols` fields with other types of
+                // value, are ignored.
+                _ => {}
             }
         }
 
-        if is_append {
-            // We're adding new symbols to the end of the symbol table.
-            for maybe_text in new_symbols.drain(..) {
-                let _sid = self.symbol_table.intern_or_add_placeholder(maybe_text);
-            }
+        let change = if is_append {
+            SymbolTableChange::Appended
         } else {
+            SymbolTableChange::Replaced
+        };
+        let symbols_defined = imported_symbols
+            .iter()
+            .map(|(_, _, symbols)| symbols.len())
+            .sum::<usize>()
+            + new_symbols.len();
+        if !is_append {
             // The symbol table has been set by defining new symbols without importing the current
-            // symbol table.
+            // symbol table. Any shared symbol tables that were imported come first.
             self.symbol_table.reset();
-            for maybe_text in new_symbols.drain(..) {
-                let _sid = self.symbol_table.intern_or_add_placeholder(maybe_text);
+            for (name, version, symbols) in imported_symbols.drain(..) {
+                self.symbol_table.add_import(name, version, symbols);
             }
         }
+        // Add the new symbols to the end of the symbol table.
+        for maybe_text in new_symbols.drain(..) {
+            let _sid = self.symbol_table.intern_or_add_placeholder(maybe_text);
+        }
+        check_limit(
+            self.limits.max_symbols,
+            self.symbol_table.len(),
+            "number of symbols",
+        )?;
+        if let Some(stats) = self.stats.as_mut() {
+            stats.record_symbol_table(symbols_defined, self.symbol_table.len());
+        }
+        self.notify_symbol_table_handler(change);
 
         self.raw_reader.step_out()?;
         Ok(())
     }
 
-    fn raw_annotations(&mut self) -> impl Iterator<Item = RawSymbolToken> + '_ {
-        // RawReader implementations do not attempt to resolve each annotation into text.
-        // Additionally, they perform all I/O related to annotations in their implementations
-        // of Reader::next. As such, it's safe to call `unwrap()` on each raw annotation.
-        self.raw_reader.annotations().map(|a| a.unwrap())
+    /// Reads the `name`, `version`, and `max_id` fields of the import struct over which the raw
+    /// reader is positioned. Returns `None` if the import should be ignored.
+    fn read_import(&mut self) -> IonResult<Option<(String, usize, Option<usize>)>> {
+        let mut name = None;
+        let mut version = 1;
+        let mut max_id = None;
+
+        self.raw_reader.step_in()?;
+        loop {
+            let ion_type = match self.raw_reader.next()? {
+                RawStreamItem::Value(ion_type) => ion_type,
+                RawStreamItem::Null(_) => continue,
+                RawStreamItem::Nothing => break,
+                RawStreamItem::VersionMarker(_, _) => {
+                    return decoding_error("Found IVM in symbol table import.")
+                }
+            };
+            let field_name = self.raw_reader.field_name()?;
+            match ion_type {
+                IonType::String if field_name.matches(system_symbol_ids::NAME, "name") => {
+                    name = Some(self.raw_reader.read_string()?);
+                }
+                IonType::Integer if field_name.matches(system_symbol_ids::VERSION, "version") => {
+                    // Versions less than 1 are treated as 1.
+                    version = self.raw_reader.read_i64()?.max(1) as usize;
+                }
+                IonType::Integer if field_name.matches(system_symbol_ids::MAX_ID, "max_id") => {
+                    // Negative values are treated as if `max_id` had not been specified.
+                    let value = self.raw_reader.read_i64()?;
+                    max_id = usize::try_from(value).ok();
+                }
+                // Other fields are ignored.
+                _ => {}
+            }
+        }
+        self.raw_reader.step_out()?;
+
+        // Imports without a name and imports of the system symbol table are ignored.
+        Ok(match name {
+            Some(name) if !name.is_empty() && name != "$ion" => Some((name, version, max_id)),
+            _ => None,
+        })
     }
 
-    pub fn symbol_table(&self) -> &SymbolTable {
-        &self.symbol_table
+    /// Looks up an imported shared symbol table in the reader's [Catalog], returning the text of
+    /// each of the symbols it contributes to the local symbol table.
+    ///
+    /// If the import specifies a `max_id`, it always contributes exactly that many symbols: the
+    /// table's symbols are truncated or padded with symbols of unknown text as needed, and if
+    /// the table is unavailable, every symbol's text is unknown. Without a `max_id`, the exact
+    /// version of the table must be available.
+    ///
+    /// `already_imported` is the number of symbols contributed by the symbol table's earlier
+    /// imports; the total cannot exceed the reader's `max_symbols` limit (or, if there is none,
+    /// [DEFAULT_MAX_IMPORTED_SYMBOLS]).
+    fn resolve_import(
+        &self,
+        (name, version, max_id): &(String, usize, Option<usize>),
+        already_imported: usize,
+    ) -> IonResult<Vec<Option<String>>> {
+        let (name, version, max_id) = (name.as_str(), *version, *max_id);
+        // If the exact version isn't available, the spec allows substituting the best available
+        // version as long as `max_id` tells us how many symbols the import should contribute.
+        let table = self.catalog.as_ref().and_then(|catalog| {
+            catalog
+                .get_table_with_version(name, version)
+                .or_else(|| max_id.and_then(|_| catalog.get_table(name)))
+        });
+        let mut symbols = match (table, max_id) {
+            (Some(table), _) => table.symbols().to_vec(),
+            (None, Some(_)) => Vec::new(),
+            (None, None) if self.catalog.is_none() => {
+                return decoding_error(format!(
+                "cannot import shared symbol table '{}' (version {}); no catalog was configured",
+                name, version
+            ))
+            }
+            (None, None) => {
+                return decoding_error(format!(
+                    "shared symbol table '{}' (version {}) was not found in the catalog",
+                    name, version
+                ))
+            }
+        };
+        // Check the limit before padding the import with placeholders; otherwise, a `max_id`
+        // declared in a few bytes of input could allocate an arbitrary amount of memory.
+        check_limit(
+            Some(
+                self.limits
+                    .max_symbols
+                    .unwrap_or(DEFAULT_MAX_IMPORTED_SYMBOLS),
+            ),
+            already_imported.saturating_add(max_id.unwrap_or(symbols.len())),
+            "number of imported symbols",
+        )?;
+        if let Some(max_id) = max_id {
+            symbols.resize(max_id, None);
+        }
+        Ok(symbols)
     }
-}
 
-impl<R: RawReader> StreamReader for UserReader<R> {
-    type Item = StreamItem;
-    type Symbol = Symbol;
+    fn notify_symbol_table_handler(&mut self, change: SymbolTableChange) {
+        if let Some(handler) = self.symbol_table_handler.as_mut() {
+            handler(change, &self.symbol_table);
+        }
+    }
 
-    fn current(&self) -> Self::Item {
-        if let Some(ion_type) = self.ion_type() {
-            return if self.is_null() {
-                StreamItem::Null(ion_type)
-            } else {
-                StreamItem::Value(ion_type)
-            };
+    /// Returns the [Symbol] associated with `sid`. If `sid` is defined but its text is unknown,
+    /// the reader's [SymbolPolicy] determines whether a symbol is returned.
+    fn resolve_sid(&self, sid: SymbolId) -> Option<Symbol> {
+        if let Some(symbol) = self.symbol_table.symbol_for(sid) {
+            return Some(symbol.clone());
+        }
+        match self.symbol_policy {
+            SymbolPolicy::SidText if self.symbol_table.sid_is_valid(sid) => {
+                Some(Symbol::owned(format!("${}", sid)))
+            }
+            _ => None,
         }
-        StreamItem::Nothing
     }
 
-    /// Advances the raw reader to the next user-level Ion value, processing any system-level directives
-    /// encountered along the way.
-    // v-- Clippy complains that `next` resembles `Iterator::next()`
-    #[allow(clippy::should_implement_trait)]
-    fn next(&mut self) -> IonResult<Self::Item> {
+    // Called when the reader advances to a user value. Returns an error if the value exceeds any
+    // of the reader's per-value limits; otherwise, updates the reader's stats (if enabled).
+    fn accept_value(&mut self, ion_type: IonType, is_null: bool) -> IonResult<()> {
+        if self.limits.max_annotations.is_some() {
+            check_limit(
+                self.limits.max_annotations,
+                self.raw_reader.number_of_annotations(),
+                "number of annotations",
+            )
+            .map_err(|error| error.with_position(self.position()))?;
+        }
+        if let Some(stats) = self.stats.as_mut() {
+            stats.record_value(ion_type, is_null);
+        }
+        Ok(())
+    }
+
+    // Returns an error if an element that is being materialized has grown beyond the reader's
+    // materialization limits.
+    pub(crate) fn check_materialized_size(&self, nodes: usize, bytes: usize) -> IonResult<()> {
+        self.located(check_limit(
+            self.limits.max_materialized_nodes,
+            nodes,
+            "number of materialized values",
+        ))?;
+        self.located(check_limit(
+            self.limits.max_materialized_bytes,
+            bytes,
+            "materialized size in bytes",
+        ))
+    }
+
+    // Returns an error if the raw reader reports that the current value's body is longer than
+    // the room that is left in the `max_materialized_bytes` budget once `bytes` have been used.
+    // This is checked before the value is read so that its contents are never allocated.
+    pub(crate) fn check_declared_materialized_size(&self, bytes: usize) -> IonResult<()> {
+        let (limit, length) = match (
+            self.limits.max_materialized_bytes,
+            self.raw_reader.declared_value_length(),
+        ) {
+            (Some(limit), Some(length)) => (limit, length),
+            _ => return Ok(()),
+        };
+        if bytes.saturating_add(length) > limit {
+            return self.located(decoding_error(format!(
+                "value declares a length of {} bytes, which exceeds the {} bytes left in the \
+                reader's max_materialized_bytes limit of {}",
+                length,
+                limit.saturating_sub(bytes),
+                limit
+            )));
+        }
+        Ok(())
+    }
+
+    // Returns the cache that materialized structs use to share their field names.
+    pub(crate) fn field_name_cache(&mut self) -> &mut FieldNameCache {
+        &mut self.field_names
+    }
+
+    // Attaches the current position to any error returned by a limit check.
+    fn located<T>(&self, result: IonResult<T>) -> IonResult<T> {
+        result.map_err(|error| error.with_position(self.position()))
+    }
+
+    // Returns an error if the raw reader reports that the current value's body is longer than
+    // the reader's `max_value_length` limit.
+    fn check_declared_length(&self, description: &str) -> IonResult<()> {
+        match self.raw_reader.declared_value_length() {
+            Some(length) => self.located(check_limit(
+                self.limits.max_value_length,
+                length,
+                description,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn raw_annotations(&mut self) -> impl Iterator<Item = RawSymbolToken> + '_ {
+        // RawReader implementations do not attempt to resolve each annotation into text.
+        // Additionally, they perform all I/O related to annotations in their implementations
+        // of Reader::next. As such, it's safe to call `unwrap()` on each raw annotation.
+        self.raw_reader.annotations().map(|a| a.unwrap())
+    }
+
+    // Advances the raw reader to the next user-level value, processing any system-level
+    // directives encountered along the way.
+    fn next_item(&mut self) -> IonResult<StreamItem> {
         use RawStreamItem::*;
         loop {
             match self.raw_reader.next()? {
-                VersionMarker(1, 0) => {
+                // Raw readers only surface Ion 1.1 version markers if Ion 1.1 support was enabled.
+                VersionMarker(1, 0) | VersionMarker(1, 1) => {
                     self.symbol_table.reset();
+                    self.notify_symbol_table_handler(SymbolTableChange::Reset);
                 }
                 VersionMarker(major, minor) => {
-                    return decoding_error(format!(
+                    return Err(decoding_error_raw(format!(
                         "Encountered a version marker for v{}.{}, but only v1.0 is supported.",
                         major, minor
-                    ));
+                    ))
+                    .with_position(self.position()));
                 }
                 Value(IonType::Struct) => {
                     // Top-level structs whose _first_ annotation is $ion_symbol_table are
@@ -319,72 +1147,281 @@ impl<R: RawReader> StreamReader for UserReader<R> {
                         // `self.read_symbol_table()` requires a mutable borrow which is not
                         // possible while iterating over the reader's annotations.
                         if is_symtab {
-                            self.read_symbol_table()?;
+                            let result = self.read_symbol_table();
+                            result.map_err(|error| error.with_position(self.position()))?;
                             continue;
                         }
                     }
+                    self.accept_value(IonType::Struct, false)?;
                     return Ok(StreamItem::Value(IonType::Struct));
                 }
-                Value(ion_type) => return Ok(StreamItem::Value(ion_type)),
-                Null(ion_type) => return Ok(StreamItem::Null(ion_type)),
+                Value(ion_type) => {
+                    self.accept_value(ion_type, false)?;
+                    return Ok(StreamItem::Value(ion_type));
+                }
+                Null(ion_type) => {
+                    self.accept_value(ion_type, true)?;
+                    return Ok(StreamItem::Null(ion_type));
+                }
                 Nothing => return Ok(StreamItem::Nothing),
             }
         }
     }
 
-    fn field_name(&self) -> IonResult<Self::Symbol> {
-        match self.raw_reader.field_name()? {
-            RawSymbolToken::SymbolId(sid) => {
-                self.symbol_table.symbol_for(sid).cloned().ok_or_else(|| {
-                    decoding_error_raw(format!("encountered field ID with unknown text: ${}", sid))
-                })
-            }
-            RawSymbolToken::Text(text) => Ok(Symbol::owned(text)),
-        }
+    /// Returns the symbol table that the reader is currently using to resolve symbol IDs.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
     }
 
-    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'a> {
-        let iterator = self
-            .raw_reader
-            .annotations()
-            .map(move |raw_token| match raw_token? {
-                RawSymbolToken::SymbolId(sid) => {
-                    self.symbol_table.symbol_for(sid).cloned().ok_or_else(|| {
-                        decoding_error_raw(format!(
-                            "found annotation ID with unknown text: ${}",
-                            sid
-                        ))
-                    })
-                }
-                RawSymbolToken::Text(text) => Ok(Symbol::owned(text)),
-            });
-        Box::new(iterator)
+    /// Returns the shared symbol tables imported by the current symbol table.
+    pub fn imports(&self) -> &[SymbolTableImport] {
+        self.symbol_table.imports()
     }
 
-    fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
-        match self.raw_reader.read_symbol()? {
-            RawSymbolToken::SymbolId(symbol_id) => {
-                if let Some(symbol) = self.symbol_table.symbol_for(symbol_id) {
-                    Ok(symbol.clone())
-                } else {
-                    return decoding_error(format!(
-                        "Found symbol ID ${}, which is not defined.",
-                        symbol_id
-                    ));
-                }
-            }
-            RawSymbolToken::Text(text) => Ok(Symbol::owned(text)),
-        }
+    /// Returns the highest symbol ID defined by the current symbol table.
+    pub fn max_id(&self) -> SymbolId {
+        self.symbol_table.max_id()
     }
 
-    // The Reader needs to expose many of the same functions as the Cursor, but only some of those
-    // need to be re-defined to allow for system value processing. Any method listed here will be
-    // delegated to self.raw_reader directly.
-    delegate! {
-        to self.raw_reader {
-            fn is_null(&self) -> bool;
+    /// Returns the text that the current symbol table associates with `sid`, if any. Returns
+    /// `None` if `sid` is undefined or its text is unknown.
+    pub fn text_for(&self, sid: SymbolId) -> Option<&str> {
+        self.symbol_table.text_for(sid)
+    }
+}
+
+impl<R: RawReader> StreamReader for UserReader<R> {
+    type Item = StreamItem;
+    type Symbol = Symbol;
+
+    fn current(&self) -> Self::Item {
+        if let Some(ion_type) = self.ion_type() {
+            return if self.is_null() {
+                StreamItem::Null(ion_type)
+            } else {
+                StreamItem::Value(ion_type)
+            };
+        }
+        StreamItem::Nothing
+    }
+
+    /// Advances the raw reader to the next user-level Ion value, processing any system-level directives
+    /// encountered along the way.
+    // v-- Clippy complains that `next` resembles `Iterator::next()`
+    #[allow(clippy::should_implement_trait)]
+    fn next(&mut self) -> IonResult<Self::Item> {
+        if self.is_peeked {
+            // `peek()` already advanced to the item that should be returned.
+            self.is_peeked = false;
+            return Ok(self.current());
+        }
+        if self.trailing_data_error.is_some() {
+            return Ok(StreamItem::Nothing);
+        }
+        let depth = self.raw_reader.depth();
+        match self.next_item() {
+            Err(error) if depth == 0 => {
+                self.recover_from(error)?;
+                Ok(StreamItem::Nothing)
+            }
+            result => result,
+        }
+    }
+
+    fn field_name(&self) -> IonResult<Self::Symbol> {
+        match self.raw_reader.field_name()? {
+            RawSymbolToken::SymbolId(sid) => self.resolve_sid(sid).ok_or_else(|| {
+                decoding_error_raw(format!("encountered field ID with unknown text: ${}", sid))
+                    .with_position(self.position())
+            }),
+            RawSymbolToken::Text(text) => Ok(Symbol::owned(text)),
+        }
+    }
+
+    fn annotation(&self, index: usize) -> IonResult<Self::Symbol> {
+        match self.raw_reader.annotation(index)? {
+            RawSymbolToken::SymbolId(sid) => self.resolve_sid(sid).ok_or_else(|| {
+                decoding_error_raw(format!("found annotation ID with unknown text: ${}", sid))
+                    .with_position(self.position())
+            }),
+            RawSymbolToken::Text(text) => Ok(Symbol::owned(text)),
+        }
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
+        match self.raw_reader.read_symbol()? {
+            RawSymbolToken::SymbolId(symbol_id) => {
+                if let Some(symbol) = self.resolve_sid(symbol_id) {
+                    Ok(symbol)
+                } else {
+                    return Err(decoding_error_raw(format!(
+                        "Found symbol ID ${}, which is not defined.",
+                        symbol_id
+                    ))
+                    .with_position(self.position()));
+                }
+            }
+            RawSymbolToken::Text(text) => Ok(Symbol::owned(text)),
+        }
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        if matches!(self.ion_type(), Some(ion_type) if ion_type.is_container()) {
+            let depth = self.raw_reader.depth() + 1;
+            self.located(check_limit(self.limits.max_depth, depth, "container depth"))?;
+        }
+        self.is_peeked = false;
+        self.raw_reader.step_in()?;
+        if let Some(stats) = self.stats.as_mut() {
+            stats.record_depth(self.raw_reader.depth());
+        }
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        self.is_peeked = false;
+        self.raw_reader.step_out()
+    }
+
+    fn resynchronize(&mut self) -> IonResult<Range<usize>> {
+        self.is_peeked = false;
+        self.raw_reader.resynchronize()
+    }
+
+    // Methods that read strings, blobs, and clobs enforce the reader's `max_value_length` limit.
+    // When the raw reader knows the value's length up front, it is checked before any of the
+    // value is read so that a declared length alone cannot cause a large allocation.
+
+    fn read_string(&mut self) -> IonResult<String> {
+        self.check_declared_length("string length")?;
+        let text = self.raw_reader.read_string()?;
+        let limit = self.limits.max_value_length;
+        self.located(check_limit(limit, text.len(), "string length"))?;
+        Ok(text)
+    }
+
+    fn read_str(&mut self) -> IonResult<&str> {
+        let limit = self.limits.max_value_length;
+        if limit.is_none() {
+            return self.raw_reader.read_str();
+        }
+        self.check_declared_length("string length")?;
+        // The text borrows from the raw reader, so the position has to be captured beforehand.
+        let position = self.position();
+        let text = self.raw_reader.read_str()?;
+        check_limit(limit, text.len(), "string length")
+            .map_err(|error| error.with_position(position))?;
+        Ok(text)
+    }
+
+    fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        F: FnOnce(&str) -> U,
+    {
+        self.check_declared_length("string length")?;
+        let limit = self.limits.max_value_length;
+        let result = self.raw_reader.map_string(|text| {
+            check_limit(limit, text.len(), "string length")?;
+            Ok(f(text))
+        })?;
+        self.located(result)
+    }
+
+    fn map_string_bytes<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.check_declared_length("string length")?;
+        let limit = self.limits.max_value_length;
+        let result = self.raw_reader.map_string_bytes(|bytes| {
+            check_limit(limit, bytes.len(), "string length")?;
+            Ok(f(bytes))
+        })?;
+        self.located(result)
+    }
+
+    fn read_blob(&mut self) -> IonResult<Vec<u8>> {
+        self.check_declared_length("blob length")?;
+        let bytes = self.raw_reader.read_blob()?;
+        let limit = self.limits.max_value_length;
+        self.located(check_limit(limit, bytes.len(), "blob length"))?;
+        Ok(bytes)
+    }
+
+    fn map_blob<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.check_declared_length("blob length")?;
+        let limit = self.limits.max_value_length;
+        let result = self.raw_reader.map_blob(|bytes| {
+            check_limit(limit, bytes.len(), "blob length")?;
+            Ok(f(bytes))
+        })?;
+        self.located(result)
+    }
+
+    fn read_clob(&mut self) -> IonResult<Vec<u8>> {
+        self.check_declared_length("clob length")?;
+        let bytes = self.raw_reader.read_clob()?;
+        let limit = self.limits.max_value_length;
+        self.located(check_limit(limit, bytes.len(), "clob length"))?;
+        Ok(bytes)
+    }
+
+    fn map_clob<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.check_declared_length("clob length")?;
+        let limit = self.limits.max_value_length;
+        let result = self.raw_reader.map_clob(|bytes| {
+            check_limit(limit, bytes.len(), "clob length")?;
+            Ok(f(bytes))
+        })?;
+        self.located(result)
+    }
+
+    fn read_blob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        // The limit is checked as each chunk arrives so that an oversized value is rejected
+        // before all of it has been read.
+        let limit = self.limits.max_value_length;
+        let mut total_length = 0;
+        let result = self.raw_reader.read_blob_chunks(&mut |chunk| {
+            total_length += chunk.len();
+            check_limit(limit, total_length, "blob length")?;
+            chunk_handler(chunk)
+        });
+        self.located(result)
+    }
+
+    fn read_clob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        let limit = self.limits.max_value_length;
+        let mut total_length = 0;
+        let result = self.raw_reader.read_clob_chunks(&mut |chunk| {
+            total_length += chunk.len();
+            check_limit(limit, total_length, "clob length")?;
+            chunk_handler(chunk)
+        });
+        self.located(result)
+    }
+
+    // The Reader needs to expose many of the same functions as the Cursor, but only some of those
+    // need to be re-defined to allow for system value processing. Any method listed here will be
+    // delegated to self.raw_reader directly.
+    delegate! {
+        to self.raw_reader {
+            fn is_null(&self) -> bool;
             fn ion_version(&self) -> (u8, u8);
             fn ion_type(&self) -> Option<IonType>;
+            fn has_annotations(&self) -> bool;
+            fn number_of_annotations(&self) -> usize;
             fn read_null(&mut self) -> IonResult<IonType>;
             fn read_bool(&mut self) -> IonResult<bool>;
             fn read_integer(&mut self) -> IonResult<Integer>;
@@ -392,18 +1429,12 @@ impl<R: RawReader> StreamReader for UserReader<R> {
             fn read_f32(&mut self) -> IonResult<f32>;
             fn read_f64(&mut self) -> IonResult<f64>;
             fn read_decimal(&mut self) -> IonResult<Decimal>;
-            fn read_string(&mut self) -> IonResult<String>;
-            fn map_string<F, U>(&mut self, f: F) -> IonResult<U> where F: FnOnce(&str) -> U;
-            fn map_string_bytes<F, U>(&mut self, f: F) -> IonResult<U> where F: FnOnce(&[u8]) -> U;
-            fn read_blob(&mut self) -> IonResult<Vec<u8>>;
-            fn map_blob<F, U>(&mut self, f: F) -> IonResult<U> where F: FnOnce(&[u8]) -> U;
-            fn read_clob(&mut self) -> IonResult<Vec<u8>>;
-            fn map_clob<F, U>(&mut self, f: F) -> IonResult<U> where F: FnOnce(&[u8]) -> U;
             fn read_timestamp(&mut self) -> IonResult<Timestamp>;
-            fn step_in(&mut self) -> IonResult<()>;
-            fn step_out(&mut self) -> IonResult<()>;
             fn parent_type(&self) -> Option<IonType>;
             fn depth(&self) -> usize;
+            fn position(&self) -> Position;
+            fn value_end_offset(&self) -> Option<usize>;
+            fn declared_value_length(&self) -> Option<usize>;
         }
     }
 }
@@ -440,6 +1471,7 @@ impl<T: AsRef<[u8]>> UserReader<RawBinaryReader<io::Cursor<T>>> {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
     use std::io;
 
     use super::*;
@@ -447,7 +1479,9 @@ mod tests {
     use crate::binary::raw_binary_reader::RawBinaryReader;
 
     use crate::result::IonResult;
+    use crate::shared_symbol_table::SharedSymbolTable;
     use crate::types::IonType;
+    use crate::value::SymbolToken;
     use crate::StreamItem::Value;
 
     type TestDataSource = io::Cursor<Vec<u8>>;
@@ -522,4 +1556,875 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_undefined_symbol_error_position() -> IonResult<()> {
+        // 0x20 is the integer 0; 0x71 0x63 is the symbol $99, which is not defined.
+        let mut reader = ion_reader_for(&[0x20, 0x71, 0x63]);
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.position(), Position::with_offset(5));
+        let error = reader.read_symbol().unwrap_err();
+        assert_eq!(error.position(), Some(Position::with_offset(5)));
+        Ok(())
+    }
+
+    // Walks the stream `{name: [1, (2)]}`, verifying the reader's depth and parent type at each step.
+    fn walk_nested_containers(reader: &mut Reader) -> IonResult<()> {
+        assert_eq!((reader.depth(), reader.parent_type()), (0, None));
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        assert_eq!((reader.depth(), reader.parent_type()), (0, None));
+        reader.step_in()?;
+        assert_eq!(
+            (reader.depth(), reader.parent_type()),
+            (1, Some(IonType::Struct))
+        );
+        assert_eq!(reader.next()?, Value(IonType::List));
+        reader.step_in()?;
+        assert_eq!(
+            (reader.depth(), reader.parent_type()),
+            (2, Some(IonType::List))
+        );
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.next()?, Value(IonType::SExpression));
+        reader.step_in()?;
+        assert_eq!(
+            (reader.depth(), reader.parent_type()),
+            (3, Some(IonType::SExpression))
+        );
+        reader.step_out()?;
+        assert_eq!(
+            (reader.depth(), reader.parent_type()),
+            (2, Some(IonType::List))
+        );
+        reader.step_out()?;
+        assert_eq!(
+            (reader.depth(), reader.parent_type()),
+            (1, Some(IonType::Struct))
+        );
+        reader.step_out()?;
+        assert_eq!((reader.depth(), reader.parent_type()), (0, None));
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_and_parent_type_text() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("{name: [1, (2)]}")?;
+        walk_nested_containers(&mut reader)
+    }
+
+    #[test]
+    fn test_depth_and_parent_type_binary() -> IonResult<()> {
+        let mut reader = ion_reader_for(&[
+            0xD7, // 7-byte struct
+            0x84, // Field ID 4 ('name')
+            0xB5, // 5-byte list
+            0x21, 0x01, // Integer 1
+            0xC2, // 2-byte s-expression
+            0x21, 0x02, // Integer 2
+        ]);
+        walk_nested_containers(&mut reader)
+    }
+
+    #[test]
+    fn test_read_str() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build(r#""foo" bar"#)?;
+        assert_eq!(reader.next()?, Value(IonType::String));
+        assert_eq!(reader.read_str()?, "foo");
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert!(reader.read_str().is_err());
+        Ok(())
+    }
+
+    // An io::Read implementation that only ever produces one byte at a time, like a slow socket.
+    struct OneByteAtATime(io::Cursor<Vec<u8>>);
+
+    impl Read for OneByteAtATime {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length = buf.len().min(1);
+            self.0.read(&mut buf[..length])
+        }
+    }
+
+    #[test]
+    fn test_build_from_read_binary() -> IonResult<()> {
+        let input = OneByteAtATime(io::Cursor::new(ion_data(EXAMPLE_STREAM)));
+        let mut reader = ReaderBuilder::new().build_from_read(input)?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.field_name()?, "foo");
+        assert_eq!(reader.read_i64()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_read_text() -> IonResult<()> {
+        let text = b"{foo: 1}\n\"hello\"".to_vec();
+        let input = OneByteAtATime(io::Cursor::new(text));
+        let mut reader = ReaderBuilder::new().build_from_read(input)?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.field_name()?, "foo");
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Value(IonType::String));
+        assert_eq!(reader.read_str()?, "hello");
+        Ok(())
+    }
+
+    #[test]
+    fn test_reader_backend_from_str() {
+        assert_eq!(
+            "native".parse::<ReaderBackend>().unwrap(),
+            ReaderBackend::Native
+        );
+        assert!("ion-java".parse::<ReaderBackend>().is_err());
+        #[cfg(feature = "ion_c")]
+        assert_eq!(
+            "ion-c".parse::<ReaderBackend>().unwrap(),
+            ReaderBackend::IonC
+        );
+    }
+
+    #[test]
+    fn test_stats() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("1 2")?;
+        reader.next()?;
+        assert_eq!(reader.stats(), None);
+
+        let ion_data = r#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            $10::{b: [1, 2, (3)], c: null.int}
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            "hello"
+        "#;
+        let mut reader = ReaderBuilder::new().with_stats(true).build(ion_data)?;
+        reader.next()?;
+        let stats = reader.stats().unwrap();
+        assert_eq!(stats.total_values(), 1);
+        assert_eq!(stats.symbol_tables(), 1);
+        assert_eq!(stats.bytes_consumed(), ion_data.find("$10").unwrap());
+
+        // Values that are skipped over at a shallower depth aren't counted.
+        reader.step_in()?;
+        reader.next()?;
+        reader.step_in()?;
+        while reader.next()? != StreamItem::Nothing {}
+        reader.step_out()?;
+        reader.step_out()?;
+        while reader.next()? != StreamItem::Nothing {}
+
+        let stats = reader.stats().unwrap();
+        assert_eq!(stats.values_of_type(IonType::Struct), 1);
+        assert_eq!(stats.values_of_type(IonType::List), 1);
+        assert_eq!(stats.values_of_type(IonType::Integer), 2);
+        assert_eq!(stats.values_of_type(IonType::SExpression), 1);
+        assert_eq!(stats.values_of_type(IonType::String), 1);
+        assert_eq!(stats.total_values(), 6);
+        assert_eq!(stats.nulls(), 0);
+        assert_eq!(stats.max_depth(), 2);
+        assert_eq!(stats.symbol_tables(), 2);
+        assert_eq!(stats.symbols_defined(), 3);
+        assert_eq!(stats.max_symbol_table_size(), 13);
+        assert_eq!(stats.bytes_consumed(), ion_data.trim_end().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_utf8_policy() -> IonResult<()> {
+        let ion_data: &[u8] = b"\"ok\"\n\"caf\xe9\" 1";
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert!(reader.next().is_err());
+
+        let warnings = Rc::new(std::cell::RefCell::new(Vec::new()));
+        let handler_warnings = Rc::clone(&warnings);
+        let mut reader = ReaderBuilder::new()
+            .with_utf8_policy(Utf8Policy::Lossy)
+            .with_invalid_utf8_handler(move |position| handler_warnings.borrow_mut().push(position))
+            .build(ion_data)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert_eq!(reader.read_str()?, "caf\u{FFFD}");
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+        assert_eq!(
+            warnings.borrow().as_slice(),
+            &[Position::with_offset(9).with_line_and_column(2, 5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unchecked_utf8() -> IonResult<()> {
+        // "ok", a string containing invalid UTF-8, and the integer 1
+        let ion_data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, 0x82, b'o', b'k', 0x84, b'c', b'a', b'f', 0xE9, 0x21, 0x01,
+        ];
+        // Strings are only validated when they are read.
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert!(reader.read_str().is_err());
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+
+        let mut reader = unsafe { ReaderBuilder::new().with_unchecked_utf8() }.build(ion_data)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert_eq!(reader.read_str()?, "ok");
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::String));
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailing_data_policy() -> IonResult<()> {
+        let ion_data = "1 {a: 2} ]]] 3\n4";
+        let build = |policy| {
+            ReaderBuilder::new()
+                .with_trailing_data_policy(policy)
+                .build(ion_data)
+        };
+        let mut reader = build(TrailingDataPolicy::Fail)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Struct));
+        assert!(reader.next().is_err());
+
+        let mut reader = build(TrailingDataPolicy::Truncate)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Struct));
+        assert!(reader.trailing_data_error().is_none());
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+        assert!(reader.trailing_data_error().is_some());
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+
+        let mut reader = build(TrailingDataPolicy::Resynchronize)?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Struct));
+        assert!(reader.next().is_err());
+        // The rest of the line containing the error was discarded.
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 4);
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+        assert!(reader.trailing_data_error().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_peek() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("1 foo::[2, 3] null.string")?;
+        assert_eq!(reader.peek()?, Value(IonType::Integer));
+        assert_eq!(reader.peek()?, Value(IonType::Integer));
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 1);
+
+        // Peek at the list and decide to step into it.
+        assert_eq!(reader.peek()?, Value(IonType::List));
+        assert!(reader.has_annotations());
+        reader.step_in()?;
+        // Stepping in discards the peeked state, so `next()` moves to the first child value.
+        assert_eq!(reader.peek()?, Value(IonType::Integer));
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 2);
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.peek()?, StreamItem::Nothing);
+        reader.step_out()?;
+
+        assert_eq!(reader.peek()?, StreamItem::Null(IonType::String));
+        assert_eq!(reader.next()?, StreamItem::Null(IonType::String));
+        assert_eq!(reader.peek()?, StreamItem::Nothing);
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotations_iterator() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("a::b::c::1 2")?;
+        reader.next()?;
+        let mut annotations = reader.annotations();
+        assert_eq!(annotations.len(), 3);
+        assert_eq!(annotations.next().unwrap()?, "a");
+        assert_eq!(annotations.len(), 2);
+        let rest: Vec<Symbol> = annotations.collect::<IonResult<_>>()?;
+        assert_eq!(rest, vec!["b", "c"]);
+        assert_eq!(reader.annotation(2)?, "c");
+        assert!(reader.annotation(3).is_err());
+
+        reader.next()?;
+        assert!(!reader.has_annotations());
+        assert_eq!(reader.annotations().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_tokens() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build(
+            r#"
+            $ion_symbol_table::{symbols: [null, "b"]}
+            $10::$11::a::{$10: 1, $11: 2, c: 3, $99: 4}
+            "#,
+        )?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        let annotations: Vec<OwnedSymbolToken> =
+            reader.annotations_symbols().collect::<IonResult<_>>()?;
+        let annotations: Vec<(Option<&str>, Option<usize>)> = annotations
+            .iter()
+            .map(|token| (token.text(), token.local_sid()))
+            .collect();
+        assert_eq!(
+            annotations,
+            vec![(None, Some(10)), (Some("b"), Some(11)), (Some("a"), None)]
+        );
+        // The text-only API can't represent the first annotation.
+        assert!(reader.annotations().next().unwrap().is_err());
+
+        reader.step_in()?;
+        let expected_fields = [(None, Some(10)), (Some("b"), Some(11)), (Some("c"), None)];
+        for (text, sid) in expected_fields {
+            reader.next()?;
+            let token = reader.field_name_symbol()?;
+            assert_eq!((token.text(), token.local_sid()), (text, sid));
+        }
+        // Undefined symbol IDs are still an error.
+        reader.next()?;
+        assert!(reader.field_name_symbol().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_explicit_format() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new()
+            .with_format(ReaderFormat::Binary)
+            .build(ion_data(EXAMPLE_STREAM))?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+
+        let mut reader = ReaderBuilder::new()
+            .with_format(ReaderFormat::Text)
+            .build("foo")?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+
+        // When its format is forced, the input is not inspected; the byte 'f' (0x66) is read as
+        // the type descriptor of a binary float rather than as the start of a symbol.
+        let mut reader = ReaderBuilder::new()
+            .with_format(ReaderFormat::Binary)
+            .build("foo")?;
+        assert_ne!(reader.next().ok(), Some(Value(IonType::Symbol)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_small_buffer_capacity() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new()
+            .with_buffer_capacity(1)
+            .build(ion_data(EXAMPLE_STREAM))?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.field_name()?, "foo");
+
+        let input = OneByteAtATime(io::Cursor::new(b"\"hello, world\"".to_vec()));
+        let mut reader = ReaderBuilder::new()
+            .with_buffer_capacity(1)
+            .build_from_read(input)?;
+        assert_eq!(reader.next()?, Value(IonType::String));
+        assert_eq!(reader.read_str()?, "hello, world");
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_depth_limit() -> IonResult<()> {
+        let limits = ReaderLimits::new().with_max_depth(2);
+        let mut reader = ReaderBuilder::new().with_limits(limits).build("[[[1]]]")?;
+        reader.next()?;
+        reader.step_in()?;
+        reader.next()?;
+        reader.step_in()?;
+        reader.next()?;
+        let error = reader.step_in().unwrap_err();
+        assert!(error.to_string().contains("container depth (3)"));
+        assert!(error.position().is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_annotations_limit() -> IonResult<()> {
+        let limits = ReaderLimits::new().with_max_annotations(2);
+        let mut reader = ReaderBuilder::new()
+            .with_limits(limits)
+            .build("a::b::1 a::b::c::2")?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_value_length_limit() -> IonResult<()> {
+        let limits = ReaderLimits::new().with_max_value_length(4);
+        let mut reader = ReaderBuilder::new()
+            .with_limits(limits)
+            .build(r#""abc" "abcde" {{aGVsbG8=}}"#)?;
+        reader.next()?;
+        assert_eq!(reader.read_str()?, "abc");
+        reader.next()?;
+        assert!(reader.read_string().is_err());
+        reader.next()?;
+        let mut sink = Vec::new();
+        assert!(reader.read_blob_into(&mut sink).is_err());
+        assert!(sink.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_value_length_is_checked_before_reading() -> IonResult<()> {
+        // A blob that declares a length of several terabytes but only contains three bytes.
+        let data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, 0xAE, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0xFF, 0x01, 0x02, 0x03,
+        ];
+        let mut reader = ReaderBuilder::hardened().build(data)?;
+        assert_eq!(reader.next()?, Value(IonType::Blob));
+        assert!(reader.read_blob().is_err());
+        assert!(reader.map_blob(|bytes| bytes.len()).is_err());
+
+        let mut reader = ReaderBuilder::hardened().build(data)?;
+        assert!(reader.elements().next().unwrap().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_symbols_limit() -> IonResult<()> {
+        // The system symbol table defines 10 symbols ($0 through $9).
+        let limits = ReaderLimits::new().with_max_symbols(11);
+        let mut reader = ReaderBuilder::new().with_limits(limits).build(
+            r#"
+            $ion_symbol_table::{symbols: ["foo"]}
+            $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["bar"]}
+            $11
+            "#,
+        )?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, "foo");
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hardened_limits() -> IonResult<()> {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let mut reader = ReaderBuilder::hardened().build(nested(64))?;
+        for _ in 0..64 {
+            reader.next()?;
+            reader.step_in()?;
+        }
+        let mut reader = ReaderBuilder::hardened().build(nested(65))?;
+        for _ in 0..64 {
+            reader.next()?;
+            reader.step_in()?;
+        }
+        reader.next()?;
+        assert!(reader.step_in().is_err());
+
+        // A blob that declares a length one byte over the 16 MiB limit is rejected before the
+        // reader tries to read (or allocate space for) its contents.
+        let data: &[u8] = &[0xE0, 0x01, 0x00, 0xEA, 0xAE, 0x08, 0x00, 0x00, 0x81, 0x01];
+        let mut reader = ReaderBuilder::hardened().build(data)?;
+        assert_eq!(reader.next()?, Value(IonType::Blob));
+        assert!(reader.read_blob().is_err());
+
+        // Unknown symbol table fields, and known ones with unexpected types, are ignored.
+        let mut reader = ReaderBuilder::hardened().build("$ion_symbol_table::{foo: 1} 5")?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 5);
+        let mut reader = ReaderBuilder::hardened().build(
+            r#"$ion_symbol_table::{imports: "x", symbols: 5, foo: [bar], symbols: ["a"]} $10"#,
+        )?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, "a");
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_policy() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{symbols: [null]}
+            $ion_symbol_table::{symbols: [null]}
+            $10
+            $10::{$10: $11}
+        "#;
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert!(reader.read_symbol().is_err());
+
+        let mut reader = ReaderBuilder::new()
+            .with_symbol_policy(SymbolPolicy::SidText)
+            .build(ion_data)?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.read_symbol()?, "$10");
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        let annotations: Vec<Symbol> = reader.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(annotations, vec![Symbol::owned("$10".to_string())]);
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Symbol));
+        assert_eq!(reader.field_name()?, "$10");
+        // $11 is not defined at all, which is always an error.
+        assert!(reader.read_symbol().is_err());
+        Ok(())
+    }
+
+    // A Catalog that holds a single version of a single shared symbol table.
+    struct SingleTableCatalog(Rc<SharedSymbolTable>);
+
+    impl Catalog for SingleTableCatalog {
+        fn get_table(&self, name: &str) -> Option<Rc<SharedSymbolTable>> {
+            Some(self.0.clone()).filter(|table| table.name() == name)
+        }
+
+        fn get_table_with_version(
+            &self,
+            name: &str,
+            version: usize,
+        ) -> Option<Rc<SharedSymbolTable>> {
+            self.get_table(name)
+                .filter(|table| table.version() == version)
+        }
+    }
+
+    fn fruit_catalog() -> IonResult<SingleTableCatalog> {
+        let symbols = vec![Some("apple".to_string()), Some("banana".to_string())];
+        let table = SharedSymbolTable::new("fruit", 2, symbols)?;
+        Ok(SingleTableCatalog(Rc::new(table)))
+    }
+
+    #[test]
+    fn test_catalog_import() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 2}],
+                symbols: ["cherry"],
+            }
+            $10 $11 $12
+        "#;
+        let mut reader = ReaderBuilder::new()
+            .with_catalog(fruit_catalog()?)
+            .build(ion_data)?;
+        for expected in ["apple", "banana", "cherry"] {
+            assert_eq!(reader.next()?, Value(IonType::Symbol));
+            assert_eq!(reader.read_symbol()?, expected);
+        }
+
+        // Without a catalog, the import cannot be resolved.
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_table_inspection() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 2}],
+                symbols: ["cherry"],
+            }
+            $12
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: [null, "date"]}
+            $14
+            $ion_1_0
+            foo
+        "#;
+        let mut reader = ReaderBuilder::new()
+            .with_catalog(fruit_catalog()?)
+            .build(ion_data)?;
+        assert_eq!(reader.max_id(), 9);
+        assert!(reader.imports().is_empty());
+
+        reader.next()?;
+        assert_eq!(reader.imports(), &[SymbolTableImport::new("fruit", 2, 2)]);
+        assert_eq!(reader.max_id(), 12);
+        assert_eq!(reader.text_for(10), Some("apple"));
+        assert_eq!(reader.text_for(12), Some("cherry"));
+        assert_eq!(reader.text_for(13), None);
+
+        // Appending to the symbol table keeps its imports.
+        reader.next()?;
+        assert_eq!(reader.imports().len(), 1);
+        assert_eq!(reader.max_id(), 14);
+        assert_eq!(reader.text_for(13), None);
+        assert!(reader.symbol_table().sid_is_valid(13));
+        assert_eq!(reader.text_for(14), Some("date"));
+
+        // An IVM resets the symbol table.
+        reader.next()?;
+        assert!(reader.imports().is_empty());
+        assert_eq!(reader.max_id(), 9);
+        assert_eq!(reader.text_for(10), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_table_handler() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{symbols: ["foo"]}
+            $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["bar"]}
+            $ion_1_0
+            baz
+        "#;
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let recorded_changes = changes.clone();
+        let mut reader = ReaderBuilder::new()
+            .with_symbol_table_handler(move |change, symbol_table| {
+                recorded_changes
+                    .borrow_mut()
+                    .push((change, symbol_table.max_id()));
+            })
+            .build(ion_data)?;
+        while reader.next()? != StreamItem::Nothing {}
+        assert_eq!(
+            *changes.borrow(),
+            vec![
+                (SymbolTableChange::Replaced, 10),
+                (SymbolTableChange::Appended, 11),
+                (SymbolTableChange::Reset, 9),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sid_text_with_missing_import() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 1, max_id: 2}, {name: "nuts", max_id: 1}],
+                symbols: ["cherry"],
+            }
+            $10::{$11: $12, cherry: $13}
+        "#;
+        // Without a catalog, the imported symbols' text is unknown.
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        assert!(reader.annotations().next().unwrap().is_err());
+
+        // Version 2 of "fruit" is found; "nuts" is missing.
+        for catalog in [None, Some(fruit_catalog()?)] {
+            let has_catalog = catalog.is_some();
+            let mut builder = ReaderBuilder::new().with_symbol_policy(SymbolPolicy::SidText);
+            if let Some(catalog) = catalog {
+                builder = builder.with_catalog(catalog);
+            }
+            let mut reader = builder.build(ion_data)?;
+            assert_eq!(reader.next()?, Value(IonType::Struct));
+            let (first, second) = if has_catalog {
+                ("apple", "banana")
+            } else {
+                ("$10", "$11")
+            };
+            let annotations: Vec<Symbol> = reader.annotations().collect::<IonResult<_>>()?;
+            assert_eq!(annotations, vec![Symbol::owned(first.to_string())]);
+            reader.step_in()?;
+            assert_eq!(reader.next()?, Value(IonType::Symbol));
+            assert_eq!(reader.field_name()?, second);
+            assert_eq!(reader.read_symbol()?, "$12");
+            assert_eq!(reader.next()?, Value(IonType::Symbol));
+            assert_eq!(reader.field_name()?, "cherry");
+            assert_eq!(reader.read_symbol()?, "cherry");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_max_id_adjusts_symbol_count() -> IonResult<()> {
+        // "fruit" version 2 has two symbols. Imports with a `max_id` contribute exactly that many
+        // symbols, whether the table is found or not.
+        let ion_data = r#"
+            $ion_symbol_table::{
+                imports: [
+                    {name: "fruit", version: 2, max_id: 1},
+                    {name: "fruit", version: 2, max_id: 3},
+                    {name: "nuts", version: 4, max_id: 2},
+                    {name: "fruit", version: 2, max_id: -1},
+                ],
+                symbols: ["cherry"],
+            }
+            $10 $11 $12 $13 $14 $15 $16 $17 $18
+        "#;
+        let mut reader = ReaderBuilder::new()
+            .with_catalog(fruit_catalog()?)
+            .build(ion_data)?;
+        let expected = [
+            Some("apple"),
+            Some("apple"),
+            Some("banana"),
+            None,
+            None,
+            None,
+            Some("apple"),
+            Some("banana"),
+            Some("cherry"),
+        ];
+        for expected in expected {
+            assert_eq!(reader.next()?, Value(IonType::Symbol));
+            match expected {
+                Some(text) => assert_eq!(reader.read_symbol()?, text),
+                None => assert!(reader.read_symbol().is_err()),
+            }
+        }
+        assert_eq!(reader.imports()[2], SymbolTableImport::new("nuts", 4, 2));
+
+        // Without a `max_id`, a missing table is still an error.
+        let ion_data = r#"$ion_symbol_table::{imports: [{name: "nuts", version: 4}]} 1"#;
+        let mut reader = ReaderBuilder::new()
+            .with_catalog(fruit_catalog()?)
+            .build(ion_data)?;
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_catalog_import_with_max_id() -> IonResult<()> {
+        // Version 1 isn't in the catalog, but `max_id` allows version 2 to be used instead.
+        let ion_data = r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 1, max_id: 3}],
+                symbols: ["cherry"],
+            }
+            $10 $11 $12 $13
+        "#;
+        let mut reader = ReaderBuilder::new()
+            .with_catalog(Rc::new(fruit_catalog()?))
+            .with_symbol_policy(SymbolPolicy::SidText)
+            .build(ion_data)?;
+        for expected in ["apple", "banana", "$12", "cherry"] {
+            assert_eq!(reader.next()?, Value(IonType::Symbol));
+            assert_eq!(reader.read_symbol()?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_max_id_is_bounded() -> IonResult<()> {
+        // Without a `max_symbols` limit, a huge `max_id` must be rejected rather than allocating
+        // a placeholder for every symbol it declares.
+        let ion_data = r#"$ion_symbol_table::{imports:[{name:"a",max_id:100000000000}]} 1"#;
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert!(reader.next().is_err());
+
+        // The same applies to the total contributed by several imports.
+        let ion_data = r#"
+            $ion_symbol_table::{imports: [{name: "a", max_id: 600000}, {name: "b", max_id: 600000}]}
+            1
+        "#;
+        let mut reader = ReaderBuilder::new().build(ion_data)?;
+        assert!(reader.next().is_err());
+
+        // A configured limit takes precedence over the default.
+        let ion_data = r#"$ion_symbol_table::{imports: [{name: "a", max_id: 20}]} 1"#;
+        let limits = ReaderLimits::new().with_max_symbols(10);
+        let mut reader = ReaderBuilder::new().with_limits(limits).build(ion_data)?;
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ion_1_1_requires_opt_in() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("$ion_1_1 foo")?;
+        assert!(reader.next().is_err());
+        let binary: &[u8] = &[0xE0, 0x01, 0x01, 0xEA, 0x6E];
+        assert!(ReaderBuilder::new().build(binary).is_err());
+
+        let mut reader = ReaderBuilder::new()
+            .with_ion_1_1(true)
+            .build("$ion_1_1 foo::{bar: [1, 2]}")?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        assert_eq!(reader.raw_reader.ion_version(), (1, 1));
+        assert_eq!(reader.annotations().next().unwrap()?, "foo");
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::List));
+        assert_eq!(reader.field_name()?, "bar");
+        Ok(())
+    }
+
+    #[test]
+    fn test_leap_second_policy() -> IonResult<()> {
+        let text = "[2016-12-31T23:59:60.5Z, 2016-12-31T23:59:58Z]";
+        let mut reader = ReaderBuilder::new().build(text)?;
+        reader.next()?;
+        reader.step_in()?;
+        assert!(reader.next().is_err());
+
+        let expected = [
+            (LeapSecondPolicy::Clamp, "2016-12-31T23:59:59.5+00:00"),
+            (LeapSecondPolicy::Carry, "2017-01-01T00:00:00.5+00:00"),
+        ];
+        for (policy, expected) in expected {
+            let mut reader = ReaderBuilder::new()
+                .with_leap_second_policy(policy)
+                .build(text)?;
+            reader.next()?;
+            reader.step_in()?;
+            assert_eq!(reader.next()?, Value(IonType::Timestamp));
+            assert_eq!(reader.read_timestamp()?.to_string(), expected);
+            assert_eq!(reader.next()?, Value(IonType::Timestamp));
+            assert_eq!(
+                reader.read_timestamp()?.to_string(),
+                "2016-12-31T23:59:58+00:00"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_binary_ion_1_1() -> IonResult<()> {
+        let data: &[u8] = &[
+            0xE0, 0x01, 0x01, 0xEA, // $ion_1_1
+            0xE7, 0xFB, 0x66, 0x6F, 0x6F, // foo::
+            0xF3, // {
+            0xFB, 0x62, 0x61, 0x72, // bar:
+            0xB2, 0x61, 0x01, // [1]
+            0x01, 0xF0, // }
+            0xE1, 0x04, // name
+        ];
+        for format in [ReaderFormat::Auto, ReaderFormat::Binary] {
+            let mut reader = ReaderBuilder::new()
+                .with_ion_1_1(true)
+                .with_format(format)
+                .build(data)?;
+            assert_eq!(reader.next()?, Value(IonType::Struct));
+            assert_eq!(reader.annotations().next().unwrap()?, "foo");
+            reader.step_in()?;
+            assert_eq!(reader.next()?, Value(IonType::List));
+            assert_eq!(reader.field_name()?, "bar");
+            reader.step_out()?;
+            assert_eq!(reader.next()?, Value(IonType::Symbol));
+            assert_eq!(reader.read_symbol()?, "name");
+            assert_eq!(reader.next()?, StreamItem::Nothing);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_json() -> IonResult<()> {
+        let json = r#"{"z": 1.5, "a": [null, "two", 3e0]} {"z": 4}"#;
+        let mut reader = ReaderBuilder::new()
+            .with_format(ReaderFormat::Json)
+            .with_json_number_policy(JsonNumberPolicy::IonText)
+            .build(json)?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Decimal));
+        assert_eq!(reader.field_name()?, "z");
+        assert_eq!(reader.read_decimal()?, Decimal::new(15, -1));
+        assert_eq!(reader.next()?, Value(IonType::List));
+        assert_eq!(reader.field_name()?, "a");
+        reader.step_in()?;
+        assert_eq!(reader.next()?, StreamItem::Null(IonType::Null));
+        assert_eq!(reader.next()?, Value(IonType::String));
+        assert_eq!(reader.next()?, Value(IonType::Float));
+        assert_eq!(reader.read_f64()?, 3.0);
+        reader.step_out()?;
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+        Ok(())
+    }
 }
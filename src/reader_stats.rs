@@ -0,0 +1,86 @@
+use crate::types::IonType;
+
+// The number of variants in the `IonType` enum.
+const NUM_ION_TYPES: usize = IonType::Struct as usize + 1;
+
+/// Statistics describing the portion of a stream that a [Reader](crate::Reader) has processed.
+///
+/// Statistics are only collected if they were enabled using
+/// [ReaderBuilder::with_stats](crate::ReaderBuilder::with_stats). They can be retrieved at any
+/// point using [UserReader::stats](crate::UserReader::stats).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ReaderStats {
+    values_by_type: [usize; NUM_ION_TYPES],
+    nulls: usize,
+    max_depth: usize,
+    symbol_tables: usize,
+    symbols_defined: usize,
+    max_symbol_table_size: usize,
+    bytes_consumed: usize,
+}
+
+impl ReaderStats {
+    /// Returns the number of values of the given type that the reader has advanced over,
+    /// including nulls of that type.
+    pub fn values_of_type(&self, ion_type: IonType) -> usize {
+        self.values_by_type[ion_type as usize]
+    }
+
+    /// Returns the total number of user values that the reader has advanced over at any depth.
+    pub fn total_values(&self) -> usize {
+        self.values_by_type.iter().sum()
+    }
+
+    /// Returns the number of null values (of any type) that the reader has advanced over.
+    pub fn nulls(&self) -> usize {
+        self.nulls
+    }
+
+    /// Returns the deepest level of container nesting that the reader has stepped into.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns the number of local symbol tables that the reader has processed.
+    pub fn symbol_tables(&self) -> usize {
+        self.symbol_tables
+    }
+
+    /// Returns the total number of symbols that local symbol tables have added to the reader's
+    /// symbol table, including imported symbols.
+    pub fn symbols_defined(&self) -> usize {
+        self.symbols_defined
+    }
+
+    /// Returns the largest number of symbols (including the system symbols) that the reader's
+    /// symbol table has held at once.
+    pub fn max_symbol_table_size(&self) -> usize {
+        self.max_symbol_table_size
+    }
+
+    /// Returns the number of bytes of input that precede the reader's current position.
+    pub fn bytes_consumed(&self) -> usize {
+        self.bytes_consumed
+    }
+
+    pub(crate) fn record_value(&mut self, ion_type: IonType, is_null: bool) {
+        self.values_by_type[ion_type as usize] += 1;
+        if is_null {
+            self.nulls += 1;
+        }
+    }
+
+    pub(crate) fn record_depth(&mut self, depth: usize) {
+        self.max_depth = self.max_depth.max(depth);
+    }
+
+    pub(crate) fn record_symbol_table(&mut self, symbols_defined: usize, symbol_table_size: usize) {
+        self.symbol_tables += 1;
+        self.symbols_defined += symbols_defined;
+        self.max_symbol_table_size = self.max_symbol_table_size.max(symbol_table_size);
+    }
+
+    pub(crate) fn set_bytes_consumed(&mut self, bytes_consumed: usize) {
+        self.bytes_consumed = bytes_consumed;
+    }
+}
@@ -4,6 +4,8 @@ use std::{fmt, io};
 
 use thiserror::Error;
 
+use crate::position::Position;
+
 /// A unified Result type representing the outcome of method calls that may fail.
 pub type IonResult<T> = Result<T, IonError>;
 
@@ -46,8 +48,13 @@ pub enum IonError {
     },
 
     /// Indicates that the data stream being read contained illegal or otherwise unreadable data.
-    #[error("{description}")]
-    DecodingError { description: String },
+    /// If the reader that raised the error knew where in the stream it was, `position` will
+    /// describe that location.
+    #[error("{description}{}", display_position(.position))]
+    DecodingError {
+        description: String,
+        position: Option<Position>,
+    },
 
     /// Returned when the user has performed an illegal operation (for example: calling stepOut()
     /// on the cursor at the top level.)
@@ -77,8 +84,12 @@ impl Clone for IonError {
                 source: io::Error::from(source.kind()),
             },
             FmtError { source } => FmtError { source: *source },
-            DecodingError { description } => DecodingError {
+            DecodingError {
+                description,
+                position,
+            } => DecodingError {
                 description: description.clone(),
+                position: *position,
             },
             IllegalOperation { operation } => IllegalOperation {
                 operation: operation.clone(),
@@ -98,7 +109,16 @@ impl PartialEq for IonError {
             // We can compare the io::Errors' ErrorKinds, offering a weak definition of equality.
             (IoError { source: s1 }, IoError { source: s2 }) => s1.kind() == s2.kind(),
             (FmtError { source: s1 }, FmtError { source: s2 }) => s1 == s2,
-            (DecodingError { description: s1 }, DecodingError { description: s2 }) => s1 == s2,
+            (
+                DecodingError {
+                    description: s1,
+                    position: p1,
+                },
+                DecodingError {
+                    description: s2,
+                    position: p2,
+                },
+            ) => s1 == s2 && p1 == p2,
             (IllegalOperation { operation: s1 }, IllegalOperation { operation: s2 }) => s1 == s2,
             (IonCError { source: s1 }, IonCError { source: s2 }) => s1 == s2,
             _ => false,
@@ -106,6 +126,39 @@ impl PartialEq for IonError {
     }
 }
 
+impl IonError {
+    /// If this is a [IonError::DecodingError] that does not yet have a [Position], returns a copy
+    /// of the error that includes the provided `position`. Otherwise, returns the error unchanged.
+    pub fn with_position(self, position: Position) -> Self {
+        match self {
+            IonError::DecodingError {
+                description,
+                position: None,
+            } => IonError::DecodingError {
+                description,
+                position: Some(position),
+            },
+            other => other,
+        }
+    }
+
+    /// Returns the location in the input stream at which this error was encountered, if known.
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            IonError::DecodingError { position, .. } => *position,
+            _ => None,
+        }
+    }
+}
+
+// Used by the `Display` implementation of `IonError::DecodingError`.
+fn display_position(position: &Option<Position>) -> String {
+    match position {
+        Some(position) => format!(" (at {})", position),
+        None => String::new(),
+    }
+}
+
 /// A convenience method for creating an IonResult containing an IonError::DecodingError with the
 /// provided description text.
 pub fn decoding_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
@@ -117,6 +170,7 @@ pub fn decoding_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
 pub fn decoding_error_raw<S: AsRef<str>>(description: S) -> IonError {
     IonError::DecodingError {
         description: description.as_ref().to_string(),
+        position: None,
     }
 }
 
@@ -0,0 +1,849 @@
+#![cfg(feature = "schema")]
+
+use std::collections::HashMap;
+use std::fmt;
+
+use regex::{Regex, RegexBuilder};
+
+use crate::ion_eq::IonEq;
+use crate::path_extractor::PathComponent;
+use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonResult};
+use crate::types::integer::IntAccess;
+use crate::types::IonType;
+use crate::value::owned::OwnedElement;
+use crate::value::reader::{native_element_reader, ElementReader};
+use crate::value::{Element, Sequence, Struct, SymbolToken};
+
+/// A collection of named types loaded from Ion Schema Language (ISL) definitions.
+///
+/// Only a subset of ISL is supported. Each top-level `type::{...}` struct defines a named type
+/// using any of the following constraints:
+///
+/// * `type`: a built-in type (`int`, `string`, `struct`, `text`, `number`, `any`, etc., or their
+///   `$`-prefixed variants that also accept nulls), another named type, or an inline definition.
+/// * `fields`: a struct mapping field names to types. A field's inline definition may include
+///   an `occurs` constraint (`required`, `optional`, an integer, or `range::[min, max]`); fields
+///   are optional by default.
+/// * `content: closed`: disallows fields that are not listed in `fields`.
+/// * `element`: the type of every child value of a list, s-expression, or struct.
+/// * `valid_values`: a list of the permitted values. Annotations are ignored when comparing.
+/// * `regex`: a pattern that string and symbol values must match. The pattern may be annotated
+///   with `i` (case-insensitive) and/or `m` (multi-line).
+///
+/// Type references may be annotated with `nullable` to also accept `null.null` and nulls of the
+/// referenced type's underlying built-in type. Schema headers and footers are accepted, but
+/// headers may not import other schemas. Definitions that use any other constraint are rejected
+/// when the schema is loaded.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::value::reader::{native_element_reader, ElementReader};
+/// use ion_rs::Schema;
+///
+/// let schema = Schema::from_ion(
+///     r#"
+///     type::{name: id, type: string, regex: "^[a-z]+-[0-9]+$"}
+///     type::{name: order, type: struct, fields: {id: {type: id, occurs: required}, qty: int}}
+///     "#,
+/// )?;
+/// let order = native_element_reader().read_one(br#"{id: "abc-12", qty: "3"}"#)?;
+/// let violations = schema.validate("order", &order)?;
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].to_string(), r#"(qty): expected a value of type int, found "3""#);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    types: HashMap<String, TypeDefinition>,
+}
+
+/// A value that failed to satisfy one of a [Schema]'s constraints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    path: Vec<PathComponent>,
+    constraint: &'static str,
+    message: String,
+}
+
+impl Violation {
+    fn new<M: Into<String>>(path: &[PathComponent], constraint: &'static str, message: M) -> Self {
+        Violation {
+            path: path.to_vec(),
+            constraint,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the location of the offending value relative to the value that was validated.
+    /// When validating a stream, the first component is the index of the top-level value.
+    pub fn path(&self) -> &[PathComponent] {
+        &self.path
+    }
+
+    /// Returns the name of the constraint that was violated, e.g. `fields` or `regex`.
+    pub fn constraint(&self) -> &str {
+        self.constraint
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (index, component) in self.path.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            match component {
+                PathComponent::Field(name) => write!(f, "{}", name)?,
+                PathComponent::Index(position) => write!(f, "{}", position)?,
+                PathComponent::Wildcard => write!(f, "*")?,
+            }
+        }
+        write!(f, "): {}", self.message)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TypeReference {
+    Named(String),
+    Inline(Box<TypeDefinition>),
+}
+
+#[derive(Debug, Clone)]
+struct TypeRef {
+    reference: TypeReference,
+    nullable: bool,
+}
+
+// The number of times that a field may appear in a struct. A `max` of `None` is unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Occurs {
+    min: usize,
+    max: Option<usize>,
+}
+
+impl Occurs {
+    const OPTIONAL: Occurs = Occurs {
+        min: 0,
+        max: Some(1),
+    };
+    const REQUIRED: Occurs = Occurs {
+        min: 1,
+        max: Some(1),
+    };
+
+    fn contains(&self, count: usize) -> bool {
+        count >= self.min && !matches!(self.max, Some(max) if count > max)
+    }
+}
+
+impl fmt::Display for Occurs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "exactly {}", max),
+            Some(max) => write!(f, "between {} and {}", self.min, max),
+            None => write!(f, "at least {}", self.min),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FieldDefinition {
+    type_ref: TypeRef,
+    occurs: Occurs,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TypeDefinition {
+    base: Option<TypeRef>,
+    fields: Vec<(String, FieldDefinition)>,
+    closed: bool,
+    element: Option<TypeRef>,
+    valid_values: Option<Vec<OwnedElement>>,
+    regex: Option<Regex>,
+}
+
+// Where a type definition appears, which determines the constraints it may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DefinitionContext {
+    TopLevel,
+    Inline,
+    Field,
+}
+
+impl Schema {
+    /// Loads the type definitions in the provided ISL text or binary data.
+    pub fn from_ion<A: AsRef<[u8]>>(data: A) -> IonResult<Schema> {
+        let mut types = HashMap::new();
+        for element in native_element_reader().read_all(data.as_ref())? {
+            let annotation = element.annotations().next().and_then(|a| a.text());
+            match annotation {
+                Some("schema_header") => {
+                    let imports = element.as_struct().and_then(|header| header.get("imports"));
+                    if matches!(imports.and_then(|i| i.as_sequence()), Some(i) if !i.is_empty()) {
+                        return decoding_error("schema imports are not supported");
+                    }
+                }
+                Some("type") => {
+                    let name = element
+                        .as_struct()
+                        .and_then(|definition| definition.get("name"))
+                        .and_then(|name| name.as_str())
+                        .ok_or_else(|| {
+                            decoding_error_raw("top-level type definitions must have a name")
+                        })?
+                        .to_owned();
+                    if core_type_matches(&name, IonType::Null, true).is_some() {
+                        return decoding_error(format!("cannot redefine built-in type '{}'", name));
+                    }
+                    let definition = parse_definition(&element, DefinitionContext::TopLevel)?;
+                    if types.insert(name.clone(), definition).is_some() {
+                        return decoding_error(format!(
+                            "type '{}' is defined more than once",
+                            name
+                        ));
+                    }
+                }
+                // Schema footers and any other top-level values are open content.
+                _ => {}
+            }
+        }
+        let schema = Schema { types };
+        for (name, definition) in schema.types.iter() {
+            schema.check_references(definition)?;
+            schema.check_base_cycle(name)?;
+        }
+        Ok(schema)
+    }
+
+    /// Returns `true` if the schema defines a type called `name`.
+    pub fn has_type(&self, name: &str) -> bool {
+        self.types.contains_key(name)
+    }
+
+    /// Returns the names of the types that the schema defines, in no particular order.
+    pub fn type_names(&self) -> impl Iterator<Item = &str> {
+        self.types.keys().map(String::as_str)
+    }
+
+    /// Checks `element` against the type called `type_name`, which may be a type defined by the
+    /// schema or a built-in type. Returns every violation that was found; an empty list means
+    /// that the element is valid.
+    pub fn validate(&self, type_name: &str, element: &OwnedElement) -> IonResult<Vec<Violation>> {
+        if !self.has_type(type_name) && core_type_matches(type_name, IonType::Null, true).is_none()
+        {
+            return illegal_operation(format!("the schema has no type named '{}'", type_name));
+        }
+        let mut path = Vec::new();
+        let mut violations = Vec::new();
+        self.check_named(type_name, element, &mut path, &mut violations);
+        Ok(violations)
+    }
+
+    /// Checks each top-level value in `data` against the type called `type_name`. The path of
+    /// each violation begins with the index of the top-level value that it refers to.
+    pub fn validate_all<A: AsRef<[u8]>>(
+        &self,
+        type_name: &str,
+        data: A,
+    ) -> IonResult<Vec<Violation>> {
+        let mut violations = Vec::new();
+        for (index, element) in native_element_reader()
+            .read_all(data.as_ref())?
+            .iter()
+            .enumerate()
+        {
+            for mut violation in self.validate(type_name, element)? {
+                violation.path.insert(0, PathComponent::Index(index));
+                violations.push(violation);
+            }
+        }
+        Ok(violations)
+    }
+
+    fn check_references(&self, definition: &TypeDefinition) -> IonResult<()> {
+        let references = definition
+            .base
+            .iter()
+            .chain(definition.element.iter())
+            .chain(definition.fields.iter().map(|(_, field)| &field.type_ref));
+        for type_ref in references {
+            match &type_ref.reference {
+                TypeReference::Named(name) => {
+                    if !self.has_type(name)
+                        && core_type_matches(name, IonType::Null, true).is_none()
+                    {
+                        return decoding_error(format!("reference to unknown type '{}'", name));
+                    }
+                }
+                TypeReference::Inline(inline) => self.check_references(inline)?,
+            }
+        }
+        Ok(())
+    }
+
+    // Named types may refer to themselves through `fields` or `element`, but a type whose
+    // `type` constraint leads back to itself could never be satisfied.
+    fn check_base_cycle(&self, name: &str) -> IonResult<()> {
+        let mut current = name;
+        for _ in 0..self.types.len() {
+            match self.named_base(current) {
+                Some(base) if base == name => {
+                    return decoding_error(format!("type '{}' is defined in terms of itself", name))
+                }
+                Some(base) => current = base,
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    // Returns the name of the type that the named type's `type` constraint refers to, if any.
+    fn named_base(&self, name: &str) -> Option<&str> {
+        match self.types.get(name)?.base.as_ref()? {
+            TypeRef {
+                reference: TypeReference::Named(base),
+                ..
+            } => Some(base),
+            _ => None,
+        }
+    }
+
+    // Follows the chain of `type` constraints that starts at `name` to a built-in type, if any.
+    fn core_type_of<'a>(&'a self, mut name: &'a str) -> Option<&'a str> {
+        for _ in 0..=self.types.len() {
+            if !self.has_type(name) {
+                return Some(name);
+            }
+            name = self.named_base(name)?;
+        }
+        None
+    }
+
+    fn check_type_ref(
+        &self,
+        type_ref: &TypeRef,
+        element: &OwnedElement,
+        path: &mut Vec<PathComponent>,
+        violations: &mut Vec<Violation>,
+    ) {
+        if type_ref.nullable && element.is_null() {
+            let accepted = match &type_ref.reference {
+                TypeReference::Named(name) => match self.core_type_of(name) {
+                    Some(core_type) => core_type_matches(
+                        &format!("${}", core_type.trim_start_matches('$')),
+                        element.ion_type(),
+                        true,
+                    )
+                    .unwrap_or(false),
+                    None => true,
+                },
+                TypeReference::Inline(_) => true,
+            };
+            if accepted || element.ion_type() == IonType::Null {
+                return;
+            }
+        }
+        match &type_ref.reference {
+            TypeReference::Named(name) => self.check_named(name, element, path, violations),
+            TypeReference::Inline(definition) => {
+                self.check_definition(definition, element, path, violations)
+            }
+        }
+    }
+
+    fn check_named(
+        &self,
+        name: &str,
+        element: &OwnedElement,
+        path: &mut Vec<PathComponent>,
+        violations: &mut Vec<Violation>,
+    ) {
+        if let Some(definition) = self.types.get(name) {
+            return self.check_definition(definition, element, path, violations);
+        }
+        // References to unknown types are rejected when the schema is loaded.
+        if core_type_matches(name, element.ion_type(), element.is_null()) == Some(false) {
+            violations.push(Violation::new(
+                path,
+                "type",
+                format!("expected a value of type {}, found {}", name, element),
+            ));
+        }
+    }
+
+    fn check_definition(
+        &self,
+        definition: &TypeDefinition,
+        element: &OwnedElement,
+        path: &mut Vec<PathComponent>,
+        violations: &mut Vec<Violation>,
+    ) {
+        if let Some(base) = &definition.base {
+            let existing = violations.len();
+            self.check_type_ref(base, element, path, violations);
+            // The remaining constraints would only repeat the type mismatch.
+            if violations.len() > existing {
+                return;
+            }
+        }
+
+        if let Some(valid_values) = &definition.valid_values {
+            let value = element.clone().with_annotations(Vec::new());
+            if !valid_values
+                .iter()
+                .any(|candidate| candidate.ion_eq(&value))
+            {
+                violations.push(Violation::new(
+                    path,
+                    "valid_values",
+                    format!("{} is not one of the valid values", value),
+                ));
+            }
+        }
+
+        if let Some(regex) = &definition.regex {
+            match element.as_str() {
+                Some(text) if regex.is_match(text) => {}
+                Some(text) => violations.push(Violation::new(
+                    path,
+                    "regex",
+                    format!("{:?} does not match the pattern /{}/", text, regex),
+                )),
+                None => violations.push(Violation::new(
+                    path,
+                    "regex",
+                    format!("expected a string or symbol, found {}", element),
+                )),
+            }
+        }
+
+        if !definition.fields.is_empty() || definition.closed {
+            self.check_fields(definition, element, path, violations);
+        }
+
+        if let Some(element_type) = &definition.element {
+            if let Some(sequence) = element.as_sequence() {
+                for (index, child) in sequence.iter().enumerate() {
+                    path.push(PathComponent::Index(index));
+                    self.check_type_ref(element_type, child, path, violations);
+                    path.pop();
+                }
+            } else if let Some(fields) = element.as_struct() {
+                for (name, child) in fields.iter() {
+                    path.push(PathComponent::Field(
+                        name.text().unwrap_or_default().to_owned(),
+                    ));
+                    self.check_type_ref(element_type, child, path, violations);
+                    path.pop();
+                }
+            } else {
+                violations.push(Violation::new(
+                    path,
+                    "element",
+                    format!(
+                        "expected a list, s-expression, or struct, found {}",
+                        element
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn check_fields(
+        &self,
+        definition: &TypeDefinition,
+        element: &OwnedElement,
+        path: &mut Vec<PathComponent>,
+        violations: &mut Vec<Violation>,
+    ) {
+        let fields = match element.as_struct() {
+            Some(fields) => fields,
+            None => {
+                let message = format!("expected a struct, found {}", element);
+                return violations.push(Violation::new(path, "fields", message));
+            }
+        };
+        for (name, field) in definition.fields.iter() {
+            let mut count = 0;
+            path.push(PathComponent::Field(name.clone()));
+            for value in fields.get_all(name) {
+                count += 1;
+                self.check_type_ref(&field.type_ref, value, path, violations);
+            }
+            if !field.occurs.contains(count) {
+                violations.push(Violation::new(
+                    path,
+                    "occurs",
+                    format!(
+                        "expected {} occurrence(s) of field '{}', found {}",
+                        field.occurs, name, count
+                    ),
+                ));
+            }
+            path.pop();
+        }
+        if definition.closed {
+            for (name, _) in fields.iter() {
+                let name = name.text().unwrap_or_default();
+                if !definition.fields.iter().any(|(field, _)| field == name) {
+                    path.push(PathComponent::Field(name.to_owned()));
+                    let message = format!("field '{}' is not allowed in closed content", name);
+                    violations.push(Violation::new(path, "content", message));
+                    path.pop();
+                }
+            }
+        }
+    }
+}
+
+// Returns whether a value of the given type is an instance of the built-in type called `name`,
+// or `None` if there is no such built-in type. Names prefixed with `$` also accept nulls.
+fn core_type_matches(name: &str, ion_type: IonType, is_null: bool) -> Option<bool> {
+    use IonType::*;
+    let (name, allows_null) = match name.strip_prefix('$') {
+        Some(name) => (name, true),
+        None => (name, false),
+    };
+    let types: &[IonType] = match name {
+        "any" => return Some(allows_null || !is_null),
+        "nothing" => return Some(false),
+        "null" if allows_null => &[Null],
+        "bool" => &[Boolean],
+        "int" => &[Integer],
+        "float" => &[Float],
+        "decimal" => &[Decimal],
+        "timestamp" => &[Timestamp],
+        "symbol" => &[Symbol],
+        "string" => &[String],
+        "clob" => &[Clob],
+        "blob" => &[Blob],
+        "list" => &[List],
+        "sexp" => &[SExpression],
+        "struct" => &[Struct],
+        "text" => &[String, Symbol],
+        "lob" => &[Clob, Blob],
+        "number" => &[Integer, Float, Decimal],
+        _ => return None,
+    };
+    Some(types.contains(&ion_type) && (allows_null || !is_null))
+}
+
+fn parse_definition(
+    element: &OwnedElement,
+    context: DefinitionContext,
+) -> IonResult<TypeDefinition> {
+    let constraints = element.as_struct().ok_or_else(|| {
+        decoding_error_raw(format!("expected a type definition, found {}", element))
+    })?;
+    let mut definition = TypeDefinition::default();
+    for (name, value) in constraints.iter() {
+        match name.text() {
+            Some("name") if context == DefinitionContext::TopLevel => {}
+            Some("occurs") if context == DefinitionContext::Field => {}
+            Some("type") => definition.base = Some(parse_type_ref(value)?),
+            Some("fields") => definition.fields = parse_fields(value)?,
+            Some("content") => {
+                if value.as_sym().and_then(|s| s.text()) != Some("closed") {
+                    return decoding_error(format!("unsupported content constraint: {}", value));
+                }
+                definition.closed = true;
+            }
+            Some("element") => definition.element = Some(parse_type_ref(value)?),
+            Some("valid_values") => definition.valid_values = Some(parse_valid_values(value)?),
+            Some("regex") => definition.regex = Some(parse_regex(value)?),
+            Some(other) => {
+                return decoding_error(format!("unsupported constraint '{}'", other));
+            }
+            None => return decoding_error("constraint names must have known text"),
+        }
+    }
+    Ok(definition)
+}
+
+fn parse_type_ref(element: &OwnedElement) -> IonResult<TypeRef> {
+    let mut nullable = false;
+    for annotation in element.annotations() {
+        match annotation.text() {
+            Some("nullable") => nullable = true,
+            _ => return decoding_error(format!("unsupported type reference: {}", element)),
+        }
+    }
+    let reference = match element.ion_type() {
+        IonType::Symbol if !element.is_null() => {
+            let name = element
+                .as_str()
+                .ok_or_else(|| decoding_error_raw("type names must have known text"))?;
+            TypeReference::Named(name.to_owned())
+        }
+        IonType::Struct if !element.is_null() => TypeReference::Inline(Box::new(parse_definition(
+            element,
+            DefinitionContext::Inline,
+        )?)),
+        _ => return decoding_error(format!("invalid type reference: {}", element)),
+    };
+    Ok(TypeRef {
+        reference,
+        nullable,
+    })
+}
+
+fn parse_fields(element: &OwnedElement) -> IonResult<Vec<(String, FieldDefinition)>> {
+    let fields = element.as_struct().ok_or_else(|| {
+        decoding_error_raw(format!("expected a struct of fields, found {}", element))
+    })?;
+    let mut definitions: Vec<(String, FieldDefinition)> = Vec::new();
+    for (name, value) in fields.iter() {
+        let name = name
+            .text()
+            .ok_or_else(|| decoding_error_raw("field names must have known text"))?;
+        if definitions.iter().any(|(existing, _)| existing == name) {
+            return decoding_error(format!("field '{}' is defined more than once", name));
+        }
+        let (type_ref, occurs) = match value.as_struct() {
+            Some(constraints) if value.annotations().next().is_none() => {
+                let occurs = match constraints.get("occurs") {
+                    Some(occurs) => parse_occurs(occurs)?,
+                    None => Occurs::OPTIONAL,
+                };
+                let definition = parse_definition(value, DefinitionContext::Field)?;
+                let type_ref = TypeRef {
+                    reference: TypeReference::Inline(Box::new(definition)),
+                    nullable: false,
+                };
+                (type_ref, occurs)
+            }
+            _ => (parse_type_ref(value)?, Occurs::OPTIONAL),
+        };
+        definitions.push((name.to_owned(), FieldDefinition { type_ref, occurs }));
+    }
+    // Struct fields are unordered; sorting them keeps the order of violations stable.
+    definitions.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(definitions)
+}
+
+fn parse_occurs(element: &OwnedElement) -> IonResult<Occurs> {
+    let invalid = || decoding_error_raw(format!("invalid occurs constraint: {}", element));
+    let is_range = element.annotations().next().and_then(|a| a.text()) == Some("range");
+    if let Some(range) = element.as_sequence().filter(|_| is_range) {
+        if range.len() != 2 || element.ion_type() != IonType::List {
+            return Err(invalid());
+        }
+        let bound = |index: usize, unbounded: &str| -> IonResult<Option<usize>> {
+            let bound = range.get(index).ok_or_else(invalid)?;
+            if bound.as_sym().and_then(|s| s.text()) == Some(unbounded) {
+                return Ok(None);
+            }
+            let value = bound.as_i64().ok_or_else(invalid)?;
+            usize::try_from(value).map(Some).map_err(|_| invalid())
+        };
+        let min = bound(0, "min")?.unwrap_or(0);
+        let max = bound(1, "max")?;
+        if matches!(max, Some(max) if max < min) {
+            return Err(invalid());
+        }
+        return Ok(Occurs { min, max });
+    }
+    match element.ion_type() {
+        IonType::Symbol => match element.as_str() {
+            Some("required") => Ok(Occurs::REQUIRED),
+            Some("optional") => Ok(Occurs::OPTIONAL),
+            _ => Err(invalid()),
+        },
+        IonType::Integer => {
+            let count = element.as_i64().ok_or_else(invalid)?;
+            let count = usize::try_from(count).map_err(|_| invalid())?;
+            Ok(Occurs {
+                min: count,
+                max: Some(count),
+            })
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_valid_values(element: &OwnedElement) -> IonResult<Vec<OwnedElement>> {
+    let values = match element.as_sequence() {
+        Some(values) if element.ion_type() == IonType::List => values,
+        _ => {
+            return decoding_error(format!("valid_values must be a list, found {}", element));
+        }
+    };
+    let mut valid_values = Vec::with_capacity(values.len());
+    for value in values.iter() {
+        if value.annotations().next().is_some() {
+            return decoding_error(format!("unsupported valid value: {}", value));
+        }
+        valid_values.push(value.clone());
+    }
+    Ok(valid_values)
+}
+
+fn parse_regex(element: &OwnedElement) -> IonResult<Regex> {
+    let pattern = match element.ion_type() {
+        IonType::String => element.as_str(),
+        _ => None,
+    }
+    .ok_or_else(|| decoding_error_raw(format!("regex must be a string, found {}", element)))?;
+    let mut builder = RegexBuilder::new(pattern);
+    for annotation in element.annotations() {
+        match annotation.text() {
+            Some("i") => builder.case_insensitive(true),
+            Some("m") => builder.multi_line(true),
+            _ => return decoding_error(format!("unsupported regex flag: {}", element)),
+        };
+    }
+    builder
+        .build()
+        .map_err(|e| decoding_error_raw(format!("invalid regex {:?}: {}", pattern, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(ion: &str) -> OwnedElement {
+        native_element_reader().read_one(ion.as_bytes()).unwrap()
+    }
+
+    fn violations(schema: &str, type_name: &str, ion: &str) -> Vec<String> {
+        let schema = Schema::from_ion(schema).unwrap();
+        schema
+            .validate(type_name, &element(ion))
+            .unwrap()
+            .iter()
+            .map(|v| format!("{} {}", v.constraint(), v))
+            .collect()
+    }
+
+    #[test]
+    fn core_types() {
+        let schema = Schema::default();
+        let check =
+            |name: &str, ion: &str| schema.validate(name, &element(ion)).unwrap().is_empty();
+        assert!(check("int", "5"));
+        assert!(!check("int", "5.0"));
+        assert!(!check("int", "null.int"));
+        assert!(check("$int", "null.int"));
+        assert!(check("$null", "null"));
+        assert!(check("text", "foo"));
+        assert!(check("text", "\"foo\""));
+        assert!(check("number", "1e0"));
+        assert!(check("lob", "{{\"hi\"}}"));
+        assert!(check("any", "[]"));
+        assert!(!check("any", "null"));
+        assert!(check("$any", "null.struct"));
+        assert!(!check("nothing", "1"));
+        assert!(schema.validate("widget", &element("1")).is_err());
+    }
+
+    #[test]
+    fn named_and_nullable_types() {
+        let schema = r#"
+            type::{name: positive, type: int, valid_values: [1, 2, 3]}
+            type::{name: wrapper, type: struct, fields: {value: nullable::positive, list: {type: list, element: positive}}}
+        "#;
+        assert!(violations(schema, "wrapper", "{value: 2, list: [1, 3]}").is_empty());
+        assert!(violations(schema, "wrapper", "{value: null.int}").is_empty());
+        assert_eq!(
+            violations(
+                schema,
+                "wrapper",
+                "{value: null.string, list: [1, 4, a::2]}"
+            ),
+            vec![
+                "valid_values (list 1): 4 is not one of the valid values",
+                "type (value): expected a value of type int, found null.string",
+            ]
+        );
+    }
+
+    #[test]
+    fn fields_and_occurs() {
+        let schema = r#"
+            type::{
+                name: person,
+                type: struct,
+                content: closed,
+                fields: {
+                    name: {type: string, occurs: required},
+                    tags: {type: symbol, occurs: range::[0, 2]},
+                    age: int,
+                },
+            }
+        "#;
+        assert!(violations(schema, "person", "{name: \"a\", tags: x, tags: y}").is_empty());
+        assert_eq!(
+            violations(
+                schema,
+                "person",
+                "{tags: x, tags: y, tags: z, age: 1e0, nickname: b}"
+            ),
+            vec![
+                "type (age): expected a value of type int, found 1e0",
+                "occurs (name): expected exactly 1 occurrence(s) of field 'name', found 0",
+                "occurs (tags): expected between 0 and 2 occurrence(s) of field 'tags', found 3",
+                "content (nickname): field 'nickname' is not allowed in closed content",
+            ]
+        );
+        assert_eq!(
+            violations(schema, "person", "5"),
+            vec!["type (): expected a value of type struct, found 5"]
+        );
+    }
+
+    #[test]
+    fn regex() {
+        let schema = r#"type::{name: code, type: text, regex: i::"^[a-z]{3}$"}"#;
+        assert!(violations(schema, "code", "ABC").is_empty());
+        assert!(violations(schema, "code", "\"abc\"").is_empty());
+        assert_eq!(
+            violations(schema, "code", "\"abcd\""),
+            vec![r#"regex (): "abcd" does not match the pattern /^[a-z]{3}$/"#]
+        );
+        assert!(Schema::from_ion(r#"type::{name: bad, regex: "("}"#).is_err());
+    }
+
+    #[test]
+    fn validate_stream() -> IonResult<()> {
+        let schema = Schema::from_ion("type::{name: item, element: int}")?;
+        let violations = schema.validate_all("item", "[1, 2] (3 x) {a: 4, b: 5.0}")?;
+        let paths: Vec<&[PathComponent]> = violations.iter().map(Violation::path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                &[PathComponent::Index(1), PathComponent::Index(1)][..],
+                &[
+                    PathComponent::Index(2),
+                    PathComponent::Field("b".to_string())
+                ][..],
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_schemas() {
+        for schema in [
+            "type::{type: int}",
+            "type::{name: a, type: b}",
+            "type::{name: a, type: a}",
+            "type::{name: a, type: b} type::{name: b, type: a}",
+            "type::{name: a, type: int} type::{name: a, type: int}",
+            "type::{name: int, type: string}",
+            "type::{name: a, annotations: [b]}",
+            "type::{name: a, occurs: required}",
+            "type::{name: a, fields: {b: {occurs: range::[2, 1]}}}",
+            "schema_header::{imports: [{id: \"other.isl\"}]}",
+        ] {
+            assert!(Schema::from_ion(schema).is_err(), "{}", schema);
+        }
+        // Types may refer to themselves through their fields or elements.
+        assert!(Schema::from_ion("type::{name: tree, element: tree}").is_ok());
+    }
+}
@@ -0,0 +1,107 @@
+//! A wrapper that carries a value's Ion annotations through serde.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+// The name that identifies an `Annotated` to the Ion serializer and deserializer, and the names
+// of its fields. Other serde formats see an ordinary struct with these two fields.
+pub(crate) const ANNOTATED_NAME: &str = "$ion_rs::Annotated";
+pub(crate) const ANNOTATIONS_FIELD: &str = "$annotations";
+pub(crate) const VALUE_FIELD: &str = "$value";
+const FIELDS: &[&str] = &[ANNOTATIONS_FIELD, VALUE_FIELD];
+
+/// A value and the Ion annotations that accompany it. When serialized using
+/// [Serializer](crate::serde::Serializer), the annotations are attached to the value; when
+/// deserialized using [Deserializer](crate::serde::Deserializer), the value's annotations are
+/// collected (rather than ignored).
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::serde::Annotated;
+/// use ion_rs::{ReaderBuilder, StreamReader};
+///
+/// let text = ion_rs::serde::to_string(&Annotated::new(["celsius"], 21))?;
+/// assert_eq!(text, "celsius::21");
+///
+/// let mut reader = ReaderBuilder::new().build("fahrenheit::70")?;
+/// reader.next()?;
+/// let reading: Annotated<i64> = reader.read_as()?;
+/// assert_eq!(reading, Annotated::new(["fahrenheit"], 70));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Annotated<T> {
+    pub annotations: Vec<String>,
+    pub value: T,
+}
+
+impl<T> Annotated<T> {
+    pub fn new<I, S>(annotations: I, value: T) -> Annotated<T>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Annotated {
+            annotations: annotations.into_iter().map(Into::into).collect(),
+            value,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Annotated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(ANNOTATED_NAME, 2)?;
+        state.serialize_field(ANNOTATIONS_FIELD, &self.annotations)?;
+        state.serialize_field(VALUE_FIELD, &self.value)?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Annotated<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(
+            ANNOTATED_NAME,
+            FIELDS,
+            AnnotatedVisitor {
+                marker: PhantomData,
+            },
+        )
+    }
+}
+
+struct AnnotatedVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for AnnotatedVisitor<T> {
+    type Value = Annotated<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an annotated value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Annotated<T>, A::Error> {
+        let mut annotations = None;
+        let mut value = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                ANNOTATIONS_FIELD => annotations = Some(map.next_value()?),
+                VALUE_FIELD => value = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Annotated {
+            annotations: annotations.unwrap_or_default(),
+            value: value.ok_or_else(|| de::Error::missing_field(VALUE_FIELD))?,
+        })
+    }
+}
@@ -0,0 +1,1161 @@
+//! A serde [Deserializer](serde::Deserializer) that reads values from a streaming Ion reader.
+
+use std::io;
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use serde::de::value::StrDeserializer;
+use serde::de::{self, Deserialize, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+use crate::raw_reader::RawReader;
+use crate::reader::{ReaderBuilder, StreamItem, UserReader};
+use crate::result::{decoding_error, IonError, IonResult};
+use crate::serde::annotated::{ANNOTATED_NAME, ANNOTATIONS_FIELD, VALUE_FIELD};
+use crate::serde::ser::{BLOB_NAME, CLOB_NAME, SEXP_NAME};
+use crate::stream_reader::StreamReader;
+use crate::IonType;
+
+/// Deserializes the value over which a [UserReader] is positioned into a Rust data structure.
+///
+/// Ion types map onto the serde data model as follows:
+/// * Nulls of any type are unit values (or `None`, when deserializing an `Option`).
+/// * Booleans, integers, and floats are their Rust equivalents. Integers that don't fit in an
+///   `i64` or `u64` are visited as `i128` or `u128`.
+/// * Strings and symbols are strings.
+/// * Decimals and timestamps are strings containing their Ion text representation.
+/// * Blobs and clobs are byte arrays. [Blob](crate::serde::ser::Blob) and
+///   [Clob](crate::serde::ser::Clob) only accept the corresponding lob type.
+/// * Lists and s-expressions are sequences. An [SExp](crate::serde::ser::SExp) only accepts an
+///   s-expression.
+/// * Structs are maps. Enum variants with data are structs with a single field whose name is
+///   the variant name; unit variants are strings or symbols.
+///
+/// Annotations are ignored unless the value is deserialized as an
+/// [Annotated](crate::serde::Annotated).
+///
+/// By default, values are coerced to the type that is being deserialized where possible: an
+/// integer can become a float, a symbol or a timestamp can become a string, and a string can
+/// become a unit enum variant. [DeserializerOptions] can disallow each of these coercions.
+///
+/// Text is passed to visitors as a `&str` that borrows from the reader's buffer, so a visitor
+/// that only needs to inspect a string, symbol, or field name does not allocate. That buffer does
+/// not outlive the value being read, so types that borrow from the input (like `&'de str` or
+/// `&'de [u8]`) can only be deserialized from in-memory input using [from_slice] or [from_str].
+///
+/// Deserializing a value consumes it; when deserialization succeeds, the reader remains
+/// positioned at the same depth so that [StreamReader::next] will advance to the following value.
+pub struct Deserializer<'de, 'r, R: RawReader> {
+    reader: &'r mut UserReader<R>,
+    options: DeserializerOptions,
+    // The in-memory input that `reader` is reading, if any. Text and bytes that appear in it
+    // verbatim are visited as borrowed from it.
+    input: Option<&'de [u8]>,
+}
+
+/// Controls which type coercions a [Deserializer] allows and how it treats unknown struct fields.
+/// Every coercion is allowed by default; [DeserializerOptions::strict] disallows all of them.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::serde::DeserializerOptions;
+/// use ion_rs::{ReaderBuilder, StreamReader};
+///
+/// let mut reader = ReaderBuilder::new().build("5")?;
+/// reader.next()?;
+/// assert!(reader.read_as_with_options::<f64>(DeserializerOptions::strict()).is_err());
+/// assert_eq!(reader.read_as::<f64>()?, 5.0);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DeserializerOptions {
+    int_to_float: bool,
+    symbol_to_string: bool,
+    string_to_enum: bool,
+    timestamp_to_string: bool,
+    deny_unknown_fields: bool,
+    human_readable: bool,
+}
+
+impl Default for DeserializerOptions {
+    fn default() -> Self {
+        DeserializerOptions {
+            int_to_float: true,
+            symbol_to_string: true,
+            string_to_enum: true,
+            timestamp_to_string: true,
+            deny_unknown_fields: false,
+            human_readable: true,
+        }
+    }
+}
+
+impl DeserializerOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns options that disallow every coercion, so that each Ion value can only be
+    /// deserialized as the Rust type that it most directly corresponds to. Unknown fields are
+    /// still ignored.
+    pub fn strict() -> Self {
+        DeserializerOptions {
+            int_to_float: false,
+            symbol_to_string: false,
+            string_to_enum: false,
+            timestamp_to_string: false,
+            deny_unknown_fields: false,
+            human_readable: true,
+        }
+    }
+
+    /// Sets whether an integer can be deserialized as an `f32` or `f64`.
+    pub fn with_int_to_float(mut self, allowed: bool) -> Self {
+        self.int_to_float = allowed;
+        self
+    }
+
+    /// Sets whether a symbol can be deserialized as a `String` or `&str`.
+    pub fn with_symbol_to_string(mut self, allowed: bool) -> Self {
+        self.symbol_to_string = allowed;
+        self
+    }
+
+    /// Sets whether a string can be deserialized as a unit enum variant. Symbols are always
+    /// accepted.
+    pub fn with_string_to_enum(mut self, allowed: bool) -> Self {
+        self.string_to_enum = allowed;
+        self
+    }
+
+    /// Sets whether a timestamp can be deserialized as a `String` containing its Ion text.
+    pub fn with_timestamp_to_string(mut self, allowed: bool) -> Self {
+        self.timestamp_to_string = allowed;
+        self
+    }
+
+    /// Sets whether a struct field that the Rust type being deserialized doesn't declare is an
+    /// error. By default, such fields are skipped without being materialized. This applies to
+    /// every struct, as if each had `#[serde(deny_unknown_fields)]`.
+    pub fn with_deny_unknown_fields(mut self, deny: bool) -> Self {
+        self.deny_unknown_fields = deny;
+        self
+    }
+
+    pub fn deny_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields
+    }
+
+    /// Sets what the deserializer's `is_human_readable` reports to the types being deserialized.
+    /// This should match the setting that the data was serialized with when it was produced by
+    /// a `Serialize` implementation that checks it. Decimals and timestamps can be deserialized
+    /// from native Ion values either way.
+    pub fn with_human_readable(mut self, human_readable: bool) -> Self {
+        self.human_readable = human_readable;
+        self
+    }
+
+    pub fn human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    pub fn int_to_float(&self) -> bool {
+        self.int_to_float
+    }
+
+    pub fn symbol_to_string(&self) -> bool {
+        self.symbol_to_string
+    }
+
+    pub fn string_to_enum(&self) -> bool {
+        self.string_to_enum
+    }
+
+    pub fn timestamp_to_string(&self) -> bool {
+        self.timestamp_to_string
+    }
+}
+
+impl<'de, 'r, R: RawReader> Deserializer<'de, 'r, R> {
+    /// Constructs a `Deserializer` that will read the value over which `reader` is positioned.
+    pub fn new(reader: &'r mut UserReader<R>) -> Self {
+        Deserializer {
+            reader,
+            options: DeserializerOptions::default(),
+            input: None,
+        }
+    }
+
+    // Constructs a `Deserializer` for a reader whose input is `input`.
+    fn for_input(reader: &'r mut UserReader<R>, input: &'de [u8]) -> Self {
+        Deserializer {
+            input: Some(input),
+            ..Deserializer::new(reader)
+        }
+    }
+
+    pub fn with_options(mut self, options: DeserializerOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn options(&self) -> &DeserializerOptions {
+        &self.options
+    }
+
+    // Visits the fields of the current struct. If `known_fields` is provided, fields with other
+    // names are reported as errors rather than passed to the visitor.
+    fn visit_struct<V: Visitor<'de>>(
+        &mut self,
+        known_fields: Option<&'static [&'static str]>,
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        self.reader.step_in()?;
+        let value = visitor.visit_map(StructAccess {
+            de: &mut *self,
+            known_fields,
+        })?;
+        self.reader.step_out()?;
+        Ok(value)
+    }
+
+    // Returns where the current value's encoding lies in the input, if the input is in memory.
+    fn location(&self) -> Option<ValueLocation<'de>> {
+        Some(ValueLocation {
+            input: self.input?,
+            start: self.reader.position().byte_offset(),
+            end: self.reader.value_end_offset(),
+        })
+    }
+
+    // Returns an error if the current value is only a float by way of a disallowed coercion.
+    fn check_float_coercion(&self) -> IonResult<()> {
+        if self.reader.current() == StreamItem::Value(IonType::Integer)
+            && !self.options.int_to_float
+        {
+            return decoding_error("expected a float, found an integer (int_to_float is disabled)");
+        }
+        Ok(())
+    }
+
+    // Returns an error if the current value is only a string by way of a disallowed coercion.
+    fn check_string_coercion(&self) -> IonResult<()> {
+        match self.reader.current() {
+            StreamItem::Value(IonType::Symbol) if !self.options.symbol_to_string => {
+                decoding_error("expected a string, found a symbol (symbol_to_string is disabled)")
+            }
+            StreamItem::Value(IonType::Timestamp) if !self.options.timestamp_to_string => {
+                decoding_error(
+                    "expected a string, found a timestamp (timestamp_to_string is disabled)",
+                )
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'de, 'a, 'r, R: RawReader> de::Deserializer<'de> for &'a mut Deserializer<'de, 'r, R> {
+    type Error = IonError;
+
+    fn is_human_readable(&self) -> bool {
+        self.options.human_readable
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        let ion_type = match self.reader.current() {
+            StreamItem::Value(ion_type) => ion_type,
+            StreamItem::Null(_) => return visitor.visit_unit(),
+            StreamItem::Nothing => {
+                return decoding_error(
+                    "cannot deserialize; the reader is not positioned on a value",
+                )
+            }
+        };
+        use IonType::*;
+        match ion_type {
+            Null => unreachable!("non-null value had IonType::Null"),
+            Boolean => visitor.visit_bool(self.reader.read_bool()?),
+            Integer => match self.reader.read_integer()? {
+                crate::Integer::I64(value) => visitor.visit_i64(value),
+                crate::Integer::BigInt(value) => {
+                    if let Some(value) = value.to_u64() {
+                        visitor.visit_u64(value)
+                    } else if let Some(value) = value.to_i128() {
+                        visitor.visit_i128(value)
+                    } else if let Some(value) = value.to_u128() {
+                        visitor.visit_u128(value)
+                    } else {
+                        decoding_error(format!("integer {} is too large to deserialize", value))
+                    }
+                }
+            },
+            Float => visitor.visit_f64(self.reader.read_f64()?),
+            Decimal => visitor.visit_string(self.reader.read_decimal()?.to_string()),
+            Timestamp => visitor.visit_string(self.reader.read_timestamp()?.to_string()),
+            Symbol => {
+                let location = self.location();
+                let text = self.reader.read_symbol()?;
+                match location.and_then(|location| location.find_str(text.as_ref())) {
+                    Some(text) => visitor.visit_borrowed_str(text),
+                    None => visitor.visit_str(text.as_ref()),
+                }
+            }
+            String => {
+                let location = self.location();
+                let text = self.reader.read_str()?;
+                match location.and_then(|location| location.find_str(text)) {
+                    Some(text) => visitor.visit_borrowed_str(text),
+                    None => visitor.visit_str(text),
+                }
+            }
+            Clob | Blob => {
+                let location = self.location();
+                let bytes = if ion_type == Clob {
+                    self.reader.read_clob()?
+                } else {
+                    self.reader.read_blob()?
+                };
+                match location.and_then(|location| location.find(&bytes)) {
+                    Some(bytes) => visitor.visit_borrowed_bytes(bytes),
+                    None => visitor.visit_byte_buf(bytes),
+                }
+            }
+            List | SExpression => {
+                self.reader.step_in()?;
+                let value = visitor.visit_seq(SequenceAccess { de: &mut *self })?;
+                self.reader.step_out()?;
+                Ok(value)
+            }
+            Struct => self.visit_struct(None, visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        match self.reader.current() {
+            StreamItem::Null(_) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        let required_type = match name {
+            BLOB_NAME => Some(IonType::Blob),
+            CLOB_NAME => Some(IonType::Clob),
+            SEXP_NAME => Some(IonType::SExpression),
+            _ => None,
+        };
+        if let Some(required_type) = required_type {
+            if self.reader.ion_type() != Some(required_type) {
+                return decoding_error(format!(
+                    "expected a {:?}, found {:?}",
+                    required_type,
+                    self.reader.ion_type()
+                ));
+            }
+        }
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        match self.reader.current() {
+            StreamItem::Value(IonType::String) if !self.options.string_to_enum => decoding_error(
+                "expected an enum variant, found a string (string_to_enum is disabled)",
+            ),
+            StreamItem::Value(IonType::String) => {
+                let variant = self.reader.read_str()?;
+                visitor.visit_enum(StrDeserializer::<IonError>::new(variant))
+            }
+            StreamItem::Value(IonType::Symbol) => {
+                let variant = self.reader.read_symbol()?;
+                visitor.visit_enum(StrDeserializer::<IonError>::new(variant.as_ref()))
+            }
+            StreamItem::Value(IonType::Struct) => {
+                self.reader.step_in()?;
+                if self.reader.next()? == StreamItem::Nothing {
+                    return decoding_error("expected an enum variant, found an empty struct");
+                }
+                let value = visitor.visit_enum(VariantAccess { de: &mut *self })?;
+                if self.reader.next()? != StreamItem::Nothing {
+                    return decoding_error("expected a struct with a single enum variant field");
+                }
+                self.reader.step_out()?;
+                Ok(value)
+            }
+            other => decoding_error(format!(
+                "expected an enum variant (a string, symbol, or struct), found {:?}",
+                other
+            )),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        if name != ANNOTATED_NAME {
+            if self.options.deny_unknown_fields
+                && self.reader.current() == StreamItem::Value(IonType::Struct)
+            {
+                return self.visit_struct(Some(fields), visitor);
+            }
+            return self.deserialize_any(visitor);
+        }
+        let annotations = self
+            .reader
+            .annotations()
+            .map(|annotation| annotation.map(|text| text.as_ref().to_string()))
+            .collect::<IonResult<Vec<String>>>()?;
+        visitor.visit_map(AnnotatedAccess {
+            de: self,
+            annotations: Some(annotations),
+            field: AnnotatedField::Annotations,
+        })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        // Advancing the reader will skip over the value without materializing it.
+        visitor.visit_unit()
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        self.check_float_coercion()?;
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        self.check_float_coercion()?;
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        self.check_string_coercion()?;
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        self.check_string_coercion()?;
+        self.deserialize_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 char bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map identifier
+    }
+}
+
+/// Deserializes the single top-level value in `input`. Returns an error if `input` is empty or
+/// contains more than one top-level value.
+///
+/// The input is read incrementally, so only the value that is being deserialized needs to fit in
+/// memory.
+pub fn from_reader<T, I>(input: I) -> IonResult<T>
+where
+    T: DeserializeOwned,
+    I: io::Read,
+{
+    from_reader_with_options(input, DeserializerOptions::default())
+}
+
+/// Like [from_reader], but using the specified options to control which type coercions are
+/// allowed.
+pub fn from_reader_with_options<T, I>(input: I, options: DeserializerOptions) -> IonResult<T>
+where
+    T: DeserializeOwned,
+    I: io::Read,
+{
+    let mut reader = ReaderBuilder::new().build_from_read(input)?;
+    if reader.next()? == StreamItem::Nothing {
+        return decoding_error("expected a value to deserialize, but the input was empty");
+    }
+    let value = reader.read_as_with_options(options)?;
+    if reader.next()? != StreamItem::Nothing {
+        return decoding_error("expected a single value, but the input contained more than one");
+    }
+    Ok(value)
+}
+
+/// Deserializes the single top-level value in `input`, which may be Ion text or binary Ion.
+/// Returns an error if `input` is empty or contains more than one top-level value.
+///
+/// Unlike [from_reader], this can deserialize types that borrow from the input, like `&str` and
+/// `&[u8]`. Strings, symbols, blobs, and clobs can be borrowed when their contents appear in the
+/// input as-is: in binary Ion, strings, blobs, and clobs; in Ion text, strings, symbols, and
+/// `{{"clobs"}}` that have no annotations and no escape sequences. Deserializing anything else as
+/// a borrowed type is an error. Struct field names are never borrowed, since binary Ion encodes
+/// them as symbol IDs and the text reader does not track where they appear.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Book<'a> {
+///     title: &'a str,
+///     #[serde(borrow)]
+///     tags: Vec<&'a str>,
+/// }
+///
+/// let book: Book = ion_rs::serde::from_str(r#"{title: "Middlemarch", tags: [novel, classic]}"#)?;
+/// assert_eq!(book.title, "Middlemarch");
+/// assert_eq!(book.tags, ["novel", "classic"]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+pub fn from_slice<'de, T: Deserialize<'de>>(input: &'de [u8]) -> IonResult<T> {
+    from_slice_with_options(input, DeserializerOptions::default())
+}
+
+/// Like [from_slice], but using the specified options to control which type coercions are
+/// allowed.
+pub fn from_slice_with_options<'de, T: Deserialize<'de>>(
+    input: &'de [u8],
+    options: DeserializerOptions,
+) -> IonResult<T> {
+    let mut reader = ReaderBuilder::new().build(input)?;
+    if reader.next()? == StreamItem::Nothing {
+        return decoding_error("expected a value to deserialize, but the input was empty");
+    }
+    let mut deserializer = Deserializer::for_input(&mut reader, input).with_options(options);
+    let value = T::deserialize(&mut deserializer).map_err(|error| {
+        let position = deserializer.reader.position();
+        error.with_position(position)
+    })?;
+    if reader.next()? != StreamItem::Nothing {
+        return decoding_error("expected a single value, but the input contained more than one");
+    }
+    Ok(value)
+}
+
+/// Deserializes the single top-level value in the Ion text `input`. See [from_slice].
+pub fn from_str<'de, T: Deserialize<'de>>(input: &'de str) -> IonResult<T> {
+    from_slice(input.as_bytes())
+}
+
+// Where the current value's encoding lies in an in-memory input.
+struct ValueLocation<'de> {
+    input: &'de [u8],
+    // The offset at which the value's encoding begins, including any annotations.
+    start: usize,
+    // The offset that follows the value's encoding, if the reader knows it.
+    end: Option<usize>,
+}
+
+impl<'de> ValueLocation<'de> {
+    // The delimiters that can precede the contents of an Ion text value that are written as-is.
+    // Identifiers have none.
+    const OPENING_DELIMITERS: [&'static [u8]; 5] = [b"'''", b"\"", b"'", b"{{\"", b""];
+
+    // Returns a slice of the input that holds `contents`, if there is one where the value's
+    // contents would be. In binary Ion, a value's contents end its encoding; in Ion text, they
+    // begin it (after an opening delimiter). Any slice with the same bytes can stand in for the
+    // contents, so comparing them is enough to know whether the slice can be borrowed.
+    fn find(&self, contents: &[u8]) -> Option<&'de [u8]> {
+        let binary_start = self.end.and_then(|end| end.checked_sub(contents.len()));
+        let text = self.input.get(self.start..).unwrap_or_default();
+        let text_starts = Self::OPENING_DELIMITERS
+            .iter()
+            .filter(|delimiter| text.starts_with(delimiter))
+            .map(|delimiter| self.start + delimiter.len());
+        binary_start
+            .into_iter()
+            .chain(text_starts)
+            .filter_map(|start| self.input.get(start..start + contents.len()))
+            .find(|candidate| *candidate == contents)
+    }
+
+    fn find_str(&self, contents: &str) -> Option<&'de str> {
+        // The slice is identical to `contents`, so it is valid UTF-8.
+        self.find(contents.as_bytes())
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+    }
+}
+
+/// An iterator that deserializes each of the remaining top-level values read by a [UserReader].
+/// Values are read one at a time, so a stream of any length can be processed as long as each of
+/// its values fits in memory.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::serde::StreamDeserializer;
+///
+/// let input: &[u8] = b"{x: 1, y: 2} {x: 3, y: 4}";
+/// let points: Vec<std::collections::HashMap<String, i64>> =
+///     StreamDeserializer::from_reader(input)?.collect::<Result<_, _>>()?;
+/// assert_eq!(points[1]["x"], 3);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+///
+/// If a value cannot be read or deserialized, the iterator yields the error and then ends.
+pub struct StreamDeserializer<R: RawReader, T> {
+    reader: UserReader<R>,
+    options: DeserializerOptions,
+    is_done: bool,
+    // The type of value being deserialized; the iterator does not own any `T`s itself.
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<R: RawReader, T: DeserializeOwned> StreamDeserializer<R, T> {
+    /// Constructs a `StreamDeserializer` that deserializes the values that follow the reader's
+    /// current position at its current depth.
+    pub fn new(reader: UserReader<R>) -> Self {
+        StreamDeserializer {
+            reader,
+            options: DeserializerOptions::default(),
+            is_done: false,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn with_options(mut self, options: DeserializerOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the underlying reader, which is positioned over the most recently deserialized
+    /// value.
+    pub fn into_inner(self) -> UserReader<R> {
+        self.reader
+    }
+}
+
+impl<'a, T: DeserializeOwned> StreamDeserializer<Box<dyn RawReader + 'a>, T> {
+    /// Constructs a `StreamDeserializer` that reads the top-level values in `input` using a
+    /// reader with the default settings. See [ReaderBuilder::build_from_read].
+    pub fn from_reader<I: io::Read + 'a>(input: I) -> IonResult<Self> {
+        Ok(StreamDeserializer::new(
+            ReaderBuilder::new().build_from_read(input)?,
+        ))
+    }
+}
+
+impl<R: RawReader, T: DeserializeOwned> Iterator for StreamDeserializer<R, T> {
+    type Item = IonResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        let result = match self.reader.next() {
+            Ok(StreamItem::Nothing) => {
+                self.is_done = true;
+                return None;
+            }
+            Ok(_) => self.reader.read_as_with_options(self.options),
+            Err(error) => Err(error),
+        };
+        self.is_done = result.is_err();
+        Some(result)
+    }
+}
+
+/// Provides the values in a list or s-expression to a [Visitor].
+struct SequenceAccess<'a, 'de, 'r, R: RawReader> {
+    de: &'a mut Deserializer<'de, 'r, R>,
+}
+
+impl<'de, 'a, 'r, R: RawReader> de::SeqAccess<'de> for SequenceAccess<'a, 'de, 'r, R> {
+    type Error = IonError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> IonResult<Option<T::Value>> {
+        if self.de.reader.next()? == StreamItem::Nothing {
+            return Ok(None);
+        }
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+/// Provides the fields in a struct to a [Visitor].
+struct StructAccess<'a, 'de, 'r, R: RawReader> {
+    de: &'a mut Deserializer<'de, 'r, R>,
+    // If unknown fields are being denied, the names of the fields that the visitor expects.
+    known_fields: Option<&'static [&'static str]>,
+}
+
+impl<'de, 'a, 'r, R: RawReader> de::MapAccess<'de> for StructAccess<'a, 'de, 'r, R> {
+    type Error = IonError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> IonResult<Option<K::Value>> {
+        if self.de.reader.next()? == StreamItem::Nothing {
+            return Ok(None);
+        }
+        let field_name = self.de.reader.field_name()?;
+        if let Some(known_fields) = self.known_fields {
+            if !known_fields.contains(&field_name.as_ref()) {
+                return Err(de::Error::unknown_field(field_name.as_ref(), known_fields));
+            }
+        }
+        let key = StrDeserializer::<IonError>::new(field_name.as_ref());
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> IonResult<V::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+/// Provides a value and its annotations to the [Visitor] of an [Annotated](crate::serde::Annotated)
+/// as if they were the fields of a struct.
+struct AnnotatedAccess<'a, 'de, 'r, R: RawReader> {
+    de: &'a mut Deserializer<'de, 'r, R>,
+    annotations: Option<Vec<String>>,
+    // The next field to be visited.
+    field: AnnotatedField,
+}
+
+enum AnnotatedField {
+    Annotations,
+    Value,
+    Done,
+}
+
+impl<'de, 'a, 'r, R: RawReader> de::MapAccess<'de> for AnnotatedAccess<'a, 'de, 'r, R> {
+    type Error = IonError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> IonResult<Option<K::Value>> {
+        let key = match self.field {
+            AnnotatedField::Annotations => ANNOTATIONS_FIELD,
+            AnnotatedField::Value => VALUE_FIELD,
+            AnnotatedField::Done => return Ok(None),
+        };
+        seed.deserialize(StrDeserializer::<IonError>::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> IonResult<V::Value> {
+        match self.field {
+            AnnotatedField::Annotations => {
+                self.field = AnnotatedField::Value;
+                let annotations = self.annotations.take().unwrap_or_default();
+                seed.deserialize(annotations.into_deserializer())
+            }
+            AnnotatedField::Value => {
+                self.field = AnnotatedField::Done;
+                seed.deserialize(&mut *self.de)
+            }
+            AnnotatedField::Done => decoding_error("an annotated value has no more fields"),
+        }
+    }
+}
+
+/// Provides an enum variant that was encoded as a single-field struct to a [Visitor].
+struct VariantAccess<'a, 'de, 'r, R: RawReader> {
+    de: &'a mut Deserializer<'de, 'r, R>,
+}
+
+impl<'de, 'a, 'r, R: RawReader> de::EnumAccess<'de> for VariantAccess<'a, 'de, 'r, R> {
+    type Error = IonError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> IonResult<(V::Value, Self)> {
+        let field_name = self.de.reader.field_name()?;
+        let variant = StrDeserializer::<IonError>::new(field_name.as_ref());
+        let value = seed.deserialize(variant)?;
+        Ok((value, self))
+    }
+}
+
+impl<'de, 'a, 'r, R: RawReader> de::VariantAccess<'de> for VariantAccess<'a, 'de, 'r, R> {
+    type Error = IonError;
+
+    fn unit_variant(self) -> IonResult<()> {
+        // The field's value carries no information; it is conventionally `null`.
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> IonResult<T::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> IonResult<V::Value> {
+        de::Deserializer::deserialize_seq(&mut *self.de, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        if self.de.options.deny_unknown_fields
+            && self.de.reader.current() == StreamItem::Value(IonType::Struct)
+        {
+            return self.de.visit_struct(Some(fields), visitor);
+        }
+        de::Deserializer::deserialize_map(&mut *self.de, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::{
+        from_reader, from_reader_with_options, from_slice, from_str, DeserializerOptions,
+        StreamDeserializer,
+    };
+    use crate::result::IonResult;
+    use crate::serde::Annotated;
+    use crate::{IonType, ReaderBuilder, StreamItem, StreamReader};
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Order {
+        id: u64,
+        customer: String,
+        items: Vec<Item>,
+        notes: Option<String>,
+        status: Status,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        sku: String,
+        quantity: i32,
+        price: f64,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    enum Status {
+        Pending,
+        Shipped { carrier: String },
+        Cancelled(String),
+    }
+
+    #[test]
+    fn read_as_struct() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build(
+            r#"
+            {
+                id: 42,
+                customer: alice,
+                items: [{sku: "A1", quantity: 2, price: 1.5e0}],
+                notes: null,
+                status: {Shipped: {carrier: "UPS"}},
+                ignored: {deeply: [nested, (value)]},
+            }
+            "#,
+        )?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Struct));
+        let order: Order = reader.read_as()?;
+        assert_eq!(
+            order,
+            Order {
+                id: 42,
+                customer: "alice".to_string(),
+                items: vec![Item {
+                    sku: "A1".to_string(),
+                    quantity: 2,
+                    price: 1.5,
+                }],
+                notes: None,
+                status: Status::Shipped {
+                    carrier: "UPS".to_string()
+                },
+            }
+        );
+        assert_eq!(reader.next()?, StreamItem::Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn read_as_selected_values() -> IonResult<()> {
+        // Skim the stream, only decoding the values that are annotated with `status`.
+        let mut reader = ReaderBuilder::new()
+            .build(r#"1 status::Pending "skip me" status::{Cancelled: "out of stock"} [1, 2]"#)?;
+        let mut statuses = Vec::new();
+        while reader.next()? != StreamItem::Nothing {
+            if reader.has_annotations() {
+                statuses.push(reader.read_as::<Status>()?);
+            }
+        }
+        assert_eq!(
+            statuses,
+            vec![
+                Status::Pending,
+                Status::Cancelled("out of stock".to_string())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn read_as_scalars() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build(
+            r#"true 18446744073709551615 1.5 2022-03-01T "text" {{aGVsbG8=}} (1 2) {a: 1, b: 2}"#,
+        )?;
+        reader.next()?;
+        assert!(reader.read_as::<bool>()?);
+        reader.next()?;
+        assert_eq!(reader.read_as::<u64>()?, u64::MAX);
+        reader.next()?;
+        assert_eq!(reader.read_as::<String>()?, "15d-1");
+        reader.next()?;
+        assert_eq!(reader.read_as::<String>()?, "2022-03-01T");
+        reader.next()?;
+        assert_eq!(reader.read_as::<String>()?, "text");
+        reader.next()?;
+        assert_eq!(reader.read_as::<serde_bytes_like::Bytes>()?.0, b"hello");
+        reader.next()?;
+        assert_eq!(reader.read_as::<(i8, i8)>()?, (1, 2));
+        reader.next()?;
+        let map: HashMap<String, i64> = reader.read_as()?;
+        assert_eq!(
+            map,
+            HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn read_as_annotated() -> IonResult<()> {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Reading {
+            unit: Annotated<f64>,
+            tags: Vec<Annotated<String>>,
+        }
+        let mut reader =
+            ReaderBuilder::new().build(r#"{unit: celsius::2.15e1, tags: [a::b::"x", "y"]}"#)?;
+        reader.next()?;
+        let reading: Reading = reader.read_as()?;
+        assert_eq!(reading.unit, Annotated::new(["celsius"], 21.5));
+        assert_eq!(
+            reading.tags,
+            vec![
+                Annotated::new(["a", "b"], "x".to_string()),
+                Annotated::new(Vec::<String>::new(), "y".to_string())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_reader_single_value() -> IonResult<()> {
+        let item: Item = from_reader(&br#"{sku: "A1", quantity: 2, price: 1.5e0}"#[..])?;
+        assert_eq!(item.quantity, 2);
+        assert!(from_reader::<Item, _>(&b""[..]).is_err());
+        assert!(from_reader::<i64, _>(&b"1 2"[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn stream_deserializer() -> IonResult<()> {
+        let input: &[u8] = br#"Pending {Cancelled: "late"} {Shipped: 5} Pending"#;
+        let mut statuses = StreamDeserializer::<_, Status>::from_reader(input)?;
+        assert_eq!(statuses.next().unwrap()?, Status::Pending);
+        assert_eq!(
+            statuses.next().unwrap()?,
+            Status::Cancelled("late".to_string())
+        );
+        assert!(statuses.next().unwrap().is_err());
+        assert!(statuses.next().is_none());
+
+        let reader = ReaderBuilder::new().build("1 2 3")?;
+        let values: Vec<i64> = StreamDeserializer::new(reader).collect::<IonResult<_>>()?;
+        assert_eq!(values, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn read_as_error_has_position() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("{id: 1, customer: 2}")?;
+        reader.next()?;
+        let error = reader.read_as::<Order>().unwrap_err();
+        let position = error.position().expect("error did not have a position");
+        assert_eq!(position.line_column(), Some((1, 19)));
+        Ok(())
+    }
+
+    #[test]
+    fn coercions() -> IonResult<()> {
+        #[derive(Deserialize, Debug, PartialEq)]
+        enum Color {
+            Red,
+        }
+        let strict = DeserializerOptions::strict();
+        let read = |text: &str| -> IonResult<_> {
+            let mut reader = ReaderBuilder::new().build(text.to_string())?;
+            reader.next()?;
+            Ok(reader)
+        };
+
+        assert_eq!(read("5")?.read_as::<f64>()?, 5.0);
+        assert!(read("5")?.read_as_with_options::<f64>(strict).is_err());
+        assert_eq!(read("5e0")?.read_as_with_options::<f64>(strict)?, 5.0);
+
+        assert_eq!(read("abc")?.read_as::<String>()?, "abc");
+        assert!(read("abc")?.read_as_with_options::<String>(strict).is_err());
+        let options = strict.with_symbol_to_string(true);
+        assert_eq!(read("abc")?.read_as_with_options::<String>(options)?, "abc");
+
+        assert_eq!(read("2021T")?.read_as::<String>()?, "2021T");
+        assert!(read("2021T")?
+            .read_as_with_options::<String>(strict)
+            .is_err());
+
+        assert_eq!(read("\"Red\"")?.read_as::<Color>()?, Color::Red);
+        assert!(read("\"Red\"")?
+            .read_as_with_options::<Color>(strict)
+            .is_err());
+        assert_eq!(
+            read("Red")?.read_as_with_options::<Color>(strict)?,
+            Color::Red
+        );
+
+        // Options apply to nested values and to every value in a stream.
+        let input: &[u8] = b"[1.5e0, 2]";
+        assert!(from_reader_with_options::<Vec<f64>, _>(input, strict).is_err());
+        let input: &[u8] = b"1e0 2";
+        let values: Vec<IonResult<f64>> = StreamDeserializer::from_reader(input)?
+            .with_options(strict)
+            .collect();
+        assert!(values[0].is_ok() && values[1].is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_fields() -> IonResult<()> {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Point {
+            x: i64,
+            y: i64,
+        }
+        #[derive(Deserialize, Debug, PartialEq)]
+        enum Shape {
+            Circle { radius: i64 },
+        }
+        let read = |text: &str| -> IonResult<_> {
+            let mut reader = ReaderBuilder::new().build(text.to_string())?;
+            reader.next()?;
+            Ok(reader)
+        };
+        let text = "{x: 1, extra: {deeply: [nested, {values: 1}]}, y: 2}";
+        assert_eq!(read(text)?.read_as::<Point>()?, Point { x: 1, y: 2 });
+        let deny = DeserializerOptions::new().with_deny_unknown_fields(true);
+        let error = read(text)?.read_as_with_options::<Point>(deny).unwrap_err();
+        assert!(error.to_string().contains("unknown field `extra`"));
+        assert_eq!(
+            read("{x: 1, y: 2}")?.read_as_with_options::<Point>(deny)?,
+            Point { x: 1, y: 2 }
+        );
+        assert!(read("{Circle: {radius: 1, color: red}}")?
+            .read_as_with_options::<Shape>(deny)
+            .is_err());
+        // Maps have no fixed set of fields.
+        let map: HashMap<String, i64> = read("{a: 1, b: 2}")?.read_as_with_options(deny)?;
+        assert_eq!(map.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn borrowed_from_text() -> IonResult<()> {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Book<'a> {
+            title: &'a str,
+            #[serde(borrow)]
+            tags: Vec<&'a str>,
+            summary: &'a str,
+            isbn: &'a [u8],
+        }
+        let book: Book = from_str(
+            r#"{title: "Middlemarch", tags: [novel, 'classic'], summary: '''A study''', isbn: {{"0141439548"}}}"#,
+        )?;
+        assert_eq!(
+            book,
+            Book {
+                title: "Middlemarch",
+                tags: vec!["novel", "classic"],
+                summary: "A study",
+                isbn: b"0141439548",
+            }
+        );
+        let map: HashMap<String, &str> = from_slice(br#"{a: "x", b: "", c: 'y'}"#)?;
+        assert_eq!(map["a"], "x");
+        assert_eq!(map["b"], "");
+        assert_eq!(map["c"], "y");
+        assert_eq!(from_str::<&str>(" \"top-level\" ")?, "top-level");
+        // Owned types are still copied from anywhere.
+        assert_eq!(from_str::<String>(r#"tag::"a\tb""#)?, "a\tb");
+
+        // Text that doesn't appear in the input as-is can't be borrowed.
+        assert!(from_str::<&str>(r#""a\tb""#).is_err());
+        assert!(from_str::<&str>(r#"tag::"ab""#).is_err());
+        assert!(from_str::<&str>(r#"'''a''' '''b'''"#).is_err());
+        assert!(from_str::<&[u8]>(r#"{{aGVsbG8=}}"#).is_err());
+        assert!(from_str::<HashMap<&str, i64>>(r#"{a: 1}"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn borrowed_from_binary() -> IonResult<()> {
+        use crate::serde::ser::{Blob, Clob};
+        use crate::serde::to_vec;
+
+        let input = to_vec(&(
+            "hello",
+            Blob(vec![1, 2, 3]),
+            Clob(b"clob".to_vec()),
+            vec!["nested"],
+        ))?;
+        let (text, blob, clob, nested): (&str, &[u8], &[u8], Vec<&str>) = from_slice(&input)?;
+        assert_eq!(text, "hello");
+        assert_eq!(blob, [1, 2, 3]);
+        assert_eq!(clob, b"clob");
+        assert_eq!(nested, ["nested"]);
+        // Symbol values are symbol IDs in binary Ion.
+        let input = to_vec(&crate::serde::ser::Symbol("hello".to_string()))?;
+        assert!(from_slice::<&str>(&input).is_err());
+        assert_eq!(from_slice::<String>(&input)?, "hello");
+        Ok(())
+    }
+
+    // A minimal stand-in for `serde_bytes::ByteBuf`, which deserializes from byte arrays.
+    mod serde_bytes_like {
+        use serde::de::{Deserialize, Deserializer, Visitor};
+        use std::fmt;
+
+        pub struct Bytes(pub Vec<u8>);
+
+        impl<'de> Deserialize<'de> for Bytes {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct BytesVisitor;
+                impl<'de> Visitor<'de> for BytesVisitor {
+                    type Value = Bytes;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "a byte array")
+                    }
+
+                    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Bytes, E> {
+                        Ok(Bytes(v))
+                    }
+                }
+                deserializer.deserialize_byte_buf(BytesVisitor)
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Support for converting between Ion and Rust data structures using [serde].
+//!
+//! This module is only available when the `serde` feature is enabled.
+//!
+//! Rust values can be converted into Ion using [to_element], encoded as binary Ion using
+//! [to_vec], or encoded as Ion text using [to_string] and [to_string_pretty]. To serialize a
+//! large value without building it in memory first, write it directly to an Ion
+//! [Writer](crate::Writer) using [to_writer].
+//!
+//! Values can be decoded directly from a streaming reader, allowing applications to skim a
+//! stream using the [StreamReader](crate::StreamReader) API and fully decode only the values
+//! they're interested in:
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() -> ion_rs::result::IonResult<()> {
+//! use ion_rs::{ReaderBuilder, StreamReader};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize, Debug, PartialEq)]
+//! struct Point {
+//!     x: i64,
+//!     y: i64,
+//! }
+//!
+//! let mut reader = ReaderBuilder::new().build("{x: 1, y: 2}")?;
+//! reader.next()?;
+//! let point: Point = reader.read_as()?;
+//! assert_eq!(point, Point { x: 1, y: 2 });
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+//! To deserialize every value in a stream, use a [StreamDeserializer]. To deserialize a stream
+//! that holds a single value, use [from_reader], or [from_slice] and [from_str] to borrow strings
+//! and bytes from in-memory input.
+
+use std::fmt::Display;
+
+use crate::result::{decoding_error_raw, illegal_operation_raw, IonError};
+
+mod annotated;
+pub mod de;
+pub mod ser;
+pub mod transcoder;
+mod types;
+
+pub use annotated::Annotated;
+pub use de::{
+    from_reader, from_reader_with_options, from_slice, from_slice_with_options, from_str,
+    Deserializer, DeserializerOptions, StreamDeserializer,
+};
+pub use ser::{
+    to_element, to_string, to_string_pretty, to_vec, FieldOrder, NoneEncoding, Serializer,
+    SerializerOptions,
+};
+pub use transcoder::{to_writer, WriterSerializer};
+
+impl serde::de::Error for IonError {
+    fn custom<T: Display>(msg: T) -> Self {
+        decoding_error_raw(msg.to_string())
+    }
+}
+
+impl serde::ser::Error for IonError {
+    fn custom<T: Display>(msg: T) -> Self {
+        illegal_operation_raw(msg.to_string())
+    }
+}
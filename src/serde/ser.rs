@@ -0,0 +1,1356 @@
+//! A serde [Serializer](serde::Serializer) that converts Rust data structures into Ion values.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{self, Serialize};
+
+use crate::binary::binary_writer::BinaryWriterBuilder;
+use crate::result::{illegal_operation, IonError, IonResult};
+use crate::serde::annotated::{ANNOTATED_NAME, ANNOTATIONS_FIELD, VALUE_FIELD};
+use crate::serde::transcoder::WriterSerializer;
+use crate::serde::types::{
+    decimal_from_fields, parse_decimal_text, parse_timestamp_text, timestamp_from_fields,
+    DECIMAL_NAME, TIMESTAMP_NAME,
+};
+use crate::text::text_writer::TextWriterBuilder;
+use crate::value::native_writer::write_element;
+use crate::value::owned::{text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedValue};
+use crate::value::{Element, Sequence, SymbolToken};
+use crate::writer::Writer;
+use crate::{Integer, IonType};
+
+/// Serializes `value` as a single binary Ion value. The returned bytes are a complete Ion stream:
+/// an Ion version marker, a local symbol table declaring any symbols that the value uses, and
+/// the value itself.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{ReaderBuilder, StreamReader};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Point {
+///     x: i64,
+///     y: i64,
+/// }
+///
+/// let bytes = ion_rs::serde::to_vec(&Point { x: 1, y: 2 })?;
+/// let mut reader = ReaderBuilder::new().build(bytes)?;
+/// reader.next()?;
+/// assert_eq!(reader.read_as::<Point>()?, Point { x: 1, y: 2 });
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> IonResult<Vec<u8>> {
+    Serializer::new().to_vec(value)
+}
+
+/// Serializes `value` as compact Ion text, with the entire value on a single line.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// let text = ion_rs::serde::to_string(&vec![(1, "one"), (2, "two")])?;
+/// assert_eq!(text, r#"[[1, "one"], [2, "two"]]"#);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> IonResult<String> {
+    Serializer::new().to_string(value)
+}
+
+/// Serializes `value` as Ion text that is indented over multiple lines to make it easier for
+/// people to read.
+pub fn to_string_pretty<T: Serialize + ?Sized>(value: &T) -> IonResult<String> {
+    Serializer::new().to_string_pretty(value)
+}
+
+/// Converts `value` into an [OwnedElement].
+pub fn to_element<T: Serialize + ?Sized>(value: &T) -> IonResult<OwnedElement> {
+    Serializer::new().to_element(value)
+}
+
+/// The order in which the fields of a serialized struct or map are written.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum FieldOrder {
+    /// Fields are written in the order in which they were serialized: declaration order for
+    /// structs, and iteration order for maps.
+    #[default]
+    Insertion,
+    /// Fields are sorted by name. Fields with the same name keep their insertion order.
+    Sorted,
+}
+
+/// How `None` values are serialized.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum NoneEncoding {
+    /// `None` is written as `null`, like unit.
+    #[default]
+    Null,
+    /// Struct and map fields whose value is `None` are left out. `None` values elsewhere, such
+    /// as in a list, are written as `null`.
+    OmitFields,
+}
+
+/// Configures how a [Serializer] maps Rust values onto Ion.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SerializerOptions {
+    field_order: FieldOrder,
+    none_encoding: NoneEncoding,
+    human_readable: bool,
+}
+
+impl Default for SerializerOptions {
+    fn default() -> Self {
+        SerializerOptions {
+            field_order: FieldOrder::default(),
+            none_encoding: NoneEncoding::default(),
+            human_readable: true,
+        }
+    }
+}
+
+impl SerializerOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the order in which struct and map fields are written. Note that iterating over a
+    /// `HashMap` produces its entries in an unspecified order; use [FieldOrder::Sorted] when
+    /// serializing one must produce the same output every time.
+    pub fn with_field_order(mut self, field_order: FieldOrder) -> Self {
+        self.field_order = field_order;
+        self
+    }
+
+    pub fn field_order(&self) -> FieldOrder {
+        self.field_order
+    }
+
+    /// Sets how `None` values are serialized. Fields that are omitted using
+    /// [NoneEncoding::OmitFields] are deserialized as `None` again.
+    pub fn with_none_encoding(mut self, none_encoding: NoneEncoding) -> Self {
+        self.none_encoding = none_encoding;
+        self
+    }
+
+    pub fn none_encoding(&self) -> NoneEncoding {
+        self.none_encoding
+    }
+
+    /// Sets what [Serializer]'s `is_human_readable` reports to the types being serialized.
+    /// Types such as [Decimal](crate::types::decimal::Decimal) and
+    /// [Timestamp](crate::types::timestamp::Timestamp) serialize themselves as text when it is
+    /// `true` (the default) and as compact structs otherwise. The Ion output is the same either
+    /// way; this matters to `Serialize` implementations that check it themselves.
+    pub fn with_human_readable(mut self, human_readable: bool) -> Self {
+        self.human_readable = human_readable;
+        self
+    }
+
+    pub fn human_readable(&self) -> bool {
+        self.human_readable
+    }
+}
+
+// The newtype name that identifies a `Symbol` to the serializer.
+pub(crate) const SYMBOL_NAME: &str = "$ion_rs::Symbol";
+
+/// Text that [Serializer] writes as an Ion symbol rather than as an Ion string. Other serde
+/// formats see a string.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::serde::ser::Symbol;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Reading {
+///     unit: Symbol,
+///     label: String,
+/// }
+///
+/// let reading = Reading {
+///     unit: Symbol::from("celsius"),
+///     label: "celsius".to_string(),
+/// };
+/// let text = ion_rs::serde::to_string(&reading)?;
+/// assert_eq!(text, r#"{unit: celsius, label: "celsius"}"#);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(pub String);
+
+impl From<String> for Symbol {
+    fn from(text: String) -> Self {
+        Symbol(text)
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(text: &str) -> Self {
+        Symbol(text.to_string())
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(SYMBOL_NAME, &self.0)
+    }
+}
+
+/// Symbols can be deserialized from Ion symbols or strings.
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Symbol)
+    }
+}
+
+// The newtype names that identify a `Blob` or `Clob` to the serializer and deserializer.
+pub(crate) const BLOB_NAME: &str = "$ion_rs::Blob";
+pub(crate) const CLOB_NAME: &str = "$ion_rs::Clob";
+
+/// Bytes that [Serializer] writes as an Ion blob. Deserializing a `Blob` using
+/// [Deserializer](crate::serde::Deserializer) fails if the value is not a blob.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Blob(pub Vec<u8>);
+
+/// Bytes that [Serializer] writes as an Ion clob. Deserializing a `Clob` using
+/// [Deserializer](crate::serde::Deserializer) fails if the value is not a clob.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Clob(pub Vec<u8>);
+
+impl Serialize for Blob {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(BLOB_NAME, &BytesRef(&self.0))
+    }
+}
+
+impl Serialize for Clob {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(CLOB_NAME, &BytesRef(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Blob {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_newtype_struct(BLOB_NAME, BytesVisitor)
+            .map(Blob)
+    }
+}
+
+impl<'de> Deserialize<'de> for Clob {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_newtype_struct(CLOB_NAME, BytesVisitor)
+            .map(Clob)
+    }
+}
+
+// Serializes a byte slice using `serialize_bytes` rather than as a sequence of integers.
+struct BytesRef<'a>(&'a [u8]);
+
+impl Serialize for BytesRef<'_> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte array")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_byte_buf(self)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(bytes)
+    }
+
+    // Formats without a byte array type usually represent bytes as a sequence of integers.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+// The newtype name that identifies an `SExp` to the serializer and deserializer.
+pub(crate) const SEXP_NAME: &str = "$ion_rs::SExp";
+
+/// A sequence that [Serializer] writes as an Ion s-expression rather than as a list. The
+/// wrapped value must serialize as a sequence, such as a `Vec` or a tuple. Deserializing an
+/// `SExp` using [Deserializer](crate::serde::Deserializer) fails if the value is not an
+/// s-expression.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::serde::ser::{SExp, Symbol};
+/// use ion_rs::{ReaderBuilder, StreamReader};
+///
+/// let text = ion_rs::serde::to_string(&SExp((Symbol::from("add"), 1, 2)))?;
+/// assert_eq!(text, "(add 1 2)");
+///
+/// let mut reader = ReaderBuilder::new().build("(1 2 3)")?;
+/// reader.next()?;
+/// assert_eq!(reader.read_as::<SExp<Vec<i64>>>()?, SExp(vec![1, 2, 3]));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SExp<T>(pub T);
+
+impl<T: Serialize> Serialize for SExp<T> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(SEXP_NAME, &self.0)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SExp<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(
+            SEXP_NAME,
+            SExpVisitor {
+                marker: PhantomData,
+            },
+        )
+    }
+}
+
+struct SExpVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for SExpVisitor<T> {
+    type Value = SExp<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an s-expression")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<SExp<T>, D::Error> {
+        T::deserialize(deserializer).map(SExp)
+    }
+}
+
+// An annotation that marks the serialized form of a `None` field value that should be omitted.
+// It only appears on values returned to a `StructSerializer` or `MapSerializer`, which drop them.
+const OMITTED_ANNOTATION: &str = "$ion_rs::omitted";
+
+fn is_omitted(element: &OwnedElement) -> bool {
+    element
+        .annotations()
+        .any(|annotation| annotation.text() == Some(OMITTED_ANNOTATION))
+}
+
+/// Converts Rust data structures into [OwnedElement]s. This is the inverse of the mapping
+/// described by [Deserializer](crate::serde::Deserializer):
+/// * Booleans, integers (including `i128` and `u128`), and floats are their Ion equivalents.
+///   Integers that don't fit in an `i64` are arbitrary-size integers.
+/// * Characters and strings are strings. A [Symbol] is a symbol.
+/// * Byte arrays are blobs. A [Clob] is a clob.
+/// * [Decimal](crate::types::decimal::Decimal)s are decimals.
+/// * [Timestamp](crate::types::timestamp::Timestamp)s are timestamps.
+/// * `None`, unit, and unit structs are `null`. (See [NoneEncoding] for other ways to encode
+///   `None`.)
+/// * Sequences, tuples, and tuple structs are lists. An [SExp] is an s-expression.
+/// * Maps and structs are structs. Map keys must serialize as strings.
+/// * Unit enum variants are symbols. Variants with data are structs with a single field whose
+///   name is the variant name.
+///
+/// The mapping can be adjusted using [SerializerOptions]:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::serde::ser::{FieldOrder, SerializerOptions};
+/// use ion_rs::serde::Serializer;
+/// use std::collections::HashMap;
+///
+/// let scores = HashMap::from([("carol", 3), ("alice", 1), ("bob", 2)]);
+/// let options = SerializerOptions::new().with_field_order(FieldOrder::Sorted);
+/// let text = Serializer::new().with_options(options).to_string(&scores)?;
+/// assert_eq!(text, "{alice: 1, bob: 2, carol: 3}");
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Serializer {
+    options: SerializerOptions,
+    // Whether the value being serialized is the value of a struct or map field.
+    is_field_value: bool,
+}
+
+impl Serializer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_options(mut self, options: SerializerOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn options(&self) -> &SerializerOptions {
+        &self.options
+    }
+
+    /// Like [to_element], but using this serializer's options.
+    pub fn to_element<T: Serialize + ?Sized>(self, value: &T) -> IonResult<OwnedElement> {
+        value.serialize(self)
+    }
+
+    /// Like [to_vec], but using this serializer's options.
+    pub fn to_vec<T: Serialize + ?Sized>(self, value: &T) -> IonResult<Vec<u8>> {
+        let element = self.to_element(value)?;
+        let mut writer = BinaryWriterBuilder::new().build(Vec::new())?;
+        write_element(&mut writer, None, &element)?;
+        writer.finish()
+    }
+
+    /// Like [to_writer](crate::serde::to_writer), but using this serializer's options.
+    pub fn to_writer<W: Writer, T: Serialize + ?Sized>(
+        self,
+        writer: &mut W,
+        value: &T,
+    ) -> IonResult<()> {
+        value.serialize(&mut WriterSerializer::new(writer).with_options(self.options))
+    }
+
+    /// Like [to_string], but using this serializer's options.
+    pub fn to_string<T: Serialize + ?Sized>(self, value: &T) -> IonResult<String> {
+        self.to_text(value, TextWriterBuilder::new())
+    }
+
+    /// Like [to_string_pretty], but using this serializer's options.
+    pub fn to_string_pretty<T: Serialize + ?Sized>(self, value: &T) -> IonResult<String> {
+        self.to_text(value, TextWriterBuilder::pretty())
+    }
+
+    fn to_text<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+        builder: TextWriterBuilder,
+    ) -> IonResult<String> {
+        let element = self.to_element(value)?;
+        let mut buffer = Vec::new();
+        let mut writer = builder.build(&mut buffer)?;
+        write_element(&mut writer, None, &element)?;
+        writer.flush()?;
+        drop(writer);
+        // The text writer only produces UTF-8.
+        let text = String::from_utf8(buffer).expect("text writer output was not UTF-8");
+        Ok(text.trim_end().to_string())
+    }
+
+    // Returns a serializer for the value of a struct or map field.
+    fn for_field_value(self) -> Serializer {
+        Serializer {
+            is_field_value: true,
+            ..self
+        }
+    }
+
+    // Returns a serializer for a value nested inside of the current one.
+    fn for_nested_value(self) -> Serializer {
+        Serializer {
+            is_field_value: false,
+            ..self
+        }
+    }
+
+    // Converts serialized fields into a struct, ordering them as the options require.
+    fn make_struct<K: AsRef<str>>(&self, mut fields: Vec<(K, OwnedElement)>) -> OwnedElement {
+        if self.options.field_order == FieldOrder::Sorted {
+            fields.sort_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
+        }
+        let fields = fields
+            .into_iter()
+            .map(|(key, value)| (text_token(key.as_ref()), value));
+        OwnedValue::Struct(OwnedStruct::from_iter(fields)).into()
+    }
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructSerializer;
+
+    fn is_human_readable(&self) -> bool {
+        self.options.human_readable
+    }
+
+    fn serialize_bool(self, v: bool) -> IonResult<OwnedElement> {
+        Ok(v.into())
+    }
+
+    fn serialize_i8(self, v: i8) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> IonResult<OwnedElement> {
+        Ok(v.into())
+    }
+
+    fn serialize_u8(self, v: u8) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i128(self, v: i128) -> IonResult<OwnedElement> {
+        Ok(OwnedValue::Integer(Integer::from(v)).into())
+    }
+
+    fn serialize_u64(self, v: u64) -> IonResult<OwnedElement> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> IonResult<OwnedElement> {
+        Ok(OwnedValue::Integer(Integer::from(v)).into())
+    }
+
+    fn serialize_f32(self, v: f32) -> IonResult<OwnedElement> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> IonResult<OwnedElement> {
+        Ok(v.into())
+    }
+
+    fn serialize_char(self, v: char) -> IonResult<OwnedElement> {
+        Ok(v.to_string().into())
+    }
+
+    fn serialize_str(self, v: &str) -> IonResult<OwnedElement> {
+        Ok(v.to_string().into())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> IonResult<OwnedElement> {
+        Ok(OwnedValue::Blob(v.to_vec()).into())
+    }
+
+    fn serialize_none(self) -> IonResult<OwnedElement> {
+        let null = self.serialize_unit()?;
+        if self.is_field_value && self.options.none_encoding == NoneEncoding::OmitFields {
+            return Ok(null.with_annotations([text_token(OMITTED_ANNOTATION)]));
+        }
+        Ok(null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> IonResult<OwnedElement> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> IonResult<OwnedElement> {
+        Ok(IonType::Null.into())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> IonResult<OwnedElement> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> IonResult<OwnedElement> {
+        Ok(text_token(variant).into())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> IonResult<OwnedElement> {
+        let element = value.serialize(self)?;
+        if name == DECIMAL_NAME {
+            return match element.as_str().and_then(parse_decimal_text) {
+                Some(decimal) => Ok(decimal.into()),
+                None => illegal_operation(format!("{} is not a valid decimal", element)),
+            };
+        }
+        if name == CLOB_NAME {
+            return match element.as_bytes() {
+                Some(bytes) => Ok(OwnedValue::Clob(bytes.to_vec()).into()),
+                None => illegal_operation(format!("{} is not a valid clob", element)),
+            };
+        }
+        if name == SEXP_NAME {
+            return match element.ion_type() {
+                IonType::List => match element.into_value() {
+                    OwnedValue::List(sequence) => Ok(OwnedValue::SExpression(sequence).into()),
+                    _ => unreachable!("an element of type list did not hold a list"),
+                },
+                _ => illegal_operation(format!(
+                    "an s-expression must contain a sequence, but found {}",
+                    element
+                )),
+            };
+        }
+        if name == SYMBOL_NAME {
+            return match element.as_str() {
+                Some(text) => Ok(text_token(text).into()),
+                None => illegal_operation(format!("{} is not valid symbol text", element)),
+            };
+        }
+        if name == TIMESTAMP_NAME {
+            return match element.as_str().and_then(parse_timestamp_text) {
+                Some(timestamp) => Ok(timestamp.into()),
+                None => illegal_operation(format!("{} is not a valid timestamp", element)),
+            };
+        }
+        Ok(element)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> IonResult<OwnedElement> {
+        Ok(variant_struct(
+            variant,
+            value.serialize(self.for_nested_value())?,
+        ))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> IonResult<SeqSerializer> {
+        Ok(SeqSerializer {
+            serializer: self.for_nested_value(),
+            elements: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> IonResult<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> IonResult<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> IonResult<SeqSerializer> {
+        Ok(SeqSerializer {
+            serializer: self.for_nested_value(),
+            elements: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> IonResult<MapSerializer> {
+        Ok(MapSerializer {
+            serializer: self.for_nested_value(),
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> IonResult<StructSerializer> {
+        Ok(StructSerializer {
+            serializer: self.for_nested_value(),
+            fields: Vec::with_capacity(len),
+            variant: None,
+            kind: StructKind::from_name(name),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> IonResult<StructSerializer> {
+        Ok(StructSerializer {
+            serializer: self.for_nested_value(),
+            fields: Vec::with_capacity(len),
+            variant: Some(variant),
+            kind: StructKind::Plain,
+        })
+    }
+}
+
+// Wraps the value of an enum variant in a struct whose only field is named after the variant.
+fn variant_struct(variant: &'static str, value: OwnedElement) -> OwnedElement {
+    OwnedValue::Struct(OwnedStruct::from_iter([(text_token(variant), value)])).into()
+}
+
+/// Collects the elements of a sequence, tuple, or tuple variant into a list.
+pub struct SeqSerializer {
+    serializer: Serializer,
+    elements: Vec<OwnedElement>,
+    // If the sequence is the value of a tuple variant, the variant's name.
+    variant: Option<&'static str>,
+}
+
+impl SeqSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.elements.push(value.serialize(self.serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> IonResult<OwnedElement> {
+        let list = OwnedValue::List(OwnedSequence::new(self.elements)).into();
+        Ok(match self.variant {
+            Some(variant) => variant_struct(variant, list),
+            None => list,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+/// Collects the entries of a map into a struct.
+pub struct MapSerializer {
+    serializer: Serializer,
+    fields: Vec<(String, OwnedElement)>,
+    // The key passed to `serialize_key`, which will be used for the next value.
+    next_key: Option<String>,
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> IonResult<()> {
+        let key = key.serialize(self.serializer)?;
+        match key.as_str() {
+            Some(text) => self.next_key = Some(text.to_string()),
+            None => {
+                return illegal_operation(format!(
+                    "map keys must serialize as strings, but found {}",
+                    key
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        let key = match self.next_key.take() {
+            Some(key) => key,
+            None => return illegal_operation("serialize_value was called before serialize_key"),
+        };
+        let value = value.serialize(self.serializer.for_field_value())?;
+        if !is_omitted(&value) {
+            self.fields.push((key, value));
+        }
+        Ok(())
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        Ok(self.serializer.make_struct(self.fields))
+    }
+}
+
+/// Collects the fields of a struct or struct variant into a struct.
+pub struct StructSerializer {
+    serializer: Serializer,
+    fields: Vec<(&'static str, OwnedElement)>,
+    // If the struct is the value of a struct variant, the variant's name.
+    variant: Option<&'static str>,
+    kind: StructKind,
+}
+
+// The structs that the serializer converts into something other than an Ion struct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum StructKind {
+    Plain,
+    // An `Annotated`, whose fields are a value and its annotations.
+    Annotated,
+    // The struct representation of a `Decimal`, used when not human-readable.
+    Decimal,
+    // The struct representation of a `Timestamp`, used when not human-readable.
+    Timestamp,
+}
+
+impl StructKind {
+    fn from_name(name: &str) -> Self {
+        match name {
+            ANNOTATED_NAME => StructKind::Annotated,
+            DECIMAL_NAME => StructKind::Decimal,
+            TIMESTAMP_NAME => StructKind::Timestamp,
+            _ => StructKind::Plain,
+        }
+    }
+}
+
+impl StructSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> IonResult<()> {
+        let value = value.serialize(self.serializer.for_field_value())?;
+        if !is_omitted(&value) {
+            self.fields.push((key, value));
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> IonResult<OwnedElement> {
+        match self.kind {
+            StructKind::Plain => {}
+            StructKind::Annotated => return annotate(self.fields),
+            StructKind::Decimal => return Ok(decimal_from_fields(&self.fields)?.into()),
+            StructKind::Timestamp => return Ok(timestamp_from_fields(&self.fields)?.into()),
+        }
+        let structure = self.serializer.make_struct(self.fields);
+        Ok(match self.variant {
+            Some(variant) => variant_struct(variant, structure),
+            None => structure,
+        })
+    }
+}
+
+// Converts the fields of a serialized `Annotated` into its value, with the annotations added to
+// any that the value already has.
+fn annotate(fields: Vec<(&'static str, OwnedElement)>) -> IonResult<OwnedElement> {
+    let mut annotations = Vec::new();
+    let mut value = None;
+    for (name, field) in fields {
+        match name {
+            ANNOTATIONS_FIELD => {
+                for annotation in field.as_sequence().into_iter().flat_map(|s| s.iter()) {
+                    match annotation.as_str() {
+                        Some(text) => annotations.push(text_token(text)),
+                        None => return illegal_operation("annotations must be strings"),
+                    }
+                }
+            }
+            VALUE_FIELD => value = Some(field),
+            _ => {}
+        }
+    }
+    let value = match value {
+        Some(value) => value,
+        None => return illegal_operation("an annotated value had no value"),
+    };
+    annotations.extend(value.annotations().cloned());
+    Ok(value.with_annotations(annotations))
+}
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for StructSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::ion_eq::IonEq;
+    use crate::serde::{Annotated, DeserializerOptions};
+    use crate::types::decimal::Decimal;
+    use crate::types::timestamp::Timestamp;
+    use crate::value::reader::{native_element_reader, ElementReader};
+    use crate::{ReaderBuilder, StreamReader};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Order {
+        id: u64,
+        customer: String,
+        items: Vec<(String, i32)>,
+        notes: Option<String>,
+        status: Status,
+        totals: BTreeMap<String, f64>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Status {
+        Pending,
+        Shipped { carrier: String },
+        Cancelled(String),
+    }
+
+    fn order(status: Status) -> Order {
+        Order {
+            id: u64::MAX,
+            customer: "alice".to_string(),
+            items: vec![("A1".to_string(), 2)],
+            notes: None,
+            status,
+            totals: BTreeMap::from([("net".to_string(), 1.5)]),
+        }
+    }
+
+    #[test]
+    fn to_element_mapping() -> IonResult<()> {
+        let element = to_element(&order(Status::Cancelled("late".to_string())))?;
+        let expected = native_element_reader().read_one(
+            br#"{
+                id: 18446744073709551615,
+                customer: "alice",
+                items: [["A1", 2]],
+                notes: null,
+                status: {Cancelled: "late"},
+                totals: {net: 1.5e0},
+            }"#,
+        )?;
+        assert_eq!(element, expected);
+        assert_eq!(to_element(&Status::Pending)?, text_token("Pending").into());
+        Ok(())
+    }
+
+    #[test]
+    fn to_vec_round_trip() -> IonResult<()> {
+        let shipped = Status::Shipped {
+            carrier: "UPS".to_string(),
+        };
+        for order in [order(Status::Pending), order(shipped)] {
+            let bytes = to_vec(&order)?;
+            // The stream begins with an Ion version marker.
+            assert_eq!(&bytes[..4], &[0xE0, 0x01, 0x00, 0xEA]);
+            let mut reader = ReaderBuilder::new().build(bytes)?;
+            reader.next()?;
+            assert_eq!(reader.read_as::<Order>()?, order);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn to_string_formats() -> IonResult<()> {
+        let value = BTreeMap::from([("items", vec![1, 2])]);
+        assert_eq!(to_string(&value)?, "{items: [1, 2]}");
+        assert_eq!(
+            to_string_pretty(&value)?,
+            "{\n    items: [\n        1,\n        2\n    ]\n}"
+        );
+        assert_eq!(to_string(&Status::Pending)?, "Pending");
+        Ok(())
+    }
+
+    #[test]
+    fn annotated_values() -> IonResult<()> {
+        let value = vec![
+            Annotated::new(["a"], Annotated::new(["b", "c"], 1)),
+            Annotated::new(Vec::<String>::new(), Annotated::new(["d"], 2)),
+        ];
+        assert_eq!(to_string(&value)?, "[a::b::c::1, d::2]");
+
+        let bytes = to_vec(&value)?;
+        let mut reader = ReaderBuilder::new().build(bytes)?;
+        reader.next()?;
+        let round_tripped: Vec<Annotated<i64>> = reader.read_as()?;
+        assert_eq!(
+            round_tripped,
+            vec![Annotated::new(["a", "b", "c"], 1), Annotated::new(["d"], 2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn decimals() -> IonResult<()> {
+        let decimals: Vec<Decimal> = native_element_reader()
+            .read_all(b"1.50 -0d3 123456789012345678901234567890.1 0d-5")?
+            .iter()
+            .map(|element| element.as_decimal().unwrap().clone())
+            .collect();
+        let element = to_element(&decimals)?;
+        for (child, decimal) in element.as_sequence().unwrap().iter().zip(&decimals) {
+            assert_eq!(child.as_decimal(), Some(decimal));
+        }
+
+        let mut reader = ReaderBuilder::new().build(to_vec(&decimals)?)?;
+        reader.next()?;
+        let round_tripped: Vec<Decimal> = reader.read_as()?;
+        // Decimal's PartialEq ignores precision and the sign of zero, but its text does not.
+        let text =
+            |decimals: &[Decimal]| decimals.iter().map(|d| d.to_string()).collect::<Vec<_>>();
+        assert_eq!(text(&round_tripped), text(&decimals));
+
+        // Decimals can also be deserialized from strings and integers.
+        let mut reader = ReaderBuilder::new().build(r#"["1.50", 7]"#)?;
+        reader.next()?;
+        let values: Vec<Decimal> = reader.read_as()?;
+        assert_eq!(values[0].to_string(), decimals[0].to_string());
+        assert_eq!(values[1], Decimal::new(7, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn timestamps() -> IonResult<()> {
+        let timestamps: Vec<Timestamp> = native_element_reader()
+            .read_all(b"2021T 2021-09-30 2021-09-30T12:34-07:00 2021-09-30T12:34:56.000123456789Z")?
+            .iter()
+            .map(|element| element.as_timestamp().unwrap().clone())
+            .collect();
+        let element = to_element(&timestamps)?;
+        for (child, timestamp) in element.as_sequence().unwrap().iter().zip(&timestamps) {
+            assert!(child.as_timestamp().unwrap().ion_eq(timestamp));
+        }
+
+        let mut reader = ReaderBuilder::new().build(to_vec(&timestamps)?)?;
+        reader.next()?;
+        let round_tripped: Vec<Timestamp> = reader.read_as()?;
+        assert!(round_tripped
+            .iter()
+            .zip(&timestamps)
+            .all(|(a, b)| a.ion_eq(b)));
+
+        // Timestamps can also be deserialized from strings.
+        let mut reader = ReaderBuilder::new().build(r#""2021-09-30""#)?;
+        reader.next()?;
+        let timestamp: Timestamp = reader.read_as()?;
+        assert!(timestamp.ion_eq(&timestamps[1]));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_timestamp_parts() {
+        #[derive(Serialize)]
+        #[serde(rename = "$ion_rs::Timestamp")]
+        struct Parts {
+            epoch_seconds: i64,
+            offset_minutes: Option<i32>,
+            precision: &'static str,
+            fractional_seconds: Option<Decimal>,
+        }
+        let parts = |epoch_seconds, offset_minutes, precision, fractional_seconds| Parts {
+            epoch_seconds,
+            offset_minutes,
+            precision,
+            fractional_seconds,
+        };
+        // 2021-09-30T12:34:56Z
+        let seconds = 1_633_005_296;
+        assert!(to_element(&parts(seconds, Some(0), "second", None)).is_ok());
+        let invalid = [
+            // Years outside of 1 through 9999
+            parts(-62_135_596_801, None, "second", None),
+            parts(253_402_300_800, None, "second", None),
+            parts(400_000_000_000, Some(0), "second", None),
+            // Fields finer than the precision
+            parts(seconds, None, "day", None),
+            parts(seconds, Some(0), "minute", None),
+            parts(seconds - 45_296, Some(0), "day", None),
+            // A local year outside of 1 through 9999
+            parts(253_402_300_740, Some(60), "minute", None),
+            // Offsets and fractional seconds that don't fit
+            parts(seconds, Some(1440), "second", None),
+            parts(seconds, None, "second", Some(Decimal::new(15, -1))),
+            parts(seconds - 56, None, "minute", Some(Decimal::new(5, -1))),
+        ];
+        for parts in &invalid {
+            assert!(to_element(parts).is_err(), "{}", parts.epoch_seconds);
+        }
+    }
+
+    #[test]
+    fn field_order() -> IonResult<()> {
+        #[derive(Serialize)]
+        struct Record {
+            zeta: i64,
+            alpha: i64,
+            mu: (),
+        }
+        let record = Record {
+            zeta: 1,
+            alpha: 2,
+            mu: (),
+        };
+        assert_eq!(to_string(&record)?, "{zeta: 1, alpha: 2, mu: null}");
+
+        let sorted = Serializer::new()
+            .with_options(SerializerOptions::new().with_field_order(FieldOrder::Sorted));
+        assert_eq!(sorted.to_string(&record)?, "{alpha: 2, mu: null, zeta: 1}");
+        let map: HashMap<String, i64> = (0..20).map(|i| (format!("k{:02}", i), i)).collect();
+        let expected: Vec<String> = (0..20).map(|i| format!("k{:02}: {}", i, i)).collect();
+        assert_eq!(
+            sorted.to_string(&map)?,
+            format!("{{{}}}", expected.join(", "))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn none_encoding() -> IonResult<()> {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Profile {
+            name: Option<String>,
+            nickname: Option<String>,
+            aliases: Vec<Option<String>>,
+            extra: BTreeMap<String, Option<i64>>,
+        }
+        let profile = Profile {
+            name: Some("Ada".to_string()),
+            nickname: None,
+            aliases: vec![None],
+            extra: BTreeMap::from([("a".to_string(), None), ("b".to_string(), Some(1))]),
+        };
+        assert_eq!(
+            to_string(&profile)?,
+            r#"{name: "Ada", nickname: null, aliases: [null], extra: {a: null, b: 1}}"#
+        );
+
+        let omitting = Serializer::new()
+            .with_options(SerializerOptions::new().with_none_encoding(NoneEncoding::OmitFields));
+        let text = omitting.to_string(&profile)?;
+        assert_eq!(text, r#"{name: "Ada", aliases: [null], extra: {b: 1}}"#);
+        assert_eq!(omitting.to_string(&Option::<i64>::None)?, "null");
+
+        let mut reader = ReaderBuilder::new().build(text)?;
+        reader.next()?;
+        let mut expected = profile;
+        expected.extra.remove("a");
+        assert_eq!(reader.read_as::<Profile>()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn symbols() -> IonResult<()> {
+        let symbols = BTreeMap::from([(Symbol::from("key"), vec![Symbol::from("value")])]);
+        let element = to_element(&symbols)?;
+        let expected = native_element_reader().read_one(b"{key: [value]}")?;
+        assert_eq!(element, expected);
+        // The symbols aren't equal to strings with the same text.
+        assert_ne!(element, to_element(&BTreeMap::from([("key", ["value"])]))?);
+
+        let mut reader = ReaderBuilder::new().build(to_vec(&symbols)?)?;
+        reader.next()?;
+        assert_eq!(reader.read_as::<BTreeMap<Symbol, Vec<Symbol>>>()?, symbols);
+        Ok(())
+    }
+
+    #[test]
+    fn lobs() -> IonResult<()> {
+        let lobs = (Blob(b"blob".to_vec()), Clob(b"clob".to_vec()));
+        let element = to_element(&lobs)?;
+        let expected = native_element_reader().read_one(br#"[{{YmxvYg==}}, {{"clob"}}]"#)?;
+        assert_eq!(element, expected);
+
+        let mut reader = ReaderBuilder::new().build(to_vec(&lobs)?)?;
+        reader.next()?;
+        assert_eq!(reader.read_as::<(Blob, Clob)>()?, lobs);
+
+        // Each marker type only accepts its own lob type.
+        let mut reader = ReaderBuilder::new().build(to_vec(&lobs)?)?;
+        reader.next()?;
+        assert!(reader.read_as::<(Clob, Blob)>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn sexps() -> IonResult<()> {
+        let sexps = vec![SExp(vec![1, 2]), SExp(vec![])];
+        let element = to_element(&sexps)?;
+        assert_eq!(element, native_element_reader().read_one(b"[(1 2), ()]")?);
+
+        let mut reader = ReaderBuilder::new().build(to_vec(&sexps)?)?;
+        reader.next()?;
+        assert_eq!(reader.read_as::<Vec<SExp<Vec<i64>>>>()?, sexps);
+
+        // Lists are not s-expressions, and s-expressions must contain sequences.
+        let mut reader = ReaderBuilder::new().build("[1, 2]")?;
+        reader.next()?;
+        assert!(reader.read_as::<SExp<Vec<i64>>>().is_err());
+        assert!(to_element(&SExp(1)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn wide_integers() -> IonResult<()> {
+        let values = (i128::MIN, u128::MAX, -1i128, u64::MAX);
+        let element = to_element(&values)?;
+        let expected = native_element_reader().read_one(
+            b"[-170141183460469231731687303715884105728, \
+               340282366920938463463374607431768211455, -1, 18446744073709551615]",
+        )?;
+        assert_eq!(element, expected);
+
+        let mut reader = ReaderBuilder::new().build(to_vec(&values)?)?;
+        reader.next()?;
+        assert_eq!(reader.read_as::<(i128, u128, i128, u64)>()?, values);
+        Ok(())
+    }
+
+    #[test]
+    fn human_readable() -> IonResult<()> {
+        let values: (Decimal, Timestamp) = native_element_reader()
+            .read_one(b"[-0d-2, 2021-09-30T12:34:56.789-07:00]")
+            .map(|element| {
+                let sequence = element.as_sequence().unwrap();
+                (
+                    sequence.get(0).unwrap().as_decimal().unwrap().clone(),
+                    sequence.get(1).unwrap().as_timestamp().unwrap().clone(),
+                )
+            })?;
+        let compact = Serializer::new()
+            .with_options(SerializerOptions::new().with_human_readable(false))
+            .to_element(&values)?;
+        assert_eq!(compact, to_element(&values)?);
+
+        // Either representation can be deserialized from native Ion values or from the other's
+        // serialized form.
+        let compact_text = r#"[
+            {negative: true, magnitude: {{}}, exponent: -2},
+            {epoch_seconds: 1633030496, offset_minutes: -420, precision: "second",
+             fractional_seconds: {negative: false, magnitude: {{AxU=}}, exponent: -3}},
+        ]"#;
+        for human_readable in [true, false] {
+            let options = DeserializerOptions::new().with_human_readable(human_readable);
+            let mut inputs = vec![to_vec(&values)?];
+            if !human_readable {
+                inputs.push(compact_text.as_bytes().to_vec());
+            }
+            for input in inputs {
+                let mut reader = ReaderBuilder::new().build(input)?;
+                reader.next()?;
+                let (decimal, timestamp): (Decimal, Timestamp) =
+                    reader.read_as_with_options(options)?;
+                assert_eq!(decimal.to_string(), values.0.to_string());
+                assert!(timestamp.ion_eq(&values.1));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn map_keys_must_be_strings() {
+        let map = BTreeMap::from([(1, 2)]);
+        assert!(to_element(&map).is_err());
+    }
+}
@@ -0,0 +1,833 @@
+//! A serde [Serializer](serde::Serializer) that writes Rust data structures directly to an Ion
+//! [Writer].
+
+use serde::ser::{self, Serialize};
+
+use crate::result::{illegal_operation, IonError, IonResult};
+use crate::serde::annotated::{ANNOTATED_NAME, ANNOTATIONS_FIELD, VALUE_FIELD};
+use crate::serde::ser::{
+    FieldOrder, MapSerializer, NoneEncoding, Serializer, SerializerOptions, StructSerializer,
+    BLOB_NAME, CLOB_NAME, SEXP_NAME, SYMBOL_NAME,
+};
+use crate::serde::types::{DECIMAL_NAME, TIMESTAMP_NAME};
+use crate::symbol_table::SymbolTable;
+use crate::value::native_writer::write_element;
+use crate::value::owned::{text_token, OwnedElement, OwnedValue};
+use crate::value::{Element, Sequence};
+use crate::writer::Writer;
+use crate::IonType;
+
+/// Serializes `value` to `writer` as a single top-level Ion value. Unlike [to_element], which
+/// this matches value for value, no intermediate [OwnedElement] is built; see
+/// [WriterSerializer] for details. The writer is not flushed.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{TextWriterBuilder, Writer};
+///
+/// let mut buffer = Vec::new();
+/// let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+/// ion_rs::serde::to_writer(&mut writer, &vec![(1, "one"), (2, "two")])?;
+/// writer.flush()?;
+/// drop(writer);
+/// assert_eq!(String::from_utf8(buffer).unwrap().trim_end(), r#"[[1, "one"], [2, "two"]]"#);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+///
+/// [to_element]: crate::serde::to_element
+pub fn to_writer<W: Writer, T: Serialize + ?Sized>(writer: &mut W, value: &T) -> IonResult<()> {
+    Serializer::new().to_writer(writer, value)
+}
+
+/// Serializes Rust values by calling the methods of an Ion [Writer] as it goes: starting a
+/// struct or sequence steps into a container, each field or element is written as soon as it
+/// is serialized, and ending the container steps out of it again. The memory that it needs is
+/// proportional to the depth of the value rather than to its size, which makes it suitable for
+/// very large values.
+///
+/// The mapping from Rust to Ion is the same as [Serializer]'s, and it honors the same
+/// [SerializerOptions]. Some values are still converted into an [OwnedElement] before they are
+/// written:
+/// * Decimals, timestamps, symbols, blobs, and clobs, which are scalars.
+/// * Structs and maps when using [FieldOrder::Sorted], whose fields cannot be written until
+///   they have all been serialized.
+///
+/// Field names can be written as symbol IDs instead of as text using
+/// [WriterSerializer::with_field_symbol_ids]:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::serde::WriterSerializer;
+/// use ion_rs::{BinaryWriterBuilder, ReaderBuilder, StreamReader, SymbolTable};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Point {
+///     x: i64,
+///     y: i64,
+/// }
+///
+/// let mut symbols = SymbolTable::new();
+/// symbols.intern("x");
+/// symbols.intern("y");
+/// let mut writer = BinaryWriterBuilder::new()
+///     .with_symbol_table(symbols.clone())
+///     .build(Vec::new())?;
+/// let mut serializer = WriterSerializer::new(&mut writer).with_field_symbol_ids(&symbols);
+/// for i in 0..3 {
+///     Point { x: i, y: -i }.serialize(&mut serializer)?;
+/// }
+/// let bytes = writer.finish()?;
+///
+/// let mut reader = ReaderBuilder::new().build(bytes)?;
+/// reader.next()?;
+/// assert_eq!(reader.read_as::<Point>()?, Point { x: 0, y: 0 });
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+pub struct WriterSerializer<'w, W: Writer> {
+    writer: &'w mut W,
+    options: SerializerOptions,
+    // Field names that are written as symbol IDs rather than as text.
+    field_symbol_ids: Option<&'w SymbolTable>,
+    // Whether the value being serialized is the value of a struct or map field.
+    is_field_value: bool,
+    // The field name and annotations to write with the next value.
+    field_name: Option<String>,
+    annotations: Vec<String>,
+    // Whether the next value is the sequence inside of an `SExp`.
+    is_sexp: bool,
+}
+
+impl<'w, W: Writer> WriterSerializer<'w, W> {
+    pub fn new(writer: &'w mut W) -> Self {
+        WriterSerializer {
+            writer,
+            options: SerializerOptions::default(),
+            field_symbol_ids: None,
+            is_field_value: false,
+            field_name: None,
+            annotations: Vec::new(),
+            is_sexp: false,
+        }
+    }
+
+    pub fn with_options(mut self, options: SerializerOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn options(&self) -> &SerializerOptions {
+        &self.options
+    }
+
+    /// Causes field names (including map keys and enum variant names used as field names)
+    /// that have a symbol ID in `symbol_table` to be written using that ID, which spares the
+    /// writer from looking up or adding their text. Every such ID must mean the same text in
+    /// the writer's symbol table; a typical `symbol_table` is the one that the writer was built
+    /// with, or one that imports the same shared symbol tables. Fields of values that are
+    /// converted into an element before being written, such as the fields of a struct written
+    /// using [FieldOrder::Sorted], still use text.
+    pub fn with_field_symbol_ids(mut self, symbol_table: &'w SymbolTable) -> Self {
+        self.field_symbol_ids = Some(symbol_table);
+        self
+    }
+
+    fn set_field_name(&mut self, field_name: &str) {
+        match self
+            .field_symbol_ids
+            .and_then(|symbol_table| symbol_table.sid_for(&field_name))
+        {
+            Some(sid) => self.writer.set_field_name(sid),
+            None => self.writer.set_field_name(field_name),
+        }
+    }
+
+    // Returns a serializer for values that are converted into an element before being written.
+    fn element_serializer(&self) -> Serializer {
+        Serializer::new().with_options(self.options)
+    }
+
+    // Prepares the writer for the next value, which has type `ion_type`, by passing on its field
+    // name and annotations. Returns the type of the value to write, which is an s-expression
+    // rather than a list inside of an `SExp`.
+    fn begin(&mut self, ion_type: IonType) -> IonResult<IonType> {
+        self.is_field_value = false;
+        let ion_type = if std::mem::take(&mut self.is_sexp) {
+            if ion_type != IonType::List {
+                return illegal_operation(format!(
+                    "an s-expression must contain a sequence, but found {}",
+                    ion_type
+                ));
+            }
+            IonType::SExpression
+        } else {
+            ion_type
+        };
+        if let Some(field_name) = self.field_name.take() {
+            self.set_field_name(&field_name);
+        }
+        let annotations = std::mem::take(&mut self.annotations);
+        self.writer
+            .set_annotations(annotations.iter().map(String::as_str));
+        Ok(ion_type)
+    }
+
+    // Writes a value that has already been converted into an element.
+    fn write_owned(&mut self, element: OwnedElement) -> IonResult<()> {
+        let element = if std::mem::take(&mut self.is_sexp) {
+            match element.ion_type() {
+                IonType::List => match element.into_value() {
+                    OwnedValue::List(sequence) => OwnedValue::SExpression(sequence).into(),
+                    _ => unreachable!("an element of type list did not hold a list"),
+                },
+                _ => {
+                    return illegal_operation(format!(
+                        "an s-expression must contain a sequence, but found {}",
+                        element
+                    ))
+                }
+            }
+        } else {
+            element
+        };
+        self.is_field_value = false;
+        let annotations: Vec<_> = std::mem::take(&mut self.annotations)
+            .iter()
+            .map(|annotation| text_token(annotation.as_str()))
+            .chain(element.annotations().cloned())
+            .collect();
+        let element = element.with_annotations(annotations);
+        if let Some(field_name) = self.field_name.take() {
+            self.set_field_name(&field_name);
+        }
+        write_element(self.writer, None, &element)
+    }
+
+    // Discards the field name and annotations of a value that is not written at all.
+    fn omit(&mut self) {
+        self.is_field_value = false;
+        self.field_name = None;
+        self.annotations.clear();
+        self.is_sexp = false;
+    }
+}
+
+impl<'a, 'w, W: Writer> ser::Serializer for &'a mut WriterSerializer<'w, W> {
+    type Ok = ();
+    type Error = IonError;
+    type SerializeSeq = WriterSeqSerializer<'a, 'w, W>;
+    type SerializeTuple = WriterSeqSerializer<'a, 'w, W>;
+    type SerializeTupleStruct = WriterSeqSerializer<'a, 'w, W>;
+    type SerializeTupleVariant = WriterSeqSerializer<'a, 'w, W>;
+    type SerializeMap = WriterMapSerializer<'a, 'w, W>;
+    type SerializeStruct = WriterStructSerializer<'a, 'w, W>;
+    type SerializeStructVariant = WriterStructSerializer<'a, 'w, W>;
+
+    fn is_human_readable(&self) -> bool {
+        self.options.human_readable()
+    }
+
+    fn serialize_bool(self, v: bool) -> IonResult<()> {
+        self.begin(IonType::Boolean)?;
+        self.writer.write_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> IonResult<()> {
+        self.begin(IonType::Integer)?;
+        self.writer.write_i64(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i128(self, v: i128) -> IonResult<()> {
+        self.begin(IonType::Integer)?;
+        self.writer.write_i128(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> IonResult<()> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> IonResult<()> {
+        self.begin(IonType::Integer)?;
+        self.writer.write_u128(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> IonResult<()> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> IonResult<()> {
+        self.begin(IonType::Float)?;
+        self.writer.write_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> IonResult<()> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> IonResult<()> {
+        self.begin(IonType::String)?;
+        self.writer.write_string(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> IonResult<()> {
+        self.begin(IonType::Blob)?;
+        self.writer.write_blob(v)
+    }
+
+    fn serialize_none(self) -> IonResult<()> {
+        if self.is_field_value && self.options.none_encoding() == NoneEncoding::OmitFields {
+            self.omit();
+            return Ok(());
+        }
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> IonResult<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> IonResult<()> {
+        self.begin(IonType::Null)?;
+        self.writer.write_null(IonType::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> IonResult<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> IonResult<()> {
+        self.begin(IonType::Symbol)?;
+        self.writer.write_symbol(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        match name {
+            SEXP_NAME => {
+                self.is_sexp = true;
+                value.serialize(self)
+            }
+            DECIMAL_NAME | TIMESTAMP_NAME | SYMBOL_NAME | BLOB_NAME | CLOB_NAME => {
+                let element = ser::Serializer::serialize_newtype_struct(
+                    self.element_serializer(),
+                    name,
+                    value,
+                )?;
+                self.write_owned(element)
+            }
+            _ => value.serialize(self),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        self.begin(IonType::Struct)?;
+        self.writer.step_in(IonType::Struct)?;
+        self.field_name = Some(variant.to_string());
+        value.serialize(&mut *self)?;
+        self.writer.step_out()
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> IonResult<WriterSeqSerializer<'a, 'w, W>> {
+        let ion_type = self.begin(IonType::List)?;
+        self.writer.step_in(ion_type)?;
+        Ok(WriterSeqSerializer {
+            serializer: self,
+            is_variant: false,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> IonResult<WriterSeqSerializer<'a, 'w, W>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> IonResult<WriterSeqSerializer<'a, 'w, W>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> IonResult<WriterSeqSerializer<'a, 'w, W>> {
+        self.begin(IonType::Struct)?;
+        self.writer.step_in(IonType::Struct)?;
+        self.set_field_name(variant);
+        self.writer.step_in(IonType::List)?;
+        Ok(WriterSeqSerializer {
+            serializer: self,
+            is_variant: true,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> IonResult<WriterMapSerializer<'a, 'w, W>> {
+        if self.options.field_order() == FieldOrder::Sorted {
+            let map = ser::Serializer::serialize_map(self.element_serializer(), len)?;
+            return Ok(WriterMapSerializer {
+                serializer: self,
+                buffered: Some(map),
+            });
+        }
+        self.begin(IonType::Struct)?;
+        self.writer.step_in(IonType::Struct)?;
+        Ok(WriterMapSerializer {
+            serializer: self,
+            buffered: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> IonResult<WriterStructSerializer<'a, 'w, W>> {
+        let mode = match name {
+            ANNOTATED_NAME => StructMode::Annotated { has_value: false },
+            DECIMAL_NAME | TIMESTAMP_NAME => StructMode::Buffered(
+                ser::Serializer::serialize_struct(self.element_serializer(), name, len)?,
+            ),
+            _ if self.options.field_order() == FieldOrder::Sorted => StructMode::Buffered(
+                ser::Serializer::serialize_struct(self.element_serializer(), name, len)?,
+            ),
+            _ => {
+                self.begin(IonType::Struct)?;
+                self.writer.step_in(IonType::Struct)?;
+                StructMode::Streaming { is_variant: false }
+            }
+        };
+        Ok(WriterStructSerializer {
+            serializer: self,
+            mode,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> IonResult<WriterStructSerializer<'a, 'w, W>> {
+        let mode = if self.options.field_order() == FieldOrder::Sorted {
+            StructMode::Buffered(ser::Serializer::serialize_struct_variant(
+                self.element_serializer(),
+                name,
+                variant_index,
+                variant,
+                len,
+            )?)
+        } else {
+            self.begin(IonType::Struct)?;
+            self.writer.step_in(IonType::Struct)?;
+            self.set_field_name(variant);
+            self.writer.step_in(IonType::Struct)?;
+            StructMode::Streaming { is_variant: true }
+        };
+        Ok(WriterStructSerializer {
+            serializer: self,
+            mode,
+        })
+    }
+}
+
+/// Writes the elements of a sequence, tuple, or tuple variant to a list.
+pub struct WriterSeqSerializer<'a, 'w, W: Writer> {
+    serializer: &'a mut WriterSerializer<'w, W>,
+    // Whether the list is the value of a tuple variant, which is wrapped in a struct.
+    is_variant: bool,
+}
+
+impl<'a, 'w, W: Writer> WriterSeqSerializer<'a, 'w, W> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.serializer.is_field_value = false;
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn finish(self) -> IonResult<()> {
+        self.serializer.writer.step_out()?;
+        if self.is_variant {
+            self.serializer.writer.step_out()?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'w, W: Writer> ser::SerializeSeq for WriterSeqSerializer<'a, 'w, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        self.finish()
+    }
+}
+
+impl<'a, 'w, W: Writer> ser::SerializeTuple for WriterSeqSerializer<'a, 'w, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        self.finish()
+    }
+}
+
+impl<'a, 'w, W: Writer> ser::SerializeTupleStruct for WriterSeqSerializer<'a, 'w, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        self.finish()
+    }
+}
+
+impl<'a, 'w, W: Writer> ser::SerializeTupleVariant for WriterSeqSerializer<'a, 'w, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        self.finish()
+    }
+}
+
+/// Writes the entries of a map to a struct.
+pub struct WriterMapSerializer<'a, 'w, W: Writer> {
+    serializer: &'a mut WriterSerializer<'w, W>,
+    // When the fields must be sorted, the map is collected into an element instead.
+    buffered: Option<MapSerializer>,
+}
+
+impl<'a, 'w, W: Writer> ser::SerializeMap for WriterMapSerializer<'a, 'w, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> IonResult<()> {
+        if let Some(map) = &mut self.buffered {
+            return ser::SerializeMap::serialize_key(map, key);
+        }
+        let key = key.serialize(self.serializer.element_serializer())?;
+        match key.as_str() {
+            Some(text) => self.serializer.field_name = Some(text.to_string()),
+            None => {
+                return illegal_operation(format!(
+                    "map keys must serialize as strings, but found {}",
+                    key
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        if let Some(map) = &mut self.buffered {
+            return ser::SerializeMap::serialize_value(map, value);
+        }
+        if self.serializer.field_name.is_none() {
+            return illegal_operation("serialize_value was called before serialize_key");
+        }
+        self.serializer.is_field_value = true;
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> IonResult<()> {
+        match self.buffered {
+            Some(map) => {
+                let element = ser::SerializeMap::end(map)?;
+                self.serializer.write_owned(element)
+            }
+            None => self.serializer.writer.step_out(),
+        }
+    }
+}
+
+/// Writes the fields of a struct or struct variant to a struct.
+pub struct WriterStructSerializer<'a, 'w, W: Writer> {
+    serializer: &'a mut WriterSerializer<'w, W>,
+    mode: StructMode,
+}
+
+enum StructMode {
+    // The fields are written as they are serialized. The struct is wrapped in another struct if
+    // it is the value of a struct variant.
+    Streaming { is_variant: bool },
+    // The struct is an `Annotated`; its annotations are applied to its value.
+    Annotated { has_value: bool },
+    // The struct is collected into an element, which is written when it ends.
+    Buffered(StructSerializer),
+}
+
+impl<'a, 'w, W: Writer> WriterStructSerializer<'a, 'w, W> {
+    fn push<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> IonResult<()> {
+        match &mut self.mode {
+            StructMode::Streaming { .. } => {
+                self.serializer.field_name = Some(key.to_string());
+                self.serializer.is_field_value = true;
+                value.serialize(&mut *self.serializer)
+            }
+            StructMode::Annotated { has_value } => match key {
+                ANNOTATIONS_FIELD => {
+                    let annotations = value.serialize(self.serializer.element_serializer())?;
+                    for annotation in annotations.as_sequence().into_iter().flat_map(|s| s.iter()) {
+                        match annotation.as_str() {
+                            Some(text) => self.serializer.annotations.push(text.to_string()),
+                            None => return illegal_operation("annotations must be strings"),
+                        }
+                    }
+                    Ok(())
+                }
+                VALUE_FIELD => {
+                    *has_value = true;
+                    value.serialize(&mut *self.serializer)
+                }
+                _ => Ok(()),
+            },
+            StructMode::Buffered(structure) => {
+                ser::SerializeStruct::serialize_field(structure, key, value)
+            }
+        }
+    }
+
+    fn finish(self) -> IonResult<()> {
+        match self.mode {
+            StructMode::Streaming { is_variant } => {
+                self.serializer.writer.step_out()?;
+                if is_variant {
+                    self.serializer.writer.step_out()?;
+                }
+                Ok(())
+            }
+            StructMode::Annotated { has_value } => {
+                if !has_value {
+                    return illegal_operation("an annotated value had no value");
+                }
+                Ok(())
+            }
+            StructMode::Buffered(structure) => {
+                let element = ser::SerializeStruct::end(structure)?;
+                self.serializer.write_owned(element)
+            }
+        }
+    }
+}
+
+impl<'a, 'w, W: Writer> ser::SerializeStruct for WriterStructSerializer<'a, 'w, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        self.finish()
+    }
+}
+
+impl<'a, 'w, W: Writer> ser::SerializeStructVariant for WriterStructSerializer<'a, 'w, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    use super::*;
+    use crate::binary::binary_writer::BinaryWriterBuilder;
+    use crate::serde::ser::{SExp, Symbol};
+    use crate::serde::{to_string, Annotated};
+    use crate::text::text_writer::TextWriterBuilder;
+    use crate::types::decimal::Decimal;
+    use crate::value::reader::{element_reader, ElementReader};
+    use crate::value::SymbolToken;
+
+    #[derive(Serialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Line(i64, i64),
+        Rect { width: i64, height: i64 },
+    }
+
+    #[derive(Serialize)]
+    struct Drawing {
+        name: String,
+        scale: Decimal,
+        tag: Symbol,
+        shapes: Vec<Shape>,
+        note: Option<String>,
+        origin: Annotated<(i64, i64)>,
+        path: SExp<(Symbol, i64)>,
+        layers: BTreeMap<String, Vec<u8>>,
+    }
+
+    fn drawing() -> Drawing {
+        Drawing {
+            name: "plan".to_string(),
+            scale: Decimal::new(15, -1),
+            tag: Symbol::from("draft"),
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(2.5),
+                Shape::Line(1, 2),
+                Shape::Rect {
+                    width: 3,
+                    height: 4,
+                },
+            ],
+            note: None,
+            origin: Annotated::new(vec!["point".to_string()], (0, 0)),
+            path: SExp((Symbol::from("move"), 3)),
+            layers: BTreeMap::from([("z".to_string(), vec![1]), ("a".to_string(), vec![])]),
+        }
+    }
+
+    fn write_text<T: Serialize + ?Sized>(serializer: Serializer, value: &T) -> IonResult<String> {
+        let mut buffer = Vec::new();
+        let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+        serializer.to_writer(&mut writer, value)?;
+        writer.flush()?;
+        drop(writer);
+        Ok(String::from_utf8(buffer).unwrap().trim_end().to_string())
+    }
+
+    #[test]
+    fn matches_element_serializer() -> IonResult<()> {
+        let option_sets = [
+            SerializerOptions::new(),
+            SerializerOptions::new().with_field_order(FieldOrder::Sorted),
+            SerializerOptions::new().with_none_encoding(NoneEncoding::OmitFields),
+            SerializerOptions::new().with_human_readable(false),
+        ];
+        for options in option_sets {
+            let serializer = Serializer::new().with_options(options);
+            assert_eq!(
+                write_text(serializer, &drawing())?,
+                serializer.to_string(&drawing())?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn binary() -> IonResult<()> {
+        let mut writer = BinaryWriterBuilder::new().build(Vec::new())?;
+        to_writer(&mut writer, &drawing())?;
+        to_writer(&mut writer, &Shape::Empty)?;
+        let bytes = writer.finish()?;
+        let expected = element_reader().read_all(to_string(&drawing())?.as_bytes())?;
+        let actual = element_reader().read_all(&bytes)?;
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0], expected[0]);
+        assert_eq!(actual[1].as_sym().and_then(|s| s.text()), Some("Empty"));
+        Ok(())
+    }
+
+    #[test]
+    fn field_symbol_ids() -> IonResult<()> {
+        let mut symbols = SymbolTable::new();
+        symbols.intern("shapes");
+        symbols.intern("Rect");
+        symbols.intern("width");
+        let mut writer = BinaryWriterBuilder::new()
+            .with_symbol_table(symbols.clone())
+            .build(Vec::new())?;
+        let mut serializer = WriterSerializer::new(&mut writer).with_field_symbol_ids(&symbols);
+        drawing().serialize(&mut serializer)?;
+        let bytes = writer.finish()?;
+        // Fields without a symbol ID in the table, such as `height`, are written as text.
+        let expected = element_reader().read_all(to_string(&drawing())?.as_bytes())?;
+        assert_eq!(element_reader().read_all(&bytes)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sexp_must_contain_a_sequence() {
+        let mut buffer = Vec::new();
+        let mut writer = TextWriterBuilder::new().build(&mut buffer).unwrap();
+        assert!(to_writer(&mut writer, &SExp(5)).is_err());
+    }
+}
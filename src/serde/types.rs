@@ -0,0 +1,401 @@
+//! serde implementations for the Ion-specific scalar types.
+
+use std::fmt;
+
+use chrono::{Datelike, FixedOffset, TimeZone, Timelike};
+use num_bigint::BigUint;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::result::{illegal_operation, illegal_operation_raw, IonResult};
+use crate::serde::ser::Blob;
+use crate::text::parsers::timestamp::parse_timestamp;
+use crate::text::text_value::TextValue;
+use crate::types::coefficient::{Coefficient, Sign};
+use crate::types::decimal::Decimal;
+use crate::types::integer::IntAccess;
+use crate::types::magnitude::Magnitude;
+use crate::types::timestamp::{Offset, Precision, Timestamp};
+use crate::value::owned::OwnedElement;
+use crate::value::Element;
+
+// The name that identifies a decimal to the Ion serializer, which encodes it as an Ion decimal.
+// In human-readable formats it is a newtype around the decimal's Ion text; otherwise it is a
+// struct with the following fields.
+pub(crate) const DECIMAL_NAME: &str = "$ion_rs::Decimal";
+const NEGATIVE_FIELD: &str = "negative";
+const MAGNITUDE_FIELD: &str = "magnitude";
+const EXPONENT_FIELD: &str = "exponent";
+const DECIMAL_FIELDS: &[&str] = &[NEGATIVE_FIELD, MAGNITUDE_FIELD, EXPONENT_FIELD];
+
+// The name that identifies a timestamp to the Ion serializer. In human-readable formats it is a
+// newtype around the timestamp's Ion text; otherwise it is a struct with the following fields.
+pub(crate) const TIMESTAMP_NAME: &str = "$ion_rs::Timestamp";
+const EPOCH_SECONDS_FIELD: &str = "epoch_seconds";
+const OFFSET_MINUTES_FIELD: &str = "offset_minutes";
+const PRECISION_FIELD: &str = "precision";
+const FRACTIONAL_SECONDS_FIELD: &str = "fractional_seconds";
+const TIMESTAMP_FIELDS: &[&str] = &[
+    EPOCH_SECONDS_FIELD,
+    OFFSET_MINUTES_FIELD,
+    PRECISION_FIELD,
+    FRACTIONAL_SECONDS_FIELD,
+];
+
+/// In human-readable formats, decimals are serialized as their Ion text representation (for
+/// example, `15d-1`). Other formats see a struct with these fields:
+/// * `negative`: whether the coefficient is negative (including negative zero).
+/// * `magnitude`: the coefficient's magnitude as big-endian bytes.
+/// * `exponent`: the exponent.
+///
+/// [Serializer](crate::serde::Serializer) encodes decimals as Ion decimals. Either way, the
+/// decimal's precision and the sign of negative zero are preserved.
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.serialize_newtype_struct(DECIMAL_NAME, &self.to_string());
+        }
+        let magnitude = match &self.coefficient.magnitude {
+            Magnitude::U64(magnitude) => BigUint::from(*magnitude).to_bytes_be(),
+            Magnitude::BigUInt(magnitude) => magnitude.to_bytes_be(),
+        };
+        let mut state = serializer.serialize_struct(DECIMAL_NAME, DECIMAL_FIELDS.len())?;
+        state.serialize_field(NEGATIVE_FIELD, &(self.coefficient.sign == Sign::Negative))?;
+        state.serialize_field(MAGNITUDE_FIELD, &Blob(magnitude))?;
+        state.serialize_field(EXPONENT_FIELD, &self.exponent)?;
+        state.end()
+    }
+}
+
+/// Decimals can be deserialized from Ion decimals, from strings containing Ion decimal text,
+/// from integers, and from the struct representation described on the `Serialize`
+/// implementation.
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_newtype_struct(DECIMAL_NAME, DecimalVisitor)
+        } else {
+            deserializer.deserialize_struct(DECIMAL_NAME, DECIMAL_FIELDS, DecimalVisitor)
+        }
+    }
+}
+
+struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an Ion decimal")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Decimal, E> {
+        parse_decimal_text(text).ok_or_else(|| E::invalid_value(de::Unexpected::Str(text), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Decimal, A::Error> {
+        let mut negative: Option<bool> = None;
+        let mut magnitude: Option<Blob> = None;
+        let mut exponent: Option<i64> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                NEGATIVE_FIELD => negative = Some(map.next_value()?),
+                MAGNITUDE_FIELD => magnitude = Some(map.next_value()?),
+                EXPONENT_FIELD => exponent = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(decimal_from_parts(
+            negative.ok_or_else(|| de::Error::missing_field(NEGATIVE_FIELD))?,
+            &magnitude
+                .ok_or_else(|| de::Error::missing_field(MAGNITUDE_FIELD))?
+                .0,
+            exponent.ok_or_else(|| de::Error::missing_field(EXPONENT_FIELD))?,
+        ))
+    }
+}
+
+fn decimal_from_parts(negative: bool, magnitude: &[u8], exponent: i64) -> Decimal {
+    let sign = if negative {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    let magnitude = Magnitude::from(BigUint::from_bytes_be(magnitude));
+    Decimal::new(Coefficient::new(sign, magnitude), exponent)
+}
+
+// Returns the value of the last field named `name`, if there is one.
+fn field<'a>(fields: &'a [(&str, OwnedElement)], name: &str) -> Option<&'a OwnedElement> {
+    fields
+        .iter()
+        .rev()
+        .find(|(field_name, _value)| *field_name == name)
+        .map(|(_name, value)| value)
+}
+
+// Converts the fields of a decimal's struct representation, as serialized by the Ion serializer
+// when it is not human-readable, into a decimal.
+pub(crate) fn decimal_from_fields(fields: &[(&str, OwnedElement)]) -> IonResult<Decimal> {
+    let negative = field(fields, NEGATIVE_FIELD).and_then(|value| value.as_bool());
+    let magnitude = field(fields, MAGNITUDE_FIELD).and_then(|value| value.as_bytes());
+    let exponent = field(fields, EXPONENT_FIELD).and_then(|value| value.as_i64());
+    match (negative, magnitude, exponent) {
+        (Some(negative), Some(magnitude), Some(exponent)) => {
+            Ok(decimal_from_parts(negative, magnitude, exponent))
+        }
+        _ => illegal_operation("a decimal's fields must be a bool, a blob, and an integer"),
+    }
+}
+
+// Parses the Ion text representation of a decimal, like `1.5` or `15d-1`.
+pub(crate) fn parse_decimal_text(text: &str) -> Option<Decimal> {
+    text.trim().parse().ok()
+}
+
+/// In human-readable formats, timestamps are serialized as their Ion text representation (for
+/// example, `2021-09-30T12:00:00.000-07:00`). Other formats see a struct with these fields:
+/// * `epoch_seconds`: the whole number of seconds between the Unix epoch and the timestamp's
+///   time unit fields, interpreted as UTC.
+/// * `offset_minutes`: the offset from UTC, or none if the offset is unknown.
+/// * `precision`: one of `year`, `month`, `day`, `minute`, or `second`.
+/// * `fractional_seconds`: a decimal holding the fractional seconds, or none if the precision is
+///   coarser than `second`.
+///
+/// [Serializer](crate::serde::Serializer) encodes timestamps as Ion timestamps. Either way, the
+/// timestamp's precision and offset are preserved.
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.serialize_newtype_struct(TIMESTAMP_NAME, &self.to_string());
+        }
+        let mut state = serializer.serialize_struct(TIMESTAMP_NAME, TIMESTAMP_FIELDS.len())?;
+        let epoch_seconds = utc().from_utc_datetime(&self.date_time).timestamp();
+        state.serialize_field(EPOCH_SECONDS_FIELD, &epoch_seconds)?;
+        state.serialize_field(OFFSET_MINUTES_FIELD, &self.offset())?;
+        state.serialize_field(PRECISION_FIELD, precision_name(self.precision))?;
+        state.serialize_field(
+            FRACTIONAL_SECONDS_FIELD,
+            &self.fractional_seconds_as_decimal(),
+        )?;
+        state.end()
+    }
+}
+
+/// Timestamps can be deserialized from Ion timestamps, from strings containing Ion timestamp
+/// text, and from the struct representation described on the `Serialize` implementation.
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_newtype_struct(TIMESTAMP_NAME, TimestampVisitor)
+        } else {
+            deserializer.deserialize_struct(TIMESTAMP_NAME, TIMESTAMP_FIELDS, TimestampVisitor)
+        }
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an Ion timestamp")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Timestamp, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Timestamp, E> {
+        parse_timestamp_text(text).ok_or_else(|| E::invalid_value(de::Unexpected::Str(text), &self))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Timestamp, A::Error> {
+        let mut epoch_seconds: Option<i64> = None;
+        let mut offset_minutes: Option<Option<i32>> = None;
+        let mut precision: Option<String> = None;
+        let mut fractional_seconds: Option<Option<Decimal>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                EPOCH_SECONDS_FIELD => epoch_seconds = Some(map.next_value()?),
+                OFFSET_MINUTES_FIELD => offset_minutes = Some(map.next_value()?),
+                PRECISION_FIELD => precision = Some(map.next_value()?),
+                FRACTIONAL_SECONDS_FIELD => fractional_seconds = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        let epoch_seconds =
+            epoch_seconds.ok_or_else(|| de::Error::missing_field(EPOCH_SECONDS_FIELD))?;
+        let precision = precision.ok_or_else(|| de::Error::missing_field(PRECISION_FIELD))?;
+        timestamp_from_parts(
+            epoch_seconds,
+            offset_minutes.flatten(),
+            &precision,
+            fractional_seconds.flatten(),
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+fn timestamp_from_parts(
+    epoch_seconds: i64,
+    offset_minutes: Option<i32>,
+    precision: &str,
+    fractional_seconds: Option<Decimal>,
+) -> Result<Timestamp, String> {
+    let precision = precision_from_name(precision)
+        .ok_or_else(|| format!("unknown timestamp precision `{}`", precision))?;
+    let date_time = match utc().timestamp_opt(epoch_seconds, 0).single() {
+        Some(date_time) => date_time.naive_utc(),
+        None => return Err("epoch_seconds is out of range".to_string()),
+    };
+    let offset = match offset_minutes {
+        Some(_) if precision < Precision::HourAndMinute => {
+            return Err(format!(
+                "timestamps with {} precision cannot have an offset_minutes",
+                precision_name(precision)
+            ))
+        }
+        Some(minutes) => Some(Offset::from_minutes(minutes).map_err(|error| error.to_string())?),
+        None => None,
+    };
+    if fractional_seconds.is_some() && precision != Precision::Second {
+        return Err(
+            "only timestamps with second precision can have fractional_seconds".to_string(),
+        );
+    }
+    // Rebuilding the timestamp from its UTC fields lets the TimestampBuilder validate the year and
+    // the fractional seconds. A negative year becomes a u32 greater than 9999, which it rejects.
+    let year = date_time.year() as u32;
+    let (month, day) = (date_time.month(), date_time.day());
+    let (hour, minute, second) = (date_time.hour(), date_time.minute(), date_time.second());
+    let timestamp = match precision {
+        Precision::Year => Timestamp::with_year(year).build(),
+        Precision::Month => Timestamp::with_year(year).with_month(month).build(),
+        Precision::Day => Timestamp::with_ymd(year, month, day).build(),
+        Precision::HourAndMinute => {
+            let setter = Timestamp::with_ymd(year, month, day).with_hour_and_minute(hour, minute);
+            match offset {
+                Some(_) => setter.build_at_offset(0),
+                None => setter.build_at_unknown_offset(),
+            }
+        }
+        Precision::Second => {
+            let mut setter = Timestamp::with_ymd(year, month, day).with_hms(hour, minute, second);
+            if let Some(decimal) = fractional_seconds {
+                setter = setter.with_fractional_seconds(decimal);
+            }
+            match offset {
+                Some(_) => setter.build_at_offset(0),
+                None => setter.build_at_unknown_offset(),
+            }
+        }
+    }
+    .map_err(|error| error.to_string())?;
+    // The builder only used the fields that the precision calls for, so any others must be zero.
+    if timestamp.date_time != date_time {
+        return Err(format!(
+            "epoch_seconds {} is not a whole {}",
+            epoch_seconds,
+            precision_name(precision)
+        ));
+    }
+    match offset {
+        Some(offset) => timestamp
+            .with_offset(offset.minutes())
+            .map_err(|error| error.to_string()),
+        None => Ok(timestamp),
+    }
+}
+
+// Converts the fields of a timestamp's struct representation, as serialized by the Ion
+// serializer when it is not human-readable, into a timestamp.
+pub(crate) fn timestamp_from_fields(fields: &[(&str, OwnedElement)]) -> IonResult<Timestamp> {
+    let epoch_seconds = field(fields, EPOCH_SECONDS_FIELD).and_then(|value| value.as_i64());
+    let offset_minutes = field(fields, OFFSET_MINUTES_FIELD)
+        .filter(|value| !value.is_null())
+        .map(|value| {
+            value
+                .as_i64()
+                .and_then(|minutes| i32::try_from(minutes).ok())
+        });
+    let precision = field(fields, PRECISION_FIELD).and_then(|value| value.as_str());
+    let fractional_seconds = field(fields, FRACTIONAL_SECONDS_FIELD)
+        .filter(|value| !value.is_null())
+        .map(|value| value.as_decimal().cloned());
+    let (epoch_seconds, precision) = match (epoch_seconds, precision) {
+        (Some(epoch_seconds), Some(precision)) => (epoch_seconds, precision),
+        _ => return illegal_operation("a timestamp must have epoch_seconds and a precision"),
+    };
+    let offset_minutes = match offset_minutes {
+        Some(None) => return illegal_operation("offset_minutes must be an i32"),
+        Some(Some(minutes)) => Some(minutes),
+        None => None,
+    };
+    let fractional_seconds = match fractional_seconds {
+        Some(None) => return illegal_operation("fractional_seconds must be a decimal"),
+        Some(Some(decimal)) => Some(decimal),
+        None => None,
+    };
+    timestamp_from_parts(epoch_seconds, offset_minutes, precision, fractional_seconds)
+        .map_err(illegal_operation_raw)
+}
+
+fn utc() -> FixedOffset {
+    FixedOffset::east_opt(0).unwrap()
+}
+
+fn precision_name(precision: Precision) -> &'static str {
+    match precision {
+        Precision::Year => "year",
+        Precision::Month => "month",
+        Precision::Day => "day",
+        Precision::HourAndMinute => "minute",
+        Precision::Second => "second",
+    }
+}
+
+fn precision_from_name(name: &str) -> Option<Precision> {
+    match name {
+        "year" => Some(Precision::Year),
+        "month" => Some(Precision::Month),
+        "day" => Some(Precision::Day),
+        "minute" => Some(Precision::HourAndMinute),
+        "second" => Some(Precision::Second),
+        _ => None,
+    }
+}
+
+// Parses the Ion text representation of a timestamp, like `2021-09-30T12:00Z`.
+pub(crate) fn parse_timestamp_text(text: &str) -> Option<Timestamp> {
+    let input = format!("{} ", text.trim());
+    match parse_timestamp(&input) {
+        Ok((remaining, TextValue::Timestamp(timestamp))) if remaining.trim().is_empty() => {
+            Some(timestamp)
+        }
+        _ => None,
+    }
+}
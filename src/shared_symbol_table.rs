@@ -0,0 +1,208 @@
+use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonResult};
+use crate::types::integer::IntAccess;
+use crate::types::IonType;
+use crate::value::owned::OwnedElement;
+use crate::value::reader::{native_element_reader, ElementReader};
+use crate::value::{Element, Sequence, Struct, SymbolToken};
+use crate::writer::Writer;
+
+/// A named, versioned list of symbols that can be imported by a stream's local symbol tables.
+///
+/// Shared symbol tables allow writers and readers that agree on a common vocabulary to omit that
+/// vocabulary's text from the data stream. See the
+/// [Ion symbols documentation](https://amzn.github.io/ion-docs/docs/symbols.html#shared-symbol-tables)
+/// for details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedSymbolTable {
+    name: String,
+    version: usize,
+    symbols: Vec<Option<String>>,
+}
+
+impl SharedSymbolTable {
+    /// Constructs a new shared symbol table. `name` must not be empty and `version` must be
+    /// greater than zero. Entries in `symbols` that are `None` represent symbols with unknown text.
+    pub fn new<N: Into<String>>(
+        name: N,
+        version: usize,
+        symbols: Vec<Option<String>>,
+    ) -> IonResult<SharedSymbolTable> {
+        let name = name.into();
+        if name.is_empty() {
+            return illegal_operation("shared symbol tables must have a non-empty name");
+        }
+        if version == 0 {
+            return illegal_operation("shared symbol table versions must be greater than zero");
+        }
+        Ok(SharedSymbolTable {
+            name,
+            version,
+            symbols,
+        })
+    }
+
+    /// Reads a shared symbol table from Ion data. The data must contain exactly one value: a
+    /// struct annotated with `$ion_shared_symbol_table` that has a `name` and (optionally) a
+    /// `version` and a list of `symbols`. Entries in the `symbols` list that are not strings are
+    /// treated as symbols with unknown text. Shared symbol tables that import other tables are
+    /// not supported.
+    pub fn from_ion<A: AsRef<[u8]>>(data: A) -> IonResult<SharedSymbolTable> {
+        let element = native_element_reader().read_one(data.as_ref())?;
+        let is_shared_symbol_table = element
+            .annotations()
+            .any(|annotation| annotation.text() == Some("$ion_shared_symbol_table"));
+        let fields = match element.as_struct() {
+            Some(fields) if is_shared_symbol_table => fields,
+            _ => {
+                return decoding_error(
+                    "expected a struct annotated with '$ion_shared_symbol_table'",
+                )
+            }
+        };
+        let name = fields
+            .get("name")
+            .and_then(OwnedElement::as_str)
+            .ok_or_else(|| decoding_error_raw("shared symbol table did not have a name"))?;
+        // Versions that are missing or less than 1 are treated as 1.
+        let version = fields
+            .get("version")
+            .and_then(OwnedElement::as_i64)
+            .map_or(1, |version| version.max(1) as usize);
+        if let Some(imports) = fields.get("imports").and_then(OwnedElement::as_sequence) {
+            if !imports.is_empty() {
+                return decoding_error(format!(
+                    "shared symbol table '{}' imports other tables, which is not supported",
+                    name
+                ));
+            }
+        }
+        let symbols = match fields.get("symbols") {
+            Some(symbols) if symbols.ion_type() == IonType::List => symbols
+                .as_sequence()
+                .map(|symbols| {
+                    symbols
+                        .iter()
+                        .map(|symbol| symbol.as_str().map(str::to_owned))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        SharedSymbolTable::new(name, version, symbols)
+    }
+
+    /// Writes this table to `writer` as a struct annotated with `$ion_shared_symbol_table`, the
+    /// format that [SharedSymbolTable::from_ion] reads. Symbols with unknown text are written
+    /// as `null`.
+    pub fn write_to<W: Writer>(&self, writer: &mut W) -> IonResult<()> {
+        writer.set_annotations(["$ion_shared_symbol_table"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("name");
+        writer.write_string(&self.name)?;
+        writer.set_field_name("version");
+        writer.write_i64(self.version as i64)?;
+        writer.set_field_name("symbols");
+        writer.step_in(IonType::List)?;
+        for symbol in &self.symbols {
+            match symbol {
+                Some(text) => writer.write_string(text)?,
+                None => writer.write_null(IonType::Null)?,
+            }
+        }
+        writer.step_out()?;
+        writer.step_out()
+    }
+
+    /// Returns the name of this shared symbol table.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of this shared symbol table.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Returns the symbols defined by this shared symbol table in order. Entries whose text is
+    /// unknown are `None`.
+    pub fn symbols(&self) -> &[Option<String>] {
+        &self.symbols
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinaryWriterBuilder, TextWriterBuilder};
+
+    #[test]
+    fn new_shared_symbol_table() -> IonResult<()> {
+        let table =
+            SharedSymbolTable::new("com.example.fruit", 2, vec![Some("apple".into()), None])?;
+        assert_eq!(table.name(), "com.example.fruit");
+        assert_eq!(table.version(), 2);
+        assert_eq!(table.symbols(), &[Some("apple".to_string()), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn read_shared_symbol_table() -> IonResult<()> {
+        let table = SharedSymbolTable::from_ion(
+            r#"$ion_shared_symbol_table::{
+                name: "com.example.fruit",
+                version: 3,
+                symbols: ["apple", null, 5, "cherry"],
+            }"#,
+        )?;
+        assert_eq!(table.name(), "com.example.fruit");
+        assert_eq!(table.version(), 3);
+        assert_eq!(
+            table.symbols(),
+            &[Some("apple".into()), None, None, Some("cherry".into())]
+        );
+
+        let table = SharedSymbolTable::from_ion(r#"$ion_shared_symbol_table::{name: "empty"}"#)?;
+        assert_eq!(table.version(), 1);
+        assert!(table.symbols().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn write_shared_symbol_table() -> IonResult<()> {
+        let table = SharedSymbolTable::new("fruit", 2, vec![Some("apple".into()), None])?;
+
+        let mut buffer = Vec::new();
+        let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+        table.write_to(&mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        assert_eq!(SharedSymbolTable::from_ion(&buffer)?, table);
+
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        table.write_to(&mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        assert_eq!(SharedSymbolTable::from_ion(&buffer)?, table);
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid_shared_symbol_tables() {
+        for ion in [
+            r#"{name: "fruit"}"#,
+            r#"$ion_shared_symbol_table::{symbols: ["apple"]}"#,
+            r#"$ion_shared_symbol_table::{name: ""}"#,
+            r#"$ion_shared_symbol_table::{name: "fruit", imports: [{name: "other"}]}"#,
+            r#"$ion_shared_symbol_table::{name: "fruit"} $ion_shared_symbol_table::{name: "fruit"}"#,
+        ] {
+            assert!(SharedSymbolTable::from_ion(ion).is_err(), "{}", ion);
+        }
+    }
+
+    #[test]
+    fn invalid_shared_symbol_tables() {
+        assert!(SharedSymbolTable::new("", 1, vec![]).is_err());
+        assert!(SharedSymbolTable::new("com.example.fruit", 0, vec![]).is_err());
+    }
+}
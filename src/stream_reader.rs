@@ -1,4 +1,8 @@
-use crate::result::IonResult;
+use std::io;
+use std::ops::Range;
+
+use crate::position::Position;
+use crate::result::{illegal_operation, IonResult};
 use crate::types::decimal::Decimal;
 use crate::types::integer::Integer;
 use crate::types::timestamp::Timestamp;
@@ -39,25 +43,30 @@ pub trait StreamReader {
 
     /// Returns an iterator that will yield each of the annotations for the current value in order.
     /// If there is no current value, returns an empty iterator.
-    // TODO: When GATs are stabilized, we can change this to a known associated type that's generic
-    //       over the lifetime of &self.
-    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'a>;
+    ///
+    /// The iterator borrows the reader's state and does not allocate. Readers that are only
+    /// available as trait objects can use [Self::number_of_annotations] and [Self::annotation]
+    /// directly.
+    fn annotations(&self) -> Annotations<'_, Self>
+    where
+        Self: Sized,
+    {
+        Annotations::new(self)
+    }
+
+    /// Returns the annotation at the specified `index` on the current value. If `index` is not
+    /// less than [Self::number_of_annotations], returns an [crate::IonError::IllegalOperation].
+    fn annotation(&self, index: usize) -> IonResult<Self::Symbol>;
 
     /// If the reader is positioned over a value with one or more annotations, returns `true`.
     /// Otherwise, returns `false`.
     fn has_annotations(&self) -> bool {
-        // Implementations are encouraged to override this when there's a cheaper way of
-        // determining whether the current value has annotations.
-        self.annotations().next().is_some()
+        self.number_of_annotations() > 0
     }
 
     /// Returns the number of annotations on the current value. If there is no current value,
     /// returns zero.
-    fn number_of_annotations(&self) -> usize {
-        // Implementations are encouraged to override this when there's a cheaper way of
-        // calculating the number of annotations.
-        self.annotations().count()
-    }
+    fn number_of_annotations(&self) -> usize;
 
     /// If the current item is a field within a struct, returns `Ok(_)` with a [Self::Symbol]
     /// representing the field's name; otherwise, returns an [crate::IonError::IllegalOperation].
@@ -104,11 +113,18 @@ pub trait StreamReader {
     /// item is not a string or an IO error is encountered while reading, returns [crate::IonError].
     fn read_string(&mut self) -> IonResult<String>;
 
+    /// Attempts to read the current item as an Ion string and return a reference to its text.
+    /// Unlike [Self::read_string], this does not allocate; the returned `&str` borrows from
+    /// the reader's internal buffer and is only valid until the reader is advanced. If the
+    /// current item is not a string or an IO error is encountered while reading,
+    /// returns [crate::IonError].
+    fn read_str(&mut self) -> IonResult<&str>;
+
     /// Takes a function that expects a string and, once the string's bytes are loaded, calls that
     /// function passing the string as a parameter. This allows users to avoid materializing the
     /// string if they only intend to inspect it for length, pattern matches, etc.
-    // TODO: Replace with a `read_str(&self) -> IonResult<&str>`
-    //       See: https://github.com/amzn/ion-rust/issues/335
+    // Unlike this method, [Self::read_str] can be called via dynamic dispatch.
+    // See: https://github.com/amzn/ion-rust/issues/335
     fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
     where
         Self: Sized,
@@ -157,6 +173,35 @@ pub trait StreamReader {
         Self: Sized,
         F: FnOnce(&[u8]) -> U;
 
+    /// Reads the current item as an Ion blob, passing its bytes to `chunk_handler` one chunk at a
+    /// time as they become available. This allows very large blobs to be streamed to their
+    /// destination without ever holding the complete value in memory. Returns the total number
+    /// of bytes in the blob. If the current item is not a blob, an IO error is encountered while
+    /// reading, or `chunk_handler` returns an error, returns [crate::IonError].
+    fn read_blob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize>;
+
+    /// Reads the current item as an Ion clob, passing its bytes to `chunk_handler` one chunk at a
+    /// time as they become available. See [Self::read_blob_chunks] for details.
+    fn read_clob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize>;
+
+    /// Reads the current item as an Ion blob, writing its bytes to `sink` as they become available.
+    /// Returns the total number of bytes written.
+    fn read_blob_into(&mut self, sink: &mut dyn io::Write) -> IonResult<usize> {
+        self.read_blob_chunks(&mut |chunk| Ok(sink.write_all(chunk)?))
+    }
+
+    /// Reads the current item as an Ion clob, writing its bytes to `sink` as they become available.
+    /// Returns the total number of bytes written.
+    fn read_clob_into(&mut self, sink: &mut dyn io::Write) -> IonResult<usize> {
+        self.read_clob_chunks(&mut |chunk| Ok(sink.write_all(chunk)?))
+    }
+
     /// Attempts to read the current item as an Ion timestamp and return [crate::Timestamp]. If the current
     /// item is not a timestamp or an IO error is encountered while reading, returns [crate::IonError].
     fn read_timestamp(&mut self) -> IonResult<Timestamp>;
@@ -180,4 +225,86 @@ pub trait StreamReader {
     /// times the Reader has stepped into a container without later stepping out. At the top level,
     /// this method returns `0`.
     fn depth(&self) -> usize;
+
+    /// Returns the location of the current item in the input stream. If the reader is not
+    /// positioned over an item, returns the location immediately following the last item read.
+    ///
+    /// Binary readers report the byte offset at which the current item's encoding begins
+    /// (including its annotations wrapper, if any). Text readers also report the line and
+    /// column at which the item's text begins.
+    fn position(&self) -> Position;
+
+    /// If the reader is positioned over a value and knows where that value's encoding ends,
+    /// returns the offset of the first byte that follows it. Otherwise, returns `None`.
+    ///
+    /// Binary readers always know where the current value ends. Text readers only know where
+    /// top-level scalars end; the end of a top-level container is found when the reader steps
+    /// out of it, at which point [Self::position] reports it.
+    fn value_end_offset(&self) -> Option<usize> {
+        None
+    }
+
+    /// If the reader is positioned over a value whose encoding declares the length of its body
+    /// before the body is read, returns that length in bytes. Otherwise, returns `None`.
+    ///
+    /// This allows callers to reject an oversized string, blob, or clob before the reader reads
+    /// (and allocates space for) its contents. Only streaming binary readers report a length;
+    /// other readers already hold the value's contents in memory.
+    fn declared_value_length(&self) -> Option<usize> {
+        None
+    }
+
+    /// Attempts to recover from a decoding error by discarding input up to the beginning of the
+    /// next top-level value and stepping out of any containers. Returns the range of byte offsets
+    /// spanning the input that was discarded, starting with the top-level value in which the
+    /// error was encountered. After a successful call, [Self::next] will resume reading at the
+    /// top level.
+    ///
+    /// Recovery is opt-in; readers never skip data unless this method is called. Binary readers
+    /// resume at the next Ion version marker, while text readers resume at the beginning of the
+    /// line following the one on which the error was encountered.
+    ///
+    /// Readers that cannot resynchronize return an [crate::IonError::IllegalOperation].
+    fn resynchronize(&mut self) -> IonResult<Range<usize>> {
+        illegal_operation("this reader does not support resynchronization")
+    }
+}
+
+/// An iterator over the annotations on a [StreamReader]'s current value.
+/// See [StreamReader::annotations].
+pub struct Annotations<'a, R: StreamReader + ?Sized> {
+    reader: &'a R,
+    index: usize,
+    count: usize,
 }
+
+impl<'a, R: StreamReader + ?Sized> Annotations<'a, R> {
+    /// Constructs an iterator over the annotations on `reader`'s current value.
+    pub fn new(reader: &'a R) -> Self {
+        Annotations {
+            reader,
+            index: 0,
+            count: reader.number_of_annotations(),
+        }
+    }
+}
+
+impl<'a, R: StreamReader + ?Sized> Iterator for Annotations<'a, R> {
+    type Item = IonResult<R::Symbol>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let annotation = self.reader.annotation(self.index);
+        self.index += 1;
+        Some(annotation)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, R: StreamReader + ?Sized> ExactSizeIterator for Annotations<'a, R> {}
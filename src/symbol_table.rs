@@ -2,15 +2,72 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::constants::v1_0;
+use crate::result::IonResult;
+use crate::shared_symbol_table::SharedSymbolTable;
 use crate::symbol::Symbol;
 use crate::types::SymbolId;
 
+/// Describes a shared symbol table that was imported into a [SymbolTable].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolTableImport {
+    name: String,
+    version: usize,
+    max_id: usize,
+}
+
+impl SymbolTableImport {
+    pub fn new<N: Into<String>>(name: N, version: usize, max_id: usize) -> SymbolTableImport {
+        SymbolTableImport {
+            name: name.into(),
+            version,
+            max_id,
+        }
+    }
+
+    /// Returns the name of the imported shared symbol table.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of the imported shared symbol table.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Returns the number of symbols that the import contributed to the symbol table.
+    pub fn max_id(&self) -> usize {
+        self.max_id
+    }
+}
+
 /// Stores mappings from Symbol IDs to text and vice-versa.
+///
+/// Symbol tables can be built up independently of any reader or writer, which makes it possible
+/// to assign symbol IDs ahead of time and to hand the same table to several writers (see
+/// [BinaryWriterBuilder::with_symbol_table](crate::BinaryWriterBuilder::with_symbol_table)).
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{SharedSymbolTable, SymbolTable};
+///
+/// let fruit = SharedSymbolTable::new("fruit", 1, vec![Some("apple".into()), None])?;
+/// let mut symbol_table = SymbolTable::new();
+/// symbol_table.import(&fruit);
+/// assert_eq!(symbol_table.intern("banana"), 12);
+/// assert_eq!(symbol_table.intern("apple"), 10);
+/// assert_eq!(symbol_table.text_for(12), Some("banana"));
+/// let local_symbols: Vec<_> = symbol_table.iter().skip(10).collect();
+/// assert_eq!(local_symbols, vec![(10, Some("apple")), (11, None), (12, Some("banana"))]);
+/// # Ok(())
+/// # }
+/// ```
 // SymbolTable instances always have at least system symbols; they are never empty.
 #[allow(clippy::len_without_is_empty)]
+#[derive(Debug, Clone)]
 pub struct SymbolTable {
     symbols_by_id: Vec<Option<Symbol>>,
     ids_by_text: HashMap<Symbol, SymbolId>,
+    imports: Vec<SymbolTableImport>,
 }
 
 impl Default for SymbolTable {
@@ -25,6 +82,7 @@ impl SymbolTable {
         let mut symbol_table = SymbolTable {
             symbols_by_id: Vec::with_capacity(v1_0::SYSTEM_SYMBOLS.len()),
             ids_by_text: HashMap::new(),
+            imports: Vec::new(),
         };
         symbol_table.initialize();
         symbol_table
@@ -40,6 +98,7 @@ impl SymbolTable {
     pub fn reset(&mut self) {
         self.symbols_by_id.clear();
         self.ids_by_text.clear();
+        self.imports.clear();
         self.initialize();
     }
 
@@ -61,6 +120,50 @@ impl SymbolTable {
         id
     }
 
+    /// Adds `text` to the symbol table and returns the newly assigned [SymbolId], even if `text` is
+    /// already defined. Lookups by text continue to return the lowest ID assigned to that text.
+    /// This is used when importing shared symbol tables, whose symbols always occupy a fixed range
+    /// of IDs.
+    pub fn add_symbol_for_text<A: AsRef<str>>(&mut self, text: A) -> SymbolId {
+        let id = self.symbols_by_id.len();
+        let rc: Rc<str> = Rc::from(text.as_ref());
+        let symbol = Symbol::shared(rc);
+        self.symbols_by_id.push(Some(symbol.clone()));
+        self.ids_by_text.entry(symbol).or_insert(id);
+        id
+    }
+
+    /// Adds the symbols of an imported shared symbol table to the end of the symbol table,
+    /// recording the import's name and version. Entries in `symbols` that are `None` are added
+    /// as symbols with unknown text.
+    pub fn add_import<N, I>(&mut self, name: N, version: usize, symbols: I)
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = Option<String>>,
+    {
+        let first_id = self.symbols_by_id.len();
+        for maybe_text in symbols {
+            match maybe_text {
+                Some(text) => self.add_symbol_for_text(text),
+                None => self.add_placeholder(),
+            };
+        }
+        let max_id = self.symbols_by_id.len() - first_id;
+        self.imports
+            .push(SymbolTableImport::new(name, version, max_id));
+    }
+
+    /// Adds the symbols of `table` to the end of the symbol table. See [Self::add_import].
+    pub fn import(&mut self, table: &SharedSymbolTable) {
+        self.add_import(table.name(), table.version(), table.symbols().to_vec());
+    }
+
+    /// Returns the shared symbol tables that have been imported into this symbol table (not
+    /// including the system symbol table) in the order in which they were imported.
+    pub fn imports(&self) -> &[SymbolTableImport] {
+        &self.imports
+    }
+
     /// Assigns unknown text to the next available symbol ID. This is used when an Ion reader
     /// encounters null or non-string values in a stream's symbol table.
     pub fn add_placeholder(&mut self) -> SymbolId {
@@ -127,8 +230,108 @@ impl SymbolTable {
         &self.symbols_by_id[start..]
     }
 
+    /// Returns an iterator over each symbol ID in the table (starting with the system symbols)
+    /// and its text, if known.
+    pub fn iter(&self) -> impl Iterator<Item = (SymbolId, Option<&str>)> + '_ {
+        self.symbols_by_id
+            .iter()
+            .enumerate()
+            .map(|(sid, symbol)| (sid, symbol.as_ref().map(|symbol| symbol.as_ref())))
+    }
+
+    /// Returns the number of symbols defined by the table itself: those that were neither
+    /// system symbols nor imported from a shared symbol table.
+    pub fn local_symbols_len(&self) -> usize {
+        let imported: usize = self.imports.iter().map(SymbolTableImport::max_id).sum();
+        self.len() - v1_0::SYSTEM_SYMBOLS.len() - imported
+    }
+
     /// Returns the number of symbols defined in the table.
     pub fn len(&self) -> usize {
         self.symbols_by_id.len()
     }
+
+    /// Creates a shared symbol table with the given name and version that defines each of this
+    /// table's symbols (including any that were imported) other than the system symbols. The
+    /// shared table's symbol IDs match this table's, so data encoded using this table can be
+    /// read using the shared table as its only import.
+    pub fn to_shared_symbol_table<N: Into<String>>(
+        &self,
+        name: N,
+        version: usize,
+    ) -> IonResult<SharedSymbolTable> {
+        let symbols = self
+            .symbols_tail(v1_0::SYSTEM_SYMBOLS.len())
+            .iter()
+            .map(|symbol| symbol.as_ref().map(|text| text.to_string()))
+            .collect();
+        SharedSymbolTable::new(name, version, symbols)
+    }
+
+    /// Returns the highest symbol ID defined in the table.
+    pub fn max_id(&self) -> SymbolId {
+        self.symbols_by_id.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern_and_look_up_symbols() -> IonResult<()> {
+        let mut symbol_table = SymbolTable::new();
+        assert_eq!(symbol_table.sid_for(&"name"), Some(4));
+        let fruit = SharedSymbolTable::new("fruit", 2, vec![Some("apple".into()), None])?;
+        symbol_table.import(&fruit);
+        assert_eq!(
+            symbol_table.imports(),
+            &[SymbolTableImport::new("fruit", 2, 2)]
+        );
+        assert_eq!(symbol_table.intern("cherry"), 12);
+        assert_eq!(symbol_table.intern("apple"), 10);
+        assert_eq!(symbol_table.intern_or_add_placeholder(None::<&str>), 13);
+        assert_eq!(symbol_table.local_symbols_len(), 2);
+        assert_eq!(symbol_table.max_id(), 13);
+        assert_eq!(symbol_table.text_for(11), None);
+        assert!(symbol_table.sid_is_valid(11));
+        assert!(!symbol_table.sid_is_valid(14));
+
+        let copy = symbol_table.clone();
+        symbol_table.reset();
+        assert_eq!(symbol_table.max_id(), 9);
+        assert!(symbol_table.imports().is_empty());
+        assert_eq!(copy.sid_for(&"cherry"), Some(12));
+        let symbols: Vec<_> = copy.iter().skip(10).collect();
+        assert_eq!(
+            symbols,
+            vec![
+                (10, Some("apple")),
+                (11, None),
+                (12, Some("cherry")),
+                (13, None)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn export_shared_symbol_table() -> IonResult<()> {
+        let mut symbol_table = SymbolTable::new();
+        symbol_table.add_import("fruit", 1, vec![Some("apple".to_string()), None]);
+        symbol_table.intern("cherry");
+        symbol_table.add_placeholder();
+        let shared = symbol_table.to_shared_symbol_table("exported", 3)?;
+        assert_eq!(shared.name(), "exported");
+        assert_eq!(shared.version(), 3);
+        assert_eq!(
+            shared.symbols(),
+            &[Some("apple".into()), None, Some("cherry".into()), None]
+        );
+        assert!(SymbolTable::new()
+            .to_shared_symbol_table("empty", 1)?
+            .symbols()
+            .is_empty());
+        Ok(())
+    }
 }
@@ -4,6 +4,7 @@ use std::ops::Range;
 use delegate::delegate;
 
 use crate::constants::v1_0::{system_symbol_ids, SYSTEM_SYMBOLS};
+use crate::position::Position;
 use crate::raw_reader::{RawReader, RawStreamItem};
 use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonError, IonResult};
@@ -604,6 +605,14 @@ impl<R: RawReader> StreamReader for SystemReader<R> {
         Ok(())
     }
 
+    fn resynchronize(&mut self) -> IonResult<Range<usize>> {
+        let discarded = self.raw_reader.resynchronize()?;
+        // Any local symbol table that was being read has been abandoned.
+        self.lst = LstData::new();
+        self.current_item = SystemStreamItem::Nothing;
+        Ok(discarded)
+    }
+
     fn field_name(&self) -> IonResult<Symbol> {
         match self.raw_reader.field_name() {
             Ok(RawSymbolToken::SymbolId(sid)) => {
@@ -619,26 +628,17 @@ impl<R: RawReader> StreamReader for SystemReader<R> {
         }
     }
 
-    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Symbol>> + 'a> {
-        let iter = self
-            .raw_reader
-            .annotations()
-            .map(|raw_token| match raw_token {
-                // If the annotation was a symbol ID, try to resolve it
-                Ok(RawSymbolToken::SymbolId(sid)) => {
-                    self.symbol_table.symbol_for(sid).cloned().ok_or_else(|| {
-                        decoding_error_raw(format!(
-                            "Found annotation with undefined symbol ${}",
-                            sid
-                        ))
-                    })
-                }
-                // If the annotation was a text literal, turn it into a `Symbol`
-                Ok(RawSymbolToken::Text(text)) => Ok(Symbol::owned(text)),
-                // If the raw reader couldn't provide the annotation, propagate the error
-                Err(error) => Err(error),
-            });
-        Box::new(iter)
+    fn annotation(&self, index: usize) -> IonResult<Symbol> {
+        match self.raw_reader.annotation(index)? {
+            // If the annotation was a symbol ID, try to resolve it
+            RawSymbolToken::SymbolId(sid) => {
+                self.symbol_table.symbol_for(sid).cloned().ok_or_else(|| {
+                    decoding_error_raw(format!("Found annotation with undefined symbol ${}", sid))
+                })
+            }
+            // If the annotation was a text literal, turn it into a `Symbol`
+            RawSymbolToken::Text(text) => Ok(Symbol::owned(text)),
+        }
     }
 
     fn read_symbol(&mut self) -> IonResult<Self::Symbol> {
@@ -669,6 +669,19 @@ impl<R: RawReader> StreamReader for SystemReader<R> {
         self.raw_reader.read_string()
     }
 
+    fn read_str(&mut self) -> IonResult<&str> {
+        if self.current_string_was_consumed() {
+            return Ok(self.lst.current_string.as_str());
+        }
+
+        if self.raw_reader.current() == RawStreamItem::Nothing {
+            return illegal_operation(
+                "called `read_str` when reader was not positioned on a value",
+            );
+        }
+        self.raw_reader.read_str()
+    }
+
     fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
     where
         F: FnOnce(&str) -> U,
@@ -711,6 +724,8 @@ impl<R: RawReader> StreamReader for SystemReader<R> {
             fn is_null(&self) -> bool;
             fn ion_version(&self) -> (u8, u8);
             fn ion_type(&self) -> Option<IonType>;
+            fn has_annotations(&self) -> bool;
+            fn number_of_annotations(&self) -> usize;
             fn read_null(&mut self) -> IonResult<IonType>;
             fn read_bool(&mut self) -> IonResult<bool>;
             fn read_integer(&mut self) -> IonResult<Integer>;
@@ -722,9 +737,14 @@ impl<R: RawReader> StreamReader for SystemReader<R> {
             fn map_blob<F, U>(&mut self, f: F) -> IonResult<U> where F: FnOnce(&[u8]) -> U;
             fn read_clob(&mut self) -> IonResult<Vec<u8>>;
             fn map_clob<F, U>(&mut self, f: F) -> IonResult<U> where F: FnOnce(&[u8]) -> U;
+            fn read_blob_chunks(&mut self, chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>) -> IonResult<usize>;
+            fn read_clob_chunks(&mut self, chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>) -> IonResult<usize>;
             fn read_timestamp(&mut self) -> IonResult<Timestamp>;
             fn depth(&self) -> usize;
             fn parent_type(&self) -> Option<IonType>;
+            fn position(&self) -> Position;
+            fn value_end_offset(&self) -> Option<usize>;
+            fn declared_value_length(&self) -> Option<usize>;
         }
     }
 }
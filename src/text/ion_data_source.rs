@@ -58,6 +58,14 @@ impl<T: Read> ToIonDataSource for BufReader<T> {
     }
 }
 
+impl ToIonDataSource for io::Stdin {
+    type DataSource = BufReader<Self>;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        BufReader::new(self)
+    }
+}
+
 impl ToIonDataSource for File {
     type DataSource = BufReader<Self>;
 
@@ -1,10 +1,12 @@
 pub mod ion_data_source;
 mod parent_container;
 pub(crate) mod parse_result;
-pub(in crate::text) mod parsers;
+pub(crate) mod parsers;
+pub mod raw_json_reader;
 pub mod raw_text_reader;
 pub mod raw_text_writer;
 mod text_buffer;
 pub mod text_formatter;
-mod text_value;
+pub(crate) mod text_value;
 pub(crate) mod text_writer;
+pub mod tokenizer;
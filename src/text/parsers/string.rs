@@ -8,7 +8,7 @@ use crate::text::text_value::TextValue;
 use nom::branch::alt;
 use nom::bytes::streaming::tag;
 use nom::character::streaming::char;
-use nom::combinator::{map, not, opt, peek};
+use nom::combinator::{map, not, opt, peek, recognize};
 use nom::multi::{fold_many0, many1};
 use nom::sequence::{delimited, terminated};
 use nom::Err::Incomplete;
@@ -47,6 +47,13 @@ fn long_string(input: &str) -> IonParseResult<TextValue> {
     )(input)
 }
 
+/// Matches a single `'''`-delimited segment of a long string, returning its text (including the
+/// delimiters). Unlike [parse_string], this does not match any whitespace, comments, or
+/// additional segments that follow it.
+pub(crate) fn long_string_segment(input: &str) -> IonParseResult<&str> {
+    recognize(delimited(tag("'''"), long_string_body, tag("'''")))(input)
+}
+
 /// Matches the body of a long string fragment. (The `hello` in `'''hello'''`.)
 fn long_string_body(input: &str) -> IonParseResult<String> {
     fold_many0(long_string_fragment, String::new, |mut string, fragment| {
@@ -38,9 +38,11 @@ where
     // If it doesn't start with a slash, it's not a match. Return a non-fatal error.
     let (remaining, _slash) = char('\\')(input).upgrade()?;
     // If the provided parser doesn't match what comes next, it's not a valid escape.
-    // Return a fatal error.
+    // Return a fatal error. If the escape is cut off by the end of the buffer, more input may
+    // complete it.
     match parser.parse(remaining) {
         Ok((remaining, string_fragment)) => Ok((remaining, string_fragment)),
+        Err(nom::Err::Incomplete(needed)) => Err(nom::Err::Incomplete(needed)),
         Err(e) => fatal_parse_error(remaining, format!("could not parse {}: {}", label, e)),
     }
 }
@@ -186,19 +188,25 @@ fn complete_surrogate_pair<'a>(
     high_surrogate_hex_digits: &'a str,
     high_surrogate_number_value: u32,
 ) -> IonParseResult<'a, char> {
-    let (_, (input_after_low_surrogate, low_surrogate_hex_digits)) =
-        // Look for a `\` followed by a \uXXXX or \UXXXXXXXX escape
-        preceded(
-            char('\\'),
-            alt((
-                escaped_char_unicode_4_digit_hex,
-                escaped_char_unicode_8_digit_hex,
-            )),
-        )(input_after_high_surrogate)
-        .or_fatal_parse_error(
-            input_after_high_surrogate,
-            "encountered an incomplete surrogate pair",
-        )?;
+    // Look for a `\` followed by a \uXXXX or \UXXXXXXXX escape
+    let low_surrogate = preceded(
+        char('\\'),
+        alt((
+            escaped_char_unicode_4_digit_hex,
+            escaped_char_unicode_8_digit_hex,
+        )),
+    )(input_after_high_surrogate);
+    let (input_after_low_surrogate, low_surrogate_hex_digits) = match low_surrogate {
+        Ok(matched) => matched,
+        // The low surrogate may be cut off by the end of the buffer.
+        Err(nom::Err::Incomplete(needed)) => return Err(nom::Err::Incomplete(needed)),
+        Err(error) => {
+            return fatal_parse_error(
+                input_after_high_surrogate,
+                format!("encountered an incomplete surrogate pair: {:?}", error),
+            )
+        }
+    };
 
     // Convert the second set of hex digits to a `u32`.
     let low_surrogate_number_value = u32::from_str_radix(low_surrogate_hex_digits, 16)
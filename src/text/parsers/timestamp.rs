@@ -2,8 +2,7 @@ use std::str::FromStr;
 
 use nom::branch::alt;
 use nom::bytes::streaming::tag;
-use nom::character::complete::digit1;
-use nom::character::streaming::{char, one_of, satisfy};
+use nom::character::streaming::{char, digit1, one_of, satisfy};
 use nom::combinator::{map, opt, recognize};
 use nom::sequence::{pair, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
@@ -15,7 +14,7 @@ use crate::text::parse_result::{
 use crate::text::parsers::{stop_character, trim_zeros_and_parse_i32, trim_zeros_and_parse_u32};
 use crate::text::text_value::TextValue;
 use crate::types::decimal::Decimal;
-use crate::types::timestamp::{FractionalSecondSetter, Timestamp};
+use crate::types::timestamp::{FractionalSecondSetter, LeapSecondPolicy, Timestamp};
 
 /// Matches the text representation of a timestamp value and returns the resulting Timestamp
 /// as a [TextValue::Timestamp].
@@ -98,7 +97,9 @@ fn timestamp_precision_ymd_hms(input: &str) -> IonParseResult<TextValue> {
         ),
         stop_character,
     )(input)?;
-    let builder = Timestamp::with_ymd(year, month, day).with_hms(hour, minute, second);
+    let builder = Timestamp::with_ymd(year, month, day)
+        .with_hms(hour, minute, second)
+        .with_leap_second_policy(LeapSecondPolicy::Clamp);
     let timestamp = if let Some(minutes) = offset {
         builder.build_at_offset(minutes)
     } else {
@@ -106,7 +107,7 @@ fn timestamp_precision_ymd_hms(input: &str) -> IonParseResult<TextValue> {
     }
     .or_fatal_parse_error(input, "could not create timestamp")?
     .1;
-    Ok((remaining, TextValue::Timestamp(timestamp)))
+    Ok((remaining, second_precision_value(timestamp, second)))
 }
 
 /// Matches the text representation of a timestamp value with fractional second precision
@@ -128,7 +129,9 @@ fn timestamp_precision_ymd_hms_fractional(input: &str) -> IonParseResult<TextVal
             ),
             stop_character,
         )(input)?;
-    let builder = Timestamp::with_ymd(year, month, day).with_hms(hour, minute, second);
+    let builder = Timestamp::with_ymd(year, month, day)
+        .with_hms(hour, minute, second)
+        .with_leap_second_policy(LeapSecondPolicy::Clamp);
     let (_, builder) = assign_fractional_seconds(fractional_text, builder)?;
     let timestamp = if let Some(minutes) = offset {
         builder.build_at_offset(minutes)
@@ -137,7 +140,17 @@ fn timestamp_precision_ymd_hms_fractional(input: &str) -> IonParseResult<TextVal
     }
     .or_fatal_parse_error(input, "could not create timestamp")?
     .1;
-    Ok((remaining, TextValue::Timestamp(timestamp)))
+    Ok((remaining, second_precision_value(timestamp, second)))
+}
+
+/// Timestamps with a leap second are built with the second clamped to `:59`. The reader decides
+/// what to do with them according to its [LeapSecondPolicy].
+fn second_precision_value(timestamp: Timestamp, second: u32) -> TextValue {
+    if second == 60 {
+        TextValue::LeapSecondTimestamp(timestamp)
+    } else {
+        TextValue::Timestamp(timestamp)
+    }
 }
 
 /// Parses the fractional seconds and stores it in the [FractionalSecondSetter].
@@ -225,15 +238,20 @@ fn hour_and_minute(input: &str) -> IonParseResult<(u32, u32)> {
     Ok((remaining, (hours, minutes)))
 }
 
-/// Matches a ':' followed by a two-digit second field. (`:44`)
+/// Matches a ':' followed by a two-digit second field. (`:44`) A leap second (`:60`) is also
+/// matched.
 fn second(input: &str) -> IonParseResult<u32> {
-    let (remaining, seconds) =
-        preceded(tag(":"), recognize(pair(one_of("012345"), digit)))(input).upgrade()?;
+    let (remaining, seconds) = preceded(
+        tag(":"),
+        alt((recognize(pair(one_of("012345"), digit)), tag("60"))),
+    )(input)
+    .upgrade()?;
     let (_, seconds) = trim_zeros_and_parse_u32(seconds, "seconds")?;
     Ok((remaining, seconds))
 }
 
-/// Recognizes a decimal point followed by one or more digits.
+/// Recognizes a decimal point followed by one or more digits. Like the rest of the timestamp
+/// parsers, this returns `Incomplete` (rather than an error) if the input ends partway through.
 fn recognize_fractional_seconds(input: &str) -> IonParseResult<&str> {
     preceded(tag("."), digit1)(input).upgrade()
 }
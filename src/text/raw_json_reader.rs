@@ -0,0 +1,845 @@
+use std::str::FromStr;
+
+use num_bigint::{BigInt, BigUint};
+
+use crate::position::Position;
+use crate::raw_reader::RawStreamItem;
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonResult};
+use crate::stream_reader::StreamReader;
+use crate::types::coefficient::{Coefficient, Sign};
+use crate::types::decimal::Decimal;
+use crate::types::integer::Integer;
+use crate::types::magnitude::Magnitude;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+
+/// Determines which Ion type a JSON reader uses to represent numbers that have a fractional part
+/// or an exponent. Numbers without either are always read as Ion integers.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum JsonNumberPolicy {
+    /// Read the number as a decimal, which preserves its exact value and precision. This is the
+    /// default.
+    #[default]
+    Decimal,
+    /// Read the number as a 64-bit float.
+    Float,
+    /// Read numbers that have an exponent as floats and all others as decimals, which is how
+    /// they would be read if the JSON were parsed as Ion text.
+    IonText,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Boolean(bool),
+    Integer(Integer),
+    Float(f64),
+    Decimal(Decimal),
+    String(String),
+    List,
+    Struct,
+}
+
+impl JsonValue {
+    fn ion_type(&self) -> IonType {
+        match self {
+            JsonValue::Null => IonType::Null,
+            JsonValue::Boolean(_) => IonType::Boolean,
+            JsonValue::Integer(_) => IonType::Integer,
+            JsonValue::Float(_) => IonType::Float,
+            JsonValue::Decimal(_) => IonType::Decimal,
+            JsonValue::String(_) => IonType::String,
+            JsonValue::List => IonType::List,
+            JsonValue::Struct => IonType::Struct,
+        }
+    }
+}
+
+// A value in the document, stored in a flat list in the order that it appeared in the input.
+// A container's children immediately follow it.
+#[derive(Debug, Clone)]
+struct Node {
+    value: JsonValue,
+    field_name: Option<String>,
+    offset: usize,
+    // The index of the first node that follows this one and all of its descendants.
+    end: usize,
+}
+
+/// A [RawReader](crate::RawReader) that reads JSON as though it were Ion.
+///
+/// JSON `null`, booleans, strings, arrays, and objects are read as Ion `null`, bools, strings,
+/// lists, and structs. Numbers without a fractional part or an exponent are read as integers of
+/// any size; other numbers are converted as specified by the reader's [JsonNumberPolicy]. Object
+/// fields are visited in the order in which they appear in the input, including duplicates. The
+/// input may contain any number of JSON values separated by whitespace, as in the JSON Lines
+/// format.
+///
+/// The reader parses the complete input when it is constructed. Most applications should use a
+/// [ReaderBuilder](crate::ReaderBuilder) with the [Json](crate::ReaderFormat::Json) format rather
+/// than constructing this reader directly.
+pub struct RawJsonReader {
+    data: Vec<u8>,
+    nodes: Vec<Node>,
+    // Any error encountered while parsing, which `next()` reports once the values preceding it
+    // have been read.
+    error: Option<(usize, String)>,
+    // The index of the next node to read at the current depth.
+    index: usize,
+    current: RawStreamItem,
+    value: Option<usize>,
+    parents: Vec<usize>,
+}
+
+impl RawJsonReader {
+    pub fn new(data: Vec<u8>) -> RawJsonReader {
+        RawJsonReader::with_number_policy(data, JsonNumberPolicy::default())
+    }
+
+    pub fn with_number_policy(data: Vec<u8>, policy: JsonNumberPolicy) -> RawJsonReader {
+        let mut parser = Parser {
+            data: &data,
+            offset: 0,
+            policy,
+            nodes: Vec::new(),
+            open_containers: Vec::new(),
+        };
+        let error = parser.parse().err().map(|message| (parser.offset, message));
+        // Containers that were still open when an error was found end with the last node that
+        // was parsed, so that the error is reported when the reader gets there.
+        for container in parser.open_containers.drain(..) {
+            parser.nodes[container].end = parser.nodes.len();
+        }
+        let nodes = parser.nodes;
+        RawJsonReader {
+            data,
+            nodes,
+            error,
+            index: 0,
+            current: RawStreamItem::Nothing,
+            value: None,
+            parents: Vec::new(),
+        }
+    }
+
+    fn position_of(&self, offset: usize) -> Position {
+        let preceding = &self.data[..offset.min(self.data.len())];
+        let line = preceding.iter().filter(|&&byte| byte == b'\n').count() + 1;
+        let line_start = preceding
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |newline| newline + 1);
+        let column = String::from_utf8_lossy(&preceding[line_start..])
+            .chars()
+            .count()
+            + 1;
+        Position::with_offset(offset).with_line_and_column(line, column)
+    }
+
+    fn current_value(&self) -> Option<&JsonValue> {
+        self.value.map(|index| &self.nodes[index].value)
+    }
+
+    fn advance(&mut self) -> IonResult<RawStreamItem> {
+        if let Some(index) = self.value.take() {
+            self.index = self.nodes[index].end;
+        }
+        let end = match self.parents.last() {
+            Some(&parent) => self.nodes[parent].end,
+            None => self.nodes.len(),
+        };
+        if self.index >= end {
+            // The parser stops at the first error, so it is only reached once every node that
+            // precedes it has been read.
+            if let Some((offset, message)) = &self.error {
+                if self.index == self.nodes.len() {
+                    return Err(decoding_error_raw(message.as_str())
+                        .with_position(self.position_of(*offset)));
+                }
+            }
+            self.current = RawStreamItem::Nothing;
+            return Ok(self.current);
+        }
+        let node = &self.nodes[self.index];
+        self.value = Some(self.index);
+        self.current = match node.value {
+            JsonValue::Null => RawStreamItem::Null(IonType::Null),
+            ref value => RawStreamItem::Value(value.ion_type()),
+        };
+        Ok(self.current)
+    }
+
+    fn expect_value(&self, ion_type: IonType) -> IonResult<&JsonValue> {
+        match self.current_value() {
+            Some(value) if value.ion_type() == ion_type && *value != JsonValue::Null => Ok(value),
+            _ => illegal_operation(format!(
+                "expected the reader to be positioned on a non-null {}, but the current item is {:?}",
+                ion_type, self.current
+            )),
+        }
+    }
+
+    fn read_text(&self) -> IonResult<&str> {
+        match self.expect_value(IonType::String)? {
+            JsonValue::String(text) => Ok(text.as_str()),
+            _ => unreachable!("expect_value() checked the value's type"),
+        }
+    }
+}
+
+// A parser that converts a JSON document into a list of nodes. Errors are reported as messages;
+// the reader attaches the parser's offset to them.
+struct Parser<'a> {
+    data: &'a [u8],
+    offset: usize,
+    policy: JsonNumberPolicy,
+    nodes: Vec<Node>,
+    // The indexes of the nodes for the containers that are currently open.
+    open_containers: Vec<usize>,
+}
+
+type ParseResult<T> = Result<T, String>;
+
+impl<'a> Parser<'a> {
+    fn parse(&mut self) -> ParseResult<()> {
+        if self.data.starts_with(b"\xEF\xBB\xBF") {
+            self.offset = 3;
+        }
+        loop {
+            self.skip_whitespace();
+            let parent = self
+                .open_containers
+                .last()
+                .map(|&index| &self.nodes[index].value);
+            let field_name = match parent {
+                None if self.offset == self.data.len() => return Ok(()),
+                Some(JsonValue::Struct) => {
+                    if self.peek() != Some(b'"') {
+                        return Err("expected a field name".to_string());
+                    }
+                    let field_name = self.parse_string()?;
+                    self.skip_whitespace();
+                    self.expect(b':')?;
+                    self.skip_whitespace();
+                    Some(field_name)
+                }
+                _ => None,
+            };
+
+            let offset = self.offset;
+            let (value, closing_delimiter) = match self.peek() {
+                Some(b'[') => (JsonValue::List, Some(b']')),
+                Some(b'{') => (JsonValue::Struct, Some(b'}')),
+                _ => (self.parse_scalar()?, None),
+            };
+            let index = self.nodes.len();
+            self.nodes.push(Node {
+                value,
+                field_name,
+                offset,
+                end: index + 1,
+            });
+            if let Some(closing_delimiter) = closing_delimiter {
+                self.offset += 1;
+                self.skip_whitespace();
+                if self.peek() == Some(closing_delimiter) {
+                    self.offset += 1;
+                } else {
+                    self.open_containers.push(index);
+                    continue;
+                }
+            }
+
+            // The value is complete. Close any containers that end after it.
+            while let Some(&container) = self.open_containers.last() {
+                self.skip_whitespace();
+                match (self.peek(), &self.nodes[container].value) {
+                    (Some(b','), _) => {
+                        self.offset += 1;
+                        break;
+                    }
+                    (Some(b']'), JsonValue::List) | (Some(b'}'), JsonValue::Struct) => {
+                        self.offset += 1;
+                        self.nodes[container].end = self.nodes.len();
+                        self.open_containers.pop();
+                    }
+                    (_, JsonValue::List) => return Err("expected ',' or ']'".to_string()),
+                    _ => return Err("expected ',' or '}'".to_string()),
+                }
+            }
+            // Top-level values must be separated by whitespace.
+            if self.open_containers.is_empty()
+                && !matches!(self.peek(), None | Some(b' ' | b'\t' | b'\n' | b'\r'))
+            {
+                return Err("expected whitespace between top-level values".to_string());
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.offset).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> ParseResult<()> {
+        if self.peek() != Some(byte) {
+            return Err(format!("expected '{}'", byte as char));
+        }
+        self.offset += 1;
+        Ok(())
+    }
+
+    // Returns an error unless the parser is at the end of the input or at a byte that can follow
+    // a keyword or number: whitespace, `,`, `]`, or `}`.
+    fn expect_delimiter(&self) -> ParseResult<()> {
+        match self.peek() {
+            None | Some(b' ' | b'\t' | b'\n' | b'\r' | b',' | b']' | b'}') => Ok(()),
+            Some(_) => Err("expected a delimiter after the value".to_string()),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.offset += 1;
+        }
+    }
+
+    fn parse_scalar(&mut self) -> ParseResult<JsonValue> {
+        for (keyword, value) in [
+            ("null", JsonValue::Null),
+            ("true", JsonValue::Boolean(true)),
+            ("false", JsonValue::Boolean(false)),
+        ] {
+            if self.data[self.offset..].starts_with(keyword.as_bytes()) {
+                self.offset += keyword.len();
+                self.expect_delimiter()?;
+                return Ok(value);
+            }
+        }
+        match self.peek() {
+            Some(b'"') => self.parse_string().map(JsonValue::String),
+            Some(b'-' | b'0'..=b'9') => {
+                let value = self.parse_number()?;
+                self.expect_delimiter()?;
+                Ok(value)
+            }
+            Some(_) => Err("expected a JSON value".to_string()),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn digits(&mut self) -> &'a str {
+        let start = self.offset;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.offset += 1;
+        }
+        // Digits are always valid UTF-8.
+        std::str::from_utf8(&self.data[start..self.offset]).unwrap()
+    }
+
+    fn parse_number(&mut self) -> ParseResult<JsonValue> {
+        let start = self.offset;
+        let is_negative = self.peek() == Some(b'-');
+        if is_negative {
+            self.offset += 1;
+        }
+        let integer_digits = self.digits();
+        if integer_digits.is_empty()
+            || (integer_digits.starts_with('0') && integer_digits.len() > 1)
+        {
+            return Err("invalid number".to_string());
+        }
+        let mut fraction_digits = None;
+        if self.peek() == Some(b'.') {
+            self.offset += 1;
+            let digits = self.digits();
+            if digits.is_empty() {
+                return Err("expected digits after the decimal point".to_string());
+            }
+            fraction_digits = Some(digits);
+        }
+        let mut exponent = None;
+        if let Some(b'e' | b'E') = self.peek() {
+            self.offset += 1;
+            let exponent_start = self.offset;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.offset += 1;
+            }
+            if self.digits().is_empty() {
+                return Err("expected digits in the exponent".to_string());
+            }
+            exponent = Some(std::str::from_utf8(&self.data[exponent_start..self.offset]).unwrap());
+        }
+        let text = std::str::from_utf8(&self.data[start..self.offset]).unwrap();
+
+        if fraction_digits.is_none() && exponent.is_none() {
+            let value = match i64::from_str(text) {
+                Ok(value) => Integer::I64(value),
+                Err(_) => Integer::BigInt(BigInt::from_str(text).unwrap()),
+            };
+            return Ok(JsonValue::Integer(value));
+        }
+        match (self.policy, exponent) {
+            (JsonNumberPolicy::Float, _) | (JsonNumberPolicy::IonText, Some(_)) => {
+                Ok(JsonValue::Float(f64::from_str(text).unwrap()))
+            }
+            _ => {
+                let fraction_digits = fraction_digits.unwrap_or("");
+                let magnitude_text = format!("{}{}", integer_digits, fraction_digits);
+                let magnitude = match u64::from_str(&magnitude_text) {
+                    Ok(value) => Magnitude::U64(value),
+                    Err(_) => Magnitude::BigUInt(BigUint::from_str(&magnitude_text).unwrap()),
+                };
+                let sign = if is_negative {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                };
+                let too_large = || "the number's exponent is too large".to_string();
+                let exponent = match exponent {
+                    Some(exponent) => {
+                        i64::from_str(exponent.trim_start_matches('+')).map_err(|_| too_large())?
+                    }
+                    None => 0,
+                };
+                let exponent = exponent
+                    .checked_sub(fraction_digits.len() as i64)
+                    .ok_or_else(too_large)?;
+                Ok(JsonValue::Decimal(Decimal::new(
+                    Coefficient::new(sign, magnitude),
+                    exponent,
+                )))
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> ParseResult<String> {
+        self.expect(b'"')?;
+        let mut text = String::new();
+        loop {
+            let start = self.offset;
+            while let Some(byte) = self.peek() {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+                self.offset += 1;
+            }
+            let segment = std::str::from_utf8(&self.data[start..self.offset]).map_err(|_| {
+                self.offset = start;
+                "invalid UTF-8 in string".to_string()
+            })?;
+            text.push_str(segment);
+            match self.peek() {
+                Some(b'"') => {
+                    self.offset += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    self.offset += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{08}',
+                        Some(b'f') => '\u{0C}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.offset += 1;
+                            text.push(self.parse_unicode_escape()?);
+                            continue;
+                        }
+                        _ => return Err("invalid escape sequence".to_string()),
+                    };
+                    self.offset += 1;
+                    text.push(escaped);
+                }
+                Some(_) => return Err("unescaped control character in string".to_string()),
+                None => return Err("unexpected end of input in string".to_string()),
+            }
+        }
+    }
+
+    fn hex_code_unit(&mut self) -> ParseResult<u32> {
+        // `from_str_radix` would also accept a leading `+`, so each digit is checked first.
+        let digits = self
+            .data
+            .get(self.offset..self.offset + 4)
+            .filter(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| "expected four hex digits".to_string())?;
+        self.offset += 4;
+        Ok(digits)
+    }
+
+    // Parses the hex digits following a `\u`, including the second half of a surrogate pair.
+    fn parse_unicode_escape(&mut self) -> ParseResult<char> {
+        let high = self.hex_code_unit()?;
+        let code_point = match high {
+            0xD800..=0xDBFF => {
+                if !self.data[self.offset..].starts_with(b"\\u") {
+                    return Err("unpaired surrogate in string".to_string());
+                }
+                self.offset += 2;
+                let low = self.hex_code_unit()?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err("unpaired surrogate in string".to_string());
+                }
+                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            }
+            code_point => code_point,
+        };
+        char::from_u32(code_point).ok_or_else(|| "unpaired surrogate in string".to_string())
+    }
+}
+
+impl StreamReader for RawJsonReader {
+    type Item = RawStreamItem;
+    type Symbol = RawSymbolToken;
+
+    fn ion_version(&self) -> (u8, u8) {
+        (1, 0)
+    }
+
+    fn next(&mut self) -> IonResult<RawStreamItem> {
+        self.advance()
+    }
+
+    fn current(&self) -> RawStreamItem {
+        self.current
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        self.current_value().map(JsonValue::ion_type)
+    }
+
+    fn annotation(&self, index: usize) -> IonResult<RawSymbolToken> {
+        illegal_operation(format!(
+            "annotation index {} is out of bounds; JSON values have no annotations",
+            index
+        ))
+    }
+
+    fn number_of_annotations(&self) -> usize {
+        0
+    }
+
+    fn field_name(&self) -> IonResult<RawSymbolToken> {
+        match self.value.and_then(|index| self.nodes[index].field_name.as_ref()) {
+            Some(field_name) => Ok(RawSymbolToken::Text(field_name.clone())),
+            None => illegal_operation(format!(
+                "field_name() can only be called when the reader is positioned inside a struct; current parent: {:?}",
+                self.parent_type()
+            )),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self.current_value(), Some(JsonValue::Null))
+    }
+
+    fn read_null(&mut self) -> IonResult<IonType> {
+        match self.current {
+            RawStreamItem::Null(ion_type) => Ok(ion_type),
+            _ => illegal_operation("the reader is not positioned on a null"),
+        }
+    }
+
+    fn read_bool(&mut self) -> IonResult<bool> {
+        match self.expect_value(IonType::Boolean)? {
+            JsonValue::Boolean(value) => Ok(*value),
+            _ => unreachable!("expect_value() checked the value's type"),
+        }
+    }
+
+    fn read_i64(&mut self) -> IonResult<i64> {
+        match self.read_integer()? {
+            Integer::I64(value) => Ok(value),
+            Integer::BigInt(_) => decoding_error("integer was too large to fit in an i64"),
+        }
+    }
+
+    fn read_integer(&mut self) -> IonResult<Integer> {
+        match self.expect_value(IonType::Integer)? {
+            JsonValue::Integer(value) => Ok(value.clone()),
+            _ => unreachable!("expect_value() checked the value's type"),
+        }
+    }
+
+    fn read_f32(&mut self) -> IonResult<f32> {
+        Ok(self.read_f64()? as f32)
+    }
+
+    fn read_f64(&mut self) -> IonResult<f64> {
+        match self.expect_value(IonType::Float)? {
+            JsonValue::Float(value) => Ok(*value),
+            _ => unreachable!("expect_value() checked the value's type"),
+        }
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Decimal> {
+        match self.expect_value(IonType::Decimal)? {
+            JsonValue::Decimal(value) => Ok(value.clone()),
+            _ => unreachable!("expect_value() checked the value's type"),
+        }
+    }
+
+    fn read_string(&mut self) -> IonResult<String> {
+        self.read_str().map(String::from)
+    }
+
+    fn read_str(&mut self) -> IonResult<&str> {
+        self.read_text()
+    }
+
+    fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&str) -> U,
+    {
+        self.read_text().map(f)
+    }
+
+    fn map_string_bytes<F, U>(&mut self, f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        self.read_text().map(|text| f(text.as_bytes()))
+    }
+
+    fn read_symbol(&mut self) -> IonResult<RawSymbolToken> {
+        illegal_operation("JSON does not contain symbol values")
+    }
+
+    fn read_blob(&mut self) -> IonResult<Vec<u8>> {
+        illegal_operation("JSON does not contain blob values")
+    }
+
+    fn map_blob<F, U>(&mut self, _f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        illegal_operation("JSON does not contain blob values")
+    }
+
+    fn read_clob(&mut self) -> IonResult<Vec<u8>> {
+        illegal_operation("JSON does not contain clob values")
+    }
+
+    fn map_clob<F, U>(&mut self, _f: F) -> IonResult<U>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> U,
+    {
+        illegal_operation("JSON does not contain clob values")
+    }
+
+    fn read_blob_chunks(
+        &mut self,
+        _chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        illegal_operation("JSON does not contain blob values")
+    }
+
+    fn read_clob_chunks(
+        &mut self,
+        _chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        illegal_operation("JSON does not contain clob values")
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Timestamp> {
+        illegal_operation("JSON does not contain timestamp values")
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        let index = match self.value {
+            Some(index) if self.nodes[index].value.ion_type().is_container() => index,
+            _ => {
+                return illegal_operation(format!(
+                    "cannot step into the current item: {:?}",
+                    self.current
+                ))
+            }
+        };
+        self.parents.push(index);
+        self.index = index + 1;
+        self.value = None;
+        self.current = RawStreamItem::Nothing;
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        let parent = match self.parents.pop() {
+            Some(parent) => parent,
+            None => return illegal_operation("Cannot step out of the top level."),
+        };
+        self.index = self.nodes[parent].end;
+        self.value = None;
+        self.current = RawStreamItem::Nothing;
+        Ok(())
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        self.parents
+            .last()
+            .map(|&parent| self.nodes[parent].value.ion_type())
+    }
+
+    fn depth(&self) -> usize {
+        self.parents.len()
+    }
+
+    fn position(&self) -> Position {
+        let offset = match self.value {
+            Some(index) => self.nodes[index].offset,
+            None => self
+                .nodes
+                .get(self.index)
+                .map_or(self.data.len(), |node| node.offset),
+        };
+        self.position_of(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw_reader::RawStreamItem::*;
+    use rstest::*;
+
+    fn reader(json: &str) -> RawJsonReader {
+        RawJsonReader::new(json.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn read_values_in_order() -> IonResult<()> {
+        let mut reader = reader(
+            r#"{"b": [1, null, true, "x\né😀"], "a": {}, "b": 18446744073709551616}
+               []"#,
+        );
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::List));
+        assert_eq!(reader.field_name()?, RawSymbolToken::Text("b".to_string()));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 1);
+        assert_eq!(reader.next()?, Null(IonType::Null));
+        assert_eq!(reader.next()?, Value(IonType::Boolean));
+        assert!(reader.read_bool()?);
+        assert_eq!(reader.next()?, Value(IonType::String));
+        assert_eq!(reader.read_str()?, "x\n\u{e9}\u{1F600}");
+        assert_eq!(reader.next()?, Nothing);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Value(IonType::Struct));
+        assert_eq!(reader.field_name()?, RawSymbolToken::Text("a".to_string()));
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.field_name()?, RawSymbolToken::Text("b".to_string()));
+        assert_eq!(
+            reader.read_integer()?,
+            Integer::BigInt(BigInt::from(u64::MAX) + 1)
+        );
+        assert_eq!(reader.next()?, Nothing);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Value(IonType::List));
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::decimal(JsonNumberPolicy::Decimal, IonType::Decimal, IonType::Decimal)]
+    #[case::float(JsonNumberPolicy::Float, IonType::Float, IonType::Float)]
+    #[case::ion_text(JsonNumberPolicy::IonText, IonType::Decimal, IonType::Float)]
+    fn number_policies(
+        #[case] policy: JsonNumberPolicy,
+        #[case] fraction_type: IonType,
+        #[case] exponent_type: IonType,
+    ) -> IonResult<()> {
+        let mut reader = RawJsonReader::with_number_policy(b"-7 -1.50 25e-1".to_vec(), policy);
+        assert_eq!(reader.next()?, Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, -7);
+        assert_eq!(reader.next()?, Value(fraction_type));
+        if fraction_type == IonType::Decimal {
+            assert_eq!(reader.read_decimal()?, Decimal::new(-150, -2));
+        } else {
+            assert_eq!(reader.read_f64()?, -1.5);
+        }
+        assert_eq!(reader.next()?, Value(exponent_type));
+        if exponent_type == IonType::Decimal {
+            assert_eq!(reader.read_decimal()?, Decimal::new(25, -1));
+        } else {
+            assert_eq!(reader.read_f64()?, 2.5);
+        }
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::trailing_comma("[1, 2,]")]
+    #[case::unterminated_list("[1, 2")]
+    #[case::missing_colon(r#"{"a" 1}"#)]
+    #[case::unquoted_field_name("{a: 1}")]
+    #[case::leading_zero("012")]
+    #[case::bare_decimal_point("1.")]
+    #[case::invalid_escape(r#""\x""#)]
+    #[case::unpaired_surrogate(r#""\ud83d""#)]
+    #[case::ion_syntax("'foo'")]
+    #[case::adjacent_keywords("truefalse")]
+    #[case::keyword_prefix("nulls")]
+    #[case::number_then_keyword("[1true]")]
+    #[case::adjacent_top_level_values("[1]2")]
+    #[case::adjacent_top_level_strings(r#""a""b""#)]
+    #[case::top_level_comma("1,2")]
+    #[case::signed_unicode_escape(r#""\u+041""#)]
+    #[case::short_unicode_escape(r#""\u41""#)]
+    #[case::exponent_overflow("1.5e-9223372036854775808")]
+    fn invalid_json(#[case] json: &str) {
+        let mut reader = reader(json);
+        let result: IonResult<()> = (|| loop {
+            match reader.next()? {
+                Value(ion_type) if ion_type.is_container() => reader.step_in()?,
+                Nothing if reader.depth() > 0 => reader.step_out()?,
+                Nothing => return Ok(()),
+                _ => {}
+            }
+        })();
+        assert!(result.is_err(), "{:?} was read without an error", json);
+    }
+
+    #[test]
+    fn delimited_values() -> IonResult<()> {
+        let mut json = reader("true\tfalse\n[1,null]{\"a\":2}");
+        assert_eq!(json.next()?, Value(IonType::Boolean));
+        assert_eq!(json.next()?, Value(IonType::Boolean));
+        assert_eq!(json.next()?, Value(IonType::List));
+        // The struct isn't separated from the list by whitespace.
+        assert!(json.next().is_err());
+
+        let mut json = reader(r#"[1,null] {"a":2} "\u0041\u00e9""#);
+        assert_eq!(json.next()?, Value(IonType::List));
+        assert_eq!(json.next()?, Value(IonType::Struct));
+        assert_eq!(json.next()?, Value(IonType::String));
+        assert_eq!(json.read_str()?, "A\u{e9}");
+        assert_eq!(json.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn errors_have_positions() {
+        let mut reader = reader("1\n[true, nul]");
+        assert_eq!(reader.next(), Ok(Value(IonType::Integer)));
+        assert_eq!(reader.next(), Ok(Value(IonType::List)));
+        reader.step_in().unwrap();
+        assert_eq!(reader.next(), Ok(Value(IonType::Boolean)));
+        let error = reader.next().unwrap_err();
+        assert_eq!(
+            error.position(),
+            Some(Position::with_offset(9).with_line_and_column(2, 8))
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use std::fmt::Display;
+use std::ops::Range;
 
 use nom::Err::{Error, Failure, Incomplete};
 
+use crate::position::Position;
 use crate::raw_reader::RawStreamItem;
 use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::{
@@ -11,6 +13,7 @@ use crate::stream_reader::StreamReader;
 use crate::text::ion_data_source::ToIonDataSource;
 use crate::text::parent_container::ParentContainer;
 use crate::text::parse_result::IonParseResult;
+use crate::text::parsers::comments::whitespace_or_comments;
 use crate::text::parsers::containers::{
     list_delimiter, list_value_or_end, s_expression_delimiter, s_expression_value_or_end,
     struct_delimiter, struct_field_name_or_end, struct_field_value,
@@ -20,11 +23,24 @@ use crate::text::text_buffer::TextBuffer;
 use crate::text::text_value::{AnnotatedTextValue, TextValue};
 use crate::types::decimal::Decimal;
 use crate::types::integer::Integer;
-use crate::types::timestamp::Timestamp;
+use crate::types::timestamp::{LeapSecondPolicy, Timestamp};
 use crate::IonType;
 
 const INITIAL_PARENTS_CAPACITY: usize = 16;
 
+/// Determines how a [RawTextReader] handles input that is not valid UTF-8.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Utf8Policy {
+    /// Fail to read any line that contains invalid UTF-8. This is the default.
+    #[default]
+    Strict,
+    /// Replace each invalid UTF-8 sequence with U+FFFD (the Unicode replacement character) and
+    /// continue reading. Because replacement characters may not have the same length as the
+    /// sequences they replace, byte offsets reported by the reader after a replacement refer to
+    /// the decoded text rather than the original input.
+    Lossy,
+}
+
 pub struct RawTextReader<T: ToIonDataSource> {
     buffer: TextBuffer<T::DataSource>,
     // If the reader is not positioned over a value inside a struct, this is None.
@@ -36,9 +52,20 @@ pub struct RawTextReader<T: ToIonDataSource> {
     //     Some(major_version, minor_version)
     // Otherwise, it is None.
     current_ivm: Option<(u8, u8)>,
+    // The location at which the text of the most recently matched item began.
+    item_position: Position,
+    // The offset at which the reader began looking for the most recent top-level item.
+    top_level_offset: usize,
+    // The offset at which the text of the most recent top-level item ended.
+    top_level_value_end: usize,
     bytes_read: usize,
     is_eof: bool,
     parents: Vec<ParentContainer>,
+    // The version declared by the most recent IVM, or (1, 0) if none has been encountered.
+    ion_version: (u8, u8),
+    // Whether `$ion_1_1` version markers are accepted. See [RawTextReader::with_ion_1_1].
+    ion_1_1_enabled: bool,
+    leap_second_policy: LeapSecondPolicy,
 }
 
 /// Represents the final outcome of a [RawTextReader]'s attempt to parse the next value in the stream.
@@ -59,14 +86,63 @@ pub(crate) enum RootParseResult<O> {
 impl<T: ToIonDataSource> RawTextReader<T> {
     pub fn new(input: T) -> RawTextReader<T> {
         let text_source = input.to_ion_data_source();
+        Self::from_text_buffer(TextBuffer::new(text_source))
+    }
+
+    /// Like [RawTextReader::new], but sizes the reader's line buffer to hold `capacity` bytes.
+    /// The line buffer grows as needed to hold longer lines of text.
+    pub fn with_buffer_capacity(input: T, capacity: usize) -> RawTextReader<T> {
+        let text_source = input.to_ion_data_source();
+        Self::from_text_buffer(TextBuffer::with_capacity(text_source, capacity))
+    }
+
+    /// Sets how the reader handles input that is not valid UTF-8.
+    pub fn with_utf8_policy(mut self, policy: Utf8Policy) -> RawTextReader<T> {
+        self.buffer.set_utf8_policy(policy);
+        self
+    }
+
+    /// Sets a handler that will be invoked for each line of input that contains invalid UTF-8
+    /// when using [Utf8Policy::Lossy]. The handler is passed the location of the first invalid
+    /// byte on the line, measured from the beginning of the input.
+    pub fn with_invalid_utf8_handler<F>(mut self, handler: F) -> RawTextReader<T>
+    where
+        F: FnMut(Position) + 'static,
+    {
+        self.buffer.set_invalid_utf8_handler(Box::new(handler));
+        self
+    }
+
+    /// Sets whether the reader accepts the Ion 1.1 version marker, `$ion_1_1`. Values that
+    /// follow it are read using the same syntax as Ion 1.0; Ion 1.1 macro invocations are not
+    /// yet supported. Ion 1.1 support is disabled by default.
+    pub fn with_ion_1_1(mut self, enabled: bool) -> RawTextReader<T> {
+        self.ion_1_1_enabled = enabled;
+        self
+    }
+
+    /// Sets how the reader handles timestamps with a leap second, such as
+    /// `2016-12-31T23:59:60Z`. By default, reading one fails.
+    pub fn with_leap_second_policy(mut self, policy: LeapSecondPolicy) -> RawTextReader<T> {
+        self.leap_second_policy = policy;
+        self
+    }
+
+    fn from_text_buffer(buffer: TextBuffer<T::DataSource>) -> RawTextReader<T> {
         RawTextReader {
-            buffer: TextBuffer::new(text_source),
+            buffer,
             current_field_name: None,
             current_value: None,
             current_ivm: None,
+            item_position: Position::default(),
+            top_level_offset: 0,
+            top_level_value_end: 0,
             bytes_read: 0,
             is_eof: false,
             parents: Vec::with_capacity(INITIAL_PARENTS_CAPACITY),
+            ion_version: (1, 0),
+            ion_1_1_enabled: false,
+            leap_second_policy: LeapSecondPolicy::default(),
         }
     }
 
@@ -74,6 +150,25 @@ impl<T: ToIonDataSource> RawTextReader<T> {
         self.bytes_read
     }
 
+    /// Returns the location of the first character that is `offset` bytes into the text buffer's
+    /// unconsumed input.
+    fn position_at(&self, offset: usize) -> Position {
+        let (line, column) = self.buffer.line_and_column_at(offset);
+        Position::with_offset(self.bytes_read + offset).with_line_and_column(line, column)
+    }
+
+    /// Returns the location of the next token in the text buffer's unconsumed input.
+    fn next_token_position(&self) -> Position {
+        self.position_at(leading_whitespace_or_comments_length(
+            self.buffer.remaining_text(),
+        ))
+    }
+
+    /// Attaches the location of the next token in the input to any decoding error in `result`.
+    fn located<V>(&self, result: IonResult<V>) -> IonResult<V> {
+        result.map_err(|error| error.with_position(self.next_token_position()))
+    }
+
     fn load_next_value(&mut self) -> IonResult<()> {
         // If the reader's current value is the beginning of a container and the user calls `next()`,
         // we need to skip the entire container. We can do this by stepping into and then out of
@@ -105,8 +200,17 @@ impl<T: ToIonDataSource> RawTextReader<T> {
                 return Ok(());
             }
 
+            self.top_level_offset = self.bytes_read;
             let next_stream_item = self.parse_next_nom(stream_item);
-            return self.process_stream_item(next_stream_item);
+            // The parser stops at the end of the item's text. (Items found at EOF are handled
+            // separately by `parse_value_at_eof`.)
+            self.top_level_value_end = self.bytes_read;
+            self.process_stream_item(next_stream_item)?;
+            if self.current_value.is_some() || self.current_ivm.is_some() {
+                // Now that we know where the item's text begins, remember that instead.
+                self.top_level_offset = self.item_position.byte_offset();
+            }
+            return Ok(());
         }
 
         // Otherwise, the `parents` stack is not empty. We're inside a container.
@@ -161,14 +265,45 @@ impl<T: ToIonDataSource> RawTextReader<T> {
         Ok(())
     }
 
+    // Timestamps with a leap second are parsed with their second clamped to `:59`. If the current
+    // value is one of them, replaces it with a Timestamp according to the reader's
+    // LeapSecondPolicy.
+    fn resolve_leap_second(&mut self) -> IonResult<()> {
+        let value = match self.current_value.as_mut() {
+            Some(value) => value.value_mut(),
+            None => return Ok(()),
+        };
+        if let TextValue::LeapSecondTimestamp(timestamp) = value {
+            let timestamp = match self.leap_second_policy {
+                LeapSecondPolicy::Reject => {
+                    return decoding_error(
+                        "found a timestamp with a leap second (a second of 60), which is not \
+                        permitted by the reader's LeapSecondPolicy",
+                    )
+                }
+                LeapSecondPolicy::Clamp => timestamp.clone(),
+                LeapSecondPolicy::Carry => timestamp.clone().plus_one_second()?,
+            };
+            *value = TextValue::Timestamp(timestamp);
+        }
+        Ok(())
+    }
+
     fn process_stream_item(
         &mut self,
         read_result: RootParseResult<RawTextStreamItem>,
     ) -> IonResult<()> {
         match read_result {
             RootParseResult::Ok(RawTextStreamItem::IonVersionMarker(1, 0)) => {
-                // We found an IVM; we currently only support Ion 1.0.
                 self.current_ivm = Some((1, 0));
+                self.ion_version = (1, 0);
+                Ok(())
+            }
+            RootParseResult::Ok(RawTextStreamItem::IonVersionMarker(1, 1))
+                if self.ion_1_1_enabled =>
+            {
+                self.current_ivm = Some((1, 1));
+                self.ion_version = (1, 1);
                 Ok(())
             }
             RootParseResult::Ok(RawTextStreamItem::IonVersionMarker(major, minor)) => {
@@ -250,7 +385,7 @@ impl<T: ToIonDataSource> RawTextReader<T> {
     where
         P: Fn(&str) -> IonParseResult<O>,
     {
-        match self.parse_next(parser) {
+        let result = match self.parse_next(parser) {
             Ok(Some(value)) => Ok(value),
             Ok(None) => decoding_error(format!(
                 "Unexpected end of input while reading {} on line {}: '{}'",
@@ -265,7 +400,8 @@ impl<T: ToIonDataSource> RawTextReader<T> {
                 self.buffer.remaining_text(),
                 e
             )),
-        }
+        };
+        self.located(result)
     }
 
     fn parse_next<P, O>(&mut self, parser: P) -> IonResult<Option<O>>
@@ -301,6 +437,7 @@ impl<T: ToIonDataSource> RawTextReader<T> {
             ref mut is_eof,
             ref mut buffer,
             ref mut bytes_read,
+            ref mut item_position,
             ..
         } = *self;
 
@@ -349,6 +486,14 @@ impl<T: ToIonDataSource> RawTextReader<T> {
                     // The difference in length tells us how many bytes were part of the
                     // text representation of the value that we found.
                     let bytes_consumed = length_before_parse - length_after_parse;
+                    // Make a note of where the matched item's text began, not counting any
+                    // leading whitespace or comments.
+                    let whitespace_length = leading_whitespace_or_comments_length(
+                        &buffer.remaining_text()[..bytes_consumed],
+                    );
+                    let (line, column) = buffer.line_and_column_at(whitespace_length);
+                    *item_position = Position::with_offset(*bytes_read + whitespace_length)
+                        .with_line_and_column(line, column);
                     buffer.consume(bytes_consumed);
                     *bytes_read += bytes_consumed;
                     return RootParseResult::Ok(value);
@@ -377,6 +522,8 @@ impl<T: ToIonDataSource> RawTextReader<T> {
         // An arbitrary, cheap-to-parse Ion value that we append to the buffer when its contents at
         // EOF are ambiguous.
         const SENTINEL_ION_TEXT: &str = "\n0\n";
+        // Any value found below will begin at the next token in the buffer.
+        self.item_position = self.next_token_position();
         // Make a note of the buffer's length; we're about to modify it.
         let original_length = self.buffer.remaining_text().len();
         // Append our sentinel value to the end of the input buffer.
@@ -405,7 +552,11 @@ impl<T: ToIonDataSource> RawTextReader<T> {
                 // which indicates that our 0 was parsed.
                 RootParseResult::Eof
             }
-            Ok((_remaining_text, value)) => {
+            Ok((remaining_text, value)) => {
+                // Note where the value's text ended, not counting any of the sentinel text.
+                let text_length = original_length + SENTINEL_ION_TEXT.len();
+                let bytes_consumed = (text_length - remaining_text.len()).min(original_length);
+                self.top_level_value_end = self.bytes_read + bytes_consumed;
                 // We found something else. The zero is still in the buffer; we can leave it there.
                 // The reader's `is_eof` flag has been set, so the text buffer will never be used
                 // again. Return the value we found.
@@ -460,8 +611,14 @@ impl<T: ToIonDataSource> RawTextReader<T> {
     }
 }
 
-// Returned by the `annotations()` method below if there is no current value.
-const EMPTY_SLICE_RAW_SYMBOL_TOKEN: &[RawSymbolToken] = &[];
+/// Returns the number of bytes of whitespace and comments at the beginning of `text`.
+fn leading_whitespace_or_comments_length(text: &str) -> usize {
+    match whitespace_or_comments(text) {
+        Ok((remaining_text, _)) => text.len() - remaining_text.len(),
+        // If `text` ends partway through a comment, only count the leading whitespace.
+        Err(_) => text.len() - text.trim_start().len(),
+    }
+}
 
 // TODO: This implementation of the text reader eagerly materializes each value that it encounters
 //       in the stream and stores it in the reader as `current_value`. Each time a user requests
@@ -476,12 +633,15 @@ impl<T: ToIonDataSource> StreamReader for RawTextReader<T> {
     type Symbol = RawSymbolToken;
 
     fn ion_version(&self) -> (u8, u8) {
-        (1, 0)
+        self.ion_version
     }
 
     fn next(&mut self) -> IonResult<RawStreamItem> {
         // Parse the next value from the stream, storing it in `self.current_value`.
-        self.load_next_value()?;
+        let result = self
+            .load_next_value()
+            .and_then(|_| self.resolve_leap_second());
+        self.located(result)?;
 
         // If we're positioned on an IVM, return the (major, minor) version tuple
         if let Some((major, minor)) = self.current_ivm {
@@ -523,18 +683,22 @@ impl<T: ToIonDataSource> StreamReader for RawTextReader<T> {
         false
     }
 
-    fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = IonResult<Self::Symbol>> + 'a> {
-        let iterator = self
+    fn annotation(&self, index: usize) -> IonResult<Self::Symbol> {
+        // The annotations are already in memory and are already resolved to text, so
+        // the only possible failure is an out-of-bounds index.
+        let annotations = self
             .current_value
             .as_ref()
             .map(|value| value.annotations())
-            .unwrap_or(EMPTY_SLICE_RAW_SYMBOL_TOKEN)
-            .iter()
-            .cloned()
-            // The annotations are already in memory and are already resolved to text, so
-            // this step cannot fail. Map each token to Ok(token).
-            .map(Ok);
-        Box::new(iterator)
+            .unwrap_or_default();
+        match annotations.get(index) {
+            Some(annotation) => Ok(annotation.clone()),
+            None => illegal_operation(format!(
+                "annotation index {} is out of bounds; the current value has {} annotations",
+                index,
+                annotations.len()
+            )),
+        }
     }
 
     fn has_annotations(&self) -> bool {
@@ -616,6 +780,13 @@ impl<T: ToIonDataSource> StreamReader for RawTextReader<T> {
         self.map_string(|s| s.to_owned())
     }
 
+    fn read_str(&mut self) -> IonResult<&str> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::String(ref value)) => Ok(value.as_str()),
+            _ => Err(self.expected("string value")),
+        }
+    }
+
     fn map_string<F, U>(&mut self, f: F) -> IonResult<U>
     where
         Self: Sized,
@@ -676,6 +847,32 @@ impl<T: ToIonDataSource> StreamReader for RawTextReader<T> {
         }
     }
 
+    fn read_blob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        // The text reader has already materialized the blob; hand it over as a single chunk.
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Blob(ref value)) => {
+                chunk_handler(value.as_slice()).map(|_| value.len())
+            }
+            _ => Err(self.expected("blob value")),
+        }
+    }
+
+    fn read_clob_chunks(
+        &mut self,
+        chunk_handler: &mut dyn FnMut(&[u8]) -> IonResult<()>,
+    ) -> IonResult<usize> {
+        // The text reader has already materialized the clob; hand it over as a single chunk.
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Clob(ref value)) => {
+                chunk_handler(value.as_slice()).map(|_| value.len())
+            }
+            _ => Err(self.expected("clob value")),
+        }
+    }
+
     fn read_timestamp(&mut self) -> IonResult<Timestamp> {
         match self.current_value.as_ref().map(|current| current.value()) {
             Some(TextValue::Timestamp(ref value)) => Ok(value.clone()),
@@ -755,6 +952,54 @@ impl<T: ToIonDataSource> StreamReader for RawTextReader<T> {
     fn depth(&self) -> usize {
         self.parents.len()
     }
+
+    fn resynchronize(&mut self) -> IonResult<Range<usize>> {
+        // Return to the top level, abandoning any containers that were in progress.
+        self.parents.clear();
+        self.current_value = None;
+        self.current_field_name = None;
+        self.current_ivm = None;
+
+        // Text Ion does not offer a reliable boundary between top-level values; assume that the
+        // next one begins on the line following the text that could not be parsed.
+        let start = self.top_level_offset.min(self.bytes_read);
+        loop {
+            let remaining_text = self.buffer.remaining_text();
+            let (bytes_to_discard, found_newline) = match remaining_text.find('\n') {
+                Some(index) => (index + 1, true),
+                None => (remaining_text.len(), false),
+            };
+            self.buffer.consume(bytes_to_discard);
+            self.bytes_read += bytes_to_discard;
+            // If the buffer only held part of a long line, discard the rest of it too.
+            if found_newline || self.buffer.load_next_line()? == 0 {
+                break;
+            }
+        }
+        self.top_level_offset = self.bytes_read;
+        Ok(start..self.bytes_read)
+    }
+
+    fn value_end_offset(&self) -> Option<usize> {
+        // The end of a container isn't known until the reader steps out of it, and values
+        // inside of containers are parsed along with their trailing delimiters.
+        match self.current_value.as_ref() {
+            Some(value)
+                if self.parents.is_empty()
+                    && (self.is_null() || !value.value().ion_type().is_container()) =>
+            {
+                Some(self.top_level_value_end)
+            }
+            _ => None,
+        }
+    }
+
+    fn position(&self) -> Position {
+        if self.current_value.is_some() || self.current_ivm.is_some() {
+            return self.item_position;
+        }
+        self.position_at(0)
+    }
 }
 
 #[cfg(test)]
@@ -962,6 +1207,7 @@ mod reader_tests {
 
         next_type(reader, IonType::String, false);
         assert_eq!(reader.read_string()?, "hello".to_string());
+        assert_eq!(reader.read_str()?, "hello");
 
         // ===== CONTAINERS =====
 
@@ -1186,4 +1432,217 @@ mod reader_tests {
         assert!(result.is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_position() -> IonResult<()> {
+        let ion_data = "1\n  foo::[\n    true]\n";
+        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.position(),
+            Position::with_offset(0).with_line_and_column(1, 1)
+        );
+        next_type(reader, IonType::List, false);
+        assert_eq!(
+            reader.position(),
+            Position::with_offset(4).with_line_and_column(2, 3)
+        );
+        reader.step_in()?;
+        next_type(reader, IonType::Boolean, false);
+        assert_eq!(
+            reader.position(),
+            Position::with_offset(15).with_line_and_column(3, 5)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decoding_error_position() {
+        let ion_data = "1\n[2, 3,, 4]";
+        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::List, false);
+        reader.step_in().unwrap();
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::Integer, false);
+        let error = reader.next().unwrap_err();
+        let position = error
+            .position()
+            .expect("decoding error did not have a position");
+        assert_eq!(position.line(), Some(2));
+        assert!(error.to_string().contains("line 2"));
+    }
+
+    #[test]
+    fn test_resynchronize() -> IonResult<()> {
+        let ion_data = "1\n[2, 3,, 4] 5\n6\n";
+        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.next().is_err());
+        // The rest of the line containing the malformed list is discarded.
+        assert_eq!(reader.resynchronize()?, 2..15);
+        assert_eq!(reader.depth(), 0);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, 6);
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_spanning_chunks() -> IonResult<()> {
+        let long_text = "\u{e9}t\u{e9} ".repeat(1_000);
+        let long_blob = base64::encode(long_text.as_bytes());
+        let ion_data = format!(
+            "{{text: \"{0}\", blob: {{{{{1}}}}}, clob: {{{{'''{0}''' '''{0}'''}}}}, n: 12345}}",
+            long_text.replace('\u{e9}', "e"),
+            long_blob
+        );
+        let ion_data = format!("{} \"{}\" 67890", ion_data, long_text);
+        let reader = &mut RawTextReader::new(ion_data.as_str());
+        // Load the input a few bytes at a time so that every value spans several chunks.
+        reader.buffer.set_chunk_length(5);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, long_text.replace('\u{e9}', "e"));
+        next_type(reader, IonType::Blob, false);
+        assert_eq!(reader.read_blob()?, long_text.as_bytes());
+        next_type(reader, IonType::Clob, false);
+        assert_eq!(reader.read_clob()?.len(), 8_000);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, 12345);
+        reader.step_out()?;
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, long_text);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, 67890);
+        assert_eq!(reader.next()?, Nothing);
+        Ok(())
+    }
+
+    // Reads every item in the stream (stepping into each container), returning a description of
+    // each item, its value, and its field name.
+    fn read_all(reader: &mut RawTextReader<&str>) -> IonResult<Vec<String>> {
+        let mut items = Vec::new();
+        loop {
+            let item = reader.next()?;
+            if item == Nothing {
+                if reader.depth() == 0 {
+                    return Ok(items);
+                }
+                reader.step_out()?;
+                continue;
+            }
+            items.push(format!(
+                "{:?} {:?} {:?}",
+                item,
+                reader.current_value,
+                reader.field_name().ok()
+            ));
+            if matches!(item, RawStreamItem::Value(ion_type) if ion_type.is_container()) {
+                reader.step_in()?;
+            }
+        }
+    }
+
+    #[test]
+    fn test_tokens_split_at_every_offset() -> IonResult<()> {
+        let tokens = [
+            "null",
+            "null.timestamp",
+            "true",
+            "false",
+            "-1_234",
+            "0x1F",
+            "0b101",
+            "1.5e-3",
+            "-inf",
+            "nan",
+            "12.50",
+            "1.5d-3",
+            "2020T",
+            "2020-01T",
+            "2020-01-01",
+            "2020-01-01T12:30Z",
+            "2020-01-01T12:30:59-05:45",
+            "2020-01-01T00:00:00.123Z",
+            "2020-01-01T00:00:00.123456789012+01:00",
+            "foo",
+            "'quoted symbol'",
+            "$10",
+            "\"a string\"",
+            "'''long''' '''string'''",
+            "{{aGVsbG8=}}",
+            "{{\"clob\"}}",
+            r#""esc \u00e9 \x41 \U0001F600 \t \
+ continued""#,
+            r#""\ud83d\ude00 pair""#,
+            r#"'sym \u00e9 \x41'"#,
+            r#"'''long \u00e9 \U0001F600''' '''\x41'''"#,
+            r#"{{"\x41\t"}}"#,
+            r#"{{'''\x41''' '''\x42'''}}"#,
+            r#"{"field \u00e9": '\x41'}"#,
+            "a::'b'::1",
+            "[1, two, \"three\"]",
+            "(a + -b)",
+            "{a: 1, 'b': [2.5], \"c\": {d: 2020-01-01T00:00:00.5Z}}",
+        ];
+        for token in tokens {
+            let input = format!("{}\n", token);
+            let expected = read_all(&mut RawTextReader::new(input.as_str()))?;
+            // A chunk length of `n` makes the first chunk end `n` bytes into the token.
+            for chunk_length in 1..input.len() {
+                let reader = &mut RawTextReader::new(input.as_str());
+                reader.buffer.set_chunk_length(chunk_length);
+                let actual = read_all(reader).unwrap_or_else(|error| {
+                    panic!("{:?} split after {} bytes: {}", token, chunk_length, error)
+                });
+                assert_eq!(
+                    actual, expected,
+                    "{:?} split after {} bytes",
+                    token, chunk_length
+                );
+            }
+        }
+
+        // With the default chunk length, padding puts the chunk boundary inside the escape.
+        let input = format!("{}\"esc \\u00e9\" ", " ".repeat(65536 - 7));
+        let reader = &mut RawTextReader::new(input.as_str());
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, "esc \u{e9}");
+
+        // With the default chunk length, padding puts the chunk boundary just after the `.`.
+        let input = format!("{}2020-01-01T00:00:00.123Z", " ".repeat(65516));
+        let reader = &mut RawTextReader::new(input.as_str());
+        next_type(reader, IonType::Timestamp, false);
+        assert_eq!(
+            reader.read_timestamp()?,
+            Timestamp::with_ymd(2020, 1, 1)
+                .with_hms(0, 0, 0)
+                .with_milliseconds(123)
+                .build_at_offset(0)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lob_chunks() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(r#"{{aGVsbG8=}} {{"world"}}"#);
+        next_type(reader, IonType::Blob, false);
+        let mut blob = Vec::new();
+        assert_eq!(reader.read_blob_into(&mut blob)?, 5);
+        assert_eq!(blob, b"hello".to_vec());
+        next_type(reader, IonType::Clob, false);
+        let mut clob = Vec::new();
+        reader.read_clob_chunks(&mut |chunk| {
+            clob.extend_from_slice(chunk);
+            Ok(())
+        })?;
+        assert_eq!(clob, b"world".to_vec());
+        Ok(())
+    }
 }
@@ -10,7 +10,7 @@ use crate::text::text_formatter::STRING_ESCAPE_CODES;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::{Precision, Timestamp};
 use crate::types::ContainerType;
-use crate::writer::Writer;
+use crate::writer::{Writer, WriterLimits};
 use crate::{Integer, IonType};
 
 pub struct RawTextWriterBuilder {
@@ -18,6 +18,8 @@ pub struct RawTextWriterBuilder {
     indentation: String,
     space_after_field_name: String,
     space_after_container_start: String,
+    limits: WriterLimits,
+    zero_offset_as_z: bool,
 }
 
 impl RawTextWriterBuilder {
@@ -37,6 +39,8 @@ impl RawTextWriterBuilder {
             space_after_field_name: String::from(" "),
             // The first value in a container appears next to the opening delimiter
             space_after_container_start: String::new(),
+            limits: WriterLimits::default(),
+            zero_offset_as_z: false,
         }
     }
 
@@ -67,6 +71,8 @@ impl RawTextWriterBuilder {
             space_after_field_name: String::from(" "),
             // The first value in a container appears on a line by itself
             space_after_container_start: String::from("\n"),
+            limits: WriterLimits::default(),
+            zero_offset_as_z: false,
         }
     }
 
@@ -99,12 +105,27 @@ impl RawTextWriterBuilder {
         self
     }
 
+    /// Sets the limits that the writer will enforce on the data it is asked to write.
+    pub fn with_limits(mut self, limits: WriterLimits) -> RawTextWriterBuilder {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets whether timestamps with a known offset of zero are written as `Z` (for example,
+    /// `2021-02-05T16:43Z`) rather than `+00:00`. Both forms are valid Ion; `+00:00` is the
+    /// default.
+    pub fn with_zero_offset_as_z(mut self, zero_offset_as_z: bool) -> RawTextWriterBuilder {
+        self.zero_offset_as_z = zero_offset_as_z;
+        self
+    }
+
     /// Constructs a new instance of [RawTextWriter] that writes values to the provided io::Write
     /// implementation.
     pub fn build<W: Write>(self, sink: W) -> IonResult<RawTextWriter<W>> {
         let raw_text_writer = RawTextWriter {
             output: BufWriter::new(sink),
             annotations: Vec::new(),
+            num_annotations: 0,
             field_name: None,
             containers: vec![EncodingLevel::default()],
             // TODO: We should consider putting these in a single struct (`WhitespaceConfig`?)
@@ -113,6 +134,8 @@ impl RawTextWriterBuilder {
             indentation: self.indentation,
             space_after_field_name: self.space_after_field_name,
             space_after_container_start: self.space_after_container_start,
+            limits: self.limits,
+            zero_offset_as_z: self.zero_offset_as_z,
         };
         // This method cannot currently fail. It returns an IonResult<_> to be consistent with the
         // other builder APIs and to allow for fallible setup operations in the future.
@@ -134,13 +157,18 @@ struct EncodingLevel {
 
 pub struct RawTextWriter<W: Write> {
     output: BufWriter<W>,
+    // The text of each annotation on the next value is stored in the first `num_annotations`
+    // entries. Entries beyond those are kept so that their allocations can be reused.
     annotations: Vec<String>,
+    num_annotations: usize,
     field_name: Option<String>,
     containers: Vec<EncodingLevel>,
     space_between_values: String,
     indentation: String,
     space_after_field_name: String,
     space_after_container_start: String,
+    limits: WriterLimits,
+    zero_offset_as_z: bool,
 }
 
 impl<W: Write> RawTextWriter<W> {
@@ -211,9 +239,12 @@ impl<W: Write> RawTextWriter<W> {
             self.write_value_delimiter()?;
             write!(&mut self.output, "{}", self.space_between_values)?;
         }
-        // Write enough indentation for the current level of depth
-        for _ in 0..self.depth() {
-            write!(&mut self.output, "{}", self.indentation)?;
+        // Write enough indentation for the current level of depth. Compact writers have no
+        // indentation; skipping the loop keeps deeply nested output from taking quadratic time.
+        if !self.indentation.is_empty() {
+            for _ in 0..self.depth() {
+                write!(&mut self.output, "{}", self.indentation)?;
+            }
         }
         Ok(())
     }
@@ -281,6 +312,16 @@ impl<W: Write> RawTextWriter<W> {
         Ok(())
     }
 
+    // Returns an error (and discards the annotations) if the next value has more annotations than
+    // the writer's limits allow.
+    fn check_annotations_limit(&mut self) -> IonResult<()> {
+        let result = self.limits.check_annotations(self.num_annotations);
+        if result.is_err() {
+            self.num_annotations = 0;
+        }
+        result
+    }
+
     // Write the field name and annotations if set
     fn write_value_metadata(&mut self) -> IonResult<()> {
         if let Some(field_name) = &self.field_name.take() {
@@ -290,13 +331,11 @@ impl<W: Write> RawTextWriter<W> {
             return illegal_operation("Values inside a struct must have a field name.");
         }
 
-        if !self.annotations.is_empty() {
-            for annotation in &self.annotations {
-                Self::write_symbol_token(&mut self.output, annotation)?;
-                write!(self.output, "::")?;
-            }
-            self.annotations.clear();
+        for annotation in &self.annotations[..self.num_annotations] {
+            Self::write_symbol_token(&mut self.output, annotation)?;
+            write!(self.output, "::")?;
         }
+        self.num_annotations = 0;
         Ok(())
     }
 
@@ -309,6 +348,7 @@ impl<W: Write> RawTextWriter<W> {
     where
         F: FnOnce(&mut BufWriter<W>) -> IonResult<()>,
     {
+        self.check_annotations_limit()?;
         self.write_space_before_value()?;
         self.write_value_metadata()?;
         scalar_writer(&mut self.output)?;
@@ -326,12 +366,20 @@ impl<W: Write> RawTextWriter<W> {
     }
 
     // Helper method for [write_timestamp]. Writes the timestamp to output using +/-HH:MM format.
-    fn write_offset(output: &mut BufWriter<W>, offset_minutes: Option<i32>) -> IonResult<()> {
+    fn write_offset(
+        output: &mut BufWriter<W>,
+        offset_minutes: Option<i32>,
+        zero_offset_as_z: bool,
+    ) -> IonResult<()> {
         if offset_minutes.is_none() {
             write!(output, "-00:00")?;
             return Ok(());
         }
         let offset_minutes = offset_minutes.unwrap();
+        if offset_minutes == 0 && zero_offset_as_z {
+            write!(output, "Z")?;
+            return Ok(());
+        }
 
         const MINUTES_PER_HOUR: i32 = 60;
         // Split the offset into a sign and magnitude for formatting
@@ -356,14 +404,20 @@ impl<W: Write> RawTextWriter<W> {
     }
 
     pub fn add_annotation<A: AsRawSymbolTokenRef>(&mut self, annotation: A) {
-        // TODO: This function currently allocates a new string for each annotation.
-        //       It will be common for this text to come from the symbol table; we should
-        //       make it possible to pass an Rc<str> or similar when applicable.
-        let text = match annotation.as_raw_symbol_token_ref() {
-            RawSymbolTokenRef::SymbolId(sid) => format!("${}", sid),
-            RawSymbolTokenRef::Text(text) => text.to_string(),
-        };
-        self.annotations.push(text);
+        // Reuse the String left behind by an earlier value's annotation if there is one.
+        if self.num_annotations == self.annotations.len() {
+            self.annotations.push(String::new());
+        }
+        let text = &mut self.annotations[self.num_annotations];
+        text.clear();
+        match annotation.as_raw_symbol_token_ref() {
+            RawSymbolTokenRef::SymbolId(sid) => {
+                text.push('$');
+                text.push_str(&sid.to_string());
+            }
+            RawSymbolTokenRef::Text(value) => text.push_str(value),
+        }
+        self.num_annotations += 1;
     }
 
     /// Writes the body (i.e. no start or end delimiters) of a string or symbol with any illegal
@@ -426,7 +480,7 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
         A: AsRawSymbolTokenRef,
         I: IntoIterator<Item = A>,
     {
-        self.annotations.clear();
+        self.num_annotations = 0;
         for annotation in annotations {
             self.add_annotation(annotation)
         }
@@ -533,7 +587,7 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
 
     /// Writes the provided Timestamp as an Ion timestamp.
     fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
-        // A space to format the offset
+        let zero_offset_as_z = self.zero_offset_as_z;
         self.write_scalar(|output| {
             let (offset_minutes, datetime) = if let Some(minutes) = value.offset {
                 // Create a datetime with the appropriate offset that we can use for formatting.
@@ -574,7 +628,7 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
             write!(output, "T{:0>2}:{:0>2}", datetime.hour(), datetime.minute())?;
             //                   ^-- delimiting T, formatted hour, delimiting colon, formatted minute
             if value.precision == Precision::HourAndMinute {
-                RawTextWriter::write_offset(output, offset_minutes)?;
+                RawTextWriter::write_offset(output, offset_minutes, zero_offset_as_z)?;
                 return Ok(());
             }
 
@@ -582,7 +636,7 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
             //                   ^-- delimiting colon, formatted second
             value.fmt_fractional_seconds(&mut *output)?;
 
-            RawTextWriter::write_offset(output, offset_minutes)?;
+            RawTextWriter::write_offset(output, offset_minutes, zero_offset_as_z)?;
             Ok(())
         })
     }
@@ -597,6 +651,7 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
 
     /// Writes the provided &str value as an Ion string.
     fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        self.limits.check_value_length(value.as_ref().len())?;
         self.write_scalar(|output| {
             write!(output, "\"")?;
             RawTextWriter::write_escaped_text_body(output, value)?;
@@ -612,6 +667,7 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
         const NUM_HEX_BYTES_PER_BYTE: usize = 4; // \xHH
 
         let value: &[u8] = value.as_ref();
+        self.limits.check_value_length(value.len())?;
 
         // Set aside enough memory to hold a clob containing all hex-encoded bytes
         let mut clob_value =
@@ -634,6 +690,7 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
 
     /// Writes the provided byte array slice as an Ion blob.
     fn write_blob<A: AsRef<[u8]>>(&mut self, value: A) -> IonResult<()> {
+        self.limits.check_value_length(value.as_ref().len())?;
         self.write_scalar(|output| {
             // Rust format strings escape curly braces by doubling them. The following string is:
             // * The opening {{ from a text Ion blob, with each brace doubled to escape it.
@@ -650,6 +707,11 @@ impl<'a, W: Write> Writer for RawTextWriter<W> {
     fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
         use IonType::*;
 
+        if !ion_type.is_container() {
+            return illegal_operation(format!("Cannot step into a(n) {:?}", ion_type));
+        }
+        self.limits.check_depth(self.depth() + 1)?;
+        self.check_annotations_limit()?;
         self.write_space_before_value()?;
         self.write_value_metadata()?;
         let container_type = match ion_type {
@@ -808,6 +870,18 @@ mod tests {
         write_scalar_test(|w| w.write_i64(7), "7");
     }
 
+    #[test]
+    fn write_i128() {
+        write_scalar_test(
+            |w| w.write_i128(i128::MIN),
+            "-170141183460469231731687303715884105728",
+        );
+        write_scalar_test(
+            |w| w.write_u128(u128::MAX),
+            "340282366920938463463374607431768211455",
+        );
+    }
+
     #[test]
     fn write_f32() {
         write_scalar_test(|w| w.write_f32(700f32), "7e2");
@@ -829,6 +903,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_annotated_values_in_sequence() {
+        writer_test(
+            |w| {
+                w.set_annotations(["foo", "bar"]);
+                w.write_i64(7)?;
+                w.set_annotations(["baz"]);
+                w.write_i64(8)?;
+                w.write_i64(9)
+            },
+            "foo::bar::7 baz::8 9",
+            "foo::bar::7\nbaz::8\n9",
+        );
+    }
+
     #[test]
     fn write_decimal() {
         let decimal_text = "731221.9948";
@@ -907,6 +996,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_timestamp_with_zero_offset_as_z() {
+        let timestamp = Timestamp::with_ymd_hms_millis(2000, 8, 22, 15, 45, 11, 931)
+            .build_at_offset(0)
+            .expect("building timestamp failed");
+        writer_test_with_builder(
+            RawTextWriterBuilder::new().with_zero_offset_as_z(true),
+            |w| w.write_timestamp(&timestamp),
+            "2000-08-22T15:45:11.931Z",
+        );
+        write_scalar_test(
+            |w| w.write_timestamp(&timestamp),
+            "2000-08-22T15:45:11.931+00:00",
+        );
+    }
+
     #[test]
     fn write_blob() {
         write_scalar_test(|w| w.write_blob("hello".as_bytes()), "{{aGVsbG8=}}");
@@ -1,6 +1,15 @@
+use std::borrow::Cow;
 use std::io;
 use std::io::BufRead;
 
+use crate::position::Position;
+use crate::text::raw_text_reader::Utf8Policy;
+
+pub(crate) type InvalidUtf8Handler = Box<dyn FnMut(Position)>;
+
+// The maximum number of bytes of a single line that the buffer will load at once by default.
+const DEFAULT_CHUNK_LENGTH: usize = 64 * 1024;
+
 /// A text buffer that pulls more bytes from the input source as needed.
 ///
 /// A parser reading from a text stream should use the [load_next_line] method to pull text into
@@ -9,6 +18,10 @@ use std::io::BufRead;
 /// with the [consume] method. If the buffer does not contain enough data to represent a full value,
 /// more data can be pulled into the buffer with [load_next_line]; this additional text will be
 /// visible in the next call to [remaining_text].
+///
+/// Lines that are longer than the buffer's chunk length are loaded one chunk at a time, so input
+/// that consists of a single enormous line (like the output of a compact text writer) does not
+/// need to be held in memory all at once.
 pub(crate) struct TextBuffer<R: BufRead> {
     // The input source to read from (an io::Cursor, BufReader, File, etc)
     input: R,
@@ -20,22 +33,72 @@ pub(crate) struct TextBuffer<R: BufRead> {
     // When the LineBuffer is first constructed and no lines
     // have been read from input, this value is 0.
     line_number: usize,
+    // The 1-based line and column of the first character that has not yet been consumed.
+    consumed_line: usize,
+    consumed_column: usize,
     // Whether `input` above has reached EOF.
     is_exhausted: bool,
+    // How to handle input that is not valid UTF-8.
+    utf8_policy: Utf8Policy,
+    // Invoked for each line containing invalid UTF-8 when using `Utf8Policy::Lossy`.
+    invalid_utf8_handler: Option<InvalidUtf8Handler>,
+    // The number of bytes that have been read from `input`. This can differ from the number of
+    // bytes of text that have been loaded if invalid UTF-8 was replaced.
+    input_bytes_read: usize,
+    // The number of characters of the current line of input that have been loaded. This is only
+    // tracked if there is an `invalid_utf8_handler` to report columns to.
+    line_characters_loaded: usize,
+    // The maximum number of bytes of a line to load at once.
+    chunk_length: usize,
+    // Holds each chunk of input while it is being decoded.
+    chunk: Vec<u8>,
 }
 
 impl<R: BufRead> TextBuffer<R> {
     /// Constructs a new LineBuffer that will pull lines of text from the provided input.
     pub fn new(input: R) -> Self {
+        Self::with_capacity(input, 128)
+    }
+
+    /// Constructs a new LineBuffer whose line buffer can initially hold `capacity` bytes.
+    pub fn with_capacity(input: R, capacity: usize) -> Self {
         Self {
             input,
-            line: String::with_capacity(128),
+            line: String::with_capacity(capacity),
             line_offset: 0,
             line_number: 0,
+            consumed_line: 1,
+            consumed_column: 1,
             is_exhausted: false,
+            utf8_policy: Utf8Policy::default(),
+            invalid_utf8_handler: None,
+            input_bytes_read: 0,
+            line_characters_loaded: 0,
+            chunk_length: DEFAULT_CHUNK_LENGTH,
+            chunk: Vec::new(),
         }
     }
 
+    /// Sets the maximum number of bytes of a line that will be loaded at once. Parsers that find
+    /// a partial value at the end of a chunk will receive an `Incomplete` result and must load
+    /// more text before trying again.
+    #[cfg(test)]
+    pub fn set_chunk_length(&mut self, chunk_length: usize) {
+        self.chunk_length = chunk_length.max(1);
+    }
+
+    /// Sets how the buffer handles input that is not valid UTF-8.
+    pub fn set_utf8_policy(&mut self, policy: Utf8Policy) {
+        self.utf8_policy = policy;
+    }
+
+    /// Sets a handler that will be invoked with the location of the first invalid byte in each
+    /// line that contains invalid UTF-8 when using [Utf8Policy::Lossy]. The location's byte
+    /// offset is relative to the beginning of the input rather than the decoded text.
+    pub fn set_invalid_utf8_handler(&mut self, handler: InvalidUtf8Handler) {
+        self.invalid_utf8_handler = Some(handler);
+    }
+
     /// Returns the trailing portion of the buffer that has not yet been marked as read via the
     /// [consume] method.
     pub fn remaining_text(&self) -> &str {
@@ -50,6 +113,23 @@ impl<R: BufRead> TextBuffer<R> {
         self.line_number
     }
 
+    /// Returns the 1-based (line, column) of the character found `offset` bytes into the
+    /// [remaining_text]. Columns are counted in characters rather than bytes.
+    /// If [offset] is not a character boundary within the remaining text, this method will panic.
+    pub fn line_and_column_at(&self, offset: usize) -> (usize, usize) {
+        let mut line = self.consumed_line;
+        let mut column = self.consumed_column;
+        for character in self.remaining_text()[..offset].chars() {
+            if character == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        (line, column)
+    }
+
     /// Returns [true] if the buffer is empty and the end of the input source has been reached;
     /// otherwise, returns false.
     pub fn is_exhausted(&self) -> bool {
@@ -71,23 +151,28 @@ impl<R: BufRead> TextBuffer<R> {
             remaining_line.is_char_boundary(number_of_bytes),
             "Cannot consume() a number of bytes that will leave invalid UTF8 in the current line."
         );
+        let (line, column) = self.line_and_column_at(number_of_bytes);
+        self.consumed_line = line;
+        self.consumed_column = column;
         self.line_offset += number_of_bytes;
     }
 
-    /// Reads the next line of text from input, appending it to the end of the buffer.
-    /// If the input is exhausted (i.e. is at EOF), returns Ok(0).
+    /// Reads the next line of text from input, appending it to the end of the buffer. If the line
+    /// is longer than the buffer's chunk length, only part of it will be loaded; the rest will be
+    /// loaded by subsequent calls. If the input is exhausted (i.e. is at EOF), returns Ok(0).
     pub fn load_next_line(&mut self) -> io::Result<usize> {
         self.load_next_n_lines(1)
     }
 
-    /// Reads the next [number_of_lines] lines of text from input, appending each one to the end of
-    /// the buffer. If fewer than [number_of_lines] remains in the input, the buffer will load as
-    /// many as possible. If the input is exhausted (i.e. is at EOF), returns Ok(0).
+    /// Reads the next [number_of_lines] lines (or partial lines; see [load_next_line]) of text from
+    /// input, appending each one to the end of the buffer. If fewer than [number_of_lines] remains
+    /// in the input, the buffer will load as many as possible. If the input is exhausted (i.e. is
+    /// at EOF), returns Ok(0).
     pub fn load_next_n_lines(&mut self, number_of_lines: usize) -> io::Result<usize> {
         self.restack_remaining_text();
         let mut total_bytes_read = 0;
         for _ in 0..number_of_lines {
-            let bytes_read = self.input.read_line(&mut self.line)?;
+            let bytes_read = self.load_next_chunk()?;
             if bytes_read == 0 {
                 self.is_exhausted = true;
                 return Ok(total_bytes_read);
@@ -101,6 +186,97 @@ impl<R: BufRead> TextBuffer<R> {
         Ok(total_bytes_read)
     }
 
+    /// Reads up to the end of the current line of input or the chunk length (whichever comes
+    /// first), decodes it according to the buffer's [Utf8Policy], and appends it to the buffer.
+    /// Returns the number of bytes of text appended to the buffer.
+    fn load_next_chunk(&mut self) -> io::Result<usize> {
+        // When a value spans several chunks, the parser starts over each time more text is
+        // loaded. Loading at least as much text as the buffer already holds means that the number
+        // of attempts grows logarithmically (rather than linearly) with the length of the value.
+        let limit = self.chunk_length.max(self.line.len());
+        let mut chunk = std::mem::take(&mut self.chunk);
+        chunk.clear();
+        let result = self
+            .read_chunk(&mut chunk, limit)
+            .and_then(|()| self.decode(&chunk));
+        self.chunk = chunk;
+        result
+    }
+
+    // Reads bytes from input into `chunk` until it finds a newline, reaches EOF, or has read
+    // `limit` bytes. The chunk will not end partway through a UTF-8 encoded character unless the
+    // input does.
+    fn read_chunk(&mut self, chunk: &mut Vec<u8>, limit: usize) -> io::Result<()> {
+        while chunk.len() < limit {
+            let available = self.input.fill_buf()?;
+            if available.is_empty() {
+                return Ok(());
+            }
+            let wanted = &available[..available.len().min(limit - chunk.len())];
+            let (length, found_newline) = match wanted.iter().position(|byte| *byte == b'\n') {
+                Some(index) => (index + 1, true),
+                None => (wanted.len(), false),
+            };
+            chunk.extend_from_slice(&wanted[..length]);
+            self.input.consume(length);
+            if found_newline {
+                return Ok(());
+            }
+        }
+        // We stopped at the limit. If that was partway through a multi-byte character, read the
+        // rest of the character.
+        while is_partial_character(chunk) {
+            let available = self.input.fill_buf()?;
+            match available.first() {
+                Some(byte) if is_continuation_byte(*byte) => {
+                    chunk.push(*byte);
+                    self.input.consume(1);
+                }
+                // The character was truncated; let the decoder handle it.
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+
+    // Appends `chunk` to the buffer as text. If it is not valid UTF-8, either returns an error or
+    // replaces the invalid sequences with U+FFFD (the Unicode replacement character).
+    fn decode(&mut self, chunk: &[u8]) -> io::Result<usize> {
+        let offset = self.input_bytes_read;
+        self.input_bytes_read += chunk.len();
+        let text = match (std::str::from_utf8(chunk), self.utf8_policy) {
+            (Ok(text), _) => Cow::Borrowed(text),
+            (Err(_), Utf8Policy::Strict) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                ))
+            }
+            (Err(error), Utf8Policy::Lossy) => {
+                if let Some(handler) = self.invalid_utf8_handler.as_mut() {
+                    // The bytes preceding the first invalid sequence are valid UTF-8.
+                    let valid_length = error.valid_up_to();
+                    let valid_text = std::str::from_utf8(&chunk[..valid_length]).unwrap();
+                    let column = self.line_characters_loaded + valid_text.chars().count() + 1;
+                    handler(
+                        Position::with_offset(offset + valid_length)
+                            .with_line_and_column(self.line_number + 1, column),
+                    );
+                }
+                String::from_utf8_lossy(chunk)
+            }
+        };
+        if self.invalid_utf8_handler.is_some() {
+            if text.ends_with('\n') {
+                self.line_characters_loaded = 0;
+            } else {
+                self.line_characters_loaded += text.chars().count();
+            }
+        }
+        self.line.push_str(&text);
+        Ok(text.len())
+    }
+
     /// Provides direct access to the [TextBuffer]'s backing [String]. This can be used to edit the
     /// input text before it is processed. Note that lines added or removed when using this method
     /// will not be reflected in subsequent calls to [lines_loaded].
@@ -137,6 +313,32 @@ impl<R: BufRead> TextBuffer<R> {
     }
 }
 
+// Returns true if `bytes` ends with an incomplete UTF-8 encoded character.
+fn is_partial_character(bytes: &[u8]) -> bool {
+    // Find the first byte of the last character, which is at most 4 bytes from the end.
+    let tail = &bytes[bytes.len().saturating_sub(4)..];
+    let (index, first_byte) = match tail
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(_, byte)| !is_continuation_byte(**byte))
+    {
+        Some((index, byte)) => (index, *byte),
+        None => return false,
+    };
+    let encoded_length = match first_byte.leading_ones() {
+        2 => 2,
+        3 => 3,
+        4 => 4,
+        _ => 1,
+    };
+    tail.len() - index < encoded_length
+}
+
+fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
 #[cfg(test)]
 pub(crate) mod text_buffer_tests {
     use super::*;
@@ -179,6 +381,20 @@ pub(crate) mod text_buffer_tests {
         assert_eq!(buffer.remaining_text(), "");
     }
 
+    #[test]
+    fn test_line_and_column() {
+        let mut buffer = text_buffer("foo\nbär baz\nquux");
+        buffer.load_next_n_lines(3).unwrap();
+        assert_eq!(buffer.line_and_column_at(0), (1, 1));
+        assert_eq!(buffer.line_and_column_at(4), (2, 1));
+        buffer.consume(4);
+        assert_eq!(buffer.line_and_column_at(0), (2, 1));
+        // 'ä' is two bytes long but occupies a single column.
+        assert_eq!(buffer.line_and_column_at(5), (2, 5));
+        buffer.consume(9);
+        assert_eq!(buffer.line_and_column_at(2), (3, 3));
+    }
+
     #[test]
     fn test_consume() {
         let mut buffer = text_buffer("foo bar baz quux");
@@ -274,4 +490,41 @@ pub(crate) mod text_buffer_tests {
         let mut buffer = TextBuffer::new(io::Cursor::new(data));
         assert!(buffer.load_next_line().is_err());
     }
+
+    #[test]
+    fn invalid_utf8_is_replaced() {
+        // Invalid UTF-8 data on the second line
+        let data: &[u8] = b"foo\nb\xffr \xc3\xa9\xc3\n";
+        let mut buffer = TextBuffer::new(io::Cursor::new(data));
+        buffer.set_utf8_policy(Utf8Policy::Lossy);
+        let warnings = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let handler_warnings = std::rc::Rc::clone(&warnings);
+        buffer.set_invalid_utf8_handler(Box::new(move |position| {
+            handler_warnings.borrow_mut().push(position)
+        }));
+        buffer.load_next_n_lines(2).unwrap();
+        assert_eq!(buffer.remaining_text(), "foo\nb\u{FFFD}r \u{e9}\u{FFFD}\n");
+        assert_eq!(
+            warnings.borrow().as_slice(),
+            &[Position::with_offset(5).with_line_and_column(2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_load_long_line_in_chunks() {
+        let mut buffer = text_buffer("ab\u{e9}cd\nef");
+        buffer.set_chunk_length(3);
+        // The chunk is extended to include the whole of the two-byte character.
+        assert_eq!(buffer.load_next_line().unwrap(), 4);
+        assert_eq!(buffer.remaining_text(), "ab\u{e9}");
+        assert_eq!(buffer.lines_loaded(), 0);
+        buffer.consume(2);
+        assert_eq!(buffer.load_next_line().unwrap(), 3);
+        assert_eq!(buffer.remaining_text(), "\u{e9}cd\n");
+        assert_eq!(buffer.lines_loaded(), 1);
+        buffer.consume(5);
+        assert_eq!(buffer.load_next_line().unwrap(), 2);
+        assert_eq!(buffer.load_next_line().unwrap(), 0);
+        assert_eq!(buffer.remaining_text(), "ef");
+    }
 }
@@ -185,9 +185,28 @@ pub(crate) const fn string_escape_code_init() -> [&'static str; 256] {
 /// This is used with the Display implementation of `OwnedElement`
 pub struct IonValueFormatter<'a, W: std::fmt::Write> {
     pub(crate) output: &'a mut W,
+    // Whether timestamps with an offset of zero are formatted with `Z` instead of `+00:00`.
+    pub(crate) zero_offset_as_z: bool,
 }
 
 impl<'a, W: std::fmt::Write> IonValueFormatter<'a, W> {
+    /// Constructs a formatter that writes Ion text to `output`. Timestamps with an offset of zero
+    /// are formatted as `+00:00`; see [Self::with_zero_offset_as_z].
+    pub fn new(output: &'a mut W) -> Self {
+        IonValueFormatter {
+            output,
+            zero_offset_as_z: false,
+        }
+    }
+
+    /// Sets whether timestamps with a known offset of zero are formatted as `Z` (for example,
+    /// `2021-02-05T16:43Z`) rather than `+00:00`. Both forms are valid Ion; `+00:00` is the
+    /// default.
+    pub fn with_zero_offset_as_z(mut self, zero_offset_as_z: bool) -> Self {
+        self.zero_offset_as_z = zero_offset_as_z;
+        self
+    }
+
     /// Returns `true` if the provided `token`'s text is an 'identifier'. That is, the text starts
     /// with a `$`, `_` or ASCII letter and is followed by a sequence of `$`, `_`, or ASCII letters
     /// and numbers. Examples:
@@ -287,10 +306,7 @@ impl<'a, W: std::fmt::Write> IonValueFormatter<'a, W> {
         Ok(())
     }
 
-    pub(crate) fn format_annotations(
-        &mut self,
-        annotations: &Vec<OwnedSymbolToken>,
-    ) -> IonResult<()> {
+    pub(crate) fn format_annotations(&mut self, annotations: &[OwnedSymbolToken]) -> IonResult<()> {
         for annotation in annotations {
             self.format_symbol(annotation.text().unwrap())?;
             write!(self.output, "::")?;
@@ -430,6 +446,10 @@ impl<'a, W: std::fmt::Write> IonValueFormatter<'a, W> {
             return Ok(());
         }
         let offset_minutes = offset_minutes.unwrap();
+        if offset_minutes == 0 && self.zero_offset_as_z {
+            write!(self.output, "Z")?;
+            return Ok(());
+        }
 
         const MINUTES_PER_HOUR: i32 = 60;
         // Split the offset into a sign and magnitude for formatting
@@ -539,9 +559,7 @@ mod formatter_test {
         F: for<'a> FnMut(&mut IonValueFormatter<'a, String>) -> IonResult<()>,
     {
         let mut actual = String::new();
-        let mut ivf = IonValueFormatter {
-            output: &mut actual,
-        };
+        let mut ivf = IonValueFormatter::new(&mut actual);
 
         let _ = f(&mut ivf);
         assert_eq!(actual, expected)
@@ -598,6 +616,26 @@ mod formatter_test {
         Ok(())
     }
 
+    #[test]
+    fn test_format_timestamp_with_zero_offset_as_z() -> IonResult<()> {
+        let utc = Timestamp::with_ymd(2000, 8, 22)
+            .with_hms(15, 45, 11)
+            .build_at_offset(0)?;
+        let unknown = Timestamp::with_ymd(2000, 8, 22)
+            .with_hour_and_minute(15, 45)
+            .build_at_unknown_offset()?;
+        let mut output = String::new();
+        let mut ivf = IonValueFormatter::new(&mut output).with_zero_offset_as_z(true);
+        ivf.format_timestamp(&utc)?;
+        ivf.format_timestamp(&unknown)?;
+        assert_eq!(output, "2000-08-22T15:45:11Z2000-08-22T15:45-00:00");
+        formatter(
+            |ivf| ivf.format_timestamp(&utc),
+            "2000-08-22T15:45:11+00:00",
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_format_symbol() -> IonResult<()> {
         formatter(|ivf| ivf.format_symbol("foo"), "foo");
@@ -23,6 +23,10 @@ impl AnnotatedTextValue {
         &self.value
     }
 
+    pub(crate) fn value_mut(&mut self) -> &mut TextValue {
+        &mut self.value
+    }
+
     pub(crate) fn is_null(&self) -> bool {
         matches!(self.value(), TextValue::Null(_))
     }
@@ -51,6 +55,9 @@ pub(crate) enum TextValue {
     Float(f64),
     Decimal(Decimal),
     Timestamp(Timestamp),
+    // A timestamp whose text had a leap second (`:60`), with the second clamped to `:59`. The
+    // reader replaces it with a `Timestamp` or an error according to its `LeapSecondPolicy`.
+    LeapSecondTimestamp(Timestamp),
     // TODO: String(&str) will be possible if/when we add reusable buffers to the TextReader.
     String(String),
     Symbol(RawSymbolToken),
@@ -71,7 +78,7 @@ impl TextValue {
             TextValue::Integer(_) => IonType::Integer,
             TextValue::Float(_) => IonType::Float,
             TextValue::Decimal(_) => IonType::Decimal,
-            TextValue::Timestamp(_) => IonType::Timestamp,
+            TextValue::Timestamp(_) | TextValue::LeapSecondTimestamp(_) => IonType::Timestamp,
             TextValue::String(_) => IonType::String,
             TextValue::Symbol(_) => IonType::Symbol,
             TextValue::Blob(_) => IonType::Blob,
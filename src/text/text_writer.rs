@@ -4,13 +4,15 @@ use crate::text::raw_text_writer::RawTextWriter;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::value::writer::TextKind;
-use crate::writer::Writer;
+use crate::writer::{Writer, WriterLimits};
 use crate::{Integer, IonType, RawTextWriterBuilder, SymbolTable};
 use delegate::delegate;
 use std::io::Write;
 
 pub struct TextWriterBuilder {
     text_kind: TextKind,
+    limits: WriterLimits,
+    zero_offset_as_z: bool,
 }
 
 impl TextWriterBuilder {
@@ -23,6 +25,8 @@ impl TextWriterBuilder {
     pub fn new() -> TextWriterBuilder {
         TextWriterBuilder {
             text_kind: TextKind::Compact,
+            limits: WriterLimits::default(),
+            zero_offset_as_z: false,
         }
     }
 
@@ -46,9 +50,32 @@ impl TextWriterBuilder {
     pub fn pretty() -> TextWriterBuilder {
         TextWriterBuilder {
             text_kind: TextKind::Pretty,
+            limits: WriterLimits::default(),
+            zero_offset_as_z: false,
         }
     }
 
+    /// Constructs a text Ion writer that enforces [WriterLimits::hardened], so that it cannot
+    /// produce data that a reader using [ReaderBuilder::hardened](crate::ReaderBuilder::hardened)
+    /// would reject. The limits can be adjusted using [TextWriterBuilder::with_limits].
+    pub fn hardened() -> TextWriterBuilder {
+        TextWriterBuilder::new().with_limits(WriterLimits::hardened())
+    }
+
+    /// Sets the limits that the writer will enforce on the data it is asked to write.
+    pub fn with_limits(mut self, limits: WriterLimits) -> TextWriterBuilder {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets whether timestamps with a known offset of zero are written as `Z` (for example,
+    /// `2021-02-05T16:43Z`) rather than `+00:00`. Both forms are valid Ion; `+00:00` is the
+    /// default.
+    pub fn with_zero_offset_as_z(mut self, zero_offset_as_z: bool) -> TextWriterBuilder {
+        self.zero_offset_as_z = zero_offset_as_z;
+        self
+    }
+
     /// Constructs a new instance of TextWriter that writes values to the provided io::Write
     /// implementation.
     pub fn build<W: Write>(self, sink: W) -> IonResult<TextWriter<W>> {
@@ -56,7 +83,10 @@ impl TextWriterBuilder {
             TextKind::Compact => RawTextWriterBuilder::new(),
             TextKind::Pretty => RawTextWriterBuilder::pretty(),
         };
-        let raw_writer = builder.build(sink)?;
+        let raw_writer = builder
+            .with_limits(self.limits)
+            .with_zero_offset_as_z(self.zero_offset_as_z)
+            .build(sink)?;
         let text_writer = TextWriter {
             raw_writer,
             symbol_table: SymbolTable::new(),
@@ -81,6 +111,14 @@ pub struct TextWriter<W: Write> {
     symbol_table: SymbolTable,
 }
 
+impl<W: Write> TextWriter<W> {
+    /// Returns the symbol table that the writer uses to resolve symbol IDs into text. Text
+    /// symbols are written inline, so this table only ever holds the system symbols.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
+    }
+}
+
 impl<W: Write> Writer for TextWriter<W> {
     fn supports_text_symbol_tokens(&self) -> bool {
         // The TextWriter can always write text field names, annotations, and symbols.
@@ -0,0 +1,375 @@
+//! A low-level view of Ion text as a sequence of tokens, for tools (like syntax highlighters,
+//! linters, and formatters) that need to see exactly how a document was written rather than the
+//! values that it represents.
+
+use std::ops::Range;
+
+use nom::bytes::streaming::tag;
+use nom::Err::{Error, Failure, Incomplete};
+
+use crate::position::Position;
+use crate::result::{decoding_error_raw, IonResult};
+use crate::text::parse_result::IonParseResult;
+use crate::text::parsers::comments::comment;
+use crate::text::parsers::string::long_string_segment;
+use crate::text::parsers::symbol::parse_operator;
+use crate::text::parsers::value::scalar;
+use crate::text::parsers::whitespace;
+use crate::types::IonType;
+
+// Appended to the input so that the streaming parsers can tell that a token at the end of the
+// input is complete. Tokens found in the sentinel are never surfaced.
+const SENTINEL_ION_TEXT: &str = "\n0\n";
+
+/// The kinds of [Token] that make up Ion text.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    /// One or more consecutive whitespace characters.
+    Whitespace,
+    /// A `// rest-of-line` or `/* block */` comment. Rest-of-line comments do not include the
+    /// newline that ends them.
+    Comment,
+    /// The text of a scalar value of the given type, like `null.int`, `5`, `"foo"`, `$10`, or
+    /// `{{aGVsbG8=}}`. Each `'''`-delimited segment of a long string is a separate token.
+    Scalar(IonType),
+    /// A symbol made of operator characters (like `+` or `<=`), which is only recognized inside
+    /// of an s-expression.
+    Operator,
+    /// `{`
+    StructStart,
+    /// `}`
+    StructEnd,
+    /// `[`
+    ListStart,
+    /// `]`
+    ListEnd,
+    /// `(`
+    SExpressionStart,
+    /// `)`
+    SExpressionEnd,
+    /// `,`, which separates list elements and struct fields.
+    Comma,
+    /// `:`, which separates a struct field's name from its value.
+    Colon,
+    /// `::`, which follows each of a value's annotations.
+    DoubleColon,
+}
+
+// Punctuation tokens, ordered such that no token appears after another one that is its prefix.
+const PUNCTUATION: &[(&str, TokenKind)] = &[
+    ("::", TokenKind::DoubleColon),
+    (":", TokenKind::Colon),
+    (",", TokenKind::Comma),
+    ("{", TokenKind::StructStart),
+    ("}", TokenKind::StructEnd),
+    ("[", TokenKind::ListStart),
+    ("]", TokenKind::ListEnd),
+    ("(", TokenKind::SExpressionStart),
+    (")", TokenKind::SExpressionEnd),
+];
+
+/// A fragment of Ion text and the location at which it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    span: Range<usize>,
+    position: Position,
+}
+
+impl<'a> Token<'a> {
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    /// Returns the token's text exactly as it appears in the input.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns the range of byte offsets in the input that the token's text occupies.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the byte offset, line, and column at which the token's text begins.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// An iterator over the [Token]s in a string of Ion text.
+///
+/// The tokenizer reports the text's syntax without interpreting it; it does not check that
+/// containers are balanced, that punctuation appears where it is allowed, or that symbol IDs are
+/// defined. If it encounters text that is not a token, it returns an error and then ends.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{IonType, TokenKind, Tokenizer};
+///
+/// let kinds = Tokenizer::new("foo::[1] // bar")
+///     .map(|token| token.map(|token| token.kind()))
+///     .collect::<ion_rs::result::IonResult<Vec<_>>>()?;
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         TokenKind::Scalar(IonType::Symbol),
+///         TokenKind::DoubleColon,
+///         TokenKind::ListStart,
+///         TokenKind::Scalar(IonType::Integer),
+///         TokenKind::ListEnd,
+///         TokenKind::Whitespace,
+///         TokenKind::Comment,
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    // `input` followed by the sentinel text.
+    padded_input: String,
+    offset: usize,
+    line: usize,
+    column: usize,
+    // The types of the containers that have been opened but not closed, used to determine
+    // whether operators are allowed.
+    containers: Vec<IonType>,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(input: &'a str) -> Tokenizer<'a> {
+        Tokenizer {
+            input,
+            padded_input: format!("{}{}", input, SENTINEL_ION_TEXT),
+            offset: 0,
+            line: 1,
+            column: 1,
+            containers: Vec::new(),
+        }
+    }
+
+    fn next_token(&mut self) -> IonResult<Option<Token<'a>>> {
+        if self.offset >= self.input.len() {
+            return Ok(None);
+        }
+        let position =
+            Position::with_offset(self.offset).with_line_and_column(self.line, self.column);
+        let (kind, mut length) = match self.match_token(&self.padded_input[self.offset..]) {
+            Some(Ok(matched)) => matched,
+            Some(Err(description)) => {
+                return Err(decoding_error_raw(description).with_position(position))
+            }
+            None => {
+                return Err(decoding_error_raw("found text that is not an Ion token")
+                    .with_position(position))
+            }
+        };
+        let remaining_length = self.input.len() - self.offset;
+        if length > remaining_length {
+            if kind != TokenKind::Whitespace {
+                return Err(decoding_error_raw("unexpected end of input").with_position(position));
+            }
+            // Whitespace at the end of the input was joined by the sentinel's.
+            length = remaining_length;
+        }
+
+        let span = self.offset..self.offset + length;
+        let text = &self.input[span.clone()];
+        match kind {
+            TokenKind::StructStart => self.containers.push(IonType::Struct),
+            TokenKind::ListStart => self.containers.push(IonType::List),
+            TokenKind::SExpressionStart => self.containers.push(IonType::SExpression),
+            TokenKind::StructEnd | TokenKind::ListEnd | TokenKind::SExpressionEnd => {
+                let _ = self.containers.pop();
+            }
+            _ => {}
+        }
+        for character in text.chars() {
+            if character == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.offset = span.end;
+        Ok(Some(Token {
+            kind,
+            text,
+            span,
+            position,
+        }))
+    }
+
+    // Returns the kind and length of the token at the beginning of `text`, an error message if
+    // the text began with a malformed token, or `None` if it did not begin with a token at all.
+    fn match_token(&self, text: &str) -> Option<Result<(TokenKind, usize), String>> {
+        use TokenKind::*;
+        let with_kind = |kind: TokenKind| move |length: usize| (kind, length);
+
+        if let Some(result) = matched_length(text, whitespace(text)) {
+            return Some(result.map(with_kind(Whitespace)));
+        }
+        if let Some(result) = matched_length(text, comment(text)) {
+            return Some(result.map(with_kind(Comment)));
+        }
+        if let Some(result) = matched_length(text, long_string_segment(text)) {
+            return Some(result.map(with_kind(Scalar(IonType::String))));
+        }
+        // Lobs begin with `{{`, so scalars must be matched before struct punctuation.
+        match scalar(text) {
+            Ok((remaining, value)) => {
+                return Some(Ok((Scalar(value.ion_type()), text.len() - remaining.len())))
+            }
+            result => {
+                if let Some(Err(description)) = matched_length(text, result) {
+                    return Some(Err(description));
+                }
+            }
+        }
+        if self.containers.last() == Some(&IonType::SExpression) {
+            if let Some(result) = matched_length(text, parse_operator(text)) {
+                return Some(result.map(with_kind(Operator)));
+            }
+        }
+        PUNCTUATION.iter().find_map(|(punctuation, kind)| {
+            let result: IonParseResult<&str> = tag(*punctuation)(text);
+            matched_length(text, result).map(|result| result.map(with_kind(*kind)))
+        })
+    }
+}
+
+// Converts the result of applying a parser to `text` into the length of the text that it matched,
+// an error message if the parser found a malformed token, or `None` if the parser did not match.
+fn matched_length<O>(text: &str, result: IonParseResult<O>) -> Option<Result<usize, String>> {
+    match result {
+        Ok((remaining, _)) => Some(Ok(text.len() - remaining.len())),
+        Err(Error(_)) => None,
+        Err(Failure(error)) => Some(Err(error
+            .description()
+            .unwrap_or("found a malformed token")
+            .to_string())),
+        Err(Incomplete(_)) => Some(Err("unexpected end of input".to_string())),
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = IonResult<Token<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_token() {
+            Ok(token) => token.map(Ok),
+            Err(error) => {
+                // Stop after reporting the error.
+                self.offset = self.input.len();
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TokenKind::*;
+
+    fn tokenize(text: &str) -> Vec<(TokenKind, &str)> {
+        Tokenizer::new(text)
+            .map(|token| token.map(|token| (token.kind(), token.text())))
+            .collect::<IonResult<Vec<_>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn tokenize_mixed_document() {
+        let tokens = tokenize("a::{b: [1, 2.5, \"c\"], 'd e':{{aGVsbG8=}}} /* f */ null.int");
+        assert_eq!(
+            tokens,
+            vec![
+                (Scalar(IonType::Symbol), "a"),
+                (DoubleColon, "::"),
+                (StructStart, "{"),
+                (Scalar(IonType::Symbol), "b"),
+                (Colon, ":"),
+                (Whitespace, " "),
+                (ListStart, "["),
+                (Scalar(IonType::Integer), "1"),
+                (Comma, ","),
+                (Whitespace, " "),
+                (Scalar(IonType::Decimal), "2.5"),
+                (Comma, ","),
+                (Whitespace, " "),
+                (Scalar(IonType::String), "\"c\""),
+                (ListEnd, "]"),
+                (Comma, ","),
+                (Whitespace, " "),
+                (Scalar(IonType::Symbol), "'d e'"),
+                (Colon, ":"),
+                (Scalar(IonType::Blob), "{{aGVsbG8=}}"),
+                (StructEnd, "}"),
+                (Whitespace, " "),
+                (Comment, "/* f */"),
+                (Whitespace, " "),
+                (Scalar(IonType::Integer), "null.int"),
+            ]
+        );
+    }
+
+    #[test]
+    fn operators_are_only_recognized_in_sexpressions() {
+        assert_eq!(
+            tokenize("(a+b)"),
+            vec![
+                (SExpressionStart, "("),
+                (Scalar(IonType::Symbol), "a"),
+                (Operator, "+"),
+                (Scalar(IonType::Symbol), "b"),
+                (SExpressionEnd, ")"),
+            ]
+        );
+        assert!(Tokenizer::new("[a+b]").any(|token| token.is_err()));
+    }
+
+    #[test]
+    fn long_string_segments_are_separate_tokens() {
+        assert_eq!(
+            tokenize("'''foo''' // bar\n'''baz'''"),
+            vec![
+                (Scalar(IonType::String), "'''foo'''"),
+                (Whitespace, " "),
+                (Comment, "// bar"),
+                (Whitespace, "\n"),
+                (Scalar(IonType::String), "'''baz'''"),
+            ]
+        );
+    }
+
+    #[test]
+    fn token_locations() -> IonResult<()> {
+        let tokens = Tokenizer::new("foo\n  [bar]\n").collect::<IonResult<Vec<_>>>()?;
+        let bar = &tokens[3];
+        assert_eq!(bar.text(), "bar");
+        assert_eq!(bar.span(), 7..10);
+        assert_eq!(bar.position().line_column(), Some((2, 4)));
+        let last = tokens.last().unwrap();
+        assert_eq!(last.kind(), Whitespace);
+        assert_eq!(last.text(), "\n");
+        assert_eq!(last.span(), 11..12);
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_tokens_end_iteration() {
+        for text in ["[1, \"foo", "/* foo", "{{ aGVsbG8", "foo #"] {
+            let mut tokenizer = Tokenizer::new(text);
+            assert!(
+                tokenizer.any(|token| token.is_err()),
+                "no error for {:?}",
+                text
+            );
+            assert!(tokenizer.next().is_none());
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::HashSet;
+
+use crate::raw_reader::RawReader;
+use crate::reader::{StreamItem, UserReader};
+use crate::result::IonResult;
+use crate::stream_reader::StreamReader;
+use crate::symbol::Symbol;
+use crate::types::IonType;
+use crate::value::native_writer::write_element;
+use crate::value::owned::OwnedElement;
+use crate::value::AnnotationsVec;
+use crate::writer::Writer;
+
+/// The result of applying a transform to the value on which a reader is positioned.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransformAction {
+    /// Pass the value on to the next transform, or to the writer if this was the last transform.
+    Keep,
+    /// Omit the value (and any children it may have) from the output.
+    Skip,
+    /// Write the provided element in place of the value. If the value is a struct field, the
+    /// element is written with the same field name.
+    Replace(OwnedElement),
+}
+
+type TransformFn<'a, R> = Box<dyn FnMut(&mut UserReader<R>) -> IonResult<TransformAction> + 'a>;
+
+/// Streams values from a reader to a writer, applying a sequence of transforms to each value
+/// along the way.
+///
+/// Each transform is invoked with the reader positioned on a value at any depth. Transforms are
+/// applied in the order in which they were added; the first one to return something other than
+/// [TransformAction::Keep] determines what happens to the value. Values that every transform
+/// keeps are copied to the writer along with their field names and annotations, and their
+/// children (if any) are transformed in turn. Skipped values are never read.
+///
+/// ```
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// use ion_rs::{ReaderBuilder, TextWriterBuilder, TransformPipeline};
+///
+/// let mut reader = ReaderBuilder::new().build("{name: \"foo\", password: \"bar\"} draft::{}")?;
+/// let mut buffer = Vec::new();
+/// let mut writer = TextWriterBuilder::new().build(&mut buffer)?;
+/// TransformPipeline::new()
+///     .skip_annotated("draft")
+///     .drop_fields(["password"])
+///     .run(&mut reader, &mut writer)?;
+/// drop(writer);
+/// assert_eq!(String::from_utf8(buffer).unwrap().trim(), "{name: \"foo\"}");
+/// # Ok(())
+/// # }
+/// ```
+pub struct TransformPipeline<'a, R: RawReader> {
+    transforms: Vec<TransformFn<'a, R>>,
+}
+
+impl<'a, R: RawReader + 'a> TransformPipeline<'a, R> {
+    pub fn new() -> TransformPipeline<'a, R> {
+        TransformPipeline {
+            transforms: Vec::new(),
+        }
+    }
+
+    /// Adds a transform to the end of the pipeline. The transform may read the current value
+    /// (which will cause it to be copied from the reader's buffer), but must not advance the
+    /// reader or change its depth.
+    pub fn with_transform<F>(mut self, transform: F) -> TransformPipeline<'a, R>
+    where
+        F: FnMut(&mut UserReader<R>) -> IonResult<TransformAction> + 'a,
+    {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+
+    /// Skips any top-level value that is not annotated with `annotation`.
+    pub fn keep_annotated<A: Into<String>>(self, annotation: A) -> TransformPipeline<'a, R> {
+        let annotation = annotation.into();
+        self.with_transform(move |reader| {
+            if reader.depth() > 0 || has_annotation(reader, &annotation)? {
+                Ok(TransformAction::Keep)
+            } else {
+                Ok(TransformAction::Skip)
+            }
+        })
+    }
+
+    /// Skips any value at any depth that is annotated with `annotation`.
+    pub fn skip_annotated<A: Into<String>>(self, annotation: A) -> TransformPipeline<'a, R> {
+        let annotation = annotation.into();
+        self.with_transform(move |reader| {
+            if has_annotation(reader, &annotation)? {
+                Ok(TransformAction::Skip)
+            } else {
+                Ok(TransformAction::Keep)
+            }
+        })
+    }
+
+    /// Skips any struct field at any depth whose name is in `field_names`.
+    pub fn drop_fields<I, S>(self, field_names: I) -> TransformPipeline<'a, R>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let field_names: HashSet<String> = field_names.into_iter().map(Into::into).collect();
+        self.with_transform(move |reader| {
+            if reader.parent_type() == Some(IonType::Struct)
+                && field_names.contains(reader.field_name()?.as_ref())
+            {
+                Ok(TransformAction::Skip)
+            } else {
+                Ok(TransformAction::Keep)
+            }
+        })
+    }
+
+    /// Replaces each value for which `mapper` returns `Some(element)` with that element. Values
+    /// for which `mapper` returns `None` are kept.
+    pub fn map_values<F>(self, mut mapper: F) -> TransformPipeline<'a, R>
+    where
+        F: FnMut(&mut UserReader<R>) -> IonResult<Option<OwnedElement>> + 'a,
+    {
+        self.with_transform(move |reader| {
+            Ok(match mapper(reader)? {
+                Some(element) => TransformAction::Replace(element),
+                None => TransformAction::Keep,
+            })
+        })
+    }
+
+    /// Transforms each of the remaining values at the reader's current depth and writes the
+    /// results to `writer`. If the writer is at the top level when all of the values have been
+    /// written, it is flushed.
+    pub fn run<W: Writer>(&mut self, reader: &mut UserReader<R>, writer: &mut W) -> IonResult<()> {
+        // The number of containers that have been stepped into below the starting depth. Nested
+        // values are visited iteratively so that deeply nested input cannot overflow the stack.
+        let mut depth = 0usize;
+        loop {
+            match next_value(reader)? {
+                Some((ion_type, is_null)) => {
+                    if self.transform_value(reader, writer, ion_type, is_null)? {
+                        depth += 1;
+                    }
+                }
+                None if depth > 0 => {
+                    reader.step_out()?;
+                    writer.step_out()?;
+                    depth -= 1;
+                }
+                None => break,
+            }
+        }
+        if writer.depth() == 0 {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    fn apply_transforms(&mut self, reader: &mut UserReader<R>) -> IonResult<TransformAction> {
+        for transform in self.transforms.iter_mut() {
+            match transform(reader)? {
+                TransformAction::Keep => continue,
+                action => return Ok(action),
+            }
+        }
+        Ok(TransformAction::Keep)
+    }
+
+    // Transforms the value on which the reader is positioned. If the value is a container that
+    // is being kept, steps into it with both the reader and the writer and returns `true`; the
+    // caller is responsible for transforming its children and stepping back out.
+    fn transform_value<W: Writer>(
+        &mut self,
+        reader: &mut UserReader<R>,
+        writer: &mut W,
+        ion_type: IonType,
+        is_null: bool,
+    ) -> IonResult<bool> {
+        let action = self.apply_transforms(reader)?;
+        if action == TransformAction::Skip {
+            return Ok(false);
+        }
+        if reader.parent_type() == Some(IonType::Struct) {
+            writer.set_field_name(reader.field_name()?);
+        }
+        if let TransformAction::Replace(element) = action {
+            write_element(writer, None, &element)?;
+            return Ok(false);
+        }
+
+        let annotations = reader
+            .annotations()
+            .collect::<IonResult<AnnotationsVec<Symbol>>>()?;
+        writer.set_annotations(annotations);
+        if is_null {
+            writer.write_null(ion_type)?;
+            return Ok(false);
+        }
+        match ion_type {
+            IonType::Null => unreachable!("non-null value had IonType::Null"),
+            IonType::Boolean => writer.write_bool(reader.read_bool()?)?,
+            IonType::Integer => writer.write_integer(&reader.read_integer()?)?,
+            IonType::Float => writer.write_f64(reader.read_f64()?)?,
+            IonType::Decimal => writer.write_decimal(&reader.read_decimal()?)?,
+            IonType::Timestamp => writer.write_timestamp(&reader.read_timestamp()?)?,
+            IonType::Symbol => writer.write_symbol(reader.read_symbol()?)?,
+            IonType::String => writer.write_string(reader.read_str()?)?,
+            IonType::Clob => writer.write_clob(reader.read_clob()?)?,
+            IonType::Blob => writer.write_blob(reader.read_blob()?)?,
+            IonType::List | IonType::SExpression | IonType::Struct => {
+                writer.step_in(ion_type)?;
+                reader.step_in()?;
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl<'a, R: RawReader + 'a> Default for TransformPipeline<'a, R> {
+    fn default() -> Self {
+        TransformPipeline::new()
+    }
+}
+
+fn has_annotation<R: RawReader>(reader: &UserReader<R>, annotation: &str) -> IonResult<bool> {
+    for candidate in reader.annotations() {
+        if candidate? == annotation {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// Advances to the next value at the current depth, returning its type and whether it is null.
+fn next_value<R: RawReader>(reader: &mut UserReader<R>) -> IonResult<Option<(IonType, bool)>> {
+    Ok(match reader.next()? {
+        StreamItem::Value(ion_type) => Some((ion_type, false)),
+        StreamItem::Null(ion_type) => Some((ion_type, true)),
+        StreamItem::Nothing => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ion_eq::IonEq;
+    use crate::value::reader::{native_element_reader, ElementReader};
+    use crate::value::{Builder, Element};
+    use crate::{BinaryWriterBuilder, Reader, ReaderBuilder, TextWriterBuilder};
+
+    fn transform_text<'r>(
+        pipeline: &mut TransformPipeline<'r, Box<dyn RawReader + 'r>>,
+        ion: &'r str,
+    ) -> Vec<OwnedElement> {
+        let mut reader = ReaderBuilder::new().build(ion).unwrap();
+        let mut buffer = Vec::new();
+        let mut writer = TextWriterBuilder::new().build(&mut buffer).unwrap();
+        pipeline.run(&mut reader, &mut writer).unwrap();
+        drop(writer);
+        native_element_reader().read_all(&buffer).unwrap()
+    }
+
+    fn elements(ion: &str) -> Vec<OwnedElement> {
+        native_element_reader().read_all(ion.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn copy_everything() {
+        let ion = r#"
+            null null.struct true -5 1e0 2.5 2022-01-01T foo "bar" {{"baz"}} {{aGVsbG8=}}
+            a::b::[1, c::(2 3), {d: e::4, 'f g': null.list}]
+        "#;
+        let mut pipeline = TransformPipeline::new();
+        assert!(transform_text(&mut pipeline, ion).ion_eq(&elements(ion)));
+    }
+
+    #[test]
+    fn filter_and_drop() {
+        let ion = r#"
+            keep::{a: 1, secret: 2, b: {secret: 3, c: drop::4, d: 5}}
+            {a: 6}
+            keep::drop::7
+        "#;
+        let mut pipeline = TransformPipeline::new()
+            .keep_annotated("keep")
+            .skip_annotated("drop")
+            .drop_fields(["secret"]);
+        let expected = elements("keep::{a: 1, b: {d: 5}}");
+        assert!(transform_text(&mut pipeline, ion).ion_eq(&expected));
+    }
+
+    #[test]
+    fn map_values() {
+        let mut pipeline = TransformPipeline::new().map_values(|reader: &mut Reader| {
+            if reader.ion_type() == Some(IonType::Integer) && !reader.is_null() {
+                let value = reader.read_i64()?;
+                return Ok(Some(
+                    OwnedElement::new_i64(value * 10).with_annotations(vec!["scaled".into()]),
+                ));
+            }
+            Ok(None)
+        });
+        let expected = elements("{a: scaled::10, b: [scaled::20, x]} scaled::30");
+        let actual = transform_text(&mut pipeline, "{a: 1, b: [2, x]} y::3");
+        assert!(actual.ion_eq(&expected));
+    }
+
+    #[test]
+    fn transform_to_binary() -> IonResult<()> {
+        let ion = "{a: 1, b: [c, \"d\"]} e::f";
+        let mut reader = ReaderBuilder::new().build(ion)?;
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        TransformPipeline::new()
+            .drop_fields(["a"])
+            .run(&mut reader, &mut writer)?;
+        drop(writer);
+        let actual = native_element_reader().read_all(&buffer)?;
+        assert!(actual.ion_eq(&elements("{b: [c, \"d\"]} e::f")));
+        Ok(())
+    }
+
+    #[test]
+    fn deeply_nested_values() -> IonResult<()> {
+        let depth = 100_000;
+        let ion = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let mut reader = ReaderBuilder::new().build(ion.as_str())?;
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut buffer)?;
+        TransformPipeline::new().run(&mut reader, &mut writer)?;
+        drop(writer);
+
+        // Count the levels of nesting in the output without materializing it.
+        let mut reader = ReaderBuilder::new().build(buffer)?;
+        let mut levels = 0;
+        while reader.next()? == StreamItem::Value(IonType::List) {
+            reader.step_in()?;
+            levels += 1;
+        }
+        assert_eq!(levels, depth);
+        Ok(())
+    }
+}
@@ -4,13 +4,41 @@ use bigdecimal::{BigDecimal, Signed};
 use num_bigint::{BigInt, BigUint, ToBigUint};
 
 use crate::ion_eq::IonEq;
-use crate::result::{illegal_operation, IonError};
+use crate::result::{decoding_error_raw, illegal_operation, IonError};
+use crate::text::parsers::decimal::parse_decimal;
+use crate::text::text_value::TextValue;
 use crate::types::coefficient::{Coefficient, Sign};
 use crate::types::magnitude::Magnitude;
 use num_traits::Zero;
 use std::convert::{TryFrom, TryInto};
 use std::fmt::{Display, Formatter};
-use std::ops::Neg;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Determines how a Decimal operation rounds a result that cannot be represented exactly at the
+/// requested scale. The descriptions below use a scale of 0 as an example.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round away from zero. `2.1` becomes `3` and `-2.1` becomes `-3`.
+    Up,
+    /// Round toward zero, truncating the discarded digits. `2.9` becomes `2` and `-2.9` becomes
+    /// `-2`.
+    Down,
+    /// Round toward positive infinity. `2.1` becomes `3` and `-2.9` becomes `-2`.
+    Ceiling,
+    /// Round toward negative infinity. `2.9` becomes `2` and `-2.1` becomes `-3`.
+    Floor,
+    /// Round to the nearest value; halfway values are rounded away from zero. `2.5` becomes `3`
+    /// and `-2.5` becomes `-3`.
+    HalfUp,
+    /// Round to the nearest value; halfway values are rounded toward zero. `2.5` becomes `2` and
+    /// `-2.5` becomes `-2`.
+    HalfDown,
+    /// Round to the nearest value; halfway values are rounded to the neighbor with an even final
+    /// digit. `2.5` becomes `2` and `3.5` becomes `4`. This is the default.
+    #[default]
+    HalfEven,
+}
 
 /// An arbitrary-precision Decimal type with a distinct representation of negative zero (`-0`).
 #[derive(Clone, Debug)]
@@ -105,6 +133,133 @@ impl Decimal {
         num_coefficient_decimal_digits > self.exponent.unsigned_abs()
     }
 
+    /// Returns the exact sum of `self` and `other`, whose exponent is the smaller of their two
+    /// exponents. For example, `1.5 + 0.25` is `1.75`, and `1.50 + 1.50` is `3.00`. Following
+    /// IEEE 754, the sum is only a negative zero if both operands are negative zeros. Returns
+    /// `None` if the operands' exponents differ by more than 100,000, since the sum would need
+    /// at least that many digits.
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let exponent = self.exponent.min(other.exponent);
+        let sum =
+            self.coefficient_at_exponent(exponent)? + other.coefficient_at_exponent(exponent)?;
+        let is_negative_zero =
+            self.coefficient.is_negative_zero() && other.coefficient.is_negative_zero();
+        Some(Decimal::from_big_int(sum, exponent, is_negative_zero))
+    }
+
+    /// Returns the exact difference of `self` and `other`. See [Decimal::checked_add].
+    pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        self.checked_add(&-other.clone())
+    }
+
+    /// Returns the exact product of `self` and `other`, whose exponent is the sum of their two
+    /// exponents. For example, `1.5 * 0.25` is `0.375`. A zero product is negative if the
+    /// operands have different signs. Returns `None` if the sum of the exponents overflows an
+    /// `i64`.
+    pub fn checked_mul(&self, other: &Decimal) -> Option<Decimal> {
+        let exponent = self.exponent.checked_add(other.exponent)?;
+        let product = self.signed_coefficient() * other.signed_coefficient();
+        let is_negative_zero = self.coefficient.sign() != other.coefficient.sign();
+        Some(Decimal::from_big_int(product, exponent, is_negative_zero))
+    }
+
+    /// Returns the quotient of `self` and `other` with the specified `scale` (that is, with an
+    /// exponent of `-scale`), rounded using `rounding_mode`. For example, `1 / 3` at a scale of
+    /// 4 is `0.3333`. A zero quotient is negative if the operands have different signs. Returns
+    /// `None` if `other` is a zero, or if computing the quotient would require scaling either
+    /// operand's coefficient by more than 100,000 digits (that is, if `e1 - e2 + scale` is
+    /// outside of `-100_000..=100_000`, where `e1` and `e2` are the operands' exponents).
+    pub fn checked_div(
+        &self,
+        other: &Decimal,
+        scale: i64,
+        rounding_mode: RoundingMode,
+    ) -> Option<Decimal> {
+        if other.is_zero() {
+            return None;
+        }
+        // The quotient's coefficient is (c1 * 10^e1) / (c2 * 10^e2) * 10^scale, so one side or
+        // the other needs to be scaled up by 10^(e1 - e2 + scale).
+        let exponent_delta = self.exponent as i128 - other.exponent as i128 + scale as i128;
+        let power_of_ten = u32::try_from(exponent_delta.unsigned_abs())
+            .ok()
+            .filter(|delta| *delta <= Self::MAX_EXPONENT_DELTA)
+            .map(|delta| BigInt::from(10u64).pow(delta))?;
+        let (numerator, denominator) = if exponent_delta >= 0 {
+            (
+                self.signed_coefficient() * power_of_ten,
+                other.signed_coefficient(),
+            )
+        } else {
+            (
+                self.signed_coefficient(),
+                other.signed_coefficient() * power_of_ten,
+            )
+        };
+
+        let mut quotient = &numerator / &denominator;
+        let remainder = &numerator % &denominator;
+        let is_negative = numerator.is_negative() != denominator.is_negative();
+        if !remainder.is_zero() {
+            // Compare the discarded fraction to one half.
+            let half_comparison = (remainder.abs() * 2u32).cmp(&denominator.abs());
+            let round_away_from_zero = match rounding_mode {
+                RoundingMode::Up => true,
+                RoundingMode::Down => false,
+                RoundingMode::Ceiling => !is_negative,
+                RoundingMode::Floor => is_negative,
+                RoundingMode::HalfUp => half_comparison != Ordering::Less,
+                RoundingMode::HalfDown => half_comparison == Ordering::Greater,
+                RoundingMode::HalfEven => match half_comparison {
+                    Ordering::Equal => (&quotient % 2u32) != BigInt::zero(),
+                    ordering => ordering == Ordering::Greater,
+                },
+            };
+            if round_away_from_zero {
+                quotient += if is_negative { -1 } else { 1 };
+            }
+        }
+        let exponent = scale.checked_neg()?;
+        let is_negative_zero = self.coefficient.sign() != other.coefficient.sign();
+        Some(Decimal::from_big_int(quotient, exponent, is_negative_zero))
+    }
+
+    // The largest number of digits that arithmetic will add to a coefficient when bringing it to
+    // another exponent. Computing `10^delta` for an unbounded `delta` can exhaust the process's
+    // memory and time, and Ion decimals' exponents are arbitrary.
+    const MAX_EXPONENT_DELTA: u32 = 100_000;
+
+    // Returns the coefficient as a signed integer. A negative zero becomes zero.
+    fn signed_coefficient(&self) -> BigInt {
+        let magnitude = BigInt::from(self.coefficient.magnitude().to_biguint().unwrap());
+        match self.coefficient.sign() {
+            Sign::Negative => -magnitude,
+            Sign::Positive => magnitude,
+        }
+    }
+
+    // Returns the signed coefficient that this Decimal would have if its value were expressed
+    // using the specified (smaller or equal) exponent. Returns `None` if that would require
+    // scaling the coefficient by more than `10^MAX_EXPONENT_DELTA`.
+    fn coefficient_at_exponent(&self, exponent: i64) -> Option<BigInt> {
+        let exponent_delta = u32::try_from(self.exponent as i128 - exponent as i128)
+            .ok()
+            .filter(|delta| *delta <= Self::MAX_EXPONENT_DELTA)?;
+        Some(self.signed_coefficient() * BigInt::from(10u64).pow(exponent_delta))
+    }
+
+    // Constructs a Decimal from a signed coefficient. If the coefficient is zero,
+    // `is_negative_zero` determines its sign.
+    fn from_big_int(coefficient: BigInt, exponent: i64, is_negative_zero: bool) -> Decimal {
+        let sign = if coefficient.is_negative() || (coefficient.is_zero() && is_negative_zero) {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let magnitude = coefficient.abs().to_biguint().unwrap();
+        Decimal::new(Coefficient::new(sign, magnitude), exponent)
+    }
+
     // Determines whether the first decimal value is greater than, equal to, or less than
     // the second decimal value.
     // TODO: This currently uses the rules for Ion equivalence to determine if two values are equal.
@@ -201,6 +356,58 @@ impl Ord for Decimal {
     }
 }
 
+/// Adds two Decimals exactly. See [Decimal::checked_add].
+///
+/// # Panics
+/// Panics if the operands' exponents differ by more than 100,000.
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, rhs: Decimal) -> Decimal {
+        self.checked_add(&rhs)
+            .expect("decimal exponents are too far apart to add")
+    }
+}
+
+/// Subtracts one Decimal from another exactly. See [Decimal::checked_add].
+///
+/// # Panics
+/// Panics if the operands' exponents differ by more than 100,000.
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, rhs: Decimal) -> Decimal {
+        self.checked_sub(&rhs)
+            .expect("decimal exponents are too far apart to subtract")
+    }
+}
+
+/// Multiplies two Decimals exactly. See [Decimal::checked_mul].
+///
+/// # Panics
+/// Panics if the sum of the operands' exponents overflows an `i64`.
+impl Mul for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, rhs: Decimal) -> Decimal {
+        self.checked_mul(&rhs).expect("decimal exponent overflowed")
+    }
+}
+
+/// Negates the Decimal's coefficient, keeping its exponent. Negating a zero produces a zero of
+/// the opposite sign.
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(mut self) -> Decimal {
+        self.coefficient.sign = match self.coefficient.sign {
+            Sign::Negative => Sign::Positive,
+            Sign::Positive => Sign::Negative,
+        };
+        self
+    }
+}
+
 macro_rules! impl_decimal_from_unsigned_primitive_integer {
     ($($t:ty),*) => ($(
         impl From<$t> for Decimal {
@@ -306,6 +513,24 @@ impl Display for Decimal {
     }
 }
 
+/// Parses the Ion text representation of a decimal, such as `1.25d-3`, `-0d0`, or `123.`.
+/// Surrounding whitespace, annotations, and other Ion values are not accepted.
+impl FromStr for Decimal {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // The text parser expects each value to be followed by a delimiter.
+        let input = format!("{} ", text);
+        match parse_decimal(&input) {
+            Ok((" ", TextValue::Decimal(decimal))) => Ok(decimal),
+            _ => Err(decoding_error_raw(format!(
+                "'{}' is not a valid Ion decimal",
+                text
+            ))),
+        }
+    }
+}
+
 /// Make a Decimal from a BigDecimal. This is a lossless operation.
 impl From<BigDecimal> for Decimal {
     fn from(value: BigDecimal) -> Self {
@@ -336,17 +561,43 @@ impl TryFrom<Decimal> for BigDecimal {
     }
 }
 
+/// Makes an ion-c [`IonDecimalPtr`](ion_c_sys::decimal::IonDecimalPtr) from a Decimal. Returns
+/// an Error if the Decimal is a negative zero, which cannot be converted.
+#[cfg(feature = "ion_c")]
+impl TryFrom<&Decimal> for ion_c_sys::decimal::IonDecimalPtr {
+    type Error = IonError;
+
+    fn try_from(value: &Decimal) -> Result<Self, Self::Error> {
+        let big_decimal: BigDecimal = value.clone().try_into()?;
+        Ok(ion_c_sys::decimal::IonDecimalPtr::try_from_bigdecimal(
+            &big_decimal,
+        )?)
+    }
+}
+
+/// Makes a Decimal from an ion-c `ION_DECIMAL`. Returns an Error if the `ION_DECIMAL` is NaN or
+/// infinite. The conversion borrows the `ION_DECIMAL` mutably, but does not change its value.
+#[cfg(feature = "ion_c")]
+impl TryFrom<&mut ion_c_sys::ION_DECIMAL> for Decimal {
+    type Error = IonError;
+
+    fn try_from(value: &mut ion_c_sys::ION_DECIMAL) -> Result<Self, Self::Error> {
+        Ok(value.try_to_bigdecimal()?.into())
+    }
+}
+
 #[cfg(test)]
 mod decimal_tests {
     use crate::result::IonResult;
     use crate::types::coefficient::{Coefficient, Sign};
-    use crate::types::decimal::Decimal;
+    use crate::types::decimal::{Decimal, RoundingMode};
     use bigdecimal::BigDecimal;
     use num_bigint::BigUint;
     use num_traits::{Float, ToPrimitive};
     use std::cmp::Ordering;
     use std::convert::TryInto;
     use std::fmt::Write;
+    use std::str::FromStr;
 
     use crate::ion_eq::IonEq;
     use rstest::*;
@@ -497,6 +748,21 @@ mod decimal_tests {
         assert!(conversion_result.is_err());
     }
 
+    #[cfg(feature = "ion_c")]
+    #[test]
+    fn test_ion_c_decimal_round_trip() -> IonResult<()> {
+        use ion_c_sys::decimal::IonDecimalPtr;
+
+        let decimal = Decimal::new(-24601, -3);
+        let mut ion_c_decimal = IonDecimalPtr::try_from(&decimal)?;
+        let actual = Decimal::try_from(&mut *ion_c_decimal)?;
+        assert_eq!(actual, decimal);
+
+        let conversion_result = IonDecimalPtr::try_from(&Decimal::negative_zero());
+        assert!(conversion_result.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_convert_from_big_decimal() {
         let big_decimal: BigDecimal = BigDecimal::new((-24601).into(), 3);
@@ -526,4 +792,143 @@ mod decimal_tests {
     fn test_precision(#[case] value: Decimal, #[case] expected: u64) {
         assert_eq!(value.precision(), expected);
     }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), Decimal::new(25, -2), Decimal::new(175, -2))]
+    #[case(Decimal::new(150, -2), Decimal::new(150, -2), Decimal::new(300, -2))]
+    #[case(Decimal::new(1, 3), Decimal::new(-1, 0), Decimal::new(999, 0))]
+    #[case(Decimal::new(-5, -1), Decimal::new(5, -1), Decimal::new(0, -1))]
+    #[case(Decimal::negative_zero(), Decimal::negative_zero_with_exponent(-2), Decimal::negative_zero_with_exponent(-2))]
+    #[case(Decimal::negative_zero(), Decimal::new(0, 0), Decimal::new(0, 0))]
+    #[case(
+        Decimal::new(BigUint::from(u64::MAX), 0),
+        Decimal::new(1, 0),
+        Decimal::new(BigUint::from(u64::MAX) + 1u32, 0)
+    )]
+    fn test_add(#[case] lhs: Decimal, #[case] rhs: Decimal, #[case] expected: Decimal) {
+        let sum = lhs.clone() + rhs.clone();
+        assert!(sum.ion_eq(&expected), "{} + {} = {}", lhs, rhs, sum);
+        assert!(rhs.clone().checked_add(&lhs).unwrap().ion_eq(&expected));
+        let difference = sum - rhs;
+        assert_eq!(difference, lhs);
+    }
+
+    #[test]
+    fn test_checked_add_failures() {
+        let one = Decimal::from(1);
+        assert_eq!(Decimal::new(1, 1_000_000_000).checked_add(&one), None);
+        assert_eq!(one.checked_sub(&Decimal::new(1, i64::MIN)), None);
+        assert_eq!(Decimal::new(1, 100_001).checked_add(&one), None);
+        assert!(Decimal::new(1, 100_000).checked_add(&one).is_some());
+    }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), Decimal::new(25, -2), Decimal::new(375, -3))]
+    #[case(Decimal::new(-2, 3), Decimal::new(4, -1), Decimal::new(-8, 2))]
+    #[case(Decimal::new(0, -2), Decimal::new(-3, 0), Decimal::negative_zero_with_exponent(-2))]
+    #[case(Decimal::negative_zero(), Decimal::negative_zero(), Decimal::new(0, 0))]
+    fn test_mul(#[case] lhs: Decimal, #[case] rhs: Decimal, #[case] expected: Decimal) {
+        let product = lhs.clone() * rhs.clone();
+        assert!(product.ion_eq(&expected), "{} * {} = {}", lhs, rhs, product);
+    }
+
+    #[rstest]
+    #[case(Decimal::new(1, 0), Decimal::new(3, 0), 4, RoundingMode::HalfEven, Decimal::new(3333, -4))]
+    #[case(Decimal::new(2, 0), Decimal::new(3, 0), 2, RoundingMode::HalfEven, Decimal::new(67, -2))]
+    #[case(Decimal::new(2, 0), Decimal::new(3, 0), 2, RoundingMode::Down, Decimal::new(66, -2))]
+    #[case(Decimal::new(15, -1), Decimal::new(5, -1), 0, RoundingMode::Up, Decimal::new(3, 0))]
+    #[case(Decimal::new(1, 3), Decimal::new(8, 0), -1, RoundingMode::Down, Decimal::new(12, 1))]
+    #[case(Decimal::new(25, -1), Decimal::new(1, 0), 0, RoundingMode::HalfEven, Decimal::new(2, 0))]
+    #[case(Decimal::new(35, -1), Decimal::new(1, 0), 0, RoundingMode::HalfEven, Decimal::new(4, 0))]
+    #[case(Decimal::new(25, -1), Decimal::new(1, 0), 0, RoundingMode::HalfUp, Decimal::new(3, 0))]
+    #[case(Decimal::new(25, -1), Decimal::new(1, 0), 0, RoundingMode::HalfDown, Decimal::new(2, 0))]
+    #[case(Decimal::new(-25, -1), Decimal::new(1, 0), 0, RoundingMode::HalfUp, Decimal::new(-3, 0))]
+    #[case(Decimal::new(-21, -1), Decimal::new(1, 0), 0, RoundingMode::Ceiling, Decimal::new(-2, 0))]
+    #[case(Decimal::new(-21, -1), Decimal::new(1, 0), 0, RoundingMode::Floor, Decimal::new(-3, 0))]
+    #[case(Decimal::new(21, -1), Decimal::new(-1, 0), 0, RoundingMode::Up, Decimal::new(-3, 0))]
+    #[case(Decimal::new(1, 0), Decimal::new(-3, 0), 0, RoundingMode::Down, Decimal::negative_zero())]
+    #[case(Decimal::new(1, 0), Decimal::new(4, 0), 5, RoundingMode::Down, Decimal::new(25000, -5))]
+    fn test_checked_div(
+        #[case] lhs: Decimal,
+        #[case] rhs: Decimal,
+        #[case] scale: i64,
+        #[case] rounding_mode: RoundingMode,
+        #[case] expected: Decimal,
+    ) {
+        let quotient = lhs.checked_div(&rhs, scale, rounding_mode).unwrap();
+        assert!(
+            quotient.ion_eq(&expected),
+            "{} / {} = {}",
+            lhs,
+            rhs,
+            quotient
+        );
+    }
+
+    #[test]
+    fn test_checked_div_failures() {
+        let one = Decimal::new(1, 0);
+        let rounding_mode = RoundingMode::HalfEven;
+        assert_eq!(one.checked_div(&Decimal::new(0, 2), 2, rounding_mode), None);
+        assert_eq!(
+            one.checked_div(&Decimal::negative_zero(), 2, rounding_mode),
+            None
+        );
+        assert_eq!(
+            Decimal::new(1, i64::MAX).checked_div(&Decimal::new(1, i64::MIN), 0, rounding_mode),
+            None
+        );
+        assert_eq!(one.checked_div(&one, 1_000_000_000, rounding_mode), None);
+        assert_eq!(
+            Decimal::new(1, -100_001).checked_div(&one, 0, rounding_mode),
+            None
+        );
+        assert!(one.checked_div(&one, 100_000, rounding_mode).is_some());
+        assert!(one
+            .checked_div(&Decimal::new(1, 100_000), 0, rounding_mode)
+            .is_some());
+    }
+
+    #[rstest]
+    #[case("1.25d-3", Decimal::new(125, -5))]
+    #[case("-0d0", Decimal::negative_zero())]
+    #[case("123.", Decimal::new(123, 0))]
+    #[case("0.50", Decimal::new(50, -2))]
+    #[case("-1_000.5D2", Decimal::new(-10005, 1))]
+    fn test_from_str(#[case] text: &str, #[case] expected: Decimal) -> IonResult<()> {
+        let decimal: Decimal = text.parse()?;
+        assert!(decimal.ion_eq(&expected), "{} parsed as {}", text, decimal);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("12")]
+    #[case("1.5e0")]
+    #[case(" 1.5")]
+    #[case("1.5 2.5")]
+    #[case("foo::1.5")]
+    fn test_from_str_invalid(#[case] text: &str) {
+        assert!(Decimal::from_str(text).is_err());
+    }
+
+    #[test]
+    fn test_neg() {
+        assert!((-Decimal::new(5, -1)).ion_eq(&Decimal::new(-5, -1)));
+        assert!((-Decimal::new(0, 2)).ion_eq(&Decimal::negative_zero_with_exponent(2)));
+        assert!((-Decimal::negative_zero()).ion_eq(&Decimal::new(0, 0)));
+    }
+
+    #[test]
+    fn test_checked_arithmetic_overflow() {
+        let huge = Decimal::new(1, i64::MAX);
+        let tiny = Decimal::new(1, i64::MIN);
+        assert_eq!(huge.checked_add(&tiny), None);
+        assert_eq!(huge.checked_sub(&tiny), None);
+        assert_eq!(huge.checked_mul(&huge), None);
+        assert!(huge
+            .checked_mul(&tiny)
+            .unwrap()
+            .ion_eq(&Decimal::new(1, -1)));
+    }
 }
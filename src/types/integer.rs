@@ -52,6 +52,38 @@ pub trait IntAccess {
     /// assert_eq!(None, i64_elem.as_big_int());
     /// ```
     fn as_big_int(&self) -> Option<&BigInt>;
+
+    /// Returns the value as an `i128` if it can be represented as such.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::types::integer::*;
+    /// let big_int = Integer::from(i128::MIN);
+    /// assert_eq!(big_int.as_i128(), Some(i128::MIN));
+    /// assert_eq!(Integer::I64(-5).as_i128(), Some(-5));
+    /// assert_eq!(Integer::from(u128::MAX).as_i128(), None);
+    /// ```
+    fn as_i128(&self) -> Option<i128> {
+        match self.as_i64() {
+            Some(value) => Some(value.into()),
+            None => self.as_big_int().and_then(|value| value.to_i128()),
+        }
+    }
+
+    /// Returns the value as a `u128` if it can be represented as such.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::types::integer::*;
+    /// assert_eq!(Integer::from(u128::MAX).as_u128(), Some(u128::MAX));
+    /// assert_eq!(Integer::I64(-5).as_u128(), None);
+    /// ```
+    fn as_u128(&self) -> Option<u128> {
+        match self.as_i64() {
+            Some(value) => u128::try_from(value).ok(),
+            None => self.as_big_int().and_then(|value| value.to_u128()),
+        }
+    }
 }
 
 /// Represents a UInt of any size. Used for reading binary integers and symbol IDs.
@@ -151,6 +183,24 @@ impl IntAccess for Integer {
     }
 }
 
+impl From<i128> for Integer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Integer::I64(value),
+            Err(_) => Integer::BigInt(BigInt::from(value)),
+        }
+    }
+}
+
+impl From<u128> for Integer {
+    fn from(value: u128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Integer::I64(value),
+            Err(_) => Integer::BigInt(BigInt::from(value)),
+        }
+    }
+}
+
 impl PartialEq for Integer {
     fn eq(&self, other: &Self) -> bool {
         use Integer::*;
@@ -236,6 +286,7 @@ where
 
 #[cfg(test)]
 mod integer_tests {
+    use crate::types::integer::IntAccess;
     use num_bigint::BigInt;
     use num_traits::Zero;
     // The 'Big' alias helps distinguish between the enum variant and the wrapped numeric type
@@ -256,6 +307,20 @@ mod integer_tests {
         assert!(Integer::zero().is_zero());
     }
 
+    #[test]
+    fn i128_round_trip() {
+        for value in [0, -1, i64::MIN as i128 - 1, i128::MIN, i128::MAX] {
+            let integer = Integer::from(value);
+            assert_eq!(integer.as_i128(), Some(value));
+            assert_eq!(matches!(integer, I64(_)), i64::try_from(value).is_ok());
+        }
+        for value in [0, u64::MAX as u128, u128::MAX] {
+            assert_eq!(Integer::from(value).as_u128(), Some(value));
+        }
+        assert_eq!(Integer::from(u128::MAX).as_i128(), None);
+        assert_eq!(Integer::from(i128::MIN).as_u128(), None);
+    }
+
     #[test]
     fn add() {
         assert_eq!(I64(0) + I64(0), I64(0));
@@ -1,16 +1,22 @@
 use crate::ion_eq::IonEq;
 use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
+use crate::text::text_formatter::IonValueFormatter;
 use crate::types::coefficient::Sign::Negative;
 use crate::types::decimal::Decimal;
 use crate::types::magnitude::Magnitude;
+use bigdecimal::BigDecimal;
 use chrono::{
     DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+    Utc, Weekday,
 };
-use num_bigint::BigUint;
-use num_traits::ToPrimitive;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{ToPrimitive, Zero};
+use std::cmp::Ordering;
 use std::convert::TryInto;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::Div;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "ion_c")]
 use ion_c_sys::timestamp::{IonDateTime, TSOffsetKind, TSPrecision};
@@ -38,6 +44,21 @@ impl Default for Precision {
     }
 }
 
+/// Determines how a [Timestamp] with a leap second (a `second` field of `60`) is handled. Ion
+/// timestamps cannot represent leap seconds, but they appear in real-world data such as logs.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum LeapSecondPolicy {
+    /// Fail to build or read a timestamp with a leap second. This is the default.
+    #[default]
+    Reject,
+    /// Replace the leap second with the last second of the same minute, `:59`. Fractional
+    /// seconds are kept.
+    Clamp,
+    /// Carry the leap second into the first second of the following minute, `:00`. Fractional
+    /// seconds are kept.
+    Carry,
+}
+
 /// Stores the precision of a Timestamp's fractional seconds, if present. This type is not
 /// self-contained; if the Timestamp has a precision that is less than or equal to nanoseconds
 /// (i.e. fewer than 10 digits), the fractional seconds value will be stored in the Timestamp's
@@ -116,9 +137,6 @@ pub struct Timestamp {
     pub(crate) fractional_seconds: Option<Mantissa>,
 }
 
-// TODO: Timestamp does not yet provide useful accessors for its individual fields. It can be
-//       instantiated and tested for equality, but will not very useful as a general purpose
-//       datetime until these methods are added.
 impl Timestamp {
     /// Converts a [NaiveDateTime] or [DateTime<FixedOffset>] to a Timestamp with the specified
     /// precision. If the precision is [Precision::Second], nanosecond precision (the maximum
@@ -154,6 +172,34 @@ impl Timestamp {
         }
     }
 
+    /// Returns this Timestamp's fractional seconds as an exact Decimal, or `None` if it has no
+    /// fractional seconds. The Decimal has the same precision as the Timestamp; a Timestamp with
+    /// 553 milliseconds would return `0.553`, and one with picoseconds would return all twelve
+    /// digits.
+    pub fn fractional_seconds_decimal(&self) -> Option<Decimal> {
+        self.fractional_seconds_as_decimal()
+    }
+
+    /// Returns this Timestamp's fractional seconds as a number of nanoseconds, or `None` if it
+    /// has no fractional seconds. Fractional seconds that are more precise than nanoseconds are
+    /// truncated; use [Timestamp::fractional_seconds_decimal] to get their exact value.
+    pub fn fractional_seconds_nanoseconds(&self) -> Option<u32> {
+        self.fractional_seconds_as_nanoseconds()
+    }
+
+    /// Returns this Timestamp's fractional seconds as an exact BigDecimal with the same scale, or
+    /// `None` if it has no fractional seconds. Unlike nanoseconds, a BigDecimal can hold
+    /// fractional seconds of any precision, so it can be used for exact arithmetic on values such
+    /// as picoseconds.
+    pub fn fractional_seconds_big_decimal(&self) -> Option<BigDecimal> {
+        let fraction = self.fractional_seconds_as_decimal()?;
+        // BigDecimal cannot represent negative zero, but a zero's exponent still matters.
+        if fraction.is_zero() {
+            return Some(BigDecimal::new(BigInt::zero(), -fraction.exponent));
+        }
+        Some(BigDecimal::try_from(fraction).unwrap())
+    }
+
     /// If the precision is [Precision::Second], returns a Decimal representation of this Timestamp's
     /// fractional seconds; otherwise, returns None.
     ///
@@ -450,6 +496,439 @@ impl Timestamp {
         FractionalSecondSetter { builder }
     }
 
+    /// Returns a Timestamp representing the current system time at an offset of `+00:00`, with
+    /// [Precision::Second] and nanosecond fractional seconds.
+    pub fn now_utc() -> Timestamp {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        Utc::now().with_timezone(&utc).into()
+    }
+
+    /// Returns a Timestamp representing the current system time at the specified offset, with
+    /// [Precision::Second] and nanosecond fractional seconds. Returns an error if the offset
+    /// is not between -24 and +24 hours (exclusive).
+    pub fn now_at_offset(offset_minutes: i32) -> IonResult<Timestamp> {
+        let now = Utc::now().naive_utc();
+        let date_time = TimestampBuilder::apply_offset(offset_minutes, true, now)?;
+        Ok(date_time.into())
+    }
+
+    /// Returns the number of seconds from `other` to this Timestamp, which is negative if `other`
+    /// is later. Offsets are taken into account, and fields beyond a Timestamp's precision count
+    /// as their lowest legal value (so `2021T` is the start of 2021). The result keeps every
+    /// fractional second digit of both Timestamps: its exponent is that of the more precise
+    /// fractional seconds, or zero if neither Timestamp has any.
+    ///
+    /// ```
+    /// # use ion_rs::result::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::types::decimal::Decimal;
+    /// use ion_rs::types::timestamp::Timestamp;
+    ///
+    /// let start = Timestamp::with_ymd_hms(2021, 2, 5, 11, 43, 50).build_at_offset(-5 * 60)?;
+    /// let end = Timestamp::with_ymd_hms_millis(2021, 2, 5, 16, 43, 51, 500).build_at_offset(0)?;
+    /// assert_eq!(end.elapsed_since(&start).to_string(), "1500d-3");
+    /// assert_eq!(start.elapsed_since(&end), Decimal::new(-15, -1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn elapsed_since(&self, other: &Timestamp) -> Decimal {
+        Decimal::from(self.seconds_since_epoch() - other.seconds_since_epoch())
+    }
+
+    /// Creates a Timestamp at an offset of `+00:00` with nanosecond precision from a number of
+    /// nanoseconds since the Unix epoch. Negative values are before the epoch. Returns an error if
+    /// the result would be outside of the years 1 through 9999.
+    pub fn from_epoch_nanos(nanoseconds: i128) -> IonResult<Timestamp> {
+        const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+        let out_of_range = || {
+            illegal_operation_raw(format!(
+                "{} nanoseconds from the Unix epoch is outside of the range that a Timestamp \
+                can represent",
+                nanoseconds
+            ))
+        };
+        let seconds = i64::try_from(nanoseconds.div_euclid(NANOSECONDS_PER_SECOND))
+            .map_err(|_| out_of_range())?;
+        let subsecond_nanoseconds = nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as u32;
+        let date_time = FixedOffset::east_opt(0)
+            .unwrap()
+            .timestamp_opt(seconds, subsecond_nanoseconds)
+            .single()
+            .filter(|date_time| is_valid_year(date_time.year()))
+            .ok_or_else(out_of_range)?;
+        Ok(date_time.into())
+    }
+
+    /// Returns the number of nanoseconds between the Unix epoch and this Timestamp. Timestamps
+    /// with an unknown offset are treated as UTC, and fractional seconds that are more precise
+    /// than nanoseconds are truncated.
+    pub fn to_epoch_nanos(&self) -> i128 {
+        const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let seconds = utc.from_utc_datetime(&self.date_time).timestamp();
+        let nanoseconds = self.fractional_seconds_as_nanoseconds().unwrap_or(0);
+        seconds as i128 * NANOSECONDS_PER_SECOND + nanoseconds as i128
+    }
+
+    // Returns the number of seconds between the Unix epoch and this Timestamp, including all of
+    // its fractional seconds.
+    fn seconds_since_epoch(&self) -> BigDecimal {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let seconds = utc.from_utc_datetime(&self.date_time).timestamp();
+        let fraction = self
+            .fractional_seconds_big_decimal()
+            .unwrap_or_else(BigDecimal::zero);
+        BigDecimal::from(seconds) + fraction
+    }
+
+    /// Returns a Timestamp representing the same instant at the specified offset, with the same
+    /// precision and fractional seconds as this one. Returns an error if the offset is not
+    /// between -24 and +24 hours (exclusive), if this Timestamp's precision is coarser than
+    /// [Precision::HourAndMinute] (since a date cannot be moved to another offset), or if the
+    /// local date at the new offset would be outside of the years 1 through 9999.
+    pub fn with_offset(&self, offset_minutes: i32) -> IonResult<Timestamp> {
+        if self.precision < Precision::HourAndMinute {
+            return illegal_operation(format!(
+                "cannot change the offset of a timestamp with {:?} precision",
+                self.precision
+            ));
+        }
+        // `date_time` is stored in UTC, so only the offset needs to change.
+        let date_time = TimestampBuilder::apply_offset(offset_minutes, true, self.date_time)?;
+        if !is_valid_year(date_time.year()) {
+            return illegal_operation(format!(
+                "moving {} to an offset of {} minutes would put it outside of the years 1 \
+                through 9999",
+                self, offset_minutes
+            ));
+        }
+        Ok(Timestamp {
+            offset: Some(*date_time.offset()),
+            ..self.clone()
+        })
+    }
+
+    /// Returns a Timestamp representing the same instant at an offset of `+00:00`. See
+    /// [Timestamp::with_offset].
+    pub fn to_utc(&self) -> IonResult<Timestamp> {
+        self.with_offset(0)
+    }
+
+    /// Returns a copy of this Timestamp with the specified precision. Lowering the precision
+    /// removes fields as [Timestamp::truncated_to] does. Raising it adds the missing fields with
+    /// their lowest values; for example, raising `2021-02T` to [Precision::Day] produces
+    /// `2021-02-01`. Timestamps raised from [Precision::Day] or coarser have an unknown offset.
+    pub fn with_precision(&self, precision: Precision) -> IonResult<Timestamp> {
+        if precision <= self.precision {
+            return Ok(self.truncated_to(precision));
+        }
+        let mut builder = self.to_builder();
+        builder.precision = precision;
+        builder.build()
+    }
+
+    /// Returns a copy of this Timestamp with the month (1-12) in its local offset replaced.
+    ///
+    /// Like the other `with_*` methods that modify an existing Timestamp, this returns an error
+    /// if the Timestamp's precision does not include the field being set (see
+    /// [Timestamp::with_precision]) or if the resulting Timestamp would be invalid. There is no
+    /// method to replace the year, since [Timestamp::with_year] begins building a new Timestamp.
+    pub fn with_month(&self, month: u32) -> IonResult<Timestamp> {
+        let month = field_as_u8("month", month)?;
+        self.modified(Precision::Month, "month", |builder| {
+            builder.month = Some(month)
+        })
+    }
+
+    /// Returns a copy of this Timestamp with the day of the month (1-31) in its local offset
+    /// replaced. See [Timestamp::with_month].
+    pub fn with_day(&self, day: u32) -> IonResult<Timestamp> {
+        let day = field_as_u8("day", day)?;
+        self.modified(Precision::Day, "day", |builder| builder.day = Some(day))
+    }
+
+    /// Returns a copy of this Timestamp with the hour (0-23) and minute (0-59) in its local offset
+    /// replaced. See [Timestamp::with_month].
+    pub fn with_hour_and_minute(&self, hour: u32, minute: u32) -> IonResult<Timestamp> {
+        let hour = field_as_u8("hour", hour)?;
+        let minute = field_as_u8("minute", minute)?;
+        self.modified(Precision::HourAndMinute, "hour and minute", |builder| {
+            builder.hour = Some(hour);
+            builder.minute = Some(minute);
+        })
+    }
+
+    /// Returns a copy of this Timestamp with the second (0-59) replaced. Fractional seconds are
+    /// kept. See [Timestamp::with_month].
+    pub fn with_second(&self, second: u32) -> IonResult<Timestamp> {
+        let second = field_as_u8("second", second)?;
+        self.modified(Precision::Second, "second", |builder| {
+            builder.second = Some(second)
+        })
+    }
+
+    /// Returns a copy of this Timestamp with the fractional seconds replaced. The Decimal must be
+    /// at least zero and less than one. See [Timestamp::with_month].
+    pub fn with_fractional_seconds(&self, fractional_seconds: Decimal) -> IonResult<Timestamp> {
+        self.modified(Precision::Second, "fractional seconds", |builder| {
+            builder.fractional_seconds = Some(Mantissa::Arbitrary(fractional_seconds));
+            builder.nanoseconds = None;
+        })
+    }
+
+    // Rebuilds the Timestamp after `modify` has changed one of its fields. The Timestamp's
+    // precision must include the field, which is described by `field_name`.
+    fn modified<F>(&self, required: Precision, field_name: &str, modify: F) -> IonResult<Timestamp>
+    where
+        F: FnOnce(&mut TimestampBuilder),
+    {
+        if self.precision < required {
+            return illegal_operation(format!(
+                "cannot set the {} of a timestamp with {:?} precision",
+                field_name, self.precision
+            ));
+        }
+        let mut builder = self.to_builder();
+        modify(&mut builder);
+        builder.build()
+    }
+
+    // Returns a TimestampBuilder that holds all of this Timestamp's fields as they appear in its
+    // local offset. Fields beyond the Timestamp's precision hold their lowest values.
+    fn to_builder(&self) -> TimestampBuilder {
+        let local = self.local_date_time();
+        let nanoseconds = match self.fractional_seconds {
+            Some(Mantissa::Digits(_)) => Some(local.nanosecond()),
+            _ => None,
+        };
+        TimestampBuilder {
+            fields_are_utc: false,
+            precision: self.precision,
+            offset: self.offset(),
+            year: local.year() as u16,
+            month: Some(local.month() as u8),
+            day: Some(local.day() as u8),
+            hour: Some(local.hour() as u8),
+            minute: Some(local.minute() as u8),
+            second: Some(local.second() as u8),
+            fractional_seconds: self.fractional_seconds.clone(),
+            nanoseconds,
+            leap_second_policy: LeapSecondPolicy::Reject,
+        }
+    }
+
+    /// Returns a copy of this Timestamp with every field more precise than `precision` removed.
+    /// For example, truncating `2021-02-05T16:43:51.123-05:00` to [Precision::Day] produces
+    /// `2021-02-05`. Timestamps with date-level precision do not have an offset, so truncating to
+    /// [Precision::Day] or coarser keeps the date as it appears in the local offset. If
+    /// `precision` is not coarser than the Timestamp's current precision, an unmodified copy is
+    /// returned.
+    pub fn truncated_to(&self, precision: Precision) -> Timestamp {
+        if precision >= self.precision {
+            return self.clone();
+        }
+        if precision == Precision::HourAndMinute {
+            // Offsets are a whole number of minutes, so the seconds can be dropped in UTC.
+            return Timestamp {
+                date_time: self
+                    .date_time
+                    .with_second(0)
+                    .unwrap()
+                    .with_nanosecond(0)
+                    .unwrap(),
+                offset: self.offset,
+                precision,
+                fractional_seconds: None,
+            };
+        }
+        let local = self.local_date_time();
+        let date = match precision {
+            Precision::Year => NaiveDate::from_ymd_opt(local.year(), 1, 1).unwrap(),
+            Precision::Month => NaiveDate::from_ymd_opt(local.year(), local.month(), 1).unwrap(),
+            _ => local.date(),
+        };
+        Timestamp::from_datetime(date.and_hms_opt(0, 0, 0).unwrap(), precision)
+    }
+
+    /// Parses an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) date-time, such as
+    /// `2021-02-05T16:43:51.123-05:00`, as a Timestamp with [Precision::Second].
+    ///
+    /// * The date and time may be separated by `T`, `t`, or a space, and `Z` may be lowercase.
+    /// * Like Ion, RFC 3339 uses an offset of `-00:00` to indicate that the offset is unknown.
+    ///   Such timestamps are parsed with an unknown offset, while `Z` and `+00:00` are parsed as
+    ///   UTC.
+    /// * All of the digits in the fractional seconds are kept, including trailing zeros.
+    /// * Leap seconds (`:60`) are rejected. See [LeapSecondPolicy].
+    pub fn parse_rfc3339(text: &str) -> IonResult<Timestamp> {
+        let invalid =
+            || illegal_operation_raw(format!("'{}' is not a valid RFC 3339 date-time", text));
+        let bytes = text.as_bytes();
+        if bytes.len() < 20
+            || !text.is_ascii()
+            || bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || !matches!(bytes[10], b'T' | b't' | b' ')
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+        {
+            return Err(invalid());
+        }
+        let number = |digits: &str| -> IonResult<u32> {
+            if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+                digits.parse().map_err(|_| invalid())
+            } else {
+                Err(invalid())
+            }
+        };
+        let builder = Timestamp::with_ymd_hms(
+            number(&text[0..4])?,
+            number(&text[5..7])?,
+            number(&text[8..10])?,
+            number(&text[11..13])?,
+            number(&text[14..16])?,
+            number(&text[17..19])?,
+        );
+
+        let mut rest = &text[19..];
+        let builder = if let Some(fraction) = rest.strip_prefix('.') {
+            let number_of_digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+            let (digits, remaining) = fraction.split_at(number_of_digits);
+            rest = remaining;
+            if number_of_digits == 0 {
+                return Err(invalid());
+            } else if number_of_digits <= 9 {
+                let nanoseconds = number(digits)? * 10u32.pow(9 - number_of_digits as u32);
+                builder.with_nanoseconds_and_precision(nanoseconds, number_of_digits as u32)
+            } else {
+                let coefficient = digits.parse::<BigUint>().map_err(|_| invalid())?;
+                builder.with_fractional_seconds(Decimal::new(coefficient, -(digits.len() as i64)))
+            }
+        } else {
+            builder
+        };
+
+        match rest {
+            "Z" | "z" => builder.build_at_offset(0),
+            "-00:00" => builder.build_at_unknown_offset(),
+            _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+                let (hours, minutes) = (number(&rest[1..3])?, number(&rest[4..6])?);
+                if hours > 23 || minutes > 59 {
+                    return Err(invalid());
+                }
+                let minutes = (hours * 60 + minutes) as i32;
+                match rest.as_bytes()[0] {
+                    b'+' => builder.build_at_offset(minutes),
+                    b'-' => builder.build_at_offset(-minutes),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Formats the Timestamp as an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) date-time.
+    /// RFC 3339 requires every field down to the second, so Timestamps with a coarser precision
+    /// are written with the missing fields set to their lowest values. For example, `2021-02T`
+    /// is written as `2021-02-01T00:00:00-00:00`. Timestamps with an unknown offset, including
+    /// all of those with [Precision::Day] or coarser, are written with an offset of `-00:00`.
+    pub fn to_rfc3339(&self) -> String {
+        if self.precision == Precision::Second {
+            return self.to_string();
+        }
+        Timestamp {
+            precision: Precision::Second,
+            fractional_seconds: None,
+            ..self.clone()
+        }
+        .to_string()
+    }
+
+    /// Returns a copy of this Timestamp with its fractional seconds rounded to `digits` decimal
+    /// places. Halfway values are rounded up. Rounding up may carry into the seconds field and
+    /// beyond; for example, rounding `2021-12-31T23:59:59.96Z` to one digit produces
+    /// `2022-01-01T00:00:00.0Z`. Rounding to zero digits removes the fractional seconds.
+    ///
+    /// Fractional seconds that already have `digits` or fewer places, and Timestamps that have
+    /// no fractional seconds, are returned unchanged; rounding never adds precision. Returns an
+    /// error if carrying would move the Timestamp past the year 9999.
+    pub fn rounded_to_scale(&self, digits: u32) -> IonResult<Timestamp> {
+        let fraction = match self.fractional_seconds_as_decimal() {
+            Some(fraction) if fraction.scale() > digits as i64 => fraction,
+            _ => return Ok(self.clone()),
+        };
+        let divisor = num_traits::pow(
+            BigUint::from(10u32),
+            (fraction.scale() - digits as i64) as usize,
+        );
+        let coefficient = BigUint::from(fraction.coefficient.magnitude().clone());
+        let mut rounded = &coefficient / &divisor;
+        if (coefficient % &divisor) * 2u32 >= divisor {
+            rounded += 1u32;
+        }
+
+        let mut timestamp = self.clone();
+        timestamp.date_time = self.date_time.with_nanosecond(0).unwrap();
+        let carry = rounded == num_traits::pow(BigUint::from(10u32), digits as usize);
+        if carry {
+            rounded = BigUint::zero();
+        }
+        timestamp.fractional_seconds = if digits == 0 {
+            None
+        } else if digits <= 9 {
+            let nanoseconds = rounded.to_u32().unwrap() * 10u32.pow(9 - digits);
+            timestamp.date_time = timestamp.date_time.with_nanosecond(nanoseconds).unwrap();
+            Some(Mantissa::Digits(digits))
+        } else {
+            Some(Mantissa::Arbitrary(Decimal::new(rounded, -(digits as i64))))
+        };
+        if carry {
+            return timestamp.plus_one_second();
+        }
+        Ok(timestamp)
+    }
+
+    /// Like converting the Timestamp into a `DateTime<FixedOffset>` with [TryInto], but returns
+    /// an error instead of truncating fractional seconds that are more precise than a
+    /// nanosecond. Trailing zeros beyond nanoseconds (as in `0.1234567890`) do not count as
+    /// precision loss.
+    pub fn try_into_date_time_exact(&self) -> IonResult<DateTime<FixedOffset>> {
+        self.check_representable_in_nanoseconds()?;
+        self.clone().try_into()
+    }
+
+    /// Like converting the Timestamp into a [NaiveDateTime] with [TryInto], but returns an error
+    /// instead of truncating fractional seconds that are more precise than a nanosecond.
+    pub fn try_into_naive_date_time_exact(&self) -> IonResult<NaiveDateTime> {
+        self.check_representable_in_nanoseconds()?;
+        self.clone().try_into()
+    }
+
+    // Returns an error if converting the fractional seconds to nanoseconds would lose precision.
+    fn check_representable_in_nanoseconds(&self) -> IonResult<()> {
+        if let Some(Mantissa::Arbitrary(fraction)) = &self.fractional_seconds {
+            let nanoseconds = self.fractional_seconds_as_nanoseconds().unwrap();
+            if *fraction != Decimal::new(nanoseconds, -9) {
+                return illegal_operation(format!(
+                    "the fractional seconds of {} cannot be represented exactly in nanoseconds",
+                    self
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // Moves the Timestamp forward by one second, as when a leap second is carried into the next
+    // minute (see [LeapSecondPolicy::Carry]) or rounding carries into the seconds field.
+    pub(crate) fn plus_one_second(mut self) -> IonResult<Timestamp> {
+        self.date_time += chrono::Duration::seconds(1);
+        if self.year() > 9999 {
+            return illegal_operation(
+                "carrying into the next second moved the timestamp past year 9999",
+            );
+        }
+        Ok(self)
+    }
+
     /// Returns the offset in minutes that has been specified in the [Timestamp].
     /// A positive value indicates Eastern Hemisphere, while a negative value indicates Western Hemisphere.
     pub fn offset(&self) -> Option<i32> {
@@ -460,6 +939,78 @@ impl Timestamp {
     pub fn precision(&self) -> Precision {
         self.precision
     }
+
+    // Returns the Timestamp's date and time as they would be written, in its local offset. A
+    // Timestamp with an unknown offset stores its local fields as though they were in UTC.
+    fn local_date_time(&self) -> NaiveDateTime {
+        match self.offset {
+            Some(offset) => offset.from_utc_datetime(&self.date_time).naive_local(),
+            None => self.date_time,
+        }
+    }
+
+    /// Returns the year in the Timestamp's local offset.
+    pub fn year(&self) -> u32 {
+        self.local_date_time().year() as u32
+    }
+
+    /// Returns the month (1-12) in the Timestamp's local offset, or `None` if the precision is
+    /// [Precision::Year].
+    pub fn month(&self) -> Option<u32> {
+        (self.precision >= Precision::Month).then(|| self.local_date_time().month())
+    }
+
+    /// Returns the day of the month (1-31) in the Timestamp's local offset, or `None` if the
+    /// precision is coarser than [Precision::Day].
+    pub fn day(&self) -> Option<u32> {
+        (self.precision >= Precision::Day).then(|| self.local_date_time().day())
+    }
+
+    /// Returns the day of the week in the Timestamp's local offset, or `None` if the precision is
+    /// coarser than [Precision::Day].
+    pub fn weekday(&self) -> Option<Weekday> {
+        (self.precision >= Precision::Day).then(|| self.local_date_time().weekday())
+    }
+
+    /// Returns the day of the year (1-366) in the Timestamp's local offset, or `None` if the
+    /// precision is coarser than [Precision::Day].
+    pub fn ordinal(&self) -> Option<u32> {
+        (self.precision >= Precision::Day).then(|| self.local_date_time().ordinal())
+    }
+
+    /// Returns the hour (0-23) in the Timestamp's local offset, or `None` if the precision is
+    /// coarser than [Precision::HourAndMinute].
+    pub fn hour(&self) -> Option<u32> {
+        (self.precision >= Precision::HourAndMinute).then(|| self.local_date_time().hour())
+    }
+
+    /// Returns the minute (0-59) in the Timestamp's local offset, or `None` if the precision is
+    /// coarser than [Precision::HourAndMinute].
+    pub fn minute(&self) -> Option<u32> {
+        (self.precision >= Precision::HourAndMinute).then(|| self.local_date_time().minute())
+    }
+
+    /// Returns the second (0-59), or `None` if the precision is coarser than
+    /// [Precision::Second].
+    pub fn second(&self) -> Option<u32> {
+        (self.precision >= Precision::Second).then(|| self.date_time.second())
+    }
+
+    /// Returns the Timestamp's fractional seconds as a number of nanoseconds, or `None` if it
+    /// has no fractional seconds. Fractional seconds that are more precise than nanoseconds are
+    /// truncated.
+    pub fn nanosecond(&self) -> Option<u32> {
+        self.fractional_seconds_as_nanoseconds()
+    }
+}
+
+/// Formats the Timestamp as Ion text, such as `2021-09-30T12:34:56.789-07:00`.
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        IonValueFormatter::new(f)
+            .format_timestamp(self)
+            .map_err(|_| std::fmt::Error)
+    }
 }
 
 /// Two Timestamps are considered equal (though not necessarily IonEq) if they represent the same
@@ -468,39 +1019,72 @@ impl Timestamp {
 /// * `2022T` == `2022T-01`
 /// * `2022T` == `2022T-01-01T00:00:00.000+00:00`
 /// * `2022T-05-11T12:00:00.000Z` == `2022T-05-11T07:00:00.000-05:00`
+/// * `2022T-05-11T12:00:06.5Z` == `2022T-05-11T12:00:06.500Z`
+/// * `2022T-05-11T12:00:06Z` == `2022T-05-11T12:00:06.000Z`
+///
+/// Because precision is ignored, this is not the notion of equality defined by the Ion data
+/// model. Use [IonEq] to also require that the precisions (including the number of digits in the
+/// fractional seconds) and offsets match.
 impl PartialEq for Timestamp {
     fn eq(&self, other: &Self) -> bool {
-        // First, compare the two Timestamps' fractional seconds. We do this first because
-        // Timestamps with different Mantissa representations are a bit tricky to compare. Once
-        // we've established that the fractional seconds match, we can compare all of the other
-        // fields in the timestamp by comparing their respective `DateTime`s.
-        if !self.fractional_seconds_equal(other) {
-            return false;
-        }
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
+/// Timestamps are ordered by the instant in time that they represent, consistent with their
+/// [PartialEq] implementation. A Timestamp with an unknown offset (`-00:00`) describes the same
+/// instant as one with the same fields at `+00:00`, so the two are ordered as equals. Precision
+/// does not affect the ordering.
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
         // When a Timestamp is created, any fields beyond its precision are set to the lowest
         // legal value for that field. So the Timestamp `2022-05T` (which has `Month` precision)
         // would have a `day` field of `1` and hour, minute, and seconds fields of `0`. This makes
-        // it easy to compare Timestamps with different precisions.
-
-        // Make copies of their respective DateTime values but with the fractional seconds zeroed
-        // out. We're not modifying `self` or `other`, and we've already compared their fractional
-        // seconds so it's ok to ignore them from here on.
-        let self_datetime = self.date_time.with_nanosecond(0).unwrap();
-        let other_datetime = other.date_time.with_nanosecond(0).unwrap();
-
-        // Apply each Timestamp's offset to the DateTime. If there's no offset, set it to UTC.
-        let self_datetime = self
-            .offset
-            .map(|offset| offset.from_utc_datetime(&self_datetime))
-            .unwrap_or_else(|| FixedOffset::east(0).from_utc_datetime(&self_datetime));
-        let other_datetime = other
-            .offset
-            .map(|offset| offset.from_utc_datetime(&other_datetime))
-            .unwrap_or_else(|| FixedOffset::east(0).from_utc_datetime(&other_datetime));
-
-        // Compare the resulting `DateTime<FixedOffset>`s
-        self_datetime == other_datetime
+        // it easy to compare Timestamps with different precisions. `date_time` is always stored
+        // in UTC, so the offsets can be ignored.
+        let self_seconds = self.date_time.with_nanosecond(0).unwrap();
+        let other_seconds = other.date_time.with_nanosecond(0).unwrap();
+        self_seconds.cmp(&other_seconds).then_with(|| {
+            // Decimal's ordering is numeric: `0.5` and `0.500` are equal, as are `0.0` and `-0.0`.
+            let zero = || Decimal::new(0, 0);
+            let self_fraction = self.fractional_seconds_as_decimal().unwrap_or_else(zero);
+            let other_fraction = other.fractional_seconds_as_decimal().unwrap_or_else(zero);
+            self_fraction.cmp(&other_fraction)
+        })
+    }
+}
+
+/// Timestamps that are equal (see [PartialEq]) have the same hash, regardless of their offsets,
+/// their precisions, and the number of digits in their fractional seconds.
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.date_time.with_nanosecond(0).unwrap().hash(state);
+        let fraction = match self.fractional_seconds_as_decimal() {
+            Some(fraction) if !fraction.is_zero() => fraction,
+            // A fraction of zero is equal to having no fractional seconds.
+            _ => return,
+        };
+        // Remove the fraction's trailing zeros so that `0.5` and `0.500` hash the same way.
+        let mut magnitude = match fraction.coefficient.magnitude() {
+            Magnitude::U64(magnitude) => BigUint::from(*magnitude),
+            Magnitude::BigUInt(magnitude) => magnitude.clone(),
+        };
+        let mut exponent = fraction.exponent;
+        let ten = BigUint::from(10u32);
+        while (&magnitude % &ten).is_zero() {
+            magnitude /= &ten;
+            exponent += 1;
+        }
+        magnitude.hash(state);
+        exponent.hash(state);
     }
 }
 
@@ -560,6 +1144,7 @@ struct TimestampBuilder {
     second: Option<u8>,
     fractional_seconds: Option<Mantissa>,
     nanoseconds: Option<u32>,
+    leap_second_policy: LeapSecondPolicy,
 }
 
 impl TimestampBuilder {
@@ -688,6 +1273,12 @@ impl TimestampBuilder {
     /// than `12`) or if the resulting timestamp would represent a non-existent point in time
     /// (like those bypassed by daylight saving time), this method will return an `Err(IonError)`.
     fn build(mut self) -> IonResult<Timestamp> {
+        // A NaiveDateTime cannot hold a `second` of 60, so a tolerated leap second starts out as
+        // `:59` and is carried into the next minute afterward if needed.
+        let is_leap_second = self.precision == Precision::Second && self.second == Some(60);
+        if is_leap_second && self.leap_second_policy != LeapSecondPolicy::Reject {
+            self.second = Some(59);
+        }
         // Start with a clean slate NaiveDateTime that we can configure. (These are cheap to copy.)
         let mut datetime: NaiveDateTime = NaiveDate::from_ymd(0, 1, 1).and_hms_nano(0, 0, 0, 0);
         // Set all of the time fields on the datetime using the data from our TimestampBuilder
@@ -721,6 +1312,9 @@ impl TimestampBuilder {
             }
             timestamp.fractional_seconds = self.fractional_seconds;
         }
+        if is_leap_second && self.leap_second_policy == LeapSecondPolicy::Carry {
+            return timestamp.plus_one_second();
+        }
         Ok(timestamp)
     }
 }
@@ -818,6 +1412,61 @@ impl HourAndMinuteSetter {
     }
 }
 
+/// A signed difference from UTC, as used in a [Timestamp]'s offset. An `Offset` is always within
+/// the `-23:59` to `+23:59` range that Ion text timestamps can express.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset {
+    minutes: i32,
+}
+
+impl Offset {
+    const MAX_MINUTES: i32 = 23 * 60 + 59;
+
+    /// Creates an Offset from a total number of minutes. A positive value indicates Eastern
+    /// Hemisphere, while a negative value indicates Western Hemisphere.
+    pub fn from_minutes(minutes: i32) -> IonResult<Offset> {
+        if !(-Self::MAX_MINUTES..=Self::MAX_MINUTES).contains(&minutes) {
+            return illegal_operation(format!(
+                "specified offset ({} minutes) is outside the range -23:59 to +23:59",
+                minutes
+            ));
+        }
+        Ok(Offset { minutes })
+    }
+
+    /// Creates an Offset from its hours (-23 to 23) and minutes (-59 to 59) components. The
+    /// components cannot have opposite signs; `-05:30` is expressed as `(-5, -30)` and `-00:30`
+    /// as `(0, -30)`.
+    pub fn from_hours_and_minutes(hours: i32, minutes: i32) -> IonResult<Offset> {
+        if !(-23..=23).contains(&hours)
+            || !(-59..=59).contains(&minutes)
+            || hours.signum() * minutes.signum() < 0
+        {
+            return illegal_operation(format!(
+                "specified offset ({} hours, {} minutes) is invalid",
+                hours, minutes
+            ));
+        }
+        Ok(Offset {
+            minutes: hours * 60 + minutes,
+        })
+    }
+
+    /// Returns the total number of minutes in this Offset.
+    pub fn minutes(&self) -> i32 {
+        self.minutes
+    }
+}
+
+/// Formats the Offset as it would appear in an Ion text timestamp, such as `-05:30`.
+impl Display for Offset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.abs();
+        write!(f, "{}{:0>2}:{:0>2}", sign, minutes / 60, minutes % 60)
+    }
+}
+
 /// Allows the user to set the `second` field on a builder that has already
 /// had its `year`, `month`, `day`, `hour`, and `minute` fields set. Or, if `HourAndMinute` is the
 /// desired precision, they may build the [Timestamp] instead, optionally specifying an offset if
@@ -852,6 +1501,18 @@ impl SecondSetter {
         self.build_at_offset(offset_minutes)
     }
 
+    /// Like [Self::build_at_offset], but the offset is validated as an [Offset] before the
+    /// Timestamp is built.
+    pub fn build_with_offset(self, offset: Offset) -> IonResult<Timestamp> {
+        self.build_at_offset(offset.minutes())
+    }
+
+    /// Like [Self::build_at_offset], but the offset is specified as hours and minutes. See
+    /// [Offset::from_hours_and_minutes].
+    pub fn build_at_offset_hm(self, hours: i32, minutes: i32) -> IonResult<Timestamp> {
+        self.build_with_offset(Offset::from_hours_and_minutes(hours, minutes)?)
+    }
+
     pub fn build_at_unknown_offset(mut self) -> IonResult<Timestamp> {
         self.builder.offset = None;
         self.into_builder().build()
@@ -878,6 +1539,14 @@ impl FractionalSecondSetter {
         FractionalSecondSetter { builder }
     }
 
+    /// Sets how the builder handles a `second` field of `60`. By default, building a Timestamp
+    /// with a leap second fails.
+    pub fn with_leap_second_policy(self, policy: LeapSecondPolicy) -> FractionalSecondSetter {
+        let mut builder = self.builder;
+        builder.leap_second_policy = policy;
+        FractionalSecondSetter { builder }
+    }
+
     pub fn with_microseconds(self, microsecond: u32) -> FractionalSecondSetter {
         let mut builder = self.builder;
         builder.fractional_seconds = Some(Mantissa::Digits(6));
@@ -922,6 +1591,18 @@ impl FractionalSecondSetter {
         self.build_at_offset(offset_minutes)
     }
 
+    /// Like [Self::build_at_offset], but the offset is validated as an [Offset] before the
+    /// Timestamp is built.
+    pub fn build_with_offset(self, offset: Offset) -> IonResult<Timestamp> {
+        self.build_at_offset(offset.minutes())
+    }
+
+    /// Like [Self::build_at_offset], but the offset is specified as hours and minutes. See
+    /// [Offset::from_hours_and_minutes].
+    pub fn build_at_offset_hm(self, hours: i32, minutes: i32) -> IonResult<Timestamp> {
+        self.build_with_offset(Offset::from_hours_and_minutes(hours, minutes)?)
+    }
+
     pub fn build_at_unknown_offset(mut self) -> IonResult<Timestamp> {
         self.builder.offset = None;
         self.into_builder().build()
@@ -1004,13 +1685,15 @@ impl From<NaiveDateTime> for Timestamp {
     }
 }
 
-// Allows a DateTime<FixedOffset> to be converted to a Timestamp with the correct offset in minutes.
-impl From<DateTime<FixedOffset>> for Timestamp {
-    fn from(fixed_offset_date_time: DateTime<FixedOffset>) -> Self {
+// Allows a DateTime in any TimeZone (such as FixedOffset, Utc, or Local) to be converted to a
+// Timestamp. The offset that was in effect at that instant is stored in minutes.
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+    fn from(zoned_date_time: DateTime<Tz>) -> Self {
+        use chrono::Offset as _;
         // Discard the offset
-        let date_time = fixed_offset_date_time.naive_utc();
+        let date_time = zoned_date_time.naive_utc();
         // Get a copy of the offset to store separately
-        let offset = Some(*fixed_offset_date_time.offset());
+        let offset = Some(zoned_date_time.offset().fix());
         let precision = Precision::Second;
         let fractional_seconds = Some(Mantissa::Digits(9));
         Timestamp {
@@ -1022,6 +1705,90 @@ impl From<DateTime<FixedOffset>> for Timestamp {
     }
 }
 
+// Converts a field value passed to one of the `Timestamp::with_*` methods into the `u8` that the
+// builder stores, rejecting values that would otherwise wrap around.
+fn field_as_u8(field_name: &str, value: u32) -> IonResult<u8> {
+    u8::try_from(value).map_err(|_| {
+        illegal_operation_raw(format!("specified {} ('{}') is invalid", field_name, value))
+    })
+}
+
+// Ion timestamps can only represent the years 1 through 9999.
+fn is_valid_year(year: i32) -> bool {
+    (1..=9999).contains(&year)
+}
+
+// Allows a SystemTime to be converted to a Timestamp at an offset of `+00:00`, with nanosecond
+// precision. Fails if the SystemTime is outside of the range that a Timestamp can represent.
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let (seconds, nanoseconds) = match time.duration_since(UNIX_EPOCH) {
+            Ok(after_epoch) => (
+                i64::try_from(after_epoch.as_secs()).ok(),
+                after_epoch.subsec_nanos(),
+            ),
+            Err(error) => {
+                // The time is before the epoch. Express it as a negative number of seconds plus
+                // a non-negative number of nanoseconds.
+                let before_epoch = error.duration();
+                let seconds = i64::try_from(before_epoch.as_secs()).ok();
+                match before_epoch.subsec_nanos() {
+                    0 => (seconds.map(|seconds| -seconds), 0),
+                    nanoseconds => (
+                        seconds.and_then(|seconds| (-seconds).checked_sub(1)),
+                        1_000_000_000 - nanoseconds,
+                    ),
+                }
+            }
+        };
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let date_time = seconds
+            .and_then(|seconds| utc.timestamp_opt(seconds, nanoseconds).single())
+            .filter(|date_time| is_valid_year(date_time.year()))
+            .ok_or_else(|| {
+                illegal_operation_raw(format!(
+                    "{:?} is outside of the range that a Timestamp can represent",
+                    time
+                ))
+            })?;
+        Ok(date_time.into())
+    }
+}
+
+// Allows a Timestamp with a known offset to be converted to a SystemTime. Fractional seconds
+// that are more precise than nanoseconds are truncated.
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = IonError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        if timestamp.offset.is_none() {
+            return illegal_operation(
+                "cannot convert a Timestamp with an unknown offset into a SystemTime",
+            );
+        }
+        let date_time = downconvert_to_naive_datetime_with_nanoseconds(&timestamp);
+        let date_time = FixedOffset::east_opt(0)
+            .unwrap()
+            .from_utc_datetime(&date_time);
+        let seconds = date_time.timestamp();
+        let nanoseconds = Duration::from_nanos(date_time.timestamp_subsec_nanos() as u64);
+        let time = if seconds >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+        };
+        time.and_then(|time| time.checked_add(nanoseconds))
+            .ok_or_else(|| {
+                illegal_operation_raw(format!(
+                    "{} is outside of the range that a SystemTime can represent",
+                    timestamp
+                ))
+            })
+    }
+}
+
 #[cfg(feature = "ion_c")]
 impl From<ion_c_sys::timestamp::IonDateTime> for Timestamp {
     fn from(ionc_dt: IonDateTime) -> Self {
@@ -1088,15 +1855,145 @@ impl TryInto<ion_c_sys::timestamp::IonDateTime> for Timestamp {
     }
 }
 
+// Converts a time::PrimitiveDateTime into the equivalent chrono NaiveDateTime. Fails if its year
+// is outside of the years 1 through 9999; the time crate can represent negative years and, with
+// its `large-dates` feature, years with more than four digits.
+#[cfg(feature = "time")]
+fn naive_from_primitive(date_time: time::PrimitiveDateTime) -> IonResult<NaiveDateTime> {
+    let out_of_range = || {
+        illegal_operation_raw(format!(
+            "{} is outside of the range that a Timestamp can represent",
+            date_time
+        ))
+    };
+    if !is_valid_year(date_time.year()) {
+        return Err(out_of_range());
+    }
+    NaiveDate::from_ymd_opt(
+        date_time.year(),
+        u8::from(date_time.month()) as u32,
+        date_time.day() as u32,
+    )
+    .and_then(|date| {
+        date.and_hms_nano_opt(
+            date_time.hour() as u32,
+            date_time.minute() as u32,
+            date_time.second() as u32,
+            date_time.nanosecond(),
+        )
+    })
+    .ok_or_else(out_of_range)
+}
+
+// Converts a chrono NaiveDateTime into the equivalent time::PrimitiveDateTime.
+#[cfg(feature = "time")]
+fn primitive_from_naive(date_time: NaiveDateTime) -> IonResult<time::PrimitiveDateTime> {
+    let to_ion_error = |error: time::error::ComponentRange| {
+        illegal_operation_raw(format!(
+            "cannot convert {} to a time::PrimitiveDateTime: {}",
+            date_time, error
+        ))
+    };
+    let month = time::Month::try_from(date_time.month() as u8).map_err(to_ion_error)?;
+    let date = time::Date::from_calendar_date(date_time.year(), month, date_time.day() as u8)
+        .map_err(to_ion_error)?;
+    let time = time::Time::from_hms_nano(
+        date_time.hour() as u8,
+        date_time.minute() as u8,
+        date_time.second() as u8,
+        date_time.nanosecond(),
+    )
+    .map_err(to_ion_error)?;
+    Ok(time::PrimitiveDateTime::new(date, time))
+}
+
+// Allows a time::PrimitiveDateTime to be converted to a Timestamp with an unknown offset.
+#[cfg(feature = "time")]
+impl TryFrom<time::PrimitiveDateTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(date_time: time::PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(naive_from_primitive(date_time)?.into())
+    }
+}
+
+// Allows a time::OffsetDateTime to be converted to a Timestamp with the correct offset in
+// minutes.
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(date_time: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let offset_seconds = date_time.offset().whole_seconds();
+        let offset = FixedOffset::east_opt(offset_seconds).ok_or_else(|| {
+            illegal_operation_raw(format!(
+                "offset of {} seconds is outside of the range that a Timestamp can represent",
+                offset_seconds
+            ))
+        })?;
+        let local = naive_from_primitive(time::PrimitiveDateTime::new(
+            date_time.date(),
+            date_time.time(),
+        ))?;
+        match offset.from_local_datetime(&local) {
+            LocalResult::Single(date_time) => Ok(date_time.into()),
+            _ => illegal_operation(format!(
+                "{} is outside of the range that a Timestamp can represent",
+                date_time
+            )),
+        }
+    }
+}
+
+// Allows a Timestamp with an unknown offset to be converted to a time::PrimitiveDateTime.
+#[cfg(feature = "time")]
+impl TryFrom<Timestamp> for time::PrimitiveDateTime {
+    type Error = IonError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let date_time: NaiveDateTime = timestamp.try_into()?;
+        primitive_from_naive(date_time)
+    }
+}
+
+// Allows a Timestamp with a known offset to be converted to a time::OffsetDateTime.
+#[cfg(feature = "time")]
+impl TryFrom<Timestamp> for time::OffsetDateTime {
+    type Error = IonError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let date_time: DateTime<FixedOffset> = timestamp.try_into()?;
+        let offset_seconds = date_time.offset().local_minus_utc();
+        let offset = time::UtcOffset::from_whole_seconds(offset_seconds).map_err(|error| {
+            illegal_operation_raw(format!(
+                "cannot convert an offset of {} seconds to a time::UtcOffset: {}",
+                offset_seconds, error
+            ))
+        })?;
+        Ok(primitive_from_naive(date_time.naive_local())?.assume_offset(offset))
+    }
+}
+
 #[cfg(test)]
 mod timestamp_tests {
     use crate::ion_eq::IonEq;
     use crate::result::IonResult;
     use crate::types::decimal::Decimal;
-    use crate::types::timestamp::{Mantissa, Precision, Timestamp};
-    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+    use crate::types::timestamp::{LeapSecondPolicy, Mantissa, Offset, Precision, Timestamp};
+    use bigdecimal::BigDecimal;
+    use chrono::{
+        DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset as _, TimeZone, Timelike,
+        Utc, Weekday,
+    };
+    use num_bigint::BigInt;
+    use rstest::rstest;
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
     use std::convert::TryInto;
+    use std::hash::{Hash, Hasher};
     use std::str::FromStr;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
     #[test]
     fn test_timestamps_with_same_ymd_hms_millis_at_known_offset_are_equal() -> IonResult<()> {
@@ -1342,6 +2239,549 @@ mod timestamp_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_timestamps_with_equal_fractional_seconds_at_different_precisions_are_equal(
+    ) -> IonResult<()> {
+        let builder = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51);
+        let timestamp1 = builder.clone().build_at_offset(0)?;
+        let timestamp2 = builder.clone().with_milliseconds(0).build_at_offset(0)?;
+        let timestamp3 = builder.clone().with_milliseconds(500).build_at_offset(0)?;
+        let timestamp4 = builder
+            .with_fractional_seconds(Decimal::new(5, -1))
+            .build_at_unknown_offset()?;
+        assert_eq!(timestamp1, timestamp2);
+        assert_eq!(timestamp3, timestamp4);
+        assert!(!timestamp1.ion_eq(&timestamp2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_ordering() -> IonResult<()> {
+        let mut timestamps = [
+            Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+                .with_fractional_seconds(Decimal::new(1234567891, -10))
+                .build_at_offset(0)?,
+            Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+                .with_nanoseconds(123_456_789)
+                .build_at_offset(0)?,
+            // 16:43:51Z at an offset of -05:00
+            Timestamp::with_ymd_hms(2021, 2, 5, 11, 43, 51).build_at_offset(-5 * 60)?,
+            Timestamp::with_ymd(2021, 2, 5).build()?,
+            Timestamp::with_year(2022).build()?,
+            Timestamp::with_year(2021).build()?,
+        ];
+        timestamps.sort();
+        let text: Vec<String> = timestamps.iter().map(|t| t.to_string()).collect();
+        assert_eq!(
+            text,
+            [
+                "2021T",
+                "2021-02-05T",
+                "2021-02-05T11:43:51-05:00",
+                "2021-02-05T16:43:51.123456789+00:00",
+                "2021-02-05T16:43:51.1234567891+00:00",
+                "2022T",
+            ]
+        );
+        assert!(timestamps[0] < timestamps[1]);
+        assert_eq!(
+            Timestamp::with_year(2021).build()?.cmp(
+                &Timestamp::with_ymd_hms(2021, 1, 1, 0, 0, 0)
+                    .with_milliseconds(0)
+                    .build_at_offset(0)?
+            ),
+            Ordering::Equal
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_equal_timestamps_have_equal_hashes() -> IonResult<()> {
+        let hash = |timestamp: &Timestamp| {
+            let mut hasher = DefaultHasher::new();
+            timestamp.hash(&mut hasher);
+            hasher.finish()
+        };
+        let equal_groups = [
+            vec![
+                Timestamp::with_year(2021).build()?,
+                Timestamp::with_ymd(2021, 1, 1).build()?,
+                Timestamp::with_ymd_hms(2021, 1, 1, 0, 0, 0)
+                    .with_milliseconds(0)
+                    .build_at_offset(0)?,
+            ],
+            vec![
+                Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+                    .with_milliseconds(500)
+                    .build_at_offset(0)?,
+                Timestamp::with_ymd_hms(2021, 2, 5, 11, 43, 51)
+                    .with_fractional_seconds(Decimal::new(50000000000u64, -11))
+                    .build_at_offset(-5 * 60)?,
+                Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+                    .with_fractional_seconds(Decimal::new(5, -1))
+                    .build_at_unknown_offset()?,
+            ],
+        ];
+        for group in &equal_groups {
+            for timestamp in group {
+                assert_eq!(timestamp, &group[0]);
+                assert_eq!(hash(timestamp), hash(&group[0]));
+            }
+        }
+        let set: HashSet<Timestamp> = equal_groups.into_iter().flatten().collect();
+        assert_eq!(set.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_elapsed_since() -> IonResult<()> {
+        let year_2021 = Timestamp::with_year(2021).build()?;
+        let year_2022 = Timestamp::with_year(2022).build()?;
+        assert_eq!(
+            year_2022.elapsed_since(&year_2021).to_string(),
+            "31536000d0"
+        );
+
+        // The result has the precision of the more precise fractional seconds, even when they
+        // are zero.
+        let start = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+            .with_fractional_seconds(Decimal::new(1, -12))
+            .build_at_unknown_offset()?;
+        let end = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 52)
+            .with_nanoseconds(0)
+            .build_at_offset(0)?;
+        assert_eq!(end.elapsed_since(&start).to_string(), "999999999999d-12");
+        assert_eq!(end.elapsed_since(&end).to_string(), "0d-9");
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_system_time_conversions() -> IonResult<()> {
+        let now = SystemTime::now();
+        let timestamp = Timestamp::try_from(now)?;
+        assert_eq!(timestamp.offset(), Some(0));
+        // SystemTime may be more precise than nanoseconds, so compare at nanosecond precision.
+        let expected = now.duration_since(UNIX_EPOCH).unwrap();
+        let round_tripped: SystemTime = timestamp.try_into()?;
+        assert_eq!(
+            round_tripped.duration_since(UNIX_EPOCH).unwrap().as_nanos(),
+            expected.as_nanos()
+        );
+
+        // 1969-12-31T23:59:58.750Z
+        let before_epoch = UNIX_EPOCH - Duration::from_millis(1_250);
+        let timestamp = Timestamp::try_from(before_epoch)?;
+        let expected =
+            Timestamp::with_ymd_hms_millis(1969, 12, 31, 23, 59, 58, 750).build_at_offset(0)?;
+        assert_eq!(timestamp, expected);
+        assert_eq!(SystemTime::try_from(expected)?, before_epoch);
+
+        let timestamp = Timestamp::with_ymd(2021, 2, 5).build()?;
+        assert!(SystemTime::try_from(timestamp).is_err());
+
+        // SystemTimes after the year 9999 or before the year 1 can't be represented.
+        let after_9999 = UNIX_EPOCH + Duration::from_secs(400_000_000_000);
+        assert!(Timestamp::try_from(after_9999).is_err());
+        let last_second = UNIX_EPOCH + Duration::from_secs(253_402_300_799);
+        assert_eq!(Timestamp::try_from(last_second)?.year(), 9999);
+        let before_year_1 = UNIX_EPOCH - Duration::from_secs(62_135_596_801);
+        assert!(Timestamp::try_from(before_year_1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_with_offset() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+            .with_fractional_seconds(Decimal::new(1234567891, -10))
+            .build_at_offset(-5 * 60)?;
+        let utc = timestamp.to_utc()?;
+        assert_eq!(utc.to_string(), "2021-02-05T21:43:51.1234567891+00:00");
+        assert_eq!(utc, timestamp);
+        assert_eq!(utc.precision(), Precision::Second);
+        assert!(utc.with_offset(-5 * 60)?.ion_eq(&timestamp));
+
+        let timestamp = Timestamp::with_ymd(2021, 2, 5)
+            .with_hour_and_minute(23, 30)
+            .build_at_unknown_offset()?;
+        assert_eq!(
+            timestamp.with_offset(90)?.to_string(),
+            "2021-02-06T01:00+01:30"
+        );
+
+        assert!(timestamp.with_offset(24 * 60).is_err());
+        assert!(Timestamp::with_ymd(2021, 2, 5).build()?.to_utc().is_err());
+
+        // The local date can't be moved past the years 1 through 9999.
+        let last_minute = Timestamp::with_ymd(9999, 12, 31)
+            .with_hour_and_minute(23, 59)
+            .build_at_offset(0)?;
+        assert!(last_minute.with_offset(60).is_err());
+        assert_eq!(
+            last_minute.with_offset(-60)?.to_string(),
+            "9999-12-31T22:59-01:00"
+        );
+        let first_minute = Timestamp::with_ymd(1, 1, 1)
+            .with_hour_and_minute(0, 0)
+            .build_at_offset(0)?;
+        assert!(first_minute.with_offset(-1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_truncated_to() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 5, 21, 43, 51)
+            .with_milliseconds(123)
+            .build_at_offset(5 * 60)?;
+        let expected = [
+            (Precision::Second, "2021-02-05T21:43:51.123+05:00"),
+            (Precision::HourAndMinute, "2021-02-05T21:43+05:00"),
+            (Precision::Day, "2021-02-05T"),
+            (Precision::Month, "2021-02T"),
+            (Precision::Year, "2021T"),
+        ];
+        for (precision, text) in expected {
+            let truncated = timestamp.truncated_to(precision);
+            assert_eq!(truncated.precision(), precision);
+            assert_eq!(truncated.to_string(), text);
+        }
+        // The local date is kept, even though the UTC date is a day earlier.
+        let timestamp = Timestamp::with_ymd(2021, 2, 5)
+            .with_hour_and_minute(1, 30)
+            .build_at_offset(5 * 60)?;
+        assert!(timestamp
+            .truncated_to(Precision::Day)
+            .ion_eq(&Timestamp::with_ymd(2021, 2, 5).build()?));
+        // Truncating to a finer precision is a no-op.
+        let date = Timestamp::with_year(2021).build()?;
+        assert!(date.truncated_to(Precision::Second).ion_eq(&date));
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_at_offset_hm() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 2, 5)
+            .with_hour_and_minute(16, 43)
+            .build_at_offset_hm(-5, -30)?;
+        assert_eq!(timestamp.offset(), Some(-330));
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+            .with_milliseconds(123)
+            .build_at_offset_hm(0, -30)?;
+        assert_eq!(timestamp.to_string(), "2021-02-05T16:43:51.123-00:30");
+
+        let offset = Offset::from_hours_and_minutes(23, 59)?;
+        assert_eq!(offset.minutes(), 1439);
+        assert_eq!(offset.to_string(), "+23:59");
+        assert_eq!(Offset::from_minutes(-1439)?.to_string(), "-23:59");
+        assert!(Offset::from_minutes(1440).is_err());
+        assert!(Offset::from_hours_and_minutes(24, 0).is_err());
+        assert!(Offset::from_hours_and_minutes(5, 60).is_err());
+        assert!(Offset::from_hours_and_minutes(-5, 30).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_leap_second_policy() -> IonResult<()> {
+        let builder = Timestamp::with_ymd_hms(2016, 12, 31, 23, 59, 60).with_milliseconds(250);
+        assert!(builder.clone().build_at_offset(0).is_err());
+        let clamped = builder
+            .clone()
+            .with_leap_second_policy(LeapSecondPolicy::Clamp)
+            .build_at_offset(0)?;
+        assert_eq!(clamped.to_string(), "2016-12-31T23:59:59.250+00:00");
+        let carried = builder
+            .with_leap_second_policy(LeapSecondPolicy::Carry)
+            .build_at_offset(-5 * 60)?;
+        assert_eq!(carried.to_string(), "2017-01-01T00:00:00.250-05:00");
+        // Seconds other than 60 are unaffected by the policy.
+        let timestamp = Timestamp::with_ymd_hms(2016, 12, 31, 23, 59, 30)
+            .with_leap_second_policy(LeapSecondPolicy::Carry)
+            .build_at_offset(0)?;
+        assert_eq!(timestamp.second(), Some(30));
+        assert!(Timestamp::with_ymd_hms(9999, 12, 31, 23, 59, 60)
+            .with_leap_second_policy(LeapSecondPolicy::Carry)
+            .build_at_offset(0)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_weekday_and_ordinal() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2020, 12, 31).build()?;
+        assert_eq!(timestamp.weekday(), Some(Weekday::Thu));
+        assert_eq!(timestamp.ordinal(), Some(366));
+        // The local date is used, even though the UTC date is a day earlier.
+        let timestamp = Timestamp::with_ymd(2021, 1, 1)
+            .with_hour_and_minute(1, 30)
+            .build_at_offset(5 * 60)?;
+        assert_eq!(timestamp.weekday(), Some(Weekday::Fri));
+        assert_eq!(timestamp.ordinal(), Some(1));
+        let timestamp = Timestamp::with_year(2021).with_month(1).build()?;
+        assert_eq!(timestamp.weekday(), None);
+        assert_eq!(timestamp.ordinal(), None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("2021-02-05T16:43:51-05:00", "2021-02-05T16:43:51-05:00")]
+    #[case("2021-02-05t16:43:51z", "2021-02-05T16:43:51+00:00")]
+    #[case("2021-02-05 16:43:51+00:00", "2021-02-05T16:43:51+00:00")]
+    #[case("2021-02-05T16:43:51-00:00", "2021-02-05T16:43:51-00:00")]
+    #[case("2021-02-05T16:43:51.120+05:30", "2021-02-05T16:43:51.120+05:30")]
+    #[case(
+        "2021-02-05T16:43:51.1234567891Z",
+        "2021-02-05T16:43:51.1234567891+00:00"
+    )]
+    fn test_parse_rfc3339(#[case] text: &str, #[case] expected: &str) -> IonResult<()> {
+        let timestamp = Timestamp::parse_rfc3339(text)?;
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(timestamp.to_rfc3339(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("2021-02-05T16:43Z")]
+    #[case("2021-02-05")]
+    #[case("2021-02-05T16:43:51")]
+    #[case("2021-02-05T16:43:51.Z")]
+    #[case("2021-02-05T16:43:51+0500")]
+    #[case("2021-02-05T16:43:51+24:00")]
+    #[case("2021-02-05T16:43:51+05:60")]
+    #[case("2021-02-05T16:43:51-05:99")]
+    #[case("2021-02-05T16:43:51+23:60")]
+    #[case("2021-02-05T16:43:51-25:00")]
+    #[case("2021-02-30T16:43:51Z")]
+    #[case("2016-12-31T23:59:60Z")]
+    #[case("2021-02-05T16:43:+1Z")]
+    fn test_parse_invalid_rfc3339(#[case] text: &str) {
+        assert!(Timestamp::parse_rfc3339(text).is_err());
+    }
+
+    #[test]
+    fn test_to_rfc3339_with_reduced_precision() -> IonResult<()> {
+        let timestamp = Timestamp::with_year(2021).with_month(2).build()?;
+        assert_eq!(timestamp.to_rfc3339(), "2021-02-01T00:00:00-00:00");
+        let timestamp = Timestamp::with_ymd(2021, 2, 5)
+            .with_hour_and_minute(16, 43)
+            .build_at_offset(-5 * 60)?;
+        assert_eq!(timestamp.to_rfc3339(), "2021-02-05T16:43:00-05:00");
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_from_datetime_with_any_time_zone() -> IonResult<()> {
+        let utc_date_time = Utc.timestamp_opt(1_612_543_431, 123_000_000).unwrap();
+        let timestamp: Timestamp = utc_date_time.into();
+        assert_eq!(timestamp.offset(), Some(0));
+        assert_eq!(timestamp.to_string(), "2021-02-05T16:43:51.123000000+00:00");
+
+        let local_date_time = utc_date_time.with_timezone(&Local);
+        let timestamp: Timestamp = local_date_time.into();
+        assert_eq!(
+            timestamp.offset(),
+            Some(local_date_time.offset().fix().local_minus_utc() / 60)
+        );
+        assert_eq!(timestamp, Timestamp::from(utc_date_time));
+        Ok(())
+    }
+
+    #[test]
+    fn test_public_fractional_seconds_accessors() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+            .with_milliseconds(553)
+            .build_at_offset(0)?;
+        assert!(timestamp
+            .fractional_seconds_decimal()
+            .unwrap()
+            .ion_eq(&Decimal::new(553, -3)));
+        assert_eq!(
+            timestamp.fractional_seconds_nanoseconds(),
+            Some(553_000_000)
+        );
+
+        let picoseconds = Decimal::new(123_456_789_012u64, -12);
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51)
+            .with_fractional_seconds(picoseconds.clone())
+            .build_at_offset(0)?;
+        assert!(timestamp
+            .fractional_seconds_decimal()
+            .unwrap()
+            .ion_eq(&picoseconds));
+        assert_eq!(
+            timestamp.fractional_seconds_nanoseconds(),
+            Some(123_456_789)
+        );
+
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51).build_at_offset(0)?;
+        assert_eq!(timestamp.fractional_seconds_decimal(), None);
+        assert_eq!(timestamp.fractional_seconds_nanoseconds(), None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("2021-02-05T16:43:51.123456Z", 3, "2021-02-05T16:43:51.123+00:00")]
+    #[case("2021-02-05T16:43:51.1235Z", 3, "2021-02-05T16:43:51.124+00:00")]
+    #[case("2021-02-05T16:43:51.5Z", 0, "2021-02-05T16:43:52+00:00")]
+    #[case("2021-02-05T16:43:51.12Z", 6, "2021-02-05T16:43:51.12+00:00")]
+    #[case("2021-12-31T23:59:59.96-00:00", 1, "2022-01-01T00:00:00.0-00:00")]
+    #[case(
+        "2021-02-05T16:43:51.99999999999-05:00",
+        10,
+        "2021-02-05T16:43:52.0000000000-05:00"
+    )]
+    #[case(
+        "2021-02-05T16:43:51.123456789012Z",
+        10,
+        "2021-02-05T16:43:51.1234567890+00:00"
+    )]
+    fn test_timestamp_rounded_to_scale(
+        #[case] text: &str,
+        #[case] digits: u32,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::parse_rfc3339(text)?;
+        assert_eq!(timestamp.rounded_to_scale(digits)?.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_rounded_to_scale_past_year_9999() -> IonResult<()> {
+        let timestamp = Timestamp::parse_rfc3339("9999-12-31T23:59:59.9Z")?;
+        assert!(timestamp.rounded_to_scale(0).is_err());
+        let date = Timestamp::with_ymd(2021, 2, 5).build()?;
+        assert!(date.rounded_to_scale(0)?.ion_eq(&date));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_exact_chrono_conversions() -> IonResult<()> {
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.1234567891+01:00")?;
+        let lossy: DateTime<FixedOffset> = timestamp.clone().try_into()?;
+        assert_eq!(lossy.nanosecond(), 123_456_789);
+        assert!(timestamp.try_into_date_time_exact().is_err());
+
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.1234567890+01:00")?;
+        let date_time = timestamp.try_into_date_time_exact()?;
+        assert_eq!(date_time.nanosecond(), 123_456_789);
+        assert_eq!(date_time.offset().local_minus_utc(), 3600);
+
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.999-00:00")?;
+        let date_time = timestamp.try_into_naive_date_time_exact()?;
+        assert_eq!(date_time.nanosecond(), 999_000_000);
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.0000000001-00:00")?;
+        assert!(timestamp.try_into_naive_date_time_exact().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fractional_seconds_big_decimal() -> IonResult<()> {
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.123456789012Z")?;
+        let fraction = timestamp.fractional_seconds_big_decimal().unwrap();
+        assert_eq!(fraction, BigDecimal::from_str("0.123456789012").unwrap());
+        assert_eq!(fraction.as_bigint_and_exponent().1, 12);
+
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.500Z")?;
+        let fraction = timestamp.fractional_seconds_big_decimal().unwrap();
+        assert_eq!(fraction.as_bigint_and_exponent(), (BigInt::from(500), 3));
+
+        let timestamp = Timestamp::with_ymd(2021, 2, 5)
+            .with_hour_and_minute(16, 43)
+            .build_at_offset(0)?;
+        assert_eq!(timestamp.fractional_seconds_big_decimal(), None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(0, "1970-01-01T00:00:00.000000000+00:00")]
+    #[case(1_612_543_431_123_456_789, "2021-02-05T16:43:51.123456789+00:00")]
+    #[case(-1, "1969-12-31T23:59:59.999999999+00:00")]
+    #[case(-62_135_596_800_000_000_000, "0001-01-01T00:00:00.000000000+00:00")]
+    #[case(253_402_300_799_999_999_999, "9999-12-31T23:59:59.999999999+00:00")]
+    fn test_timestamp_epoch_nanos(#[case] nanoseconds: i128, #[case] text: &str) -> IonResult<()> {
+        let timestamp = Timestamp::from_epoch_nanos(nanoseconds)?;
+        assert_eq!(timestamp.to_string(), text);
+        assert_eq!(timestamp.to_epoch_nanos(), nanoseconds);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_epoch_nanos_out_of_range() -> IonResult<()> {
+        assert!(Timestamp::from_epoch_nanos(-62_135_596_800_000_000_001).is_err());
+        assert!(Timestamp::from_epoch_nanos(253_402_300_800_000_000_000).is_err());
+        assert!(Timestamp::from_epoch_nanos(i128::MAX).is_err());
+        let timestamp = Timestamp::parse_rfc3339("1970-01-01T00:00:01.0000000019-00:00")?;
+        assert_eq!(timestamp.to_epoch_nanos(), 1_000_000_001);
+        let timestamp = Timestamp::with_ymd(1970, 1, 2).build()?;
+        assert_eq!(timestamp.to_epoch_nanos(), 86_400_000_000_000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_setters_return_modified_copies() -> IonResult<()> {
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.123-05:00")?;
+        assert_eq!(
+            timestamp.with_month(3)?.to_string(),
+            "2021-03-05T16:43:51.123-05:00"
+        );
+        assert_eq!(
+            timestamp.with_day(28)?.to_string(),
+            "2021-02-28T16:43:51.123-05:00"
+        );
+        assert_eq!(
+            timestamp.with_hour_and_minute(23, 59)?.to_string(),
+            "2021-02-05T23:59:51.123-05:00"
+        );
+        assert_eq!(
+            timestamp.with_second(0)?.to_string(),
+            "2021-02-05T16:43:00.123-05:00"
+        );
+        assert_eq!(
+            timestamp
+                .with_fractional_seconds(Decimal::new(1234567891, -10))?
+                .to_string(),
+            "2021-02-05T16:43:51.1234567891-05:00"
+        );
+        // The original is unchanged.
+        assert_eq!(timestamp.to_string(), "2021-02-05T16:43:51.123-05:00");
+
+        assert!(timestamp.with_day(30).is_err());
+        assert!(timestamp.with_second(60).is_err());
+        assert!(timestamp
+            .with_fractional_seconds(Decimal::new(1, 0))
+            .is_err());
+        let date = Timestamp::with_ymd(2021, 2, 5).build()?;
+        assert!(date.with_hour_and_minute(12, 0).is_err());
+
+        // Values that don't fit in a u8 are rejected rather than wrapped around.
+        let date = Timestamp::with_ymd(2021, 5, 6).build()?;
+        assert!(date.with_month(257).is_err());
+        assert!(date.with_day(263).is_err());
+        assert!(timestamp.with_hour_and_minute(256, 0).is_err());
+        assert!(timestamp.with_hour_and_minute(0, 256 + 30).is_err());
+        assert!(timestamp.with_second(u32::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_with_precision() -> IonResult<()> {
+        let timestamp = Timestamp::with_year(2021).with_month(2).build()?;
+        let raised = timestamp.with_precision(Precision::Day)?;
+        assert!(raised.ion_eq(&Timestamp::with_ymd(2021, 2, 1).build()?));
+        let raised = timestamp.with_precision(Precision::Second)?;
+        assert_eq!(raised.to_string(), "2021-02-01T00:00:00-00:00");
+
+        let timestamp = Timestamp::parse_rfc3339("2021-02-05T16:43:51.123-05:00")?;
+        assert_eq!(
+            timestamp
+                .with_precision(Precision::HourAndMinute)?
+                .to_string(),
+            "2021-02-05T16:43-05:00"
+        );
+        assert!(timestamp
+            .with_precision(Precision::Second)?
+            .ion_eq(&timestamp));
+        Ok(())
+    }
+
     #[test]
     fn test_timestamp_try_into_naive_datetime() -> IonResult<()> {
         let timestamp = Timestamp::with_ymd_hms(2021, 4, 6, 10, 15, 0).build_at_unknown_offset()?;
@@ -1374,6 +2814,41 @@ mod timestamp_tests {
         Ok(())
     }
 
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_timestamp_time_crate_conversions() -> IonResult<()> {
+        use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+        let date = Date::from_calendar_date(2021, Month::February, 5).unwrap();
+        let time = Time::from_hms_milli(17, 39, 51, 194).unwrap();
+        let primitive = PrimitiveDateTime::new(date, time);
+        let offset = primitive.assume_offset(UtcOffset::from_hms(-4, 0, 0).unwrap());
+        let expected = Timestamp::with_ymd_hms_millis(2021, 2, 5, 17, 39, 51, 194);
+
+        let timestamp = Timestamp::try_from(offset)?;
+        assert_eq!(timestamp, expected.clone().build_at_offset(-4 * 60)?);
+        assert_eq!(timestamp.offset(), Some(-4 * 60));
+        assert_eq!(OffsetDateTime::try_from(timestamp)?, offset);
+
+        let timestamp = Timestamp::try_from(primitive)?;
+        assert_eq!(timestamp, expected.build_at_unknown_offset()?);
+        assert_eq!(PrimitiveDateTime::try_from(timestamp.clone())?, primitive);
+        // A Timestamp with an unknown offset is not an OffsetDateTime.
+        assert!(OffsetDateTime::try_from(timestamp).is_err());
+
+        // The time crate can represent years that a Timestamp can't.
+        for year in [-1, 0] {
+            let date = Date::from_calendar_date(year, Month::December, 31).unwrap();
+            let primitive = PrimitiveDateTime::new(date, time);
+            assert!(Timestamp::try_from(primitive).is_err());
+            assert!(Timestamp::try_from(primitive.assume_utc()).is_err());
+        }
+        let date = Date::from_calendar_date(1, Month::January, 1).unwrap();
+        let midnight = PrimitiveDateTime::new(date, Time::MIDNIGHT);
+        assert_eq!(Timestamp::try_from(midnight)?.year(), 1);
+        Ok(())
+    }
+
     #[test]
     fn test_timestamp_try_into_fixed_offset_datetime() -> IonResult<()> {
         let timestamp = Timestamp::with_ymd_hms(2021, 4, 6, 10, 15, 0).build_at_offset(-5 * 60)?;
@@ -1463,6 +2938,72 @@ mod timestamp_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_timestamp_accessors() -> IonResult<()> {
+        // Fields are reported in the local offset, even when that's on a different day than UTC.
+        let timestamp = Timestamp::with_ymd_hms(2021, 12, 31, 23, 30, 15)
+            .with_milliseconds(449)
+            .build_at_offset(-5 * 60)?;
+        assert_eq!(timestamp.year(), 2021);
+        assert_eq!(timestamp.month(), Some(12));
+        assert_eq!(timestamp.day(), Some(31));
+        assert_eq!(timestamp.hour(), Some(23));
+        assert_eq!(timestamp.minute(), Some(30));
+        assert_eq!(timestamp.second(), Some(15));
+        assert_eq!(timestamp.nanosecond(), Some(449_000_000));
+
+        let timestamp = Timestamp::with_ymd(2021, 2, 5).build()?;
+        assert_eq!(timestamp.day(), Some(5));
+        assert_eq!(timestamp.hour(), None);
+        assert_eq!(timestamp.second(), None);
+        assert_eq!(timestamp.nanosecond(), None);
+
+        let timestamp = Timestamp::with_year(2021).build()?;
+        assert_eq!(timestamp.year(), 2021);
+        assert_eq!(timestamp.month(), None);
+
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 5, 17, 39, 51)
+            .with_fractional_seconds(Decimal::new(1234567891, -10))
+            .build_at_unknown_offset()?;
+        assert_eq!(timestamp.hour(), Some(17));
+        assert_eq!(timestamp.nanosecond(), Some(123_456_789));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_now() -> IonResult<()> {
+        let before = Utc::now();
+        let utc = Timestamp::now_utc();
+        let local = Timestamp::now_at_offset(-5 * 60)?;
+        let after = Utc::now();
+
+        assert_eq!(utc.offset(), Some(0));
+        assert_eq!(utc.precision(), Precision::Second);
+        assert_eq!(utc.fractional_seconds_scale(), Some(9));
+        assert_eq!(local.offset(), Some(-5 * 60));
+        for timestamp in [utc, local] {
+            let date_time: DateTime<FixedOffset> = timestamp.try_into()?;
+            assert!(before <= date_time && date_time <= after);
+        }
+
+        assert!(Timestamp::now_at_offset(24 * 60).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_display() -> IonResult<()> {
+        let timestamp = Timestamp::with_year(2021).with_month(2).build()?;
+        assert_eq!(timestamp.to_string(), "2021-02T");
+        let timestamp =
+            Timestamp::with_ymd_hms_millis(2021, 2, 5, 17, 39, 51, 194).build_at_offset(-4 * 60)?;
+        assert_eq!(timestamp.to_string(), "2021-02-05T17:39:51.194-04:00");
+        let timestamp = Timestamp::with_ymd(2021, 2, 5)
+            .with_hour_and_minute(17, 39)
+            .build_at_unknown_offset()?;
+        assert_eq!(timestamp.to_string(), "2021-02-05T17:39-00:00");
+        Ok(())
+    }
+
     #[test]
     fn test_timestamp_precision() -> IonResult<()> {
         let timestamp = Timestamp::with_year(2021).with_month(2).build()?;
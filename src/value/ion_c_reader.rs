@@ -1,110 +1,106 @@
 #![cfg(feature = "ion_c")]
 
+use std::io;
+
+use crate::reader::{ReaderBackend, ReaderBuilder};
 use crate::result::IonResult;
+use crate::value::native_reader::NativeElementIterator;
 use crate::value::owned::{text_token, OwnedElement, OwnedSequence, OwnedStruct};
 use crate::value::reader::ElementReader;
 use crate::IonType;
 use ion_c_sys::reader::{IonCReader, IonCReaderHandle};
-use ion_c_sys::ION_TYPE;
-
-struct IonCReaderIterator<'a> {
-    reader: IonCReaderHandle<'a>,
-    done: bool,
-}
 
-impl<'a> IonCReaderIterator<'a> {
-    /// Moves the reader forward converting to `IonResult`.
-    #[inline]
-    fn read_next(&mut self) -> IonResult<ION_TYPE> {
-        Ok(self.reader.next()?)
+/// Advances `reader` to the next value at its current depth and materializes that value (along
+/// with any children it has) as an [OwnedElement]. Returns `Ok(None)` if there are no more values
+/// at the current depth.
+///
+/// This allows values to be read from an ion-c reader that is shared with other code; the
+/// reader is left positioned on the value that was returned.
+pub fn read_element<R: IonCReader>(reader: &mut R) -> IonResult<Option<OwnedElement>> {
+    match reader.next()? {
+        ion_c_sys::ION_TYPE_EOF => Ok(None),
+        ionc_type => Ok(Some(materialize(reader, ionc_type.try_into()?)?)),
     }
+}
 
-    /// Materializes a value with an [`IonType`]
-    fn materialize(&mut self, ion_type: IonType) -> IonResult<OwnedElement> {
-        use crate::types::integer::Integer;
-        use crate::value::owned::OwnedValue::*;
-        use crate::value::owned::{OwnedSymbolToken, OwnedValue};
-        // TODO when doing BorrowedElement, we can compare against the input buffer if
-        //      there is one and be smart about when to materialize strings...
-
-        // TODO deal with local SIDs/sources, this requires deeper integration with Ion C
-        //      than we're willing to do right now...
-
-        let annotations: Vec<OwnedSymbolToken> = self
-            .reader
-            .get_annotations()?
-            .iter()
-            .map(|s| (*s).into())
-            .collect();
-
-        let value: OwnedValue = if self.reader.is_null()? {
-            Null(ion_type)
-        } else {
-            match ion_type {
-                // technically unreachable...
-                IonType::Null => Null(ion_type),
-                IonType::Boolean => Boolean(self.reader.read_bool()?),
-                // TODO deal with the big integer case
-                IonType::Integer => Integer(if let Ok(ival) = self.reader.read_i64() {
-                    Integer::I64(ival)
-                } else {
-                    Integer::BigInt(self.reader.read_bigint()?)
-                }),
-                IonType::Float => Float(self.reader.read_f64()?),
-                IonType::Decimal => Decimal(self.reader.read_bigdecimal()?.into()),
-                IonType::Timestamp => Timestamp(self.reader.read_datetime()?.into()),
-                // TODO get the `ION_SYMBOL` value and extract the complete symbolic information.
-                IonType::Symbol => Symbol(self.reader.read_string()?.as_str().into()),
-                IonType::String => String(self.reader.read_string()?.as_str().into()),
-                IonType::Clob => Clob(self.reader.read_bytes()?),
-                IonType::Blob => Blob(self.reader.read_bytes()?),
-                IonType::List => List(self.materialize_sequence()?),
-                IonType::SExpression => SExpression(self.materialize_sequence()?),
-                IonType::Struct => Struct(self.materialize_struct()?),
-            }
-        };
+/// Materializes the value on which `reader` is positioned, which has the given [`IonType`].
+fn materialize<R: IonCReader>(reader: &mut R, ion_type: IonType) -> IonResult<OwnedElement> {
+    use crate::types::integer::Integer;
+    use crate::value::owned::OwnedValue::*;
+    use crate::value::owned::{OwnedSymbolToken, OwnedValue};
+    // TODO when doing BorrowedElement, we can compare against the input buffer if
+    //      there is one and be smart about when to materialize strings...
+
+    // TODO deal with local SIDs/sources, this requires deeper integration with Ion C
+    //      than we're willing to do right now...
+
+    let annotations: Vec<OwnedSymbolToken> = reader
+        .get_annotations()?
+        .iter()
+        .map(|s| (*s).into())
+        .collect();
+
+    let value: OwnedValue = if reader.is_null()? {
+        Null(ion_type)
+    } else {
+        match ion_type {
+            // technically unreachable...
+            IonType::Null => Null(ion_type),
+            IonType::Boolean => Boolean(reader.read_bool()?),
+            // TODO deal with the big integer case
+            IonType::Integer => Integer(if let Ok(ival) = reader.read_i64() {
+                Integer::I64(ival)
+            } else {
+                Integer::BigInt(reader.read_bigint()?)
+            }),
+            IonType::Float => Float(reader.read_f64()?),
+            IonType::Decimal => Decimal(reader.read_bigdecimal()?.into()),
+            IonType::Timestamp => Timestamp(reader.read_datetime()?.into()),
+            // TODO get the `ION_SYMBOL` value and extract the complete symbolic information.
+            IonType::Symbol => Symbol(reader.read_string()?.as_str().into()),
+            IonType::String => String(reader.read_string()?.as_str().into()),
+            IonType::Clob => Clob(reader.read_bytes()?),
+            IonType::Blob => Blob(reader.read_bytes()?),
+            IonType::List => List(materialize_sequence(reader)?),
+            IonType::SExpression => SExpression(materialize_sequence(reader)?),
+            IonType::Struct => Struct(materialize_struct(reader)?),
+        }
+    };
 
-        Ok(OwnedElement::new(annotations, value))
-    }
+    Ok(OwnedElement::new(annotations, value))
+}
 
-    /// Materializes a top-level value with a known Ion C type.
-    #[inline]
-    fn materialize_top_level(&mut self, ionc_type: ION_TYPE) -> IonResult<OwnedElement> {
-        self.materialize(ionc_type.try_into()?)
+fn materialize_sequence<R: IonCReader>(reader: &mut R) -> IonResult<OwnedSequence> {
+    let mut children = Vec::new();
+    reader.step_in()?;
+    while let Some(child) = read_element(reader)? {
+        children.push(child);
     }
+    reader.step_out()?;
+    Ok(children.into_iter().collect())
+}
 
-    fn materialize_sequence(&mut self) -> IonResult<OwnedSequence> {
-        let mut children = Vec::new();
-        self.reader.step_in()?;
-        loop {
-            let ionc_type = self.read_next()?;
-            if let ion_c_sys::ION_TYPE_EOF = ionc_type {
-                break;
-            }
-
-            children.push(self.materialize_top_level(ionc_type)?);
+fn materialize_struct<R: IonCReader>(reader: &mut R) -> IonResult<OwnedStruct> {
+    let mut fields = vec![];
+    reader.step_in()?;
+    loop {
+        let ionc_type = reader.next()?;
+        if let ion_c_sys::ION_TYPE_EOF = ionc_type {
+            break;
         }
-        self.reader.step_out()?;
-        Ok(children.into_iter().collect())
-    }
-
-    fn materialize_struct(&mut self) -> IonResult<OwnedStruct> {
-        let mut fields = vec![];
-        self.reader.step_in()?;
-        loop {
-            let ionc_type = self.read_next()?;
-            if let ion_c_sys::ION_TYPE_EOF = ionc_type {
-                break;
-            }
 
-            // TODO get the `ION_SYMBOL` value and extract the complete symbolic information.
-            let token = text_token(self.reader.get_field_name()?.as_str());
-            let elem = self.materialize_top_level(ionc_type)?;
-            fields.push((token, elem));
-        }
-        self.reader.step_out()?;
-        Ok(fields.into_iter().collect())
+        // TODO get the `ION_SYMBOL` value and extract the complete symbolic information.
+        let token = text_token(reader.get_field_name()?.as_str());
+        let elem = materialize(reader, ionc_type.try_into()?)?;
+        fields.push((token, elem));
     }
+    reader.step_out()?;
+    Ok(fields.into_iter().collect())
+}
+
+struct IonCReaderIterator<'a> {
+    reader: IonCReaderHandle<'a>,
+    done: bool,
 }
 
 impl<'a> Iterator for IonCReaderIterator<'a> {
@@ -115,26 +111,12 @@ impl<'a> Iterator for IonCReaderIterator<'a> {
         if self.done {
             return None;
         }
-        // perform scaffolding over the Some/None part of the API
-        match self.read_next() {
-            Ok(ionc_type) => {
-                if let ion_c_sys::ION_TYPE_EOF = ionc_type {
-                    // reader says nothing, we're done!
-                    self.done = true;
-                    None
-                } else {
-                    // we've got something
-                    let result = self.materialize_top_level(ionc_type);
-                    if result.is_err() {
-                        // a failure means the iterator is done
-                        self.done = true;
-                    }
-                    Some(result)
-                }
-            }
-            // next failed...
-            Err(e) => Some(Err(e)),
+        let result = read_element(&mut self.reader).transpose();
+        // reaching the end of the stream or failing means the iterator is done
+        if !matches!(result, Some(Ok(_))) {
+            self.done = true;
         }
+        result
     }
 }
 
@@ -152,6 +134,17 @@ impl ElementReader for IonCElementReader {
             done: false,
         }))
     }
+
+    fn iterate_over_reader<'b, R: io::Read + 'b>(
+        &self,
+        input: R,
+    ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>> {
+        // ion-c can only read from a buffer, so the input is loaded into one that the reader owns.
+        let reader = ReaderBuilder::new()
+            .with_backend(ReaderBackend::IonC)
+            .build_from_read(input)?;
+        Ok(Box::new(NativeElementIterator::new(reader)))
+    }
 }
 
 #[cfg(test)]
@@ -219,4 +212,29 @@ mod ion_c_reader_tests {
         assert_eq!(expected, actual);
         Ok(())
     }
+
+    #[test]
+    fn read_and_write_elements_with_handles() -> IonResult<()> {
+        use crate::value::ion_c_writer::write_element;
+        use crate::value::reader::element_reader;
+        use ion_c_sys::writer::{IonCWriter, IonCWriterHandle, WriterMode};
+
+        let elements = element_reader().read_all(br#"foo::{a: [1, 2.5, "three"]} 4d0"#)?;
+        let mut buffer = vec![0; 128];
+        let len = {
+            let mut writer = IonCWriterHandle::new_buf_mode(&mut buffer, WriterMode::Binary)?;
+            for element in &elements {
+                write_element(&mut writer, None, element)?;
+            }
+            writer.finish()?
+        };
+
+        let mut reader = IonCReaderHandle::try_from(&buffer[..len])?;
+        let mut actual = Vec::new();
+        while let Some(element) = read_element(&mut reader)? {
+            actual.push(element);
+        }
+        assert_eq!(elements, actual);
+        Ok(())
+    }
 }
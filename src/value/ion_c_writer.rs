@@ -7,22 +7,28 @@ use ion_c_sys::ION_WRITER_OPTIONS;
 
 pub type SliceElementWriter<'a> = IonCSliceElementWriter<'a>;
 
+/// Returns the Ion C writer options corresponding to the given [`Format`].
+pub(crate) fn writer_options(format: Format) -> ION_WRITER_OPTIONS {
+    let mut options: ION_WRITER_OPTIONS = Default::default();
+    match format {
+        Text(kind) => {
+            options.output_as_binary = 0;
+            match kind {
+                Compact => options.pretty_print = 0,
+                Pretty => options.pretty_print = 1,
+            };
+        }
+        Binary => {
+            options.output_as_binary = 1;
+        }
+    };
+    options
+}
+
 impl<'a> IonCSliceElementWriter<'a> {
     pub(crate) fn new(buf: &'a mut [u8], format: Format) -> IonResult<Self> {
         let data = buf.as_ptr();
-        let mut options: ION_WRITER_OPTIONS = Default::default();
-        match format {
-            Text(kind) => {
-                options.output_as_binary = 0;
-                match kind {
-                    Compact => options.pretty_print = 0,
-                    Pretty => options.pretty_print = 1,
-                };
-            }
-            Binary => {
-                options.output_as_binary = 1;
-            }
-        };
+        let mut options = writer_options(format);
         let writer = IonCWriterHandle::new_buf(buf, &mut options)?;
         Ok(Self {
             data,
@@ -30,106 +36,110 @@ impl<'a> IonCSliceElementWriter<'a> {
             error: None,
         })
     }
+}
 
-    /// Writes an element with an optional field name context (if being written recursively).
-    /// This cannot be made generic due to the lack of GAT making it impossible for IonC's
-    /// writer to push down the context--it could be written in terms of
-    /// `IonCAnnotationsFieldWriterContext` but that would just complicate the code to work around
-    /// the lack of GAT.
-    pub(crate) fn write_element<E: Element>(
-        &mut self,
-        field_name_opt: Option<&str>,
-        element: &E,
-    ) -> IonResult<()> {
-        let annotations_opt: Option<Vec<_>> = element.annotations().map(|tok| tok.text()).collect();
-        if let Some(annotations) = annotations_opt {
-            // get a writing context with the annotations (which could be empty)
-            let mut af_writer = self.writer.annotations(&annotations);
-            if let Some(field_name) = field_name_opt {
-                // decorate the annotation context with the field name when we have one
-                af_writer.field(field_name);
-            }
+/// Writes `element` (and any children it has) to an ion-c `writer`, decorating it with
+/// `field_name_opt` if one is provided. The writer must be positioned inside a struct if and
+/// only if a field name is provided.
+///
+/// This allows elements to be written to an ion-c writer that is shared with other code.
+// This cannot be made generic due to the lack of GAT making it impossible for IonC's
+// writer to push down the context--it could be written in terms of
+// `IonCAnnotationsFieldWriterContext` but that would just complicate the code to work around
+// the lack of GAT.
+pub fn write_element<E: Element>(
+    writer: &mut IonCWriterHandle<'_>,
+    field_name_opt: Option<&str>,
+    element: &E,
+) -> IonResult<()> {
+    let annotations_opt: Option<Vec<_>> = element.annotations().map(|tok| tok.text()).collect();
+    if let Some(annotations) = annotations_opt {
+        // get a writing context with the annotations (which could be empty)
+        let mut af_writer = writer.annotations(&annotations);
+        if let Some(field_name) = field_name_opt {
+            // decorate the annotation context with the field name when we have one
+            af_writer.field(field_name);
+        }
 
-            let ion_type = element.ion_type();
-            if element.is_null() {
-                af_writer.write_null(ion_type.into())?;
-            } else {
-                // non-null element values
-                match ion_type {
-                    IonType::Null => {
-                        // handled in the null-arm
-                    }
-                    IonType::Boolean => {
-                        af_writer.write_bool(try_to!(element.as_bool()))?;
-                    }
-                    IonType::Integer => {
-                        let any_int = try_to!(element.as_integer());
-                        match any_int {
-                            Integer::I64(i64_val) => {
-                                af_writer.write_i64(*i64_val)?;
-                            }
-                            Integer::BigInt(big_val) => {
-                                af_writer.write_bigint(big_val)?;
-                            }
+        let ion_type = element.ion_type();
+        if element.is_null() {
+            af_writer.write_null(ion_type.into())?;
+        } else {
+            // non-null element values
+            match ion_type {
+                IonType::Null => {
+                    // handled in the null-arm
+                }
+                IonType::Boolean => {
+                    af_writer.write_bool(try_to!(element.as_bool()))?;
+                }
+                IonType::Integer => {
+                    let any_int = try_to!(element.as_integer());
+                    match any_int {
+                        Integer::I64(i64_val) => {
+                            af_writer.write_i64(*i64_val)?;
+                        }
+                        Integer::BigInt(big_val) => {
+                            af_writer.write_bigint(big_val)?;
                         }
                     }
-                    IonType::Float => {
-                        af_writer.write_f64(try_to!(element.as_f64()))?;
-                    }
-                    IonType::Decimal => {
-                        // TODO reconsider Decimal/BigDecimal internal factoring to avoid the clone
-                        let decimal = try_to!(element.as_decimal());
-                        let big_decimal = decimal.clone().try_into()?;
-                        af_writer.write_bigdecimal(&big_decimal)?;
-                    }
-                    IonType::Timestamp => {
-                        // TODO reconsider Timestamp/IonDateTime factoring to avoid the clone
-                        let timestamp = try_to!(element.as_timestamp());
-                        let ion_dt = timestamp.clone().try_into()?;
-                        af_writer.write_datetime(&ion_dt)?;
-                    }
-                    IonType::Symbol => {
-                        af_writer.write_symbol(try_to!(element.as_str()))?;
-                    }
-                    IonType::String => {
-                        af_writer.write_string(try_to!(element.as_str()))?;
-                    }
-                    IonType::Clob => {
-                        af_writer.write_clob(try_to!(element.as_bytes()))?;
-                    }
-                    IonType::Blob => {
-                        af_writer.write_blob(try_to!(element.as_bytes()))?;
-                    }
-                    IonType::List | IonType::SExpression => {
-                        af_writer.start_container(ion_type.into())?;
-                        {
-                            let seq = try_to!(element.as_sequence());
-                            for child in seq.iter() {
-                                self.write(child)?;
-                            }
+                }
+                IonType::Float => {
+                    af_writer.write_f64(try_to!(element.as_f64()))?;
+                }
+                IonType::Decimal => {
+                    // TODO reconsider Decimal/BigDecimal internal factoring to avoid the clone
+                    let decimal = try_to!(element.as_decimal());
+                    let big_decimal = decimal.clone().try_into()?;
+                    af_writer.write_bigdecimal(&big_decimal)?;
+                }
+                IonType::Timestamp => {
+                    // TODO reconsider Timestamp/IonDateTime factoring to avoid the clone
+                    let timestamp = try_to!(element.as_timestamp());
+                    let ion_dt = timestamp.clone().try_into()?;
+                    af_writer.write_datetime(&ion_dt)?;
+                }
+                IonType::Symbol => {
+                    af_writer.write_symbol(try_to!(element.as_str()))?;
+                }
+                IonType::String => {
+                    af_writer.write_string(try_to!(element.as_str()))?;
+                }
+                IonType::Clob => {
+                    af_writer.write_clob(try_to!(element.as_bytes()))?;
+                }
+                IonType::Blob => {
+                    af_writer.write_blob(try_to!(element.as_bytes()))?;
+                }
+                IonType::List | IonType::SExpression => {
+                    af_writer.start_container(ion_type.into())?;
+                    {
+                        let seq = try_to!(element.as_sequence());
+                        for child in seq.iter() {
+                            write_element(writer, None, child)?;
                         }
-                        self.writer.finish_container()?;
                     }
-                    IonType::Struct => {
-                        af_writer.start_container(ion_type.into())?;
-                        {
-                            let structure = try_to!(element.as_struct());
-                            for (field_name_token, child) in structure.iter() {
-                                let field_name = try_to!(field_name_token.text());
-                                self.write_element(Some(field_name), child)?;
-                            }
+                    writer.finish_container()?;
+                }
+                IonType::Struct => {
+                    af_writer.start_container(ion_type.into())?;
+                    {
+                        let structure = try_to!(element.as_struct());
+                        for (field_name_token, child) in structure.iter() {
+                            let field_name = try_to!(field_name_token.text());
+                            write_element(writer, Some(field_name), child)?;
                         }
-                        self.writer.finish_container()?;
                     }
+                    writer.finish_container()?;
                 }
             }
-            Ok(())
-        } else {
-            illegal_operation(format!(
-                "Could not serialize annotation(s) with no text: {:?}",
-                element
-            ))
         }
+        Ok(())
+    } else {
+        illegal_operation(format!(
+            "Could not serialize annotation(s) with no text: {:?}",
+            element
+        ))
     }
 }
 
@@ -138,7 +148,7 @@ impl<'a> ElementWriter for IonCSliceElementWriter<'a> {
 
     #[inline]
     fn write<E: Element>(&mut self, element: &E) -> IonResult<()> {
-        self.write_element(None, element)
+        write_element(&mut self.writer, None, element)
     }
 
     fn finish(self) -> IonResult<Self::Output> {
@@ -211,19 +211,28 @@ use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
 use crate::IonType;
 use num_bigint::BigInt;
+use smallvec::SmallVec;
 use std::fmt::Debug;
 
 pub mod borrowed;
 pub mod native_reader;
 pub mod native_writer;
 pub mod owned;
+#[cfg(feature = "rayon")]
+mod parallel_reader;
+pub mod prefetch;
+
+// Storage for an owned element's annotations. Most values have zero or one annotation, so a
+// single annotation is stored inline rather than in a separate heap allocation. (SmallVec is
+// invariant over its item type, so BorrowedElement continues to use a Vec.)
+pub(crate) type AnnotationsVec<T> = SmallVec<[T; 1]>;
 pub mod reader;
 pub mod writer;
 
 #[cfg(feature = "ion_c")]
 pub mod ion_c_reader;
 #[cfg(feature = "ion_c")]
-mod ion_c_writer;
+pub mod ion_c_writer;
 
 /// The shared symbol table source of a given [`SymbolToken`].
 pub trait ImportSource: Debug + PartialEq {
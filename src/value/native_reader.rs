@@ -1,23 +1,151 @@
+use std::collections::HashSet;
+use std::io;
+use std::mem;
+use std::ops::Range;
+use std::sync::Arc;
+
 use crate::raw_reader::RawReader;
-use crate::reader::ReaderBuilder;
+use crate::reader::{ReaderBuilder, ReaderLimits, SymbolPolicy, TrailingDataPolicy};
 use crate::result::IonResult;
 use crate::value::owned;
-use crate::value::owned::{OwnedElement, OwnedSequence, OwnedStruct, OwnedValue};
-use crate::value::reader::ElementReader;
+use crate::value::owned::{OwnedElement, OwnedSequence, OwnedStruct, OwnedSymbolToken, OwnedValue};
+use crate::value::reader::{ElementRangeIterator, ElementReader};
+use crate::value::{AnnotationsVec, SymbolToken};
 use crate::{IonType, StreamItem, StreamReader, UserReader};
 
+// The default for `NativeElementReader::with_max_depth`. Cloning, comparing, and formatting an
+// `OwnedElement` recurse once per level of nesting; this keeps them well within the 2 MiB stack
+// that Rust gives a spawned thread.
+const DEFAULT_MAX_DEPTH: usize = 512;
+
 /// Provides an implementation of [ElementReader] that is backed by a native Rust [Reader].
-pub struct NativeElementReader;
+#[derive(Debug, Clone, Default)]
+pub struct NativeElementReader {
+    trailing_data_policy: TrailingDataPolicy,
+    max_depth: Option<usize>,
+    max_materialized_nodes: Option<usize>,
+    max_materialized_bytes: Option<usize>,
+    symbol_policy: SymbolPolicy,
+    unchecked_utf8: bool,
+}
+
+impl NativeElementReader {
+    /// Sets what the element reader will do when it encounters invalid data at the top level of
+    /// its input. For example, using [TrailingDataPolicy::Truncate] causes
+    /// [ElementReader::read_all] to return the values that precede the invalid data instead of
+    /// an error.
+    pub fn with_trailing_data_policy(mut self, policy: TrailingDataPolicy) -> NativeElementReader {
+        self.trailing_data_policy = policy;
+        self
+    }
+
+    /// Limits how deeply nested the containers in the input can be. Input that exceeds the limit
+    /// causes a decoding error instead of being materialized. By default, containers can be
+    /// nested at most 512 levels deep; see [OwnedElement] for which operations on deeper
+    /// elements could overflow the stack.
+    pub fn with_max_depth(mut self, max_depth: usize) -> NativeElementReader {
+        self.max_depth = Some(max_depth);
+        self
+    }
 
-struct NativeElementIterator<R: RawReader> {
+    /// Limits the number of values (counting nested values) that a single top-level element can
+    /// contain. Input that exceeds the limit causes a decoding error instead of being
+    /// materialized. By default, elements can be any size.
+    pub fn with_max_materialized_nodes(mut self, max_nodes: usize) -> NativeElementReader {
+        self.max_materialized_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Limits the approximate number of bytes of memory that a single top-level element can use.
+    /// See [ReaderLimits::with_max_materialized_bytes].
+    pub fn with_max_materialized_bytes(mut self, max_bytes: usize) -> NativeElementReader {
+        self.max_materialized_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Sets how symbols whose text is unknown are materialized. Using [SymbolPolicy::SidText]
+    /// allows streams that use the symbols of unavailable shared symbol tables to be read, with
+    /// each symbol whose text is unknown materialized as text of the form `$<sid>`.
+    pub fn with_symbol_policy(mut self, policy: SymbolPolicy) -> NativeElementReader {
+        self.symbol_policy = policy;
+        self
+    }
+
+    /// Skips UTF-8 validation of the strings in binary input. See
+    /// [ReaderBuilder::with_unchecked_utf8].
+    ///
+    /// # Safety
+    ///
+    /// Every string in the input must be valid UTF-8.
+    pub unsafe fn with_unchecked_utf8(mut self) -> NativeElementReader {
+        self.unchecked_utf8 = true;
+        self
+    }
+
+    pub(crate) fn reader_builder(&self) -> ReaderBuilder {
+        let mut limits =
+            ReaderLimits::new().with_max_depth(self.max_depth.unwrap_or(DEFAULT_MAX_DEPTH));
+        if let Some(max_nodes) = self.max_materialized_nodes {
+            limits = limits.with_max_materialized_nodes(max_nodes);
+        }
+        if let Some(max_bytes) = self.max_materialized_bytes {
+            limits = limits.with_max_materialized_bytes(max_bytes);
+        }
+        let builder = ReaderBuilder::new()
+            .with_trailing_data_policy(self.trailing_data_policy)
+            .with_limits(limits)
+            .with_symbol_policy(self.symbol_policy);
+        if self.unchecked_utf8 {
+            // SAFETY: The caller of `with_unchecked_utf8` guaranteed that the input's strings
+            // are valid UTF-8.
+            unsafe { builder.with_unchecked_utf8() }
+        } else {
+            builder
+        }
+    }
+}
+
+pub(crate) struct NativeElementIterator<R: RawReader> {
     reader: UserReader<R>,
 }
 
+impl<R: RawReader> NativeElementIterator<R> {
+    pub(crate) fn new(reader: UserReader<R>) -> Self {
+        NativeElementIterator { reader }
+    }
+}
+
 impl<R: RawReader> Iterator for NativeElementIterator<R> {
     type Item = IonResult<OwnedElement>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.materialize_next().transpose()
+        self.reader.materialize_next().transpose()
+    }
+}
+
+struct NativeElementRangeIterator<R: RawReader> {
+    reader: UserReader<R>,
+}
+
+impl<R: RawReader> Iterator for NativeElementRangeIterator<R> {
+    type Item = IonResult<(OwnedElement, Range<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.materialize_next_with_range().transpose()
+    }
+}
+
+/// An iterator over the remaining values at a [UserReader]'s current depth, materializing each
+/// one as an [OwnedElement]. See [UserReader::elements].
+pub struct Elements<'a, R: RawReader> {
+    reader: &'a mut UserReader<R>,
+}
+
+impl<'a, R: RawReader> Iterator for Elements<'a, R> {
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.materialize_next().transpose()
     }
 }
 
@@ -26,99 +154,391 @@ impl ElementReader for NativeElementReader {
         &'a self,
         data: &'b [u8],
     ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>> {
-        let reader = ReaderBuilder::new().build(data)?;
-        let iterator = NativeElementIterator { reader };
+        let reader = self.reader_builder().build(data)?;
+        let iterator = NativeElementIterator::new(reader);
+        Ok(Box::new(iterator))
+    }
+
+    fn iterate_over_with_ranges<'b>(&self, data: &'b [u8]) -> IonResult<ElementRangeIterator<'b>> {
+        let reader = self.reader_builder().build(data)?;
+        Ok(Box::new(NativeElementRangeIterator { reader }))
+    }
+
+    fn iterate_over_reader<'b, R: io::Read + 'b>(
+        &self,
+        input: R,
+    ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>> {
+        let reader = self.reader_builder().build_from_read(input)?;
+        let iterator = NativeElementIterator::new(reader);
         Ok(Box::new(iterator))
     }
 }
 
-impl<R: RawReader> NativeElementIterator<R> {
+impl<R: RawReader> UserReader<R> {
+    /// Returns an iterator that materializes each of the remaining values at the reader's current
+    /// depth as an [OwnedElement]. If the reader has not stepped into a container, this is each
+    /// of the remaining top-level values in the stream.
+    ///
+    /// This can be called at any point in the stream, allowing applications to mix the streaming
+    /// API with element materialization. Iteration begins with the value that follows the one
+    /// over which the reader is currently positioned (if any).
+    ///
+    /// Unlike [NativeElementReader], this does not limit how deeply nested the elements can be
+    /// unless the reader was built with a maximum depth. See [OwnedElement] for which operations
+    /// on deeply nested elements could overflow the stack.
+    pub fn elements(&mut self) -> Elements<'_, R> {
+        Elements { reader: self }
+    }
+
     /// Advances the reader to the next value in the stream and uses [Self::materialize_current]
     /// to materialize it.
-    fn materialize_next(&mut self) -> IonResult<Option<OwnedElement>> {
+    pub(crate) fn materialize_next(&mut self) -> IonResult<Option<OwnedElement>> {
+        let depth = self.depth();
         // Advance the reader to the next value
-        let _ = self.reader.next()?;
-        self.materialize_current()
+        let _ = self.next()?;
+        let result = self.materialize_current();
+        self.recover_if_top_level(depth, result)
+    }
+
+    /// Like [Self::materialize_next], but also returns the range of input offsets that the
+    /// value's encoding spans.
+    fn materialize_next_with_range(&mut self) -> IonResult<Option<(OwnedElement, Range<usize>)>> {
+        let depth = self.depth();
+        let _ = self.next()?;
+        let result = self.materialize_current_with_range();
+        self.recover_if_top_level(depth, result)
+    }
+
+    fn materialize_current_with_range(
+        &mut self,
+    ) -> IonResult<Option<(OwnedElement, Range<usize>)>> {
+        let start = self.position().byte_offset();
+        // If the reader doesn't know where the value ends yet, it will once the value has been
+        // materialized and the reader has stepped out of it.
+        let end = self.value_end_offset();
+        let element = match self.materialize_current()? {
+            Some(element) => element,
+            None => return Ok(None),
+        };
+        let end = end.unwrap_or_else(|| self.position().byte_offset());
+        Ok(Some((element, start..end)))
     }
 
-    /// Recursively materialize the reader's current Ion value and returns it as `Ok(Some(element))`.
+    // If materializing a top-level value failed, applies the reader's trailing data policy.
+    // (Errors encountered while advancing to the value have already been handled by `next()`.)
+    fn recover_if_top_level<T>(
+        &mut self,
+        depth: usize,
+        result: IonResult<Option<T>>,
+    ) -> IonResult<Option<T>> {
+        match result {
+            Err(error) if depth == 0 => {
+                self.recover_from(error)?;
+                Ok(None)
+            }
+            result => result,
+        }
+    }
+
+    /// Materializes the reader's current Ion value and returns it as `Ok(Some(element))`.
     /// If there are no more values at this level, returns `Ok(None)`.
     /// If an error occurs while materializing the value, returns an `Err`.
     /// Calling this method advances the reader and consumes the current value.
+    ///
+    /// Containers are materialized using an explicit stack rather than recursion so that deeply
+    /// nested input cannot overflow the call stack. To bound the amount of memory spent on
+    /// nesting, configure a maximum depth using [ReaderLimits](crate::ReaderLimits). To bound
+    /// the total size of the element, configure its maximum number of values or bytes.
     fn materialize_current(&mut self) -> IonResult<Option<OwnedElement>> {
-        // Collect this item's annotations into a Vec. We have to do this before materializing the
-        // value itself because materializing a collection requires advancing the reader further.
-        let mut annotations = Vec::new();
-        // Current API limitations require `self.reader.annotations()` to heap allocate its
-        // iterator even if there aren't annotations. `self.reader.has_annotations()` is trivial
-        // and allows us to skip the heap allocation in the common case.
-        if self.reader.has_annotations() {
-            for annotation in self.reader.annotations() {
+        // The containers that have been stepped into but not yet completely materialized.
+        let mut parents: Vec<PartialContainer> = Vec::new();
+        // The number of values in the element so far and the approximate memory they use.
+        let mut nodes: usize = 0;
+        let mut bytes: usize = 0;
+        loop {
+            let ion_type = match self.current() {
+                StreamItem::Nothing => {
+                    // We've reached the end of the innermost container. If there isn't one,
+                    // there are no more values at this level of the stream.
+                    let parent = match parents.pop() {
+                        Some(parent) => parent,
+                        None => return Ok(None),
+                    };
+                    self.step_out()?;
+                    let field_name = parent.field_name.clone();
+                    let element = parent.into_element();
+                    match parents.last_mut() {
+                        Some(grandparent) => grandparent.push(field_name, element),
+                        None => return Ok(Some(element)),
+                    }
+                    let _ = self.next()?;
+                    continue;
+                }
+                StreamItem::Null(ion_type) | StreamItem::Value(ion_type) => ion_type,
+            };
+
+            // Collect this item's annotations. We have to do this before materializing the value
+            // itself because materializing a collection requires advancing the reader.
+            let mut annotations = AnnotationsVec::new();
+            let mut metadata_length = 0;
+            for annotation in self.annotations() {
                 // If the annotation couldn't be resolved to text, early return the error.
                 let annotation = annotation?;
-                let symbol = owned::text_token(annotation.as_ref());
-                annotations.push(symbol);
+                metadata_length += annotation.as_ref().len();
+                annotations.push(owned::text_token(annotation.as_ref()));
             }
-        }
+            let field_name = match parents.last() {
+                Some(parent) if parent.ion_type == IonType::Struct => {
+                    let field_name = self.field_name()?;
+                    metadata_length += field_name.as_ref().len();
+                    let text = self.field_name_cache().intern(field_name.as_ref());
+                    Some(owned::text_token(text))
+                }
+                _ => None,
+            };
+            nodes += 1;
+            bytes = bytes.saturating_add(mem::size_of::<OwnedElement>() + metadata_length);
 
-        let value = match self.reader.current() {
-            // No more values at this level of the stream
-            StreamItem::Nothing => return Ok(None),
-            // This is a typed null
-            StreamItem::Null(ion_type) => OwnedValue::Null(ion_type),
-            // This is a non-null value
-            StreamItem::Value(ion_type) => {
+            let value = if self.is_null() {
+                OwnedValue::Null(ion_type)
+            } else {
                 use IonType::*;
+                if matches!(ion_type, String | Clob | Blob) {
+                    self.check_declared_materialized_size(bytes)?;
+                }
                 match ion_type {
                     Null => unreachable!("non-null value had IonType::Null"),
-                    Boolean => OwnedValue::Boolean(self.reader.read_bool()?),
-                    Integer => OwnedValue::Integer(self.reader.read_integer()?),
-                    Float => OwnedValue::Float(self.reader.read_f64()?),
-                    Decimal => OwnedValue::Decimal(self.reader.read_decimal()?),
-                    Timestamp => OwnedValue::Timestamp(self.reader.read_timestamp()?),
-                    Symbol => {
-                        OwnedValue::Symbol(owned::text_token(self.reader.read_symbol()?.as_ref()))
+                    Boolean => OwnedValue::Boolean(self.read_bool()?),
+                    Integer => OwnedValue::Integer(self.read_integer()?),
+                    Float => OwnedValue::Float(self.read_f64()?),
+                    Decimal => OwnedValue::Decimal(self.read_decimal()?),
+                    Timestamp => OwnedValue::Timestamp(self.read_timestamp()?),
+                    Symbol => OwnedValue::Symbol(owned::text_token(self.read_symbol()?.as_ref())),
+                    String => OwnedValue::String(self.read_string()?),
+                    Clob => OwnedValue::Clob(self.read_clob()?),
+                    Blob => OwnedValue::Blob(self.read_blob()?),
+                    // It's a collection; step into it and begin materializing its children.
+                    List | SExpression | Struct => {
+                        self.check_materialized_size(nodes, bytes)?;
+                        self.step_in()?;
+                        parents.push(PartialContainer {
+                            ion_type,
+                            annotations,
+                            field_name,
+                            field_names: Vec::new(),
+                            children: Vec::new(),
+                        });
+                        let _ = self.next()?;
+                        continue;
                     }
-                    String => OwnedValue::String(self.reader.read_string()?),
-                    Clob => OwnedValue::Clob(self.reader.read_clob()?),
-                    Blob => OwnedValue::Blob(self.reader.read_blob()?),
-                    // It's a collection; recursively materialize all of this value's children
-                    List => OwnedValue::List(self.materialize_sequence()?),
-                    SExpression => OwnedValue::SExpression(self.materialize_sequence()?),
-                    Struct => OwnedValue::Struct(self.materialize_struct()?),
                 }
+            };
+            let payload_length = match &value {
+                OwnedValue::String(text) => text.len(),
+                OwnedValue::Symbol(token) => token.text().map_or(0, str::len),
+                OwnedValue::Clob(data) | OwnedValue::Blob(data) => data.len(),
+                _ => 0,
+            };
+            bytes = bytes.saturating_add(payload_length);
+            self.check_materialized_size(nodes, bytes)?;
+            let element = OwnedElement::from_parts(annotations, value);
+            match parents.last_mut() {
+                Some(parent) => parent.push(field_name, element),
+                None => return Ok(Some(element)),
             }
-        };
-        Ok(Some(OwnedElement::new(annotations, value)))
+            let _ = self.next()?;
+        }
     }
+}
+
+// The maximum number of distinct field names that a reader will share between the structs that
+// it materializes. Streams typically use a few dozen field names; this keeps a stream whose field
+// names are all distinct from growing the cache without bound.
+const MAX_CACHED_FIELD_NAMES: usize = 1024;
+
+/// Interns the text of the field names that a reader materializes so that every struct with a
+/// given field name shares a single allocation of its text.
+#[derive(Debug, Default)]
+pub(crate) struct FieldNameCache {
+    names: HashSet<Arc<str>>,
+}
 
-    /// Steps into the current sequence and materializes each of its children to construct
-    /// an [OwnedSequence]. When all of the the children have been materialized, steps out.
-    /// The reader MUST be positioned over a list or s-expression when this is called.
-    fn materialize_sequence(&mut self) -> IonResult<OwnedSequence> {
-        let mut child_elements = Vec::new();
-        self.reader.step_in()?;
-        while let Some(element) = self.materialize_next()? {
-            child_elements.push(element);
+impl FieldNameCache {
+    pub(crate) fn intern(&mut self, text: &str) -> Arc<str> {
+        if let Some(name) = self.names.get(text) {
+            return Arc::clone(name);
         }
-        self.reader.step_out()?;
-        Ok(OwnedSequence::new(child_elements))
-    }
-
-    /// Steps into the current struct and materializes each of its fields to construct
-    /// an [OwnedStruct]. When all of the the fields have been materialized, steps out.
-    /// The reader MUST be positioned over a struct when this is called.
-    fn materialize_struct(&mut self) -> IonResult<OwnedStruct> {
-        let mut child_elements = Vec::new();
-        self.reader.step_in()?;
-        while let StreamItem::Value(_) | StreamItem::Null(_) = self.reader.next()? {
-            let field = self.reader.field_name()?;
-            let value = self
-                .materialize_current()?
-                .expect("materialize_current() returned None for user data");
-            child_elements.push((owned::text_token(field.as_ref()), value));
+        let name: Arc<str> = Arc::from(text);
+        if self.names.len() < MAX_CACHED_FIELD_NAMES {
+            self.names.insert(Arc::clone(&name));
         }
-        self.reader.step_out()?;
-        Ok(OwnedStruct::from_iter(child_elements.into_iter()))
+        name
+    }
+}
+
+// A container whose children are in the process of being materialized.
+struct PartialContainer {
+    ion_type: IonType,
+    annotations: AnnotationsVec<OwnedSymbolToken>,
+    // The container's own field name, if its parent is a struct.
+    field_name: Option<OwnedSymbolToken>,
+    // If the container is a struct, the field name of each of its children.
+    field_names: Vec<OwnedSymbolToken>,
+    children: Vec<OwnedElement>,
+}
+
+impl PartialContainer {
+    fn push(&mut self, field_name: Option<OwnedSymbolToken>, element: OwnedElement) {
+        if let Some(field_name) = field_name {
+            self.field_names.push(field_name);
+        }
+        self.children.push(element);
+    }
+
+    fn into_element(self) -> OwnedElement {
+        let value = match self.ion_type {
+            IonType::List => OwnedValue::List(OwnedSequence::new(self.children)),
+            IonType::SExpression => OwnedValue::SExpression(OwnedSequence::new(self.children)),
+            IonType::Struct => OwnedValue::Struct(OwnedStruct::from_iter(
+                self.field_names.into_iter().zip(self.children),
+            )),
+            scalar => unreachable!("{:?} is not a container type", scalar),
+        };
+        OwnedElement::from_parts(self.annotations, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::native_writer::NativeElementWriter;
+    use crate::value::reader::{element_reader, native_element_reader, ElementReader};
+    use crate::value::writer::ElementWriter;
+    use crate::value::{Element, Sequence, Struct};
+    use crate::TextWriterBuilder;
+
+    fn elements_for(text: &str) -> Vec<OwnedElement> {
+        element_reader()
+            .read_all(text.as_bytes())
+            .expect("invalid test data")
+    }
+
+    #[test]
+    fn elements_mid_stream() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("1 foo::{a: [2, 3]} (4) five")?;
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 1);
+        let elements = reader.elements().collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(elements, elements_for("foo::{a: [2, 3]} (4) five"));
+        Ok(())
+    }
+
+    #[test]
+    fn elements_inside_container() -> IonResult<()> {
+        let mut reader = ReaderBuilder::new().build("[1, {b: 2}, null.int] 3")?;
+        reader.next()?;
+        reader.step_in()?;
+        let elements = reader.elements().collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(elements, elements_for("1 {b: 2} null.int"));
+        reader.step_out()?;
+        // The reader can continue to be used after iteration.
+        assert_eq!(reader.next()?, StreamItem::Value(IonType::Integer));
+        assert_eq!(reader.read_i64()?, 3);
+        Ok(())
+    }
+
+    // Returns the number of lists nested within `element`, including itself.
+    fn list_depth(element: &OwnedElement) -> usize {
+        let mut depth = 0;
+        let mut current = Some(element);
+        while let Some(element) = current {
+            depth += 1;
+            current = element.as_sequence().and_then(|sequence| sequence.get(0));
+        }
+        depth
+    }
+
+    #[test]
+    fn materialize_deeply_nested_values() -> IonResult<()> {
+        const DEPTH: usize = 5_000;
+        let ion_data = format!("{}{}", "[".repeat(DEPTH), "]".repeat(DEPTH));
+        let reader = native_element_reader().with_max_depth(DEPTH);
+        let elements = reader.read_all(ion_data.as_bytes())?;
+        assert_eq!(list_depth(&elements[0]), DEPTH);
+
+        let mut buffer = Vec::new();
+        let mut writer = NativeElementWriter::new(TextWriterBuilder::new().build(&mut buffer)?);
+        writer.write_all(&elements)?;
+        writer.finish()?;
+        let elements = reader.read_all(&buffer)?;
+        assert_eq!(list_depth(&elements[0]), DEPTH);
+
+        let limited_reader = native_element_reader().with_max_depth(DEPTH - 1);
+        assert!(limited_reader.read_all(ion_data.as_bytes()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn default_max_depth() -> IonResult<()> {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let elements = native_element_reader().read_all(nested(DEFAULT_MAX_DEPTH).as_bytes())?;
+        assert_eq!(list_depth(&elements[0]), DEFAULT_MAX_DEPTH);
+        // An element at the default limit can be cloned, compared, and formatted.
+        assert_eq!(elements.clone(), elements);
+        assert!(!format!("{:?} {}", elements[0], elements[0]).is_empty());
+
+        let too_deep = nested(DEFAULT_MAX_DEPTH + 1);
+        assert!(native_element_reader()
+            .read_all(too_deep.as_bytes())
+            .is_err());
+        let mut reader = ReaderBuilder::new().build(too_deep.as_str())?;
+        let elements = reader.elements().collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(list_depth(&elements[0]), DEFAULT_MAX_DEPTH + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn materialization_budget() -> IonResult<()> {
+        // Each of these values contains four values, counting itself.
+        let ion_data = r#"[1, 2, 3] {a: "x", b: "y", c: "z"} [1, [2]]"#;
+        let elements = native_element_reader()
+            .with_max_materialized_nodes(4)
+            .read_all(ion_data.as_bytes())?;
+        assert_eq!(elements.len(), 3);
+
+        let mut elements = native_element_reader()
+            .with_max_materialized_nodes(3)
+            .iterate_over(ion_data.as_bytes())?;
+        let error = elements.next().unwrap().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("number of materialized values (4)"));
+
+        let element_size = mem::size_of::<OwnedElement>();
+        let limited_reader = native_element_reader().with_max_materialized_bytes(element_size);
+        assert!(limited_reader.read_one(b"\"a\"").is_err());
+        let elements = limited_reader.read_all(b"1 2")?;
+        assert_eq!(elements.len(), 2);
+
+        // A binary blob whose header declares a 16 MiB body (of which only one byte is present)
+        // is rejected without reading its contents.
+        let data: &[u8] = &[0xE0, 0x01, 0x00, 0xEA, 0xAE, 0x08, 0x00, 0x00, 0x81, 0x01];
+        let limited_reader = native_element_reader().with_max_materialized_bytes(1024);
+        let error = limited_reader.read_one(data).unwrap_err();
+        assert!(error.to_string().contains("max_materialized_bytes"));
+        Ok(())
+    }
+
+    #[test]
+    fn materialized_structs_share_field_names() -> IonResult<()> {
+        let elements =
+            native_element_reader().read_all(b"{name: 1} {name: 2} $ion_1_0 {name: 3}")?;
+        let field_name_text = |element: &OwnedElement| {
+            let (field_name, _value) = element.as_struct().unwrap().iter().next().unwrap();
+            field_name.text().unwrap().as_ptr()
+        };
+        assert_eq!(field_name_text(&elements[0]), field_name_text(&elements[1]));
+        assert_eq!(field_name_text(&elements[0]), field_name_text(&elements[2]));
+        Ok(())
     }
 }
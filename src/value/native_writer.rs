@@ -11,7 +11,7 @@ impl<W: Writer> ElementWriter for NativeElementWriter<W> {
     type Output = W;
 
     fn write<E: Element>(&mut self, element: &E) -> IonResult<()> {
-        self.write_element(None, element)
+        write_element(&mut self.writer, None, element)
     }
 
     fn finish(mut self) -> IonResult<Self::Output> {
@@ -25,62 +25,93 @@ impl<W: Writer> NativeElementWriter<W> {
     pub fn new(writer: W) -> Self {
         NativeElementWriter { writer }
     }
+}
 
-    /// Recursively writes the given `element` and its child elements (if any) to the underlying
-    /// writer.
-    fn write_element<E: Element>(
-        &mut self,
-        field_name: Option<&str>,
-        element: &E,
-    ) -> IonResult<()> {
-        if let Some(field_name) = field_name {
-            self.writer.set_field_name(field_name);
-        }
+// The children of a container that remain to be written, each paired with its field name (if
+// the container is a struct).
+type Children<'a, E> = Box<dyn Iterator<Item = (Option<&'a str>, &'a E)> + 'a>;
 
-        let element_annotations = element.annotations().map(|token| {
-            if let Some(text) = token.text() {
-                RawSymbolTokenRef::Text(text)
-            } else if let Some(sid) = token.local_sid() {
-                RawSymbolTokenRef::SymbolId(sid)
-            } else {
-                unreachable!("cannot write annotation with neither text nor symbol ID")
+/// Writes the given `element` and its child elements (if any) to `writer`.
+///
+/// Containers are written using an explicit stack rather than recursion so that deeply nested
+/// elements cannot overflow the call stack.
+pub(crate) fn write_element<W: Writer, E: Element>(
+    writer: &mut W,
+    field_name: Option<&str>,
+    element: &E,
+) -> IonResult<()> {
+    let mut parents: Vec<Children<E>> = Vec::new();
+    if let Some(children) = write_value(writer, field_name, element)? {
+        parents.push(children);
+    }
+    while let Some(children) = parents.last_mut() {
+        match children.next() {
+            Some((field_name, child)) => {
+                if let Some(grandchildren) = write_value(writer, field_name, child)? {
+                    parents.push(grandchildren);
+                }
+            }
+            None => {
+                parents.pop();
+                writer.step_out()?;
             }
-        });
-        self.writer.set_annotations(element_annotations);
+        }
+    }
+    Ok(())
+}
 
-        if element.is_null() {
-            return self.writer.write_null(element.ion_type());
+// Writes a scalar `element` to `writer`. If `element` is a container, steps into it instead and
+// returns an iterator over the children that need to be written before stepping out.
+fn write_value<'a, W: Writer, E: Element>(
+    writer: &mut W,
+    field_name: Option<&str>,
+    element: &'a E,
+) -> IonResult<Option<Children<'a, E>>> {
+    if let Some(field_name) = field_name {
+        writer.set_field_name(field_name);
+    }
+
+    let element_annotations = element.annotations().map(|token| {
+        if let Some(text) = token.text() {
+            RawSymbolTokenRef::Text(text)
+        } else if let Some(sid) = token.local_sid() {
+            RawSymbolTokenRef::SymbolId(sid)
+        } else {
+            unreachable!("cannot write annotation with neither text nor symbol ID")
         }
+    });
+    writer.set_annotations(element_annotations);
 
-        match element.ion_type() {
-            IonType::Null => unreachable!("element has IonType::Null but is_null() was false"),
-            IonType::Boolean => self.writer.write_bool(element.as_bool().unwrap()),
-            IonType::Integer => self.writer.write_integer(element.as_integer().unwrap()),
-            IonType::Float => self.writer.write_f64(element.as_f64().unwrap()),
-            IonType::Decimal => self.writer.write_decimal(element.as_decimal().unwrap()),
-            IonType::Timestamp => self.writer.write_timestamp(element.as_timestamp().unwrap()),
-            IonType::Symbol => self
-                .writer
-                .write_symbol(element.as_sym().unwrap().text().unwrap()),
-            IonType::String => self.writer.write_string(element.as_str().unwrap()),
-            IonType::Clob => self.writer.write_clob(element.as_bytes().unwrap()),
-            IonType::Blob => self.writer.write_blob(element.as_bytes().unwrap()),
-            IonType::List | IonType::SExpression => {
-                self.writer.step_in(element.ion_type())?;
-                for value in element.as_sequence().unwrap().iter() {
-                    self.write(value)?;
-                }
-                self.writer.step_out()
-            }
-            IonType::Struct => {
-                self.writer.step_in(IonType::Struct)?;
-                for (field, value) in element.as_struct().unwrap().iter() {
-                    self.write_element(Some(field.text().unwrap()), value)?;
-                }
-                self.writer.step_out()
-            }
+    if element.is_null() {
+        writer.write_null(element.ion_type())?;
+        return Ok(None);
+    }
+
+    match element.ion_type() {
+        IonType::Null => unreachable!("element has IonType::Null but is_null() was false"),
+        IonType::Boolean => writer.write_bool(element.as_bool().unwrap())?,
+        IonType::Integer => writer.write_integer(element.as_integer().unwrap())?,
+        IonType::Float => writer.write_f64(element.as_f64().unwrap())?,
+        IonType::Decimal => writer.write_decimal(element.as_decimal().unwrap())?,
+        IonType::Timestamp => writer.write_timestamp(element.as_timestamp().unwrap())?,
+        IonType::Symbol => writer.write_symbol(element.as_sym().unwrap().text().unwrap())?,
+        IonType::String => writer.write_string(element.as_str().unwrap())?,
+        IonType::Clob => writer.write_clob(element.as_bytes().unwrap())?,
+        IonType::Blob => writer.write_blob(element.as_bytes().unwrap())?,
+        IonType::List | IonType::SExpression => {
+            writer.step_in(element.ion_type())?;
+            let children = element.as_sequence().unwrap().iter();
+            return Ok(Some(Box::new(children.map(|child| (None, child)))));
+        }
+        IonType::Struct => {
+            writer.step_in(IonType::Struct)?;
+            let fields = element.as_struct().unwrap().iter();
+            return Ok(Some(Box::new(
+                fields.map(|(field, child)| (Some(field.text().unwrap()), child)),
+            )));
         }
     }
+    Ok(None)
 }
 
 #[cfg(test)]
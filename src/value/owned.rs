@@ -12,23 +12,23 @@ use crate::types::decimal::Decimal;
 use crate::types::integer::Integer;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
-use crate::value::Builder;
+use crate::value::{AnnotationsVec, Builder};
 use crate::IonType;
 use num_bigint::BigInt;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::iter::FromIterator;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// An owned implementation of  [`ImportSource`].
 #[derive(Debug, Clone)]
 pub struct OwnedImportSource {
-    table: Rc<str>,
+    table: Arc<str>,
     sid: SymbolId,
 }
 
 impl OwnedImportSource {
-    pub fn new<T: Into<Rc<str>>>(table: T, sid: SymbolId) -> Self {
+    pub fn new<T: Into<Arc<str>>>(table: T, sid: SymbolId) -> Self {
         Self {
             table: table.into(),
             sid,
@@ -57,14 +57,14 @@ impl ImportSource for OwnedImportSource {
 /// An owned implementation of [`SymbolToken`].
 #[derive(Debug, Clone)]
 pub struct OwnedSymbolToken {
-    text: Option<Rc<str>>,
+    text: Option<Arc<str>>,
     local_sid: Option<SymbolId>,
     source: Option<OwnedImportSource>,
 }
 
 impl OwnedSymbolToken {
-    fn new(
-        text: Option<Rc<str>>,
+    pub(crate) fn new(
+        text: Option<Arc<str>>,
         local_sid: Option<SymbolId>,
         source: Option<OwnedImportSource>,
     ) -> Self {
@@ -86,7 +86,7 @@ pub fn local_sid_token(local_sid: SymbolId) -> OwnedSymbolToken {
 /// Constructs an [`OwnedSymbolToken`] with just text.
 /// A common case for text and synthesizing tokens.
 #[inline]
-pub fn text_token<T: Into<Rc<str>>>(text: T) -> OwnedSymbolToken {
+pub fn text_token<T: Into<Arc<str>>>(text: T) -> OwnedSymbolToken {
     OwnedSymbolToken::new(Some(text.into()), None, None)
 }
 
@@ -104,7 +104,7 @@ impl PartialEq for OwnedSymbolToken {
 
 impl Eq for OwnedSymbolToken {}
 
-impl<T: Into<Rc<str>>> From<T> for OwnedSymbolToken {
+impl<T: Into<Arc<str>>> From<T> for OwnedSymbolToken {
     /// Constructs an owned token that has only text.
     fn from(text: T) -> Self {
         text_token(text)
@@ -127,7 +127,7 @@ impl SymbolToken for OwnedSymbolToken {
     }
 
     fn with_text(self, text: &'static str) -> Self {
-        OwnedSymbolToken::new(Some(Rc::from(text)), self.local_sid, self.source)
+        OwnedSymbolToken::new(Some(Arc::from(text)), self.local_sid, self.source)
     }
 
     fn with_local_sid(self, local_sid: SymbolId) -> Self {
@@ -143,7 +143,7 @@ impl SymbolToken for OwnedSymbolToken {
     }
 
     fn text_token(text: &'static str) -> Self {
-        OwnedSymbolToken::new(Some(Rc::from(text)), None, None)
+        OwnedSymbolToken::new(Some(Arc::from(text)), None, None)
     }
 
     fn local_sid_token(local_sid: usize) -> Self {
@@ -265,6 +265,12 @@ impl Sequence for OwnedSequence {
     }
 }
 
+impl Drop for OwnedSequence {
+    fn drop(&mut self) {
+        drop_iteratively(std::mem::take(&mut self.children));
+    }
+}
+
 impl PartialEq for OwnedSequence {
     fn eq(&self, other: &Self) -> bool {
         self.children == other.children
@@ -273,30 +279,37 @@ impl PartialEq for OwnedSequence {
 
 impl Eq for OwnedSequence {}
 
-/// An owned implementation of [`Struct`]
+/// An owned implementation of [`Struct`]. Its fields are iterated over in the order in which
+/// they were added.
 #[derive(Debug, Clone)]
 pub struct OwnedStruct {
-    text_fields: HashMap<Rc<str>, Vec<(OwnedSymbolToken, OwnedElement)>>,
-    no_text_fields: Vec<(OwnedSymbolToken, OwnedElement)>,
+    fields: Vec<(OwnedSymbolToken, OwnedElement)>,
+    // The indexes in `fields` of the fields with each name. Fields without text are not indexed.
+    text_fields: HashMap<Arc<str>, Vec<usize>>,
 }
 
 impl OwnedStruct {
+    fn no_text_fields(&self) -> impl Iterator<Item = &(OwnedSymbolToken, OwnedElement)> {
+        self.fields
+            .iter()
+            .filter(|(name, _value)| name.text.is_none())
+    }
+
     fn eq_text_fields(&self, other: &Self) -> bool {
         // check if both the text_fields have same (field_name,value) pairs
-        self.text_fields.iter().all(|(key, value)| {
-            value
-                .iter()
-                .all(|(_my_s, my_v)| other.get_all(key).any(|other_v| my_v.ion_eq(other_v)))
-                && value.len() == other.get_all(key).count()
+        self.text_fields.iter().all(|(key, indexes)| {
+            indexes.iter().all(|&index| {
+                let my_v = &self.fields[index].1;
+                other.get_all(key).any(|other_v| my_v.ion_eq(other_v))
+            }) && indexes.len() == other.get_all(key).count()
         })
     }
 
     fn eq_no_text_fields(&self, other: &Self) -> bool {
         // check if both the no_text_fields are same values
-        self.no_text_fields.iter().all(|(my_k, my_v)| {
+        self.no_text_fields().all(|(my_k, my_v)| {
             other
-                .no_text_fields
-                .iter()
+                .no_text_fields()
                 .any(|(other_k, other_v)| my_k == other_k && my_v.ion_eq(other_v))
         })
     }
@@ -309,28 +322,26 @@ where
 {
     /// Returns an owned struct from the given iterator of field names/values.
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut text_fields: HashMap<Rc<str>, Vec<(OwnedSymbolToken, OwnedElement)>> =
-            HashMap::new();
-        let mut no_text_fields: Vec<(OwnedSymbolToken, OwnedElement)> = Vec::new();
+        let mut fields: Vec<(OwnedSymbolToken, OwnedElement)> = Vec::new();
+        let mut text_fields: HashMap<Arc<str>, Vec<usize>> = HashMap::new();
 
         for (k, v) in iter {
             let key = k.into();
             let val = v.into();
 
-            match key.text() {
-                Some(text) => {
-                    let vals = text_fields.entry(text.into()).or_insert_with(Vec::new);
-                    vals.push((key, val));
-                }
-                None => {
-                    no_text_fields.push((key, val));
-                }
+            if let Some(text) = key.text.clone() {
+                // The map's key shares the token's text rather than copying it.
+                text_fields
+                    .entry(text)
+                    .or_insert_with(Vec::new)
+                    .push(fields.len());
             }
+            fields.push((key, val));
         }
 
         Self {
+            fields,
             text_fields,
-            no_text_fields,
         }
     }
 }
@@ -343,23 +354,14 @@ impl Struct for OwnedStruct {
         &'a self,
     ) -> Box<dyn Iterator<Item = (&'a Self::FieldName, &'a Self::Element)> + 'a> {
         // convert &(k, v) -> (&k, &v)
-        // flattens the fields_with_text_key HashMap and chains with fields_with_no_text_key
-        // to return all fields with iterator
-        Box::new(
-            self.text_fields
-                .values()
-                .flatten()
-                .into_iter()
-                .chain(self.no_text_fields.iter())
-                .map(|(k, v)| (k, v)),
-        )
+        Box::new(self.fields.iter().map(|(k, v)| (k, v)))
     }
 
     fn get<T: AsRef<str>>(&self, field_name: T) -> Option<&Self::Element> {
         self.text_fields
             .get(field_name.as_ref())?
             .last()
-            .map(|(_s, v)| v)
+            .map(|&index| &self.fields[index].1)
     }
 
     fn get_all<'a, T: AsRef<str>>(
@@ -370,16 +372,43 @@ impl Struct for OwnedStruct {
             self.text_fields
                 .get(field_name.as_ref())
                 .into_iter()
-                .flat_map(|v| v.iter())
-                .map(|(_s, v)| v),
+                .flat_map(|indexes| indexes.iter())
+                .map(move |&index| &self.fields[index].1),
         )
     }
 }
 
+impl OwnedStruct {
+    // Returns mutable references to the values of every field named `field_name`.
+    pub(crate) fn get_all_mut<'a>(
+        &'a mut self,
+        field_name: &'a str,
+    ) -> impl Iterator<Item = &'a mut OwnedElement> + 'a {
+        self.fields
+            .iter_mut()
+            .filter(move |(name, _value)| name.text() == Some(field_name))
+            .map(|(_name, value)| value)
+    }
+
+    // Moves the struct's field values into `values`, leaving it empty.
+    fn take_values(&mut self, values: &mut Vec<OwnedElement>) {
+        self.text_fields.clear();
+        values.extend(self.fields.drain(..).map(|(_name, value)| value));
+    }
+}
+
+impl Drop for OwnedStruct {
+    fn drop(&mut self) {
+        let mut values = Vec::new();
+        self.take_values(&mut values);
+        drop_iteratively(values);
+    }
+}
+
 impl PartialEq for OwnedStruct {
     fn eq(&self, other: &Self) -> bool {
         // check if both text_fields and no_text_fields have same length
-        self.text_fields.len() == other.text_fields.len() && self.no_text_fields.len() == other.no_text_fields.len()
+        self.text_fields.len() == other.text_fields.len() && self.no_text_fields().count() == other.no_text_fields().count()
             // check if text_fields and no_text_fields are equal
             // we need to test equality in both directions for both text_fields and no_text_fields
             // A good example for this is annotated vs not annotated values in struct
@@ -453,27 +482,76 @@ pub enum OwnedValue {
     // TODO fill this in with the rest of the value types...
 }
 
+// Left to the compiler, dropping a container drops each of its children recursively, which can
+// overflow the stack if the container is deeply nested. Instead, containers move their children
+// into `pending` when they are dropped. Each pending element's own children are moved into
+// `pending` in turn before it is dropped, so dropping it does not recurse.
+fn drop_iteratively(mut pending: Vec<OwnedElement>) {
+    while let Some(mut element) = pending.pop() {
+        match &mut element.value {
+            OwnedValue::List(sequence) | OwnedValue::SExpression(sequence) => {
+                pending.append(&mut sequence.children)
+            }
+            OwnedValue::Struct(structure) => structure.take_values(&mut pending),
+            _ => {}
+        }
+    }
+}
+
 /// An owned implementation of [`Element`]
+///
+/// Materializing, writing, and dropping an element visit its nested containers iteratively, so
+/// they work at any depth. Cloning, comparing (with `==` or [IonEq]), and formatting (with
+/// `Debug` or `Display`) recurse once per level of nesting instead, and can overflow the stack
+/// for an element nested thousands of levels deep. For this reason,
+/// [NativeElementReader](crate::value::native_reader::NativeElementReader) limits nesting to 512
+/// levels by default.
 #[derive(Debug, Clone)]
 pub struct OwnedElement {
-    annotations: Vec<OwnedSymbolToken>,
+    annotations: AnnotationsVec<OwnedSymbolToken>,
     value: OwnedValue,
 }
 
 impl OwnedElement {
     pub fn new(annotations: Vec<OwnedSymbolToken>, value: OwnedValue) -> Self {
+        Self::from_parts(AnnotationsVec::from_vec(annotations), value)
+    }
+
+    // Like `new`, but avoids allocating a Vec for callers that collect annotations themselves.
+    pub(crate) fn from_parts(
+        annotations: AnnotationsVec<OwnedSymbolToken>,
+        value: OwnedValue,
+    ) -> Self {
         Self { annotations, value }
     }
+
+    // Discards the element's annotations, returning its value.
+    pub(crate) fn into_value(self) -> OwnedValue {
+        self.value
+    }
+
+    pub(crate) fn as_struct_mut(&mut self) -> Option<&mut OwnedStruct> {
+        match &mut self.value {
+            OwnedValue::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
 }
 
 impl Display for OwnedElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        let mut ivf = IonValueFormatter { output: f };
+        let mut ivf = IonValueFormatter::new(f);
 
         // display for annotations of this owned_element
         ivf.format_annotations(&self.annotations)
             .map_err(|_| std::fmt::Error)?;
 
+        if self.is_null() {
+            return ivf
+                .format_null(self.ion_type())
+                .map_err(|_| std::fmt::Error);
+        }
+
         match self.ion_type() {
             IonType::Null => ivf.format_null(IonType::Null),
             IonType::Boolean => ivf.format_bool(self.as_bool().unwrap()),
@@ -600,7 +678,7 @@ impl Element for OwnedElement {
     }
 
     fn with_annotations<I: IntoIterator<Item = Self::SymbolToken>>(self, annotations: I) -> Self {
-        OwnedElement::new(annotations.into_iter().collect(), self.value)
+        OwnedElement::from_parts(annotations.into_iter().collect(), self.value)
     }
 
     fn is_null(&self) -> bool {
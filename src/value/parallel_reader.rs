@@ -0,0 +1,237 @@
+use std::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::binary::constants::v1_0::{length_codes, IVM};
+use crate::binary::header::Header;
+use crate::binary::var_uint::VarUInt;
+use crate::binary::IonTypeCode;
+use crate::result::IonResult;
+use crate::value::native_reader::NativeElementReader;
+use crate::value::owned::OwnedElement;
+use crate::value::reader::ElementReader;
+
+// The symbol ID of `$ion_symbol_table`.
+const ION_SYMBOL_TABLE_SID: usize = 3;
+
+// The approximate number of bytes of user values that are decoded together by a single task.
+const TARGET_CHUNK_SIZE: usize = 64 * 1024;
+
+impl NativeElementReader {
+    /// Reads every top-level value in `data`, materializing them on multiple threads. The values
+    /// are returned in the order in which they appear in the stream.
+    ///
+    /// Binary Ion is split into chunks of top-level values using the lengths declared in their
+    /// headers. Each chunk is decoded along with the local symbol tables that are in effect at
+    /// its position in the stream, so symbol tables that appear partway through the stream
+    /// (including ones that append to the previous table) are honored.
+    ///
+    /// Text Ion has no declared lengths, so it is read on the current thread as if by
+    /// [ElementReader::read_all]. If the input is malformed, it is also re-read that way so that
+    /// errors (and this reader's [TrailingDataPolicy](crate::TrailingDataPolicy)) are reported
+    /// exactly as they would be by [ElementReader::read_all].
+    pub fn read_all_parallel(&self, data: &[u8]) -> IonResult<Vec<OwnedElement>> {
+        let chunks = match split_binary(data) {
+            Some(chunks) if chunks.len() > 1 => chunks,
+            _ => return self.read_all(data),
+        };
+        let results: IonResult<Vec<Vec<OwnedElement>>> = chunks
+            .par_iter()
+            .map(|chunk| self.read_all(&chunk.to_stream(data)))
+            .collect();
+        match results {
+            Ok(elements) => Ok(elements.into_iter().flatten().collect()),
+            Err(_) => self.read_all(data),
+        }
+    }
+}
+
+// A run of top-level user values and the local symbol tables that must be read before them.
+#[derive(Debug, Clone)]
+struct Chunk {
+    symbol_tables: Vec<Range<usize>>,
+    values: Range<usize>,
+}
+
+impl Chunk {
+    // Constructs a self-contained binary Ion stream that contains this chunk's values.
+    fn to_stream(&self, data: &[u8]) -> Vec<u8> {
+        let symbol_tables_length: usize = self.symbol_tables.iter().map(|range| range.len()).sum();
+        let mut stream = Vec::with_capacity(IVM.len() + symbol_tables_length + self.values.len());
+        stream.extend_from_slice(&IVM);
+        for range in &self.symbol_tables {
+            stream.extend_from_slice(&data[range.clone()]);
+        }
+        stream.extend_from_slice(&data[self.values.clone()]);
+        stream
+    }
+}
+
+// The kinds of top-level entities that the splitter distinguishes between.
+enum TopLevelItem {
+    VersionMarker,
+    SymbolTable,
+    // Values and NOP padding
+    Other,
+}
+
+// Splits a binary Ion 1.0 stream into chunks of top-level values. Returns `None` if `data` is
+// not binary Ion 1.0 or if a value's header could not be parsed.
+fn split_binary(data: &[u8]) -> Option<Vec<Chunk>> {
+    if !data.starts_with(&IVM) {
+        return None;
+    }
+    let mut chunks = Vec::new();
+    let mut symbol_tables: Vec<Range<usize>> = Vec::new();
+    let mut values_start = IVM.len();
+    let mut position = IVM.len();
+    while position < data.len() {
+        let (item, length) = next_item(&data[position..])?;
+        let end = position
+            .checked_add(length)
+            .filter(|end| *end <= data.len())?;
+        match item {
+            TopLevelItem::Other => {
+                if end - values_start >= TARGET_CHUNK_SIZE {
+                    chunks.push(Chunk {
+                        symbol_tables: symbol_tables.clone(),
+                        values: values_start..end,
+                    });
+                    values_start = end;
+                }
+            }
+            TopLevelItem::VersionMarker | TopLevelItem::SymbolTable => {
+                if values_start < position {
+                    chunks.push(Chunk {
+                        symbol_tables: symbol_tables.clone(),
+                        values: values_start..position,
+                    });
+                }
+                // A version marker resets the symbol table; a symbol table either replaces or
+                // appends to the one before it, so every table since the last marker is kept.
+                if let TopLevelItem::VersionMarker = item {
+                    symbol_tables.clear();
+                } else {
+                    symbol_tables.push(position..end);
+                }
+                values_start = end;
+            }
+        }
+        position = end;
+    }
+    if values_start < data.len() {
+        chunks.push(Chunk {
+            symbol_tables,
+            values: values_start..data.len(),
+        });
+    }
+    Some(chunks)
+}
+
+// Identifies the top-level entity at the beginning of `data` and returns its encoded length.
+fn next_item(data: &[u8]) -> Option<(TopLevelItem, usize)> {
+    if data.starts_with(&IVM) {
+        return Some((TopLevelItem::VersionMarker, IVM.len()));
+    }
+    let header = Header::from_byte(data[0]).ok()?;
+    if header.ion_type_code == IonTypeCode::Annotation && header.length_code == 0 {
+        // Only the version marker may use this type descriptor.
+        return None;
+    }
+    let mut body = &data[1..];
+    let body_length = match (header.ion_type_code, header.length_code) {
+        (IonTypeCode::Boolean, _) | (_, length_codes::NULL) => 0,
+        (IonTypeCode::Struct, 1) | (_, length_codes::VAR_UINT) => {
+            VarUInt::read(&mut body).ok()?.value()
+        }
+        (_, length_code) => length_code as usize,
+    };
+    let header_length = data.len() - body.len();
+    let length = header_length.checked_add(body_length)?;
+    let item = if header.ion_type_code == IonTypeCode::Annotation
+        && is_symbol_table(body.get(..body_length)?)
+    {
+        TopLevelItem::SymbolTable
+    } else {
+        TopLevelItem::Other
+    };
+    Some((item, length))
+}
+
+// Returns true if `body` (the contents of an annotation wrapper) is a struct whose first
+// annotation is `$ion_symbol_table`.
+fn is_symbol_table(mut body: &[u8]) -> bool {
+    let annotations_length = match VarUInt::read(&mut body) {
+        Ok(length) => length.value(),
+        Err(_) => return false,
+    };
+    let first_annotation = match VarUInt::read(&mut body) {
+        Ok(annotation) => annotation,
+        Err(_) => return false,
+    };
+    if first_annotation.value() != ION_SYMBOL_TABLE_SID {
+        return false;
+    }
+    let remaining_annotations_length =
+        annotations_length.saturating_sub(first_annotation.size_in_bytes());
+    match body.get(remaining_annotations_length) {
+        Some(byte) => matches!(
+            Header::from_byte(*byte),
+            Ok(header) if header.ion_type_code == IonTypeCode::Struct
+        ),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::native_writer::write_element;
+    use crate::value::reader::native_element_reader;
+    use crate::writer::Writer;
+    use crate::BinaryWriterBuilder;
+
+    #[test]
+    fn read_all_parallel_matches_read_all() -> IonResult<()> {
+        // Enough values that the stream is split into several chunks. Flushing periodically
+        // writes a symbol table that appends each batch's new symbols to the previous table.
+        let text: String = (0..6_000)
+            .map(|i| {
+                format!(
+                    "{{name: sym_{}, id: {}, tags: [a, \"a longer string value\"]}} ",
+                    i / 100,
+                    i
+                )
+            })
+            .collect();
+        let elements = native_element_reader().read_all(text.as_bytes())?;
+        let mut data = Vec::new();
+        let mut writer = BinaryWriterBuilder::new().build(&mut data)?;
+        for (index, element) in elements.iter().enumerate() {
+            write_element(&mut writer, None, element)?;
+            if index % 1_000 == 999 {
+                writer.flush()?;
+            }
+        }
+        writer.flush()?;
+        drop(writer);
+
+        let chunks = split_binary(&data).unwrap();
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().any(|chunk| chunk.symbol_tables.len() > 1));
+
+        let reader = native_element_reader();
+        assert_eq!(reader.read_all_parallel(&data)?, elements);
+        assert_eq!(reader.read_all_parallel(text.as_bytes())?, elements);
+        Ok(())
+    }
+
+    #[test]
+    fn read_all_parallel_reports_errors() {
+        let reader = native_element_reader();
+        // An IVM followed by a string whose declared length exceeds the input.
+        assert!(reader
+            .read_all_parallel(&[0xE0, 0x01, 0x00, 0xEA, 0x85, b'a'])
+            .is_err());
+    }
+}
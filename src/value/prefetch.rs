@@ -0,0 +1,109 @@
+//! Provides [PrefetchedElements], an iterator that decodes upcoming top-level values on a
+//! background thread while the current value is being processed.
+
+use std::io;
+use std::panic;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread::{self, JoinHandle};
+
+use crate::result::IonResult;
+use crate::value::native_reader::{NativeElementIterator, NativeElementReader};
+use crate::value::owned::OwnedElement;
+
+impl NativeElementReader {
+    /// Reads the top-level values in `input` on a background thread, which decodes up to
+    /// `capacity` values ahead of the consumer. This overlaps reading and decoding the stream
+    /// with the work that the application does with each value.
+    ///
+    /// The returned iterator yields values and errors in the same way as
+    /// [ElementReader::iterate_over_reader](crate::value::reader::ElementReader::iterate_over_reader).
+    /// A `capacity` of zero means that the background thread decodes the next value but waits to
+    /// hand it over until the consumer asks for it.
+    ///
+    /// Dropping the iterator stops the background thread once it finishes decoding the value
+    /// that it is working on.
+    pub fn prefetch<R>(&self, input: R, capacity: usize) -> PrefetchedElements
+    where
+        R: io::Read + Send + 'static,
+    {
+        let (sender, receiver) = sync_channel(capacity);
+        let element_reader = self.clone();
+        let worker = thread::spawn(move || {
+            let reader = match element_reader.reader_builder().build_from_read(input) {
+                Ok(reader) => reader,
+                Err(error) => {
+                    let _ = sender.send(Err(error));
+                    return;
+                }
+            };
+            for result in NativeElementIterator::new(reader) {
+                let is_error = result.is_err();
+                // If the consumer has dropped the receiver, there is nobody left to decode for.
+                if sender.send(result).is_err() || is_error {
+                    return;
+                }
+            }
+        });
+        PrefetchedElements {
+            receiver,
+            worker: Some(worker),
+        }
+    }
+}
+
+/// An iterator over top-level values that are decoded on a background thread.
+/// See [NativeElementReader::prefetch].
+pub struct PrefetchedElements {
+    receiver: Receiver<IonResult<OwnedElement>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Iterator for PrefetchedElements {
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.receiver.recv() {
+            Ok(result) => Some(result),
+            Err(_) => {
+                // The background thread has exited. If it panicked, propagate the panic rather
+                // than reporting a truncated stream as if it had ended normally.
+                if let Some(worker) = self.worker.take() {
+                    if let Err(payload) = worker.join() {
+                        panic::resume_unwind(payload);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::reader::{native_element_reader, ElementReader};
+
+    #[test]
+    fn prefetch_matches_iterate_over() -> IonResult<()> {
+        let text: String = (0..1_000).map(|i| format!("{{id: {}}} ", i)).collect();
+        let reader = native_element_reader();
+        let expected = reader.read_all(text.as_bytes())?;
+        for capacity in [0, 1, 64] {
+            let elements: Vec<OwnedElement> = reader
+                .prefetch(io::Cursor::new(text.clone().into_bytes()), capacity)
+                .collect::<IonResult<_>>()?;
+            assert_eq!(elements, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn prefetch_stops_after_error() {
+        let reader = native_element_reader();
+        let mut elements = reader.prefetch(io::Cursor::new(b"1 2 {".to_vec()), 4);
+        assert!(matches!(elements.next(), Some(Ok(_))));
+        assert!(matches!(elements.next(), Some(Ok(_))));
+        assert!(matches!(elements.next(), Some(Err(_))));
+        assert!(elements.next().is_none());
+    }
+}
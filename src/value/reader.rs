@@ -3,13 +3,22 @@
 //! Provides APIs to read Ion data into [`Element`](super::Element) from different sources such
 //! as slices or files.
 
-use crate::result::{decoding_error, IonResult};
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::result::{decoding_error, illegal_operation, IonResult};
 use crate::value::native_reader::NativeElementReader;
 use crate::value::owned::OwnedElement;
 
 #[cfg(feature = "ion_c")]
 use crate::value::ion_c_reader::IonCElementReader;
 
+/// An iterator over top-level elements and the range of input offsets that each one spans.
+pub type ElementRangeIterator<'a> =
+    Box<dyn Iterator<Item = IonResult<(OwnedElement, Range<usize>)>> + 'a>;
+
 // TODO add/refactor trait/implementation for borrowing over some context
 //      we could make it generic with generic associated types or just have a lifetime
 //      scoped implementation
@@ -38,6 +47,39 @@ pub trait ElementReader {
         data: &'b [u8],
     ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>>;
 
+    /// Parses Ion from the given [`io::Read`] implementation and yields each top-level value as
+    /// an [`Element`](super::Element) instance.
+    ///
+    /// Unlike [`ElementReader::iterate_over`], this does not require the caller to load the
+    /// complete input into a slice first. Errors are reported in the same way.
+    fn iterate_over_reader<'b, R: io::Read + 'b>(
+        &self,
+        input: R,
+    ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>>;
+
+    /// Opens the file at the given path and yields each of its top-level values as an
+    /// [`Element`](super::Element) instance. See [`ElementReader::iterate_over_reader`].
+    ///
+    /// This will return an [`IonError`](crate::result::IonError) if the file could not be opened.
+    fn iterate_over_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>>>> {
+        let file = File::open(path)?;
+        self.iterate_over_reader(file)
+    }
+
+    /// Like [`ElementReader::iterate_over`], but also yields the range of offsets in `data` that
+    /// each top-level value's encoding spans, including its annotations. The range does not
+    /// include any surrounding whitespace, comments, or system values like symbol tables, so
+    /// `&data[range]` is the value's exact text or binary encoding.
+    ///
+    /// Implementations that cannot report byte ranges return an
+    /// [`IonError::IllegalOperation`](crate::result::IonError::IllegalOperation).
+    fn iterate_over_with_ranges<'b>(&self, _data: &'b [u8]) -> IonResult<ElementRangeIterator<'b>> {
+        illegal_operation("this element reader cannot report the byte ranges of elements")
+    }
+
     /// Parses given Ion over a given slice into an [`Vec`] returning an
     /// [`IonError`](crate::result::IonError) if any error occurs during the parse.
     #[inline]
@@ -81,7 +123,7 @@ pub fn element_reader() -> impl ElementReader {
 }
 
 pub fn native_element_reader() -> NativeElementReader {
-    NativeElementReader {}
+    NativeElementReader::default()
 }
 
 #[cfg(feature = "ion_c")]
@@ -96,8 +138,9 @@ mod reader_tests {
     use crate::types::timestamp::Timestamp as TS;
     use crate::value::owned::OwnedValue::*;
     use crate::value::owned::*;
+    use crate::value::Builder;
     use crate::value::Element;
-    use crate::IonType;
+    use crate::{IonType, SymbolPolicy, TrailingDataPolicy};
     use bigdecimal::BigDecimal;
     use num_bigint::BigInt;
     use rstest::*;
@@ -309,4 +352,89 @@ mod reader_tests {
         assert!(actual.as_f64().unwrap().is_nan());
         Ok(())
     }
+
+    #[test]
+    fn iterate_over_reader() -> IonResult<()> {
+        let data: &[u8] = b"1 two \"three\" [4]";
+        let expected = element_reader().read_all(data)?;
+        let actual = element_reader()
+            .iterate_over_reader(io::Cursor::new(data.to_vec()))?
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_over_file() -> IonResult<()> {
+        let data: &[u8] = b"{foo: bar} baz::5";
+        let path = std::env::temp_dir().join(format!("ion-rs-elements-{}.ion", std::process::id()));
+        std::fs::write(&path, data)?;
+        let actual = element_reader()
+            .iterate_over_file(&path)
+            .and_then(|elements| elements.collect::<IonResult<Vec<_>>>());
+        std::fs::remove_file(&path)?;
+        assert_eq!(element_reader().read_all(data)?, actual?);
+        assert!(element_reader().iterate_over_file(&path).is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text(&b"1 /* two */ two::2 [3, (4)] {five: 5} \"six\""[..])]
+    #[case::binary(&[
+        0xE0, 0x01, 0x00, 0xEA, // IVM
+        0x21, 0x01, // 1
+        0xE4, 0x81, 0x84, 0x21, 0x02, // $4::2
+        0xB5, 0x21, 0x03, 0xC2, 0x71, 0x01, // [3, ($1)]
+        0xD3, 0x84, 0x21, 0x05, // {$4: 5}
+    ][..])]
+    fn iterate_over_with_ranges(#[case] data: &[u8]) -> IonResult<()> {
+        let expected = native_element_reader().read_all(data)?;
+        let mut actual = Vec::new();
+        for result in native_element_reader().iterate_over_with_ranges(data)? {
+            let (element, range) = result?;
+            // Each range can be read on its own to produce the same element. Binary values need
+            // to be preceded by an IVM.
+            let mut value_data = Vec::new();
+            if data.starts_with(&[0xE0]) {
+                value_data.extend_from_slice(&data[..4]);
+            }
+            value_data.extend_from_slice(&data[range]);
+            assert_eq!(native_element_reader().read_one(&value_data)?, element);
+            actual.push(element);
+        }
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn read_all_with_trailing_data_policy() -> IonResult<()> {
+        let data = b"1 [2, 3] [4, 5 }\n6";
+        let expected = native_element_reader().read_all(b"1 [2, 3]")?;
+        assert!(native_element_reader().read_all(data).is_err());
+
+        let truncating =
+            native_element_reader().with_trailing_data_policy(TrailingDataPolicy::Truncate);
+        assert_eq!(truncating.read_all(data)?, expected);
+
+        let resynchronizing =
+            native_element_reader().with_trailing_data_policy(TrailingDataPolicy::Resynchronize);
+        let results: Vec<_> = resynchronizing.iterate_over(data)?.collect();
+        assert_eq!(results.len(), 4);
+        assert!(results[2].is_err());
+        assert_eq!(results[3].as_ref().unwrap(), &OwnedElement::new_i64(6));
+        Ok(())
+    }
+
+    #[test]
+    fn read_all_with_symbol_policy() -> IonResult<()> {
+        let data = br#"
+            $ion_symbol_table::{imports: [{name: "missing", max_id: 2}]}
+            $10::{$11: $10}
+        "#;
+        assert!(native_element_reader().read_all(data).is_err());
+        let reader = native_element_reader().with_symbol_policy(SymbolPolicy::SidText);
+        let expected = native_element_reader().read_all(br#"'$10'::{'$11': '$10'}"#)?;
+        assert_eq!(reader.read_all(data)?, expected);
+        Ok(())
+    }
 }
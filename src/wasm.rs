@@ -0,0 +1,44 @@
+//! Helpers for using this crate from WebAssembly in a browser, where Ion data is typically
+//! exchanged with JavaScript as a `Uint8Array`.
+//!
+//! The crate compiles for `wasm32-unknown-unknown` as long as the `ion_c` feature (which links
+//! against a native library) is disabled. These helpers are enabled by the `wasm` feature.
+
+use js_sys::Uint8Array;
+
+use crate::convert::convert;
+use crate::reader::{Reader, ReaderBuilder};
+use crate::result::{illegal_operation_raw, IonResult};
+use crate::value::owned::OwnedElement;
+use crate::value::writer::{Format, TextKind};
+
+/// Constructs a [Reader] over a copy of the contents of `input`, which may be text or binary
+/// Ion.
+pub fn reader_from_uint8_array(input: &Uint8Array) -> IonResult<Reader<'static>> {
+    ReaderBuilder::new().build(input.to_vec())
+}
+
+/// Reads all of the top-level values in `input` (which may be text or binary Ion) as elements.
+pub fn read_elements(input: &Uint8Array) -> IonResult<Vec<OwnedElement>> {
+    reader_from_uint8_array(input)?.elements().collect()
+}
+
+/// Re-encodes the Ion data in `input` using the given [Format], returning the result as a new
+/// `Uint8Array`. See [convert] for details.
+pub fn convert_uint8_array(input: &Uint8Array, to: Format) -> IonResult<Uint8Array> {
+    let mut output = Vec::new();
+    convert(input.to_vec().as_slice(), &mut output, to)?;
+    Ok(Uint8Array::from(output.as_slice()))
+}
+
+/// Returns the Ion data in `input` (which may be text or binary Ion) as pretty-printed text.
+pub fn pretty_print(input: &Uint8Array) -> IonResult<String> {
+    let mut output = Vec::new();
+    convert(
+        input.to_vec().as_slice(),
+        &mut output,
+        Format::Text(TextKind::Pretty),
+    )?;
+    String::from_utf8(output)
+        .map_err(|_| illegal_operation_raw("the text writer produced invalid UTF-8"))
+}
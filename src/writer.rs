@@ -1,5 +1,8 @@
 use crate::raw_symbol_token_ref::AsRawSymbolTokenRef;
-use crate::result::IonResult;
+use crate::reader::{
+    HARDENED_MAX_ANNOTATIONS, HARDENED_MAX_DEPTH, HARDENED_MAX_SYMBOLS, HARDENED_MAX_VALUE_LENGTH,
+};
+use crate::result::{illegal_operation, IonResult};
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::IonType;
@@ -44,6 +47,16 @@ pub trait Writer {
     /// Writes an Ion `integer` with the specified value to the output stream.
     fn write_integer(&mut self, value: &Integer) -> IonResult<()>;
 
+    /// Writes an Ion `integer` with the specified value to the output stream.
+    fn write_i128(&mut self, value: i128) -> IonResult<()> {
+        self.write_integer(&Integer::from(value))
+    }
+
+    /// Writes an Ion `integer` with the specified value to the output stream.
+    fn write_u128(&mut self, value: u128) -> IonResult<()> {
+        self.write_integer(&Integer::from(value))
+    }
+
     /// Writes an Ion `float` with the specified value to the output stream.
     fn write_f32(&mut self, value: f32) -> IonResult<()>;
 
@@ -97,3 +110,109 @@ pub trait Writer {
     /// This method can only be called when the writer is at the top level.
     fn flush(&mut self) -> IonResult<()>;
 }
+
+/// Upper bounds on the data that a writer will encode. Attempting to write data that exceeds one
+/// of these limits causes the writer to return an [IonError::IllegalOperation] instead of
+/// encoding it. Enforcing the same limits as the readers that will consume the output prevents a
+/// writer from producing data that those readers would reject.
+///
+/// By default, no limits are enforced.
+///
+/// [IonError::IllegalOperation]: crate::result::IonError::IllegalOperation
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct WriterLimits {
+    max_depth: Option<usize>,
+    max_annotations: Option<usize>,
+    max_symbols: Option<usize>,
+    max_value_length: Option<usize>,
+}
+
+impl WriterLimits {
+    /// Constructs a `WriterLimits` that does not limit the writer in any way.
+    pub fn new() -> WriterLimits {
+        WriterLimits::default()
+    }
+
+    /// Constructs a `WriterLimits` with the same conservative limits as
+    /// [ReaderLimits::hardened](crate::ReaderLimits::hardened).
+    pub fn hardened() -> WriterLimits {
+        WriterLimits::new()
+            .with_max_depth(HARDENED_MAX_DEPTH)
+            .with_max_annotations(HARDENED_MAX_ANNOTATIONS)
+            .with_max_symbols(HARDENED_MAX_SYMBOLS)
+            .with_max_value_length(HARDENED_MAX_VALUE_LENGTH)
+    }
+
+    /// Limits how deeply the writer can step into nested containers.
+    pub fn with_max_depth(mut self, max_depth: usize) -> WriterLimits {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Limits the number of annotations that a single value can have.
+    pub fn with_max_annotations(mut self, max_annotations: usize) -> WriterLimits {
+        self.max_annotations = Some(max_annotations);
+        self
+    }
+
+    /// Limits the number of symbols (including the system symbols) that a binary writer's symbol
+    /// table can hold. Text writers do not add symbols to a symbol table, so this limit has no
+    /// effect on them.
+    pub fn with_max_symbols(mut self, max_symbols: usize) -> WriterLimits {
+        self.max_symbols = Some(max_symbols);
+        self
+    }
+
+    /// Limits the length in bytes of any string, blob, or clob that is written.
+    pub fn with_max_value_length(mut self, max_value_length: usize) -> WriterLimits {
+        self.max_value_length = Some(max_value_length);
+        self
+    }
+
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    pub fn max_annotations(&self) -> Option<usize> {
+        self.max_annotations
+    }
+
+    pub fn max_symbols(&self) -> Option<usize> {
+        self.max_symbols
+    }
+
+    pub fn max_value_length(&self) -> Option<usize> {
+        self.max_value_length
+    }
+
+    pub(crate) fn check_depth(&self, depth: usize) -> IonResult<()> {
+        check_limit(self.max_depth, depth, "container depth")
+    }
+
+    pub(crate) fn check_annotations(&self, num_annotations: usize) -> IonResult<()> {
+        check_limit(
+            self.max_annotations,
+            num_annotations,
+            "number of annotations",
+        )
+    }
+
+    pub(crate) fn check_symbols(&self, num_symbols: usize) -> IonResult<()> {
+        check_limit(self.max_symbols, num_symbols, "number of symbols")
+    }
+
+    pub(crate) fn check_value_length(&self, length: usize) -> IonResult<()> {
+        check_limit(self.max_value_length, length, "value length")
+    }
+}
+
+// Returns an illegal operation error if `actual` is greater than `limit`.
+fn check_limit(limit: Option<usize>, actual: usize, description: &str) -> IonResult<()> {
+    match limit {
+        Some(limit) if actual > limit => illegal_operation(format!(
+            "{} ({}) exceeds the writer's configured limit of {}",
+            description, actual, limit
+        )),
+        _ => Ok(()),
+    }
+}
@@ -282,7 +282,7 @@ trait ElementApi {
                             elems, elem
                         ),
                         Err(e) => match e {
-                            IonError::DecodingError { description: _ } => (),
+                            IonError::DecodingError { .. } => (),
                             other => {
                                 panic!("Got an error we did not expect for duplicates: {:?}", other)
                             }